@@ -0,0 +1,91 @@
+//! Compares the Robin Hood layout of [`HashMap`], which stores each entry inline alongside its
+//! hash, with the SwissTable-style layout of [`SwissMap`], which scans a separate array of tags.
+//!
+//! Run with `cargo bench --bench hash_layout`.
+#![feature(test)]
+
+extern crate test;
+
+use standard_lib::collections::hash::{HashMap, SwissMap};
+use test::{Bencher, black_box};
+
+const LEN: usize = 10_000;
+
+/// A value large enough that touching it during a probe has a noticeable cost.
+type Payload = [u64; 8];
+
+/// Returns `len` distinct pseudo-random keys, generated with xorshift so that every run is the
+/// same.
+fn keys(len: usize, seed: u64) -> impl Iterator<Item = u64> {
+    // This deliberately duplicates XorShift::next_u64 from the crate's util module, which is
+    // private to the crate and only compiled for its tests.
+    let mut state = seed;
+    // Keep the keys distinct by mixing the index into the low bits.
+    (0..len as u64).map(move |index| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state << 16) | (index & 0xFFFF)
+    })
+}
+
+macro_rules! layout_benches {
+    ($module:ident, $map:ident) => {
+        mod $module {
+            use super::*;
+
+            fn filled() -> $map<u64, Payload> {
+                let mut map = $map::new();
+                for key in keys(LEN, 1) {
+                    map.insert(key, [key; 8]);
+                }
+                map
+            }
+
+            #[bench]
+            fn insert(b: &mut Bencher) {
+                b.iter(|| black_box(filled()));
+            }
+
+            #[bench]
+            fn get_hit(b: &mut Bencher) {
+                let map = filled();
+                b.iter(|| {
+                    for key in keys(LEN, 1) {
+                        black_box(map.get(&key));
+                    }
+                });
+            }
+
+            #[bench]
+            fn get_miss(b: &mut Bencher) {
+                let map = filled();
+                b.iter(|| {
+                    for key in keys(LEN, 2) {
+                        black_box(map.get(&key));
+                    }
+                });
+            }
+
+            #[bench]
+            fn remove_insert(b: &mut Bencher) {
+                let mut map = filled();
+                b.iter(|| {
+                    for key in keys(LEN / 2, 1) {
+                        let value = map.remove(&key);
+                        map.insert(key, black_box(value).unwrap_or_default());
+                    }
+                });
+            }
+
+            #[bench]
+            fn iter(b: &mut Bencher) {
+                let map = filled();
+                b.iter(|| black_box(map.values().map(|value| value[0]).fold(0, u64::wrapping_add)));
+            }
+        }
+    };
+}
+
+layout_benches!(robin_hood, HashMap);
+layout_benches!(swiss, SwissMap);
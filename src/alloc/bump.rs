@@ -0,0 +1,232 @@
+use std::alloc::{AllocError as StdAllocError, Allocator, Global, Layout};
+use std::cell::Cell;
+use std::fmt::{self, Debug, Formatter};
+use std::ptr::{self, NonNull};
+
+use crate::util::error::{AllocError, CapacityOverflow, TryReserveError};
+
+/// The alignment of the chunk of memory managed by a [`Bump`]. Allocations with a larger alignment
+/// are still supported, at the cost of some padding.
+const CHUNK_ALIGN: usize = 16;
+
+/// A bump (or arena) allocator, which hands out memory from a single, fixed-size chunk by moving an
+/// offset forwards.
+///
+/// Allocating is just an alignment adjustment, a bounds check and an addition. Deallocating is a
+/// no-op, unless the memory being deallocated is the most recent allocation, in which case the
+/// offset is moved back so that the memory can be reused. Everything else is released at once, when
+/// the Bump is [`reset`](Bump::reset) or dropped. This suits groups of allocations that are all
+/// discarded together, such as those made while handling a single request.
+///
+/// [`Allocator`] is implemented for `&Bump`, so that several collections can share one Bump, while
+/// the borrow checker ensures that it outlives all of them.
+///
+/// # Time Complexity
+/// For this analysis of time complexity, variables are defined as follows:
+/// - `n`: The size of the allocation in question.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `allocate` | `O(1)` |
+/// | `deallocate` | `O(1)` |
+/// | `grow`, `shrink` | `O(1)`*, `O(n)` |
+/// | `reset` | `O(1)` |
+///
+/// \* If the memory being resized is the most recent allocation, it is resized in place.
+///
+/// # Examples
+/// ```
+/// #![feature(allocator_api)]
+/// # use standard_lib::alloc::Bump;
+/// # use standard_lib::collections::contiguous::Vector;
+/// let bump = Bump::with_cap(1024);
+///
+/// let mut vec = Vector::new_in(&bump);
+/// vec.extend(0_u32..10);
+/// assert_eq!(vec.iter().sum::<u32>(), 45);
+/// assert!(bump.used() >= 10 * size_of::<u32>());
+/// ```
+pub struct Bump {
+    ptr: NonNull<u8>,
+    cap: usize,
+    offset: Cell<usize>,
+}
+
+impl Bump {
+    /// Creates a new Bump, which can allocate up to `cap` bytes, less any padding required for
+    /// alignment.
+    ///
+    /// # Panics
+    /// Panics if `cap` exceeds [`isize::MAX`].
+    pub fn with_cap(cap: usize) -> Bump {
+        Bump::try_with_cap(cap).unwrap_or_else(|error| error.handle())
+    }
+
+    /// Creates a new Bump, which can allocate up to `cap` bytes, returning an error rather than
+    /// panicking or aborting if the chunk can't be allocated.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if `cap` exceeds [`isize::MAX`] or if the global allocator
+    /// fails to provide the memory.
+    pub fn try_with_cap(cap: usize) -> Result<Bump, TryReserveError> {
+        let layout = Layout::from_size_align(cap, CHUNK_ALIGN).or(Err(CapacityOverflow))?;
+        let ptr = Global.allocate(layout).or(Err(AllocError { layout }))?;
+
+        Ok(Bump {
+            ptr: ptr.cast(),
+            cap,
+            offset: Cell::new(0),
+        })
+    }
+
+    /// Returns the total number of bytes that the Bump can allocate.
+    pub const fn cap(&self) -> usize {
+        self.cap
+    }
+
+    /// Returns the number of bytes that have been used, including padding and memory which has been
+    /// deallocated but not yet reclaimed.
+    pub const fn used(&self) -> usize {
+        self.offset.get()
+    }
+
+    /// Returns the number of bytes that remain available, before accounting for alignment.
+    pub const fn remaining(&self) -> usize {
+        self.cap - self.used()
+    }
+
+    /// Reclaims all memory allocated by the Bump, so that it can be reused. This takes a mutable
+    /// reference, so the borrow checker ensures that no collections are still using the memory.
+    pub const fn reset(&mut self) {
+        *self.offset.get_mut() = 0;
+    }
+
+    /// Returns the offset of `ptr` from the start of the Bump's chunk.
+    fn offset_of(&self, ptr: NonNull<u8>) -> usize {
+        ptr.addr().get() - self.ptr.addr().get()
+    }
+
+    /// Returns true if the `size` bytes at `ptr` were the most recent allocation, meaning that they
+    /// can be resized or reclaimed by moving the offset.
+    fn is_last(&self, ptr: NonNull<u8>, size: usize) -> bool {
+        self.offset_of(ptr) + size == self.offset.get()
+    }
+
+    /// Moves the offset to reserve `layout` at the next suitably aligned position, returning the
+    /// offset of the reserved memory, or None if there isn't enough room.
+    fn bump(&self, layout: Layout) -> Option<usize> {
+        let base = self.ptr.addr().get();
+        let start = (base + self.offset.get()).checked_next_multiple_of(layout.align())? - base;
+        let end = start.checked_add(layout.size()).filter(|end| *end <= self.cap)?;
+
+        self.offset.set(end);
+        Some(start)
+    }
+
+    /// Allocates `new_layout` and copies the first `size` bytes from `ptr` into it, for when
+    /// memory can't be resized in place.
+    ///
+    /// # Safety
+    /// `ptr` must be valid for reads of `size` bytes, which must not exceed `new_layout.size()`.
+    unsafe fn relocate(
+        &self,
+        ptr: NonNull<u8>,
+        size: usize,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, StdAllocError> {
+        let start = self.bump(new_layout).ok_or(StdAllocError)?;
+
+        // SAFETY: bump ensures that start + new_layout.size() <= cap, so the new pointer is within
+        // the chunk. The new memory was just reserved, so it can't overlap with ptr, and the caller
+        // guarantees that both are valid for size bytes.
+        unsafe {
+            let new_ptr = self.ptr.add(start);
+            ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), size);
+            Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size()))
+        }
+    }
+}
+
+// SAFETY: Memory handed out by a Bump remains valid until the Bump is reset or dropped, which both
+// require that no references to it (and therefore no allocations) remain. Memory is never handed
+// out twice, except after being deallocated.
+unsafe impl Allocator for &Bump {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, StdAllocError> {
+        let start = self.bump(layout).ok_or(StdAllocError)?;
+
+        // SAFETY: bump ensures that start + layout.size() <= cap, so the resulting pointer is
+        // within (or one past the end of) the chunk.
+        let ptr = unsafe { self.ptr.add(start) };
+        Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        // Only the most recent allocation can be reclaimed, everything else waits for a reset.
+        if self.is_last(ptr, layout.size()) {
+            self.offset.set(self.offset_of(ptr));
+        }
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, StdAllocError> {
+        let start = self.offset_of(ptr);
+
+        if self.is_last(ptr, old_layout.size())
+            && ptr.addr().get().is_multiple_of(new_layout.align())
+            && let Some(end) = start.checked_add(new_layout.size())
+            && end <= self.cap
+        {
+            self.offset.set(end);
+            return Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()));
+        }
+
+        // SAFETY: The caller guarantees that ptr is valid for old_layout, which is no larger than
+        // new_layout.
+        unsafe { self.relocate(ptr, old_layout.size(), new_layout) }
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, StdAllocError> {
+        if ptr.addr().get().is_multiple_of(new_layout.align()) {
+            if self.is_last(ptr, old_layout.size()) {
+                self.offset.set(self.offset_of(ptr) + new_layout.size());
+            }
+            return Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()));
+        }
+
+        // SAFETY: The caller guarantees that ptr is valid for old_layout, which is no smaller than
+        // new_layout.
+        unsafe { self.relocate(ptr, new_layout.size(), new_layout) }
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        // SAFETY: The layout was already validated when the chunk was allocated.
+        let layout = unsafe { Layout::from_size_align_unchecked(self.cap, CHUNK_ALIGN) };
+
+        // SAFETY: ptr was allocated by Global with the same layout.
+        unsafe { Global.deallocate(self.ptr, layout) }
+    }
+}
+
+// SAFETY: A Bump uniquely owns its chunk of memory, so it can be moved between threads. It isn't
+// Sync, because the offset is updated through a shared reference.
+unsafe impl Send for Bump {}
+
+impl Debug for Bump {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Bump")
+            .field("cap", &self.cap)
+            .field("used", &self.used())
+            .finish()
+    }
+}
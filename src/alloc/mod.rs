@@ -0,0 +1,22 @@
+//! Memory allocators, for use with the allocator parameter of collections such as
+//! [`Array`](crate::collections::contiguous::Array),
+//! [`Vector`](crate::collections::contiguous::Vector) and
+//! [`HashMap`](crate::collections::hash::HashMap).
+//!
+//! # Purpose
+//! The [`Global`](std::alloc::Global) allocator is a good general-purpose default, but it knows
+//! nothing about how long allocations will live for. When many allocations are made and then all
+//! discarded together, an allocator that takes advantage of that pattern can skip most of the
+//! bookkeeping.
+//!
+//! # Method
+//! Allocators here implement the unstable [`Allocator`](std::alloc::Allocator) trait, so they can
+//! be used with any collection that accepts one. They currently obtain their own memory from the
+//! global allocator, so they don't (yet) remove this crate's dependence on it.
+#![cfg(feature = "alloc")]
+#![warn(missing_docs)]
+
+mod bump;
+mod tests;
+
+pub use bump::*;
@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+use std::alloc::{Allocator, Layout};
+use std::ptr;
+
+use super::*;
+
+#[test]
+fn test_bump_allocate() {
+    let bump = Bump::with_cap(64);
+    let alloc = &bump;
+
+    let byte = alloc.allocate(Layout::new::<u8>()).expect("Bump should have room for one byte.");
+    let word = alloc.allocate(Layout::new::<u64>()).expect("Bump should have room for a u64.");
+    assert!(
+        word.cast::<u8>().addr().get().is_multiple_of(align_of::<u64>()),
+        "Allocations should be aligned."
+    );
+    assert!(
+        word.cast::<u8>() > byte.cast::<u8>(),
+        "Allocations should be made in order."
+    );
+    assert_eq!(bump.used(), 16, "Padding should be counted as used.");
+
+    assert!(alloc.allocate(Layout::new::<[u8; 48]>()).is_ok());
+    assert_eq!(bump.remaining(), 0);
+    assert!(
+        alloc.allocate(Layout::new::<u8>()).is_err(),
+        "Allocation should fail once the Bump is full."
+    );
+
+    let empty = Bump::with_cap(0);
+    assert!((&empty).allocate(Layout::new::<u8>()).is_err());
+    assert!((&empty).allocate(Layout::new::<()>()).is_ok(), "Zero-sized allocations should fit.");
+}
+
+#[test]
+fn test_bump_reuse() {
+    let mut bump = Bump::with_cap(64);
+    let alloc = &bump;
+
+    let first = alloc.allocate(Layout::new::<u32>()).expect("Bump has room.").cast::<u8>();
+    let last = alloc.allocate(Layout::new::<u32>()).expect("Bump has room.").cast::<u8>();
+
+    // SAFETY: Both pointers were allocated by this Bump with the provided layouts.
+    unsafe {
+        alloc.deallocate(first, Layout::new::<u32>());
+        assert_eq!(bump.used(), 8, "Only the most recent allocation should be reclaimed.");
+
+        let grown = alloc.grow(last, Layout::new::<u32>(), Layout::new::<[u32; 4]>())
+            .expect("Bump has room.");
+        assert_eq!(grown.cast(), last, "The most recent allocation should grow in place.");
+        assert_eq!(bump.used(), 20);
+
+        let moved = alloc.grow(first, Layout::new::<u32>(), Layout::new::<[u32; 2]>())
+            .expect("Bump has room.");
+        assert_ne!(moved.cast(), first, "Other allocations should be moved to grow.");
+
+        let shrunk = alloc.shrink(moved.cast(), Layout::new::<[u32; 2]>(), Layout::new::<u32>())
+            .expect("Shrinking should never fail.");
+        assert_eq!(shrunk.cast(), moved.cast::<u8>());
+        assert_eq!(bump.used(), 24, "Shrinking the most recent allocation should reclaim memory.");
+    }
+
+    bump.reset();
+    assert_eq!(bump.used(), 0);
+    assert_eq!(bump.remaining(), 64);
+}
+
+#[test]
+#[cfg(feature = "contiguous")]
+fn test_bump_vector() {
+    use crate::collections::contiguous::{TryReserveError, Vector};
+    use crate::util::alloc::CountedDrop;
+
+    let bump = Bump::with_cap(1024);
+    let counter = CountedDrop::new(0);
+
+    let mut vec = Vector::new_in(&bump);
+    for i in 0..100_u32 {
+        vec.push(i);
+    }
+    assert_eq!(vec.iter().sum::<u32>(), (0..100).sum());
+    assert!(
+        bump.used() <= 128 * size_of::<u32>(),
+        "Growing the most recent allocation shouldn't leave gaps."
+    );
+
+    assert!(
+        matches!(vec.try_reserve(1024), Err(TryReserveError::AllocError(_))),
+        "Exceeding the Bump's capacity should be reported as an allocator failure."
+    );
+    assert_eq!(vec.len(), 100, "A failed reserve should leave the Vector unchanged.");
+
+    let mut other = Vector::with_cap_in(4, &bump);
+    other.extend([counter.clone(), counter.clone()]);
+    let cloned = other.clone();
+    assert!(ptr::eq(*cloned.allocator(), &bump), "Clones should share a Bump.");
+    drop((other, cloned));
+    assert_eq!(counter.take(), 4, "Values in a Bump should still be dropped.");
+}
+
+#[test]
+#[cfg(feature = "contiguous")]
+fn test_bump_vector_try_push() {
+    use crate::collections::contiguous::{TryReserveError, Vector};
+    use crate::util::alloc::CountedDrop;
+
+    let bump = Bump::with_cap(2 * size_of::<CountedDrop>());
+    let counter = CountedDrop::new(0);
+
+    let mut vec = Vector::with_cap_in(2, &bump);
+    vec.extend([counter.clone(), counter.clone()]);
+
+    let error = vec.try_push(counter.clone()).expect_err("The Bump should be out of memory.");
+    assert!(
+        matches!(error.error, TryReserveError::AllocError(_)),
+        "Exceeding the Bump's capacity should be reported as an allocator failure."
+    );
+    let error = vec.try_insert(0, error.into_inner()).expect_err("The Bump is still full.");
+    assert_eq!(*counter.borrow(), 0, "A failed push or insert should return the value.");
+    assert_eq!(vec.len(), 2, "A failed push or insert should leave the Vector unchanged.");
+
+    drop((vec, error));
+    assert_eq!(counter.take(), 3);
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn test_bump_hash_map() {
+    use std::hash::RandomState;
+
+    use crate::collections::hash::HashMap;
+
+    let bump = Bump::with_cap(1 << 16);
+    let mut map: HashMap<usize, usize, RandomState, _> = HashMap::new_in(&bump);
+
+    for i in 0..200 {
+        map.insert(i, i * 2);
+    }
+    for i in (0..200).step_by(2) {
+        map.remove(&i);
+    }
+
+    assert_eq!(map.len(), 100);
+    assert!((0..200).all(|i| map.get(&i) == (i % 2 == 1).then_some(&(i * 2))));
+    assert_eq!(map.drain().count(), 100);
+    assert!(map.is_empty());
+    assert!(bump.used() > 0);
+}
+
+#[test]
+#[cfg(feature = "hash")]
+fn test_bump_swiss_map() {
+    use std::hash::RandomState;
+
+    use crate::collections::hash::SwissMap;
+
+    let bump = Bump::with_cap(1 << 16);
+    let mut map: SwissMap<usize, usize, RandomState, _> = SwissMap::with_cap_in(200, &bump);
+    let used = bump.used();
+    assert!(used > 0);
+
+    for i in 0..200 {
+        map.insert(i, i * 2);
+    }
+    map.retain(|i, _| i % 2 == 1);
+
+    assert_eq!(map.len(), 100);
+    assert!((0..200).all(|i| map.get(&i) == (i % 2 == 1).then_some(&(i * 2))));
+    assert_eq!(bump.used(), used, "Reserved capacity should avoid further allocations.");
+    assert_eq!(map.drain().count(), 100);
+    assert!(map.is_empty());
+}
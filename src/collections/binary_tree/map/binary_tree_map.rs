@@ -1,14 +1,48 @@
 use std::borrow::Borrow;
 use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::Index;
 
-use super::{Branch, IntoKeys, IntoValues, Iter, Keys, Values};
+use super::{Branch, IntoKeys, IntoValues, Iter, Keys, Values, ValuesMut};
+use crate::collections::contiguous::Vector;
+use crate::util::error::NoValueForKey;
+use crate::util::result::ResultExtension;
 
+/// A map of keys to values which keeps its entries ordered with the help of the [`Ord`] trait.
+///
+/// Entries are stored in a self-balancing binary search tree, specifically an AVL tree: after each
+/// insertion or removal, rotations are used to ensure that the heights of the two subtrees of any
+/// node differ by at most one. This bounds the height of the tree to roughly `1.44 * log2(n)`, so
+/// no sequence of operations can degrade the tree into a list.
+///
+/// Iteration always produces entries in ascending order of their keys.
+///
+/// It is a logic error for keys in a BinaryTreeMap to be manipulated in a way that changes their
+/// order. Because of this, BinaryTreeMap's API prevents mutable access to its keys.
+///
+/// # Time Complexity
+/// For this analysis of time complexity, variables are defined as follows:
+/// - `n`: The number of entries in the BinaryTreeMap.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `len` | `O(1)` |
+/// | `insert` | `O(log n)` |
+/// | `remove` | `O(log n)` |
+/// | `get`, `get_mut` | `O(log n)` |
+/// | `contains` | `O(log n)` |
+/// | `first`, `last` | `O(log n)` |
+/// | `take_first`, `take_last` | `O(log n)` |
+/// | `iter`*, `values_mut`* | `O(n)` |
+/// | `into_iter`* | `O(n log n)` |
+///
+/// \* When exhausted. Borrowed iterators also allocate space for `O(log n)` node references.
 pub struct BinaryTreeMap<K: Ord, V> {
     pub(crate) root: Branch<K, V>,
     pub(crate) len: usize,
 }
 
 impl<K: Ord, V> BinaryTreeMap<K, V> {
+    /// Creates a new, empty BinaryTreeMap. No memory is allocated until an entry is inserted.
     pub const fn new() -> BinaryTreeMap<K, V> {
         BinaryTreeMap {
             root: Branch(None),
@@ -16,19 +50,33 @@ impl<K: Ord, V> BinaryTreeMap<K, V> {
         }
     }
 
+    /// Returns the length of the BinaryTreeMap (the number of entries it contains).
     pub const fn len(&self) -> usize {
         self.len
     }
 
+    /// Returns true if the BinaryTreeMap contains no entries.
     pub const fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
+    /// Returns the height of the BinaryTreeMap's underlying tree, which is 0 when empty and at most
+    /// `1.44 * log2(len + 2)`.
+    pub const fn height(&self) -> usize {
+        self.root.height()
+    }
+
+    /// Inserts the provided key-value pair, rebalancing the tree if required. If the key was
+    /// already present, its value is replaced and the old value is returned.
     pub fn insert(&mut self, key: K, value: V) -> Option<V> {
-        self.len += 1;
-        self.root.insert(key, value)
+        let result = self.root.insert(key, value);
+        if result.is_none() {
+            self.len += 1;
+        }
+        result
     }
 
+    /// Removes the entry for the provided key, returning it if it exists.
     pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
     where
         K: Borrow<Q>,
@@ -41,6 +89,7 @@ impl<K: Ord, V> BinaryTreeMap<K, V> {
         result
     }
 
+    /// Removes the entry for the provided key, returning its value if it exists.
     pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
     where
         K: Borrow<Q>,
@@ -49,6 +98,7 @@ impl<K: Ord, V> BinaryTreeMap<K, V> {
         self.remove_entry(key).map(|(_, v)| v)
     }
 
+    /// Returns references to the key-value pair for the provided key, if it exists.
     pub fn get_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
     where
         K: Borrow<Q>,
@@ -57,6 +107,7 @@ impl<K: Ord, V> BinaryTreeMap<K, V> {
         self.root.get_entry(key)
     }
 
+    /// Returns a reference to the value associated with the provided key, if it exists.
     pub fn get<Q>(&self, key: &Q) -> Option<&V>
     where
         K: Borrow<Q>,
@@ -65,6 +116,7 @@ impl<K: Ord, V> BinaryTreeMap<K, V> {
         self.get_entry(key).map(|(_, v)| v)
     }
 
+    /// Returns a mutable reference to the value associated with the provided key, if it exists.
     pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
     where
         K: Borrow<Q>,
@@ -73,7 +125,8 @@ impl<K: Ord, V> BinaryTreeMap<K, V> {
         self.root.get_mut(key)
     }
 
-    pub fn contains<Q>(&mut self, key: &Q) -> bool
+    /// Returns true if the BinaryTreeMap contains the provided key.
+    pub fn contains<Q>(&self, key: &Q) -> bool
     where
         K: Borrow<Q>,
         Q: Ord + ?Sized,
@@ -81,61 +134,92 @@ impl<K: Ord, V> BinaryTreeMap<K, V> {
         self.root.contains(key)
     }
 
+    /// Returns references to the entry with the smallest key, if the BinaryTreeMap isn't empty.
     pub fn first_entry(&self) -> Option<(&K, &V)> {
         self.root.first_entry()
     }
 
+    /// Returns a reference to the value with the smallest key, if the BinaryTreeMap isn't empty.
     pub fn first(&self) -> Option<&V> {
         self.first_entry().map(|(_, v)| v)
     }
 
+    /// Removes and returns the entry with the smallest key, if the BinaryTreeMap isn't empty.
     pub fn take_first_entry(&mut self) -> Option<(K, V)> {
-        self.root.take_first_entry()
+        let result = self.root.take_first_entry();
+        if result.is_some() {
+            self.len -= 1;
+        }
+        result
     }
 
+    /// Removes the entry with the smallest key and returns its value, if the BinaryTreeMap isn't
+    /// empty.
     pub fn take_first(&mut self) -> Option<V> {
         self.take_first_entry().map(|(_, v)| v)
     }
 
+    /// Returns references to the entry with the largest key, if the BinaryTreeMap isn't empty.
     pub fn last_entry(&self) -> Option<(&K, &V)> {
         self.root.last_entry()
     }
 
+    /// Returns a reference to the value with the largest key, if the BinaryTreeMap isn't empty.
     pub fn last(&self) -> Option<&V> {
         self.last_entry().map(|(_, v)| v)
     }
 
+    /// Removes and returns the entry with the largest key, if the BinaryTreeMap isn't empty.
     pub fn take_last_entry(&mut self) -> Option<(K, V)> {
-        self.root.take_last_entry()
+        let result = self.root.take_last_entry();
+        if result.is_some() {
+            self.len -= 1;
+        }
+        result
     }
 
+    /// Removes the entry with the largest key and returns its value, if the BinaryTreeMap isn't
+    /// empty.
     pub fn take_last(&mut self) -> Option<V> {
         self.take_last_entry().map(|(_, v)| v)
     }
 
+    /// Returns an iterator over all key-value pairs in the BinaryTreeMap, as references, in order
+    /// of the keys.
     pub fn iter(&self) -> Iter<'_, K, V> {
         self.into_iter()
     }
 
+    /// Returns an owned iterator over all keys in the BinaryTreeMap, in order.
     pub fn into_keys(self) -> IntoKeys<K, V> {
         IntoKeys(self.into_iter())
     }
 
+    /// Returns an iterator over all keys in the BinaryTreeMap, as references, in order.
     pub fn keys<'a>(&'a self) -> Keys<'a, K, V> {
         Keys(self.iter())
     }
 
+    /// Returns an owned iterator over all values in the BinaryTreeMap, in order of the keys.
     pub fn into_values(self) -> IntoValues<K, V> {
         IntoValues(self.into_iter())
     }
 
-    // pub fn values_mut<'a>(&'a mut self) -> ValuesMut<'a, K, V> {
-    //     ValuesMut {
-    //         len: self.len(),
-    //         inner: self.arr.iter_mut(),
-    //     }
-    // }
+    /// Returns an iterator over all values in the BinaryTreeMap, as mutable references, in order
+    /// of the keys.
+    pub fn values_mut<'a>(&'a mut self) -> ValuesMut<'a, K, V> {
+        let mut stack = Vector::new();
+        let len = self.len();
+        self.root.push_left_spine_mut(&mut stack);
+
+        ValuesMut {
+            stack,
+            len,
+        }
+    }
 
+    /// Returns an iterator over all values in the BinaryTreeMap, as references, in order of the
+    /// keys.
     pub fn values<'a>(&'a self) -> Values<'a, K, V> {
         Values(self.iter())
     }
@@ -147,6 +231,38 @@ impl<K: Ord, V> Default for BinaryTreeMap<K, V> {
     }
 }
 
+impl<Q, K, V> Index<&Q> for BinaryTreeMap<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).ok_or(NoValueForKey).throw()
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for BinaryTreeMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+
+    fn extend_one(&mut self, (key, value): (K, V)) {
+        self.insert(key, value);
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for BinaryTreeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = BinaryTreeMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
 impl<K: Ord + Debug, V: Debug> Debug for BinaryTreeMap<K, V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("BinaryTreeMap")
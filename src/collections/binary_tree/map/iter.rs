@@ -1,6 +1,7 @@
 use std::iter::FusedIterator;
 
-use super::BinaryTreeMap;
+use super::{BinaryTreeMap, Branch, Node};
+use crate::collections::contiguous::Vector;
 
 impl<K: Ord, V> IntoIterator for BinaryTreeMap<K, V> {
     type Item = (K, V);
@@ -12,15 +13,23 @@ impl<K: Ord, V> IntoIterator for BinaryTreeMap<K, V> {
     }
 }
 
+/// A type for owned iteration over a [`BinaryTreeMap`], in order of the keys. Produces values of
+/// type `(K, V)`.
+///
+/// See [`BinaryTreeMap::into_iter`].
 pub struct IntoIter<K: Ord, V>(BinaryTreeMap<K, V>);
 
 impl<K: Ord, V> Iterator for IntoIter<K, V> {
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        // This isn't good, it takes log2 n every time, but the alternative is a doubly linked one.
+        // This takes O(log n) every time, but avoids the need for parent pointers.
         self.0.take_first_entry()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
 }
 
 impl<K: Ord, V> DoubleEndedIterator for IntoIter<K, V> {
@@ -29,9 +38,9 @@ impl<K: Ord, V> DoubleEndedIterator for IntoIter<K, V> {
     }
 }
 
-impl<K: Ord, V> FusedIterator for IntoIter<K, V> {}
+impl<K: Ord, V> ExactSizeIterator for IntoIter<K, V> {}
 
-// TODO: write Iter for BinaryTreeMap
+impl<K: Ord, V> FusedIterator for IntoIter<K, V> {}
 
 impl<'a, K: Ord, V> IntoIterator for &'a BinaryTreeMap<K, V> {
     type Item = (&'a K, &'a V);
@@ -39,23 +48,73 @@ impl<'a, K: Ord, V> IntoIterator for &'a BinaryTreeMap<K, V> {
     type IntoIter = Iter<'a, K, V>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Iter(self)
+        let mut front = Vector::new();
+        let mut back = Vector::new();
+        self.root.push_left_spine(&mut front);
+        self.root.push_right_spine(&mut back);
+
+        Iter {
+            front,
+            back,
+            len: self.len(),
+        }
     }
 }
 
-pub struct Iter<'a, K: Ord, V>(&'a BinaryTreeMap<K, V>);
+/// A type for borrowed iteration over a [`BinaryTreeMap`], in order of the keys. Produces values of
+/// type `(&K, &V)`.
+///
+/// See [`BinaryTreeMap::iter`].
+pub struct Iter<'a, K: Ord, V> {
+    // Each stack holds the path of nodes that are yet to be produced from its end, so it never
+    // grows beyond the height of the tree.
+    pub(crate) front: Vector<&'a Node<K, V>>,
+    pub(crate) back: Vector<&'a Node<K, V>>,
+    // The two stacks traverse the whole tree independently, so we use the number of remaining
+    // entries to stop them from crossing.
+    pub(crate) len: usize,
+}
 
 impl<'a, K: Ord, V> Iterator for Iter<'a, K, V> {
     type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        // FIXME: Can't even use the same thing rip
-        todo!()
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = self.front.pop()?;
+        node.right.push_left_spine(&mut self.front);
+        self.len -= 1;
+        Some(node.tuple())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
 }
 
+impl<'a, K: Ord, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = self.back.pop()?;
+        node.left.push_right_spine(&mut self.back);
+        self.len -= 1;
+        Some(node.tuple())
+    }
+}
+
+impl<'a, K: Ord, V> ExactSizeIterator for Iter<'a, K, V> {}
+
 impl<'a, K: Ord, V> FusedIterator for Iter<'a, K, V> {}
 
+/// A type for owned iteration over a [`BinaryTreeMap`]'s keys, in order. Produces values of type
+/// `K`.
+///
+/// See [`BinaryTreeMap::into_keys`].
 pub struct IntoKeys<K: Ord, V>(pub(crate) IntoIter<K, V>);
 
 impl<K: Ord, V> Iterator for IntoKeys<K, V> {
@@ -70,8 +129,20 @@ impl<K: Ord, V> Iterator for IntoKeys<K, V> {
     }
 }
 
+impl<K: Ord, V> DoubleEndedIterator for IntoKeys<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(k, _)| k)
+    }
+}
+
+impl<K: Ord, V> ExactSizeIterator for IntoKeys<K, V> {}
+
 impl<K: Ord, V> FusedIterator for IntoKeys<K, V> {}
 
+/// A type for borrowed iteration over a [`BinaryTreeMap`]'s keys, in order. Produces values of type
+/// `&K`.
+///
+/// See [`BinaryTreeMap::keys`].
 pub struct Keys<'a, K: Ord, V>(pub(crate) Iter<'a, K, V>);
 
 impl<'a, K: Ord, V> Iterator for Keys<'a, K, V> {
@@ -86,8 +157,20 @@ impl<'a, K: Ord, V> Iterator for Keys<'a, K, V> {
     }
 }
 
+impl<'a, K: Ord, V> DoubleEndedIterator for Keys<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(k, _)| k)
+    }
+}
+
+impl<'a, K: Ord, V> ExactSizeIterator for Keys<'a, K, V> {}
+
 impl<'a, K: Ord, V> FusedIterator for Keys<'a, K, V> {}
 
+/// A type for owned iteration over a [`BinaryTreeMap`]'s values, in order of the keys. Produces
+/// values of type `V`.
+///
+/// See [`BinaryTreeMap::into_values`].
 pub struct IntoValues<K: Ord, V>(pub(crate) IntoIter<K, V>);
 
 impl<K: Ord, V> Iterator for IntoValues<K, V> {
@@ -102,10 +185,50 @@ impl<K: Ord, V> Iterator for IntoValues<K, V> {
     }
 }
 
+impl<K: Ord, V> DoubleEndedIterator for IntoValues<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, v)| v)
+    }
+}
+
+impl<K: Ord, V> ExactSizeIterator for IntoValues<K, V> {}
+
 impl<K: Ord, V> FusedIterator for IntoValues<K, V> {}
 
-// TODO: write ValuesMut for BinaryTreeMap
+/// A type for mutable iteration over a [`BinaryTreeMap`]'s values, in order of the keys. Produces
+/// values of type `&mut V`.
+///
+/// See [`BinaryTreeMap::values_mut`].
+pub struct ValuesMut<'a, K: Ord, V> {
+    // Each node on the path is split into its value and right subtree, so that no two mutable
+    // references overlap.
+    pub(crate) stack: Vector<(&'a mut V, &'a mut Branch<K, V>)>,
+    pub(crate) len: usize,
+}
+
+impl<'a, K: Ord, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (value, right) = self.stack.pop()?;
+        right.push_left_spine_mut(&mut self.stack);
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K: Ord, V> ExactSizeIterator for ValuesMut<'a, K, V> {}
+
+impl<'a, K: Ord, V> FusedIterator for ValuesMut<'a, K, V> {}
 
+/// A type for borrowed iteration over a [`BinaryTreeMap`]'s values, in order of the keys. Produces
+/// values of type `&V`.
+///
+/// See [`BinaryTreeMap::values`].
 pub struct Values<'a, K: Ord, V>(pub(crate) Iter<'a, K, V>);
 
 impl<'a, K: Ord, V> Iterator for Values<'a, K, V> {
@@ -120,4 +243,12 @@ impl<'a, K: Ord, V> Iterator for Values<'a, K, V> {
     }
 }
 
+impl<'a, K: Ord, V> DoubleEndedIterator for Values<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, v)| v)
+    }
+}
+
+impl<'a, K: Ord, V> ExactSizeIterator for Values<'a, K, V> {}
+
 impl<'a, K: Ord, V> FusedIterator for Values<'a, K, V> {}
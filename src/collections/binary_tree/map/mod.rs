@@ -1,6 +1,12 @@
+//! A module containing [`BinaryTreeMap`] and associated types.
+//!
+//! The included iterator types provide owned, borrowed and mutable iteration over entries, keys
+//! or values in a map, always in order of the keys.
+
 mod binary_tree_map;
 mod iter;
 mod node;
+mod tests;
 
 pub use binary_tree_map::*;
 pub use iter::*;
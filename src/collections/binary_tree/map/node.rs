@@ -13,6 +13,8 @@ pub(crate) struct Node<K: Ord, V> {
     pub right: Branch<K, V>,
     pub key: K,
     pub value: V,
+    /// The height of the subtree rooted at this node, where a leaf has a height of 1.
+    pub height: usize,
 }
 
 impl<K: Ord, V> Node<K, V> {
@@ -23,15 +25,105 @@ impl<K: Ord, V> Node<K, V> {
     pub const fn tuple(&self) -> (&K, &V) {
         (&self.key, &self.value)
     }
+
+    fn update_height(&mut self) {
+        self.height = 1 + self.left.height().max(self.right.height());
+    }
 }
 
 impl<K: Ord, V> Branch<K, V> {
+    pub const fn height(&self) -> usize {
+        match &self.0 {
+            Some(node) => node.height,
+            None => 0,
+        }
+    }
+
+    /// Returns the difference in height between the right and left subtrees of this branch's node.
+    /// For a balanced AVL tree, this is always in the range `-1..=1`.
+    fn balance(&self) -> isize {
+        match &self.0 {
+            Some(node) => node.right.height() as isize - node.left.height() as isize,
+            None => 0,
+        }
+    }
+
+    /// Rotates this branch to the left, so that the right child takes the place of the current
+    /// node, which becomes its left child. Does nothing if there is no right child.
+    fn rotate_left(&mut self) {
+        let Some(mut node) = self.0.take() else {
+            return;
+        };
+        let Some(mut right) = node.right.0.take() else {
+            self.0 = Some(node);
+            return;
+        };
+
+        node.right = mem::take(&mut right.left);
+        node.update_height();
+        right.left = Branch(Some(node));
+        right.update_height();
+        self.0 = Some(right);
+    }
+
+    /// Rotates this branch to the right, so that the left child takes the place of the current
+    /// node, which becomes its right child. Does nothing if there is no left child.
+    fn rotate_right(&mut self) {
+        let Some(mut node) = self.0.take() else {
+            return;
+        };
+        let Some(mut left) = node.left.0.take() else {
+            self.0 = Some(node);
+            return;
+        };
+
+        node.left = mem::take(&mut left.right);
+        node.update_height();
+        left.right = Branch(Some(node));
+        left.update_height();
+        self.0 = Some(left);
+    }
+
+    /// Updates the height of this branch's node and restores the AVL invariant, assuming that both
+    /// subtrees are already balanced and differ in height by at most 2.
+    fn rebalance(&mut self) {
+        let Some(node) = &mut self.0 else {
+            return;
+        };
+        node.update_height();
+
+        match self.balance() {
+            2.. => {
+                if let Some(node) = &mut self.0
+                    && node.right.balance() < 0
+                {
+                    node.right.rotate_right();
+                }
+                self.rotate_left();
+            },
+            ..=-2 => {
+                if let Some(node) = &mut self.0
+                    && node.left.balance() > 0
+                {
+                    node.left.rotate_left();
+                }
+                self.rotate_right();
+            },
+            _ => {},
+        }
+    }
+
     pub fn insert(&mut self, key: K, value: V) -> Option<V> {
         match &mut self.0 {
-            Some(node) => match key.cmp(&node.key) {
-                Ordering::Less => node.left.insert(key, value),
-                Ordering::Greater => node.right.insert(key, value),
-                Ordering::Equal => Some(mem::replace(&mut node.value, value)),
+            Some(node) => {
+                let result = match key.cmp(&node.key) {
+                    Ordering::Less => node.left.insert(key, value),
+                    Ordering::Greater => node.right.insert(key, value),
+                    // Replacing a value doesn't change the shape of the tree.
+                    Ordering::Equal => return Some(mem::replace(&mut node.value, value)),
+                };
+                self.rebalance();
+                result
             },
             None => {
                 self.0 = Some(Box::new(Node {
@@ -39,6 +131,7 @@ impl<K: Ord, V> Branch<K, V> {
                     right: None.into(),
                     key,
                     value,
+                    height: 1,
                 }));
                 None
             },
@@ -50,18 +143,33 @@ impl<K: Ord, V> Branch<K, V> {
         K: Borrow<Q>,
         Q: Ord + ?Sized,
     {
-        match &mut self.0 {
-            Some(node) => match key.cmp(node.key.borrow()) {
-                Ordering::Less => node.left.remove_entry(key),
-                Ordering::Greater => node.right.remove_entry(key),
-                Ordering::Equal => Some(
-                    // SAFETY: We've already matched self.0 as a Some, but we need the mutable
-                    // reference here.
-                    unsafe { mem::take(&mut self.0).unwrap_unchecked().into_tuple() }
-                ),
+        let node = self.0.as_mut()?;
+        let result = match key.cmp(node.key.borrow()) {
+            Ordering::Less => node.left.remove_entry(key),
+            Ordering::Greater => node.right.remove_entry(key),
+            Ordering::Equal => return self.take_root(),
+        };
+        self.rebalance();
+        result
+    }
+
+    /// Removes the node at the root of this branch, replacing it with its in-order successor if it
+    /// has two children.
+    fn take_root(&mut self) -> Option<(K, V)> {
+        let mut node = self.0.take()?;
+
+        match node.right.take_first_node() {
+            Some(mut successor) => {
+                successor.left = mem::take(&mut node.left);
+                successor.right = mem::take(&mut node.right);
+                self.0 = Some(successor);
+                self.rebalance();
             },
-            None => None,
+            // Without a right subtree, the left subtree is at most a single (balanced) node.
+            None => *self = mem::take(&mut node.left),
         }
+
+        Some(node.into_tuple())
     }
 
     pub fn get_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
@@ -79,21 +187,6 @@ impl<K: Ord, V> Branch<K, V> {
         }
     }
 
-    // pub fn get<Q>(&self, key: &Q) -> Option<&V>
-    // where
-    //     K: Borrow<Q>,
-    //     Q: Ord + ?Sized
-    // {
-    //     match &self.0 {
-    //         Some(node) => match key.cmp(node.key.borrow()) {
-    //             Ordering::Less => node.left.get(key),
-    //             Ordering::Greater => node.right.get(key),
-    //             Ordering::Equal => Some(&node.value),
-    //         },
-    //         None => None,
-    //     }
-    // }
-
     pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
     where
         K: Borrow<Q>,
@@ -109,19 +202,12 @@ impl<K: Ord, V> Branch<K, V> {
         }
     }
 
-    pub fn contains<Q>(&mut self, key: &Q) -> bool
+    pub fn contains<Q>(&self, key: &Q) -> bool
     where
         K: Borrow<Q>,
         Q: Ord + ?Sized,
     {
-        match &mut self.0 {
-            Some(node) => match key.cmp(node.key.borrow()) {
-                Ordering::Less => node.left.contains(key),
-                Ordering::Greater => node.right.contains(key),
-                Ordering::Equal => true,
-            },
-            None => false,
-        }
+        self.get_entry(key).is_some()
     }
 
     pub fn first_entry(&self) -> Option<(&K, &V)> {
@@ -134,20 +220,25 @@ impl<K: Ord, V> Branch<K, V> {
         }
     }
 
-    pub fn take_first_entry(&mut self) -> Option<(K, V)> {
-        match &mut self.0 {
-            Some(node) => match node.left.take_first_entry() {
-                Some(e) => Some(e),
-                None => Some(
-                    // SAFETY: We've already matched self.0 as a Some, but we need the mutable
-                    // reference here.
-                    unsafe { mem::take(&mut self.0).unwrap_unchecked().into_tuple() }
-                ),
-            },
-            None => None,
+    /// Removes the node with the smallest key from this branch, rebalancing on the way back up.
+    fn take_first_node(&mut self) -> Option<Box<Node<K, V>>> {
+        let node = self.0.as_mut()?;
+
+        if node.left.is_some() {
+            let first = node.left.take_first_node();
+            self.rebalance();
+            first
+        } else {
+            let mut node = self.0.take()?;
+            *self = mem::take(&mut node.right);
+            Some(node)
         }
     }
 
+    pub fn take_first_entry(&mut self) -> Option<(K, V)> {
+        self.take_first_node().map(|node| node.into_tuple())
+    }
+
     pub fn last_entry(&self) -> Option<(&K, &V)> {
         match &self.0 {
             Some(node) => match node.right.last_entry() {
@@ -158,19 +249,64 @@ impl<K: Ord, V> Branch<K, V> {
         }
     }
 
+    /// Removes the node with the largest key from this branch, rebalancing on the way back up.
+    fn take_last_node(&mut self) -> Option<Box<Node<K, V>>> {
+        let node = self.0.as_mut()?;
+
+        if node.right.is_some() {
+            let last = node.right.take_last_node();
+            self.rebalance();
+            last
+        } else {
+            let mut node = self.0.take()?;
+            *self = mem::take(&mut node.left);
+            Some(node)
+        }
+    }
+
     pub fn take_last_entry(&mut self) -> Option<(K, V)> {
-        match &mut self.0 {
-            Some(node) => match node.right.take_last_entry() {
-                Some(e) => Some(e),
-                None => Some(
-                    // SAFETY: We've already matched self.0 as a Some, but we need the mutable
-                    // reference here.
-                    unsafe { mem::take(&mut self.0).unwrap_unchecked().into_tuple() }
-                ),
-            },
-            None => None,
+        self.take_last_node().map(|node| node.into_tuple())
+    }
+
+    /// Pushes this branch's node and all of its left descendants onto `stack`, so that the node
+    /// with the smallest key ends up on top.
+    pub fn push_left_spine<'a>(&'a self, stack: &mut Vector<&'a Node<K, V>>) {
+        let mut branch = self;
+        while let Some(node) = &branch.0 {
+            stack.push(node);
+            branch = &node.left;
         }
     }
+
+    /// Pushes this branch's node and all of its right descendants onto `stack`, so that the node
+    /// with the largest key ends up on top.
+    pub fn push_right_spine<'a>(&'a self, stack: &mut Vector<&'a Node<K, V>>) {
+        let mut branch = self;
+        while let Some(node) = &branch.0 {
+            stack.push(node);
+            branch = &node.right;
+        }
+    }
+
+    /// Pushes the value and right subtree of this branch's node and all of its left descendants
+    /// onto `stack`, splitting each node's borrow so that the values can be produced mutably.
+    pub fn push_left_spine_mut<'a>(
+        &'a mut self,
+        stack: &mut Vector<(&'a mut V, &'a mut Branch<K, V>)>,
+    ) {
+        let mut branch = self;
+        while let Some(node) = &mut branch.0 {
+            let Node { left, right, value, .. } = &mut **node;
+            stack.push((value, right));
+            branch = left;
+        }
+    }
+}
+
+impl<K: Ord, V> Default for Branch<K, V> {
+    fn default() -> Self {
+        Branch(None)
+    }
 }
 
 impl<K: Ord, V> Deref for Branch<K, V> {
@@ -0,0 +1,373 @@
+#![cfg(test)]
+
+use super::*;
+use crate::collections::contiguous::Vector;
+use crate::util::alloc::CountedDrop;
+use crate::util::random::XorShift;
+
+/// Checks the AVL invariants of `branch`, returning its height: every stored height is correct,
+/// sibling subtrees differ in height by at most one and all keys are strictly ordered.
+fn check_branch<K: Ord, V>(branch: &Branch<K, V>, lower: Option<&K>, upper: Option<&K>) -> usize {
+    let Some(node) = &branch.0 else {
+        return 0;
+    };
+
+    assert!(lower.is_none_or(|lower| *lower < node.key), "Keys should be ordered.");
+    assert!(upper.is_none_or(|upper| node.key < *upper), "Keys should be ordered.");
+
+    let left = check_branch(&node.left, lower, Some(&node.key));
+    let right = check_branch(&node.right, Some(&node.key), upper);
+    assert!(left.abs_diff(right) <= 1, "Sibling subtrees should differ in height by at most one.");
+    assert_eq!(node.height, 1 + left.max(right), "Stored heights should be correct.");
+    node.height
+}
+
+fn check_invariants<K: Ord, V>(map: &BinaryTreeMap<K, V>) {
+    check_branch(&map.root, None, None);
+    assert_eq!(map.iter().count(), map.len(), "len should match the number of entries.");
+}
+
+// ============================================================================
+// BinaryTreeMap::new / Default / is_empty
+// ============================================================================
+
+#[test]
+fn test_new_is_empty() {
+    let map: BinaryTreeMap<i32, i32> = BinaryTreeMap::new();
+    assert!(map.is_empty());
+    assert_eq!(map.len(), 0);
+    assert_eq!(map.height(), 0);
+}
+
+#[test]
+fn test_default_is_empty() {
+    let map: BinaryTreeMap<i32, i32> = BinaryTreeMap::default();
+    assert!(map.is_empty());
+}
+
+// ============================================================================
+// insert / len
+// ============================================================================
+
+#[test]
+fn test_insert_increments_len() {
+    let mut map = BinaryTreeMap::new();
+    assert_eq!(map.insert(1, "a"), None);
+    assert_eq!(map.insert(2, "b"), None);
+    assert_eq!(map.len(), 2);
+}
+
+#[test]
+fn test_insert_replace_keeps_len() {
+    let mut map = BinaryTreeMap::new();
+    map.insert(1, "a");
+    assert_eq!(map.insert(1, "b"), Some("a"));
+    assert_eq!(map.len(), 1, "Replacing a value shouldn't change the length.");
+    assert_eq!(map.get(&1), Some(&"b"));
+}
+
+// ============================================================================
+// Balancing
+// ============================================================================
+
+#[test]
+fn test_ascending_inserts_stay_balanced() {
+    let map: BinaryTreeMap<usize, ()> = (0..1023).map(|i| (i, ())).collect();
+    check_invariants(&map);
+    assert_eq!(map.height(), 10, "A full tree should be perfectly balanced.");
+}
+
+#[test]
+fn test_descending_inserts_stay_balanced() {
+    let map: BinaryTreeMap<usize, ()> = (0..1000).rev().map(|i| (i, ())).collect();
+    check_invariants(&map);
+    assert!(map.height() <= 14);
+}
+
+#[test]
+fn test_zigzag_inserts_stay_balanced() {
+    let mut map = BinaryTreeMap::new();
+    for i in 0..500 {
+        map.insert(i, ());
+        map.insert(1000 - i, ());
+    }
+    check_invariants(&map);
+}
+
+#[test]
+fn test_height_is_logarithmic() {
+    let mut map = BinaryTreeMap::new();
+    for i in 0..100_000 {
+        map.insert(i, i);
+    }
+    // The height of an AVL tree is less than 1.4405 * log2(n + 2) - 0.3277.
+    assert!(map.height() <= 24, "Height {} is too large for 100000 entries.", map.height());
+}
+
+// ============================================================================
+// get / get_mut / contains / Index
+// ============================================================================
+
+#[test]
+fn test_get_missing() {
+    let map: BinaryTreeMap<i32, i32> = (0..10).map(|i| (i * 2, i)).collect();
+    assert_eq!(map.get(&3), None);
+    assert_eq!(map.get(&-1), None);
+    assert_eq!(map.get(&20), None);
+}
+
+#[test]
+fn test_get_entry() {
+    let map: BinaryTreeMap<i32, i32> = (0..10).map(|i| (i * 2, i)).collect();
+    assert_eq!(map.get_entry(&8), Some((&8, &4)));
+}
+
+#[test]
+fn test_get_mut() {
+    let mut map: BinaryTreeMap<i32, i32> = (0..10).map(|i| (i, i)).collect();
+    *map.get_mut(&5).expect("Key 5 should be present.") = 50;
+    assert_eq!(map[&5], 50);
+    assert_eq!(map.get_mut(&10), None);
+}
+
+#[test]
+fn test_contains() {
+    let map: BinaryTreeMap<i32, ()> = [3, 1, 2].into_iter().map(|i| (i, ())).collect();
+    assert!(map.contains(&1));
+    assert!(!map.contains(&4));
+}
+
+#[test]
+fn test_borrowed_keys() {
+    let mut map = BinaryTreeMap::new();
+    map.insert(String::from("b"), 2);
+    map.insert(String::from("a"), 1);
+    assert_eq!(map.get("a"), Some(&1));
+    assert_eq!(map.remove("b"), Some(2));
+}
+
+#[test]
+#[should_panic]
+fn test_index_missing_panics() {
+    let map: BinaryTreeMap<i32, i32> = BinaryTreeMap::new();
+    let _ = map[&0];
+}
+
+// ============================================================================
+// remove
+// ============================================================================
+
+#[test]
+fn test_remove_missing() {
+    let mut map: BinaryTreeMap<i32, i32> = (0..10).map(|i| (i, i)).collect();
+    assert_eq!(map.remove(&10), None);
+    assert_eq!(map.len(), 10);
+}
+
+#[test]
+fn test_remove_leaf() {
+    let mut map: BinaryTreeMap<i32, i32> = (0..3).map(|i| (i, i)).collect();
+    assert_eq!(map.remove_entry(&2), Some((2, 2)));
+    assert_eq!(*map.keys().copied().collect::<Vector<_>>(), [0, 1]);
+    check_invariants(&map);
+}
+
+#[test]
+fn test_remove_with_two_children_keeps_subtrees() {
+    let mut map: BinaryTreeMap<i32, i32> = (0..15).map(|i| (i, i)).collect();
+    let root = map.root.0.as_ref().map(|node| node.key).expect("The map shouldn't be empty.");
+
+    assert_eq!(map.remove(&root), Some(root));
+    assert_eq!(map.len(), 14);
+    assert!(
+        (0..15).all(|i| map.contains(&i) == (i != root)),
+        "Removing a node shouldn't lose its children."
+    );
+    check_invariants(&map);
+}
+
+#[test]
+fn test_remove_all_stays_balanced() {
+    let mut map: BinaryTreeMap<usize, usize> = (0..500).map(|i| (i, i)).collect();
+    for i in (0..500).step_by(3) {
+        assert_eq!(map.remove(&i), Some(i));
+        check_invariants(&map);
+    }
+    for i in 0..500 {
+        map.remove(&i);
+    }
+    assert!(map.is_empty());
+    assert_eq!(map.height(), 0);
+}
+
+// ============================================================================
+// first / last / take_first / take_last
+// ============================================================================
+
+#[test]
+fn test_first_last_empty() {
+    let mut map: BinaryTreeMap<i32, i32> = BinaryTreeMap::new();
+    assert_eq!(map.first(), None);
+    assert_eq!(map.last(), None);
+    assert_eq!(map.take_first(), None);
+    assert_eq!(map.take_last(), None);
+}
+
+#[test]
+fn test_first_last() {
+    let map: BinaryTreeMap<i32, char> = [(5, 'e'), (1, 'a'), (9, 'i')].into_iter().collect();
+    assert_eq!(map.first_entry(), Some((&1, &'a')));
+    assert_eq!(map.last_entry(), Some((&9, &'i')));
+}
+
+#[test]
+fn test_take_first_last_updates_len() {
+    let mut map: BinaryTreeMap<i32, i32> = (0..100).map(|i| (i, i)).collect();
+    assert_eq!(map.take_first_entry(), Some((0, 0)));
+    assert_eq!(map.take_last(), Some(99));
+    assert_eq!(map.len(), 98);
+    check_invariants(&map);
+}
+
+#[test]
+fn test_take_first_drains_in_order() {
+    let mut map: BinaryTreeMap<i32, i32> =
+        [4, 2, 6, 1, 3, 5, 7].into_iter().map(|i| (i, i)).collect();
+    let mut taken = Vector::new();
+    while let Some(value) = map.take_first() {
+        taken.push(value);
+        check_invariants(&map);
+    }
+    assert_eq!(*taken, [1, 2, 3, 4, 5, 6, 7]);
+}
+
+// ============================================================================
+// Iteration
+// ============================================================================
+
+#[test]
+fn test_iter_empty() {
+    let map: BinaryTreeMap<i32, i32> = BinaryTreeMap::new();
+    assert_eq!(map.iter().next(), None);
+    assert_eq!(map.iter().next_back(), None);
+}
+
+#[test]
+fn test_iter_in_order() {
+    let map: BinaryTreeMap<i32, i32> =
+        [8, 3, 10, 1, 6, 14, 4, 7, 13].into_iter().map(|i| (i, -i)).collect();
+    assert_eq!(*map.keys().copied().collect::<Vector<_>>(), [1, 3, 4, 6, 7, 8, 10, 13, 14]);
+    assert_eq!(
+        *map.values().copied().collect::<Vector<_>>(),
+        [-1, -3, -4, -6, -7, -8, -10, -13, -14]
+    );
+}
+
+#[test]
+fn test_iter_double_ended() {
+    let map: BinaryTreeMap<i32, ()> = (0..10).map(|i| (i, ())).collect();
+    assert_eq!(*map.keys().rev().copied().collect::<Vector<_>>(), [9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+
+    let mut iter = map.keys();
+    let mut interleaved = Vector::new();
+    while let (Some(front), back) = (iter.next(), iter.next_back()) {
+        interleaved.push(*front);
+        interleaved.extend(back.copied());
+    }
+    assert_eq!(
+        *interleaved,
+        [0, 9, 1, 8, 2, 7, 3, 6, 4, 5],
+        "Both ends should meet without overlap."
+    );
+}
+
+#[test]
+fn test_iter_exact_size() {
+    let map: BinaryTreeMap<i32, ()> = (0..10).map(|i| (i, ())).collect();
+    let mut iter = map.iter();
+    iter.next();
+    iter.next_back();
+    assert_eq!(iter.len(), 8);
+}
+
+#[test]
+fn test_values_mut() {
+    let mut map: BinaryTreeMap<i32, i32> = (0..100).map(|i| (i, i)).collect();
+    assert_eq!(map.values_mut().len(), 100);
+    for value in map.values_mut() {
+        *value *= 2;
+    }
+    assert!(map.iter().all(|(k, v)| *v == k * 2));
+}
+
+#[test]
+fn test_into_iter() {
+    let map: BinaryTreeMap<i32, i32> = [3, 1, 2].into_iter().map(|i| (i, i * 10)).collect();
+    let mut iter = map.into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some((3, 30)));
+    assert_eq!(iter.next(), Some((1, 10)));
+    assert_eq!(iter.len(), 1);
+}
+
+#[test]
+fn test_into_keys_values() {
+    let map: BinaryTreeMap<i32, i32> = [3, 1, 2].into_iter().map(|i| (i, i * 10)).collect();
+    assert_eq!(*map.into_keys().collect::<Vector<_>>(), [1, 2, 3]);
+
+    let map: BinaryTreeMap<i32, i32> = [3, 1, 2].into_iter().map(|i| (i, i * 10)).collect();
+    assert_eq!(*map.into_values().collect::<Vector<_>>(), [10, 20, 30]);
+}
+
+// ============================================================================
+// Drop
+// ============================================================================
+
+#[test]
+fn test_drops() {
+    let counter = CountedDrop::new(0);
+    let mut map = BinaryTreeMap::new();
+    for i in 0..100 {
+        map.insert(i, counter.clone());
+    }
+
+    map.insert(0, counter.clone());
+    assert_eq!(*counter.borrow(), 1, "Replaced values should be returned and dropped.");
+
+    for i in 0..10 {
+        map.remove(&i);
+    }
+    assert_eq!(*counter.borrow(), 11);
+
+    let mut iter = map.into_iter();
+    iter.next();
+    drop(iter);
+    assert_eq!(*counter.borrow(), 101, "Every value should be dropped exactly once.");
+}
+
+// ============================================================================
+// Randomized comparison
+// ============================================================================
+
+#[test]
+fn test_matches_std_btree_map() {
+    let mut map = BinaryTreeMap::new();
+    let mut reference = std::collections::BTreeMap::new();
+
+    let mut random = XorShift::default();
+    for step in 0..20_000 {
+        let key = random.next_u64() % 1000;
+        match random.next_u64() % 4 {
+            0 => assert_eq!(map.remove(&key), reference.remove(&key)),
+            1 => assert_eq!(map.take_first_entry(), reference.pop_first()),
+            _ => assert_eq!(map.insert(key, step), reference.insert(key, step)),
+        }
+
+        if step % 1000 == 0 {
+            check_invariants(&map);
+        }
+    }
+
+    assert_eq!(map.len(), reference.len());
+    assert!(map.iter().eq(reference.iter()), "Iteration should match a BTreeMap.");
+}
@@ -1,4 +1,6 @@
-//! WIP: Binary tree map and set types. Currently unfinished.
+//! Ordered collection types based on self-balancing binary search trees. Currently this includes
+//! [`BinaryTreeMap`], an AVL tree mapping keys to values in order of the [`Ord`] trait.
+#![cfg(feature = "binary-tree")]
 
 pub mod map;
 
@@ -0,0 +1,253 @@
+use std::fmt::{self, Debug, Formatter};
+use std::ops::{Index, IndexMut};
+
+use super::{Drain, Iter, IterMut};
+use crate::collections::contiguous::Deque;
+use crate::util::error::TryReserveError;
+
+/// A ring buffer with a capacity chosen at runtime, which overwrites its oldest item when a new
+/// one is pushed while it is full. This makes it suitable for bounded histories, such as undo
+/// stacks, where only the most recent items are worth keeping.
+///
+/// Unlike [`CircStack`](super::super::CircStack), the buffer lives on the heap as a [`Deque`] which
+/// is never allowed to grow, and only the slots which hold items are initialized, so it can start
+/// empty and be [`resize`](CircularBuffer::resize)d at any time. The ring indexing, slices and
+/// iterators are all shared with the Deque.
+///
+/// Items are indexed and iterated from oldest to newest. Iterators are double-ended, so they can be
+/// reversed to go from newest to oldest.
+///
+/// # Time Complexity
+/// For this analysis of time complexity, variables are defined as follows:
+/// - `n`: The number of items in the CircularBuffer.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `get` | `O(1)` |
+/// | `len` | `O(1)` |
+/// | `push` | `O(1)` |
+/// | `pop`, `pop_oldest` | `O(1)` |
+/// | `resize` | `O(n)` |
+/// | `drain` | `O(n)` |
+///
+/// # Examples
+/// ```
+/// # use standard_lib::collections::circular::CircularBuffer;
+/// let mut history = CircularBuffer::with_cap(3);
+/// for edit in ["a", "b", "c"] {
+///     history.push(edit);
+/// }
+///
+/// assert_eq!(history.push("d"), Some("a"));
+/// assert!(history.iter().eq(&["b", "c", "d"]));
+/// assert!(history.iter().rev().eq(&["d", "c", "b"]));
+///
+/// assert_eq!(history.pop(), Some("d"));
+/// history.resize(1);
+/// assert!(history.iter().eq(&["c"]));
+/// ```
+pub struct CircularBuffer<T> {
+    pub(crate) deque: Deque<T>, // Ordered from oldest to newest, with len <= cap at all times.
+}
+
+impl<T> CircularBuffer<T> {
+    /// Creates a new, empty CircularBuffer which can hold up to `cap` items.
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    pub fn with_cap(cap: usize) -> CircularBuffer<T> {
+        CircularBuffer {
+            deque: Deque::with_cap(cap),
+        }
+    }
+
+    /// Creates a new, empty CircularBuffer which can hold up to `cap` items, returning an error
+    /// rather than panicking or aborting if the allocation fails.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if memory layout size exceeds [`isize::MAX`] or if the
+    /// allocator fails to provide the memory.
+    pub fn try_with_cap(cap: usize) -> Result<CircularBuffer<T>, TryReserveError> {
+        Ok(CircularBuffer {
+            deque: Deque::try_with_cap(cap)?,
+        })
+    }
+
+    /// Returns the number of items in the CircularBuffer.
+    pub const fn len(&self) -> usize {
+        self.deque.len()
+    }
+
+    /// Returns true if the CircularBuffer contains no items.
+    pub const fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+
+    /// Returns the maximum number of items that the CircularBuffer can hold.
+    pub const fn cap(&self) -> usize {
+        self.deque.cap()
+    }
+
+    /// Returns true if the CircularBuffer is full, so that pushing will overwrite the oldest item.
+    pub const fn is_full(&self) -> bool {
+        self.len() == self.cap()
+    }
+
+    /// Returns a reference to the item at `index`, counting from the oldest item, or [`None`] if
+    /// it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.deque.get(index)
+    }
+
+    /// Returns a mutable reference to the item at `index`, counting from the oldest item, or
+    /// [`None`] if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.deque.get_mut(index)
+    }
+
+    /// Returns a reference to the most recently pushed item, if there is one.
+    pub fn newest(&self) -> Option<&T> {
+        self.deque.back()
+    }
+
+    /// Returns a reference to the oldest item, if there is one.
+    pub fn oldest(&self) -> Option<&T> {
+        self.deque.front()
+    }
+
+    /// Adds `value` as the newest item in the CircularBuffer. If the buffer is full, the oldest
+    /// item is removed to make room and returned. If the capacity is 0, `value` is returned
+    /// instead.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if self.cap() == 0 { return Some(value); }
+
+        // Popping first leaves room for the push, so the Deque never grows.
+        let oldest = if self.is_full() { self.deque.pop_front() } else { None };
+        self.deque.push_back(value);
+        oldest
+    }
+
+    /// Removes the newest item from the CircularBuffer and returns it, if there is one.
+    pub fn pop(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+
+    /// Removes the oldest item from the CircularBuffer and returns it, if there is one.
+    pub fn pop_oldest(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    /// Changes the capacity of the CircularBuffer to `new_cap`. If it holds more than `new_cap`
+    /// items, the oldest items are dropped so that only the newest `new_cap` remain.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the CircularBuffer would have a size that exceeds
+    /// [`isize::MAX`].
+    pub fn resize(&mut self, new_cap: usize) {
+        self.try_resize(new_cap).unwrap_or_else(|error| error.handle())
+    }
+
+    /// Changes the capacity of the CircularBuffer to `new_cap`, returning an error rather than
+    /// panicking or aborting if the allocation fails. If it holds more than `new_cap` items, the
+    /// oldest items are dropped so that only the newest `new_cap` remain.
+    ///
+    /// If an error is returned, the capacity is left unchanged, although any items which didn't
+    /// fit will already have been dropped.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the memory layout of the CircularBuffer would have a size
+    /// that exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    pub fn try_resize(&mut self, new_cap: usize) -> Result<(), TryReserveError> {
+        while self.len() > new_cap {
+            self.deque.pop_front();
+        }
+
+        self.deque.move_to_start();
+        self.deque.arr.try_realloc(new_cap)
+    }
+
+    /// Removes and drops all items in the CircularBuffer, without changing the capacity.
+    pub fn clear(&mut self) {
+        self.deque.clear();
+    }
+
+    /// Removes all items from the CircularBuffer, returning them as an iterator from oldest to
+    /// newest. Any items which aren't produced are dropped along with the iterator.
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        self.deque.drain(..)
+    }
+
+    /// Returns a pair of slices which contain the items of the CircularBuffer from oldest to
+    /// newest. The second slice is empty unless the items wrap around the end of the buffer.
+    pub const fn as_slices(&self) -> (&[T], &[T]) {
+        self.deque.as_slices()
+    }
+
+    /// Returns a pair of mutable slices which contain the items of the CircularBuffer from oldest
+    /// to newest. The second slice is empty unless the items wrap around the end of the buffer.
+    pub const fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        self.deque.as_mut_slices()
+    }
+
+    /// Returns an iterator over all items in the CircularBuffer, as references, from oldest to
+    /// newest. Use [`rev`](Iterator::rev) to iterate from newest to oldest instead.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.into_iter()
+    }
+
+    /// Returns an iterator over all items in the CircularBuffer, as mutable references, from
+    /// oldest to newest. Use [`rev`](Iterator::rev) to iterate from newest to oldest instead.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.into_iter()
+    }
+}
+
+impl<T> Index<usize> for CircularBuffer<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.deque[index]
+    }
+}
+
+impl<T> IndexMut<usize> for CircularBuffer<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.deque[index]
+    }
+}
+
+impl<T> Extend<T> for CircularBuffer<T> {
+    /// Pushes each item in turn, so that only the newest items remain if there are more than the
+    /// capacity.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T: Clone> Clone for CircularBuffer<T> {
+    fn clone(&self) -> Self {
+        let mut buffer = CircularBuffer::with_cap(self.cap());
+        buffer.extend(self.iter().cloned());
+        buffer
+    }
+}
+
+impl<T: PartialEq> PartialEq for CircularBuffer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deque == other.deque
+    }
+}
+
+impl<T: Eq> Eq for CircularBuffer<T> {}
+
+impl<T: Debug> Debug for CircularBuffer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CircularBuffer")
+            .field_with("contents", |f| f.debug_list().entries(self.iter()).finish())
+            .field("len", &self.len())
+            .field("cap", &self.cap())
+            .finish()
+    }
+}
@@ -0,0 +1,14 @@
+//! A module containing [`CircularBuffer`] and associtated types.
+//!
+//! Included types are [`IntoIter`] for owned iteration, [`Iter`] and [`IterMut`] for borrowed
+//! iteration and [`Drain`] for removing all items. These are the iterators of
+//! [`Deque`](crate::collections::contiguous::Deque), which a [`CircularBuffer`] is built on.
+//!
+//! [`CircularBuffer`] is also re-exported under the parent module.
+
+mod circular_buffer;
+mod iter;
+mod tests;
+
+pub use circular_buffer::*;
+pub use iter::*;
@@ -0,0 +1,149 @@
+#![cfg(test)]
+
+use std::panic::{self, AssertUnwindSafe};
+
+use super::*;
+use crate::util::alloc::{CountedDrop, PanicOnDrop, ZeroSizedType};
+use crate::util::panic::assert_panics;
+
+/// Creates a CircularBuffer with capacity 5 which has had 0..8 pushed, so that it contains 3..8
+/// starting at index 3 of the buffer, wrapping around the end.
+fn wrapped() -> CircularBuffer<usize> {
+    let mut buffer = CircularBuffer::with_cap(5);
+    buffer.extend(0..8);
+    buffer
+}
+
+#[test]
+fn test_push_overwrites_oldest() {
+    let mut buffer = CircularBuffer::with_cap(3);
+    assert_eq!(buffer.push(0), None);
+    assert_eq!(buffer.push(1), None);
+    assert_eq!(buffer.push(2), None);
+    assert!(buffer.is_full());
+
+    assert_eq!(buffer.push(3), Some(0));
+    assert_eq!(buffer.push(4), Some(1));
+    assert_eq!(buffer.len(), 3);
+    assert_eq!((buffer.oldest(), buffer.newest()), (Some(&2), Some(&4)));
+    assert_eq!(buffer[0], 2, "Indexing should be relative to the oldest item.");
+    assert_panics!({ let _ = buffer[3]; });
+
+    let mut empty = CircularBuffer::with_cap(0);
+    assert_eq!(empty.push(0), Some(0));
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_pop_and_pop_oldest() {
+    let mut buffer = wrapped();
+    assert_eq!(buffer.pop(), Some(7));
+    assert_eq!(buffer.pop_oldest(), Some(3));
+    assert_eq!(buffer.pop(), Some(6));
+    assert_eq!(buffer.pop_oldest(), Some(4));
+    assert_eq!(buffer.pop(), Some(5));
+    assert_eq!((buffer.pop(), buffer.pop_oldest()), (None, None));
+
+    // The buffer should be reusable after being emptied from either end.
+    buffer.extend(0..7);
+    assert!(buffer.iter().copied().eq(2..7));
+}
+
+#[test]
+fn test_iter_both_directions() {
+    let mut buffer = wrapped();
+    assert_eq!(buffer.as_slices(), (&[3, 4][..], &[5, 6, 7][..]));
+    assert!(buffer.iter().copied().eq(3..8));
+    assert!(buffer.iter().rev().copied().eq((3..8).rev()));
+
+    buffer.iter_mut().for_each(|value| *value *= 10);
+    assert!(buffer.clone().into_iter().rev().eq([70, 60, 50, 40, 30]));
+    assert_eq!(
+        format!("{buffer:?}"),
+        "CircularBuffer { contents: [30, 40, 50, 60, 70], len: 5, cap: 5 }"
+    );
+}
+
+#[test]
+fn test_resize() {
+    let mut buffer = wrapped();
+    buffer.resize(8);
+    assert_eq!(buffer.cap(), 8);
+    assert!(buffer.iter().copied().eq(3..8));
+    buffer.extend(8..12);
+    assert!(buffer.iter().copied().eq(4..12));
+
+    // Shrinking should keep the newest items.
+    buffer.pop_oldest();
+    buffer.push(12);
+    buffer.resize(3);
+    assert_eq!(buffer.cap(), 3);
+    assert!(buffer.iter().copied().eq(10..13));
+    assert_eq!(buffer.push(13), Some(10));
+
+    let counter = CountedDrop::new(0);
+    let mut buffer = CircularBuffer::with_cap(4);
+    buffer.extend((0..6).map(|_| counter.clone()));
+    assert_eq!(counter.take(), 2);
+    buffer.resize(1);
+    assert_eq!(counter.take(), 3, "Items which don't fit should be dropped.");
+    buffer.resize(0);
+    assert_eq!(counter.take(), 1);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_drain() {
+    let mut buffer = wrapped();
+    let mut drain = buffer.drain();
+    assert_eq!(drain.len(), 5);
+    assert_eq!(drain.next(), Some(3));
+    assert_eq!(drain.next_back(), Some(7));
+    assert!(drain.eq(4..7));
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.cap(), 5);
+
+    let counter = CountedDrop::new(0);
+    let mut buffer = CircularBuffer::with_cap(4);
+    buffer.extend((0..6).map(|_| counter.clone()));
+    assert_eq!(counter.take(), 2);
+    let mut drain = buffer.drain();
+    drop(drain.next());
+    assert_eq!(counter.take(), 1);
+    drop(drain);
+    assert_eq!(counter.take(), 3, "Items that weren't produced should be dropped.");
+
+    buffer.extend((0..2).map(|_| counter.clone()));
+    drop(buffer);
+    assert_eq!(counter.take(), 2);
+}
+
+#[test]
+fn test_drain_panic_safety() {
+    let counter = CountedDrop::new(0);
+    let item = |panics| PanicOnDrop { _counter: counter.clone(), panics };
+    let mut buffer = CircularBuffer::with_cap(5);
+    buffer.extend([false, false, false, true, false, false, false].map(item));
+    assert_eq!(counter.take(), 2);
+
+    // The items wrap around the end of the buffer, and the panic happens in the first part of them.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| drop(buffer.drain())));
+    assert!(result.is_err());
+    assert_eq!(counter.take(), 5, "The rest of the items should be dropped after a panic.");
+    assert!(buffer.is_empty());
+
+    buffer.extend([false, false].map(item));
+    drop(buffer);
+    assert_eq!(counter.take(), 2, "The CircularBuffer should be usable after a panic.");
+}
+
+#[test]
+fn test_zero_sized_types() {
+    let mut buffer = CircularBuffer::with_cap(3);
+    buffer.extend([ZeroSizedType; 5]);
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.pop(), Some(ZeroSizedType));
+    buffer.resize(10);
+    buffer.extend([ZeroSizedType; 10]);
+    assert_eq!(buffer.iter().rev().count(), 10);
+}
@@ -1,6 +1,9 @@
 #![cfg(feature = "circ")]
 
+pub mod buffer;
 pub mod stack;
 
+#[doc(inline)]
+pub use buffer::CircularBuffer;
 #[doc(inline)]
 pub use stack::CircStack;
\ No newline at end of file
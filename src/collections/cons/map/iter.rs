@@ -0,0 +1,53 @@
+use std::hash::{BuildHasher, Hash};
+use std::iter::FusedIterator;
+use std::rc::Rc;
+use std::slice;
+
+use super::{Node, Pair, PHashMap};
+use crate::collections::contiguous::Vector;
+
+/// See [`PHashMap::iter`].
+pub struct Iter<'a, K, V> {
+    // The nodes which are yet to be visited, and the pairs of the node currently being visited.
+    pub(crate) nodes: Vector<&'a Node<K, V>>,
+    pub(crate) pairs: slice::Iter<'a, Pair<K, V>>,
+    pub(crate) len: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pair) = self.pairs.next() {
+                self.len -= 1;
+                return Some((&pair.key, &pair.value));
+            }
+
+            let node = self.nodes.pop()?;
+            self.pairs = node.pairs.iter();
+            self.nodes.extend(node.nodes.iter().map(Rc::as_ref));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K, V> FusedIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher> IntoIterator for &'a PHashMap<K, V, B> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            nodes: self.root.as_deref().into_iter().collect(),
+            pairs: [].iter(),
+            len: self.len,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+mod iter;
+mod node;
+mod phash_map;
+
+pub use iter::*;
+pub(crate) use node::*;
+pub use phash_map::*;
+
+#[cfg(test)]
+mod test;
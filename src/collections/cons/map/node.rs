@@ -0,0 +1,189 @@
+use std::borrow::Borrow;
+use std::mem;
+use std::rc::Rc;
+
+use crate::collections::contiguous::Vector;
+
+/// The number of bits of a hash which are used at each level of the trie.
+pub(crate) const BITS: u32 = 5;
+/// A mask for the bits of a hash which are used at a single level of the trie.
+pub(crate) const MASK: u64 = (1 << BITS) - 1;
+/// Once the shift reaches the number of bits in a hash, every key in a node has the same hash, so
+/// the node is a bucket of collisions which is searched linearly.
+pub(crate) const HASH_BITS: u32 = u64::BITS;
+
+/// A key-value pair stored alongside the hash of its key, so that it can be moved further down the
+/// trie without hashing the key again.
+#[derive(Clone)]
+pub(crate) struct Pair<K, V> {
+    pub(crate) hash: u64,
+    pub(crate) key: K,
+    pub(crate) value: V,
+}
+
+/// A node of a [`PHashMap`](super::PHashMap)'s trie. Each group of [`BITS`] of a hash selects a
+/// slot, which holds either a pair or a child node, marked by a bit in `pair_map` or `node_map`.
+/// Only occupied slots are stored, so the position of a slot is the number of lower bits set.
+#[derive(Clone)]
+pub(crate) struct Node<K, V> {
+    pub(crate) pair_map: u32,
+    pub(crate) node_map: u32,
+    pub(crate) pairs: Vector<Pair<K, V>>,
+    pub(crate) nodes: Vector<Rc<Node<K, V>>>,
+}
+
+/// Returns the bit for the slot of `hash` in a node at the level `shift`.
+const fn bit(hash: u64, shift: u32) -> u32 {
+    1 << ((hash >> shift) & MASK)
+}
+
+/// Returns the position of the slot marked by `bit`, among the occupied slots in `map`.
+const fn position(map: u32, bit: u32) -> usize {
+    (map & (bit - 1)).count_ones() as usize
+}
+
+/// Inserts `value` at `index` in `vec`, which may be the end of it unlike [`Vector::insert`].
+fn insert_at<T>(vec: &mut Vector<T>, index: usize, value: T) {
+    if index == vec.len() {
+        vec.push(value);
+    } else {
+        vec.insert(index, value);
+    }
+}
+
+impl<K, V> Node<K, V> {
+    /// Creates a new node with no slots occupied.
+    pub(crate) fn new() -> Node<K, V> {
+        Node {
+            pair_map: 0,
+            node_map: 0,
+            pairs: Vector::new(),
+            nodes: Vector::new(),
+        }
+    }
+
+    /// Creates a node at the level `shift` containing the two pairs, with as many levels of nodes
+    /// below it as are needed to separate them.
+    fn two(shift: u32, a: Pair<K, V>, b: Pair<K, V>) -> Node<K, V> {
+        let mut node = Node::new();
+        if shift >= HASH_BITS {
+            node.pairs.push(a);
+            node.pairs.push(b);
+            return node;
+        }
+
+        let (bit_a, bit_b) = (bit(a.hash, shift), bit(b.hash, shift));
+        if bit_a == bit_b {
+            node.node_map = bit_a;
+            node.nodes.push(Rc::new(Node::two(shift + BITS, a, b)));
+        } else {
+            node.pair_map = bit_a | bit_b;
+            let (first, second) = if bit_a < bit_b { (a, b) } else { (b, a) };
+            node.pairs.push(first);
+            node.pairs.push(second);
+        }
+        node
+    }
+
+    /// Returns the pair for `key`, if the trie below this node at the level `shift` contains it.
+    pub(crate) fn get<Q>(&self, mut shift: u32, hash: u64, key: &Q) -> Option<&Pair<K, V>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let mut node = self;
+        while shift < HASH_BITS {
+            let bit = bit(hash, shift);
+            if node.pair_map & bit != 0 {
+                let pair = &node.pairs[position(node.pair_map, bit)];
+                return (pair.hash == hash && pair.key.borrow() == key).then_some(pair);
+            }
+            if node.node_map & bit == 0 { return None; }
+
+            node = &*node.nodes[position(node.node_map, bit)];
+            shift += BITS;
+        }
+
+        node.pairs.iter().find(|pair| pair.key.borrow() == key)
+    }
+}
+
+impl<K: Eq + Clone, V: Clone> Node<K, V> {
+    /// Inserts `pair` into the trie below this node at the level `shift`, returning the old value
+    /// if its key was already present. Any shared nodes on the way are copied.
+    pub(crate) fn insert(&mut self, shift: u32, pair: Pair<K, V>) -> Option<V> {
+        if shift >= HASH_BITS {
+            return match self.pairs.iter_mut().find(|existing| existing.key == pair.key) {
+                Some(existing) => Some(mem::replace(&mut existing.value, pair.value)),
+                None => {
+                    self.pairs.push(pair);
+                    None
+                },
+            };
+        }
+
+        let bit = bit(pair.hash, shift);
+        if self.pair_map & bit != 0 {
+            let index = position(self.pair_map, bit);
+            let existing = &mut self.pairs[index];
+            if existing.hash == pair.hash && existing.key == pair.key {
+                return Some(mem::replace(&mut existing.value, pair.value));
+            }
+
+            // Another key is in this slot, so move both keys into a new node.
+            let existing = self.pairs.remove(index);
+            self.pair_map ^= bit;
+            self.node_map |= bit;
+            let node = Node::two(shift + BITS, existing, pair);
+            insert_at(&mut self.nodes, position(self.node_map, bit), Rc::new(node));
+            None
+        } else if self.node_map & bit != 0 {
+            let node = &mut self.nodes[position(self.node_map, bit)];
+            Rc::make_mut(node).insert(shift + BITS, pair)
+        } else {
+            self.pair_map |= bit;
+            insert_at(&mut self.pairs, position(self.pair_map, bit), pair);
+            None
+        }
+    }
+
+    /// Removes the pair for `key` from the trie below this node at the level `shift`, returning it
+    /// if it was present. Any shared nodes on the way are copied, so the key should be known to be
+    /// present to avoid copying them needlessly.
+    pub(crate) fn remove<Q>(&mut self, shift: u32, hash: u64, key: &Q) -> Option<Pair<K, V>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        if shift >= HASH_BITS {
+            let index = self.pairs.iter().position(|pair| pair.key.borrow() == key)?;
+            return Some(self.pairs.remove(index));
+        }
+
+        let bit = bit(hash, shift);
+        if self.pair_map & bit != 0 {
+            let index = position(self.pair_map, bit);
+            let pair = &self.pairs[index];
+            if pair.hash != hash || pair.key.borrow() != key { return None; }
+
+            self.pair_map ^= bit;
+            Some(self.pairs.remove(index))
+        } else if self.node_map & bit != 0 {
+            let index = position(self.node_map, bit);
+            let node = Rc::make_mut(&mut self.nodes[index]);
+            let removed = node.remove(shift + BITS, hash, key)?;
+
+            // If the child is left with a single pair, move it up into this node instead. This
+            // keeps the trie as shallow as possible, and undoes the levels added by two.
+            if node.nodes.is_empty() && node.pairs.len() == 1 && let Some(pair) = node.pairs.pop() {
+                self.nodes.remove(index);
+                self.node_map ^= bit;
+                self.pair_map |= bit;
+                insert_at(&mut self.pairs, position(self.pair_map, bit), pair);
+            }
+            Some(removed)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use std::borrow::Borrow;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{BuildHasher, Hash, RandomState};
+use std::ops::Index;
+use std::rc::Rc;
+
+use super::{Iter, Node, Pair};
+use crate::util::error::NoValueForKey;
+use crate::util::result::ResultExtension;
+
+/// A persistent map of keys to values, implemented as a hash array mapped trie. Like a
+/// [`ConsBranch`](crate::collections::cons::ConsBranch), cloning a `PHashMap` is cheap, with the
+/// clone sharing all of its nodes with the original through [`Rc`]s.
+///
+/// Each level of the trie uses 5 bits of a key's hash to select one of 32 slots. Only the occupied
+/// slots of a node are stored, so small nodes stay small. Keys whose entire hashes are equal are
+/// stored together at the bottom of the trie, and searched linearly.
+///
+/// Updates can be made in two ways:
+/// - [`insert`](PHashMap::insert) and [`remove`](PHashMap::remove) leave the map unchanged,
+///   returning a new map which shares all but the updated path of nodes with it.
+/// - [`insert_mut`](PHashMap::insert_mut) and [`remove_mut`](PHashMap::remove_mut) update the map
+///   in place. Nodes which are unique to this map are modified directly, and only shared nodes are
+///   copied.
+///
+/// It is a logic error for keys in a PHashMap to be manipulated in a way that changes their hash.
+///
+/// # Time Complexity
+/// For this analysis of time complexity, variables are defined as follows:
+/// - `n`: The number of items in the PHashMap.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `clone` | `O(1)` |
+/// | `len` | `O(1)` |
+/// | `get` | `O(log32 n)`* |
+/// | `insert`, `insert_mut` | `O(log32 n)`* |
+/// | `remove`, `remove_mut` | `O(log32 n)`* |
+/// | `contains` | `O(log32 n)`* |
+///
+/// \* In the event of a hash collision, these functions will take additional time, to search the
+/// keys with the same hash.
+///
+/// # Examples
+/// ```
+/// # use standard_lib::collections::cons::PHashMap;
+/// let empty: PHashMap<&str, i32> = PHashMap::new();
+/// let outer = empty.insert("x", 1).insert("y", 2);
+/// let inner = outer.insert("x", 3).remove("y");
+///
+/// assert_eq!(outer.get("x"), Some(&1));
+/// assert_eq!(inner.get("x"), Some(&3));
+/// assert!(outer.contains("y") && !inner.contains("y"));
+/// ```
+pub struct PHashMap<K: Hash + Eq, V, B: BuildHasher = RandomState> {
+    pub(crate) root: Option<Rc<Node<K, V>>>,
+    pub(crate) len: usize,
+    pub(crate) hasher: B,
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default> PHashMap<K, V, B> {
+    /// Creates a new, empty PHashMap with the default value for `B`.
+    pub fn new() -> PHashMap<K, V, B> {
+        PHashMap::with_hasher(B::default())
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher> PHashMap<K, V, B> {
+    /// Creates a new, empty PHashMap with the provided `hasher`.
+    pub const fn with_hasher(hasher: B) -> PHashMap<K, V, B> {
+        PHashMap {
+            root: None,
+            len: 0,
+            hasher,
+        }
+    }
+
+    /// Returns the number of entries in the PHashMap.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the PHashMap contains no entries.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the map's [`BuildHasher`].
+    pub const fn hasher(&self) -> &B {
+        &self.hasher
+    }
+
+    /// Returns the key-value pair associated with the provided `key`, or None if the map contains
+    /// no values for `key`.
+    pub fn get_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_pair(key).map(|pair| (&pair.key, &pair.value))
+    }
+
+    /// Returns a reference to the value associated with the provided `key`, or None if the map
+    /// contains no values for `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_pair(key).map(|pair| &pair.value)
+    }
+
+    /// Returns true if there is a value associated with the provided `key`.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_pair(key).is_some()
+    }
+
+    /// Returns an iterator over references to the keys and values of the PHashMap, in an
+    /// arbitrary order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.into_iter()
+    }
+
+    /// Returns the pair associated with the provided `key`, if it exists.
+    fn get_pair<Q>(&self, key: &Q) -> Option<&Pair<K, V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.root.as_ref()?.get(0, self.hasher.hash_one(key), key)
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, B: BuildHasher + Clone> PHashMap<K, V, B> {
+    /// Returns a new PHashMap with `key` associated with `value`, replacing any existing value.
+    pub fn insert(&self, key: K, value: V) -> PHashMap<K, V, B> {
+        let mut res = self.clone();
+        res.insert_mut(key, value);
+        res
+    }
+
+    /// Returns a new PHashMap without the entry associated with `key`. If there is no such entry,
+    /// the result shares all of its nodes with this map.
+    pub fn remove<Q>(&self, key: &Q) -> PHashMap<K, V, B>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let mut res = self.clone();
+        res.remove_mut(key);
+        res
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, B: BuildHasher> PHashMap<K, V, B> {
+    /// Associates `key` with `value` in place, returning the previous value associated with `key`
+    /// if there was one.
+    pub fn insert_mut(&mut self, key: K, value: V) -> Option<V> {
+        let hash = self.hasher.hash_one(&key);
+        let root = self.root.get_or_insert_with(|| Rc::new(Node::new()));
+
+        let old = Rc::make_mut(root).insert(0, Pair { hash, key, value });
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Removes the entry associated with `key` in place, returning the value if it exists.
+    pub fn remove_mut<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.hasher.hash_one(key);
+        // Removing copies any shared nodes on the path to the key, so check that it's there first.
+        self.root.as_ref()?.get(0, hash, key)?;
+
+        let removed = Rc::make_mut(self.root.as_mut()?).remove(0, hash, key)?;
+        self.len -= 1;
+        if self.len == 0 {
+            self.root = None;
+        }
+        Some(removed.value)
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Clone> Clone for PHashMap<K, V, B> {
+    /// Creates a cheap (shallow) clone of this `PHashMap`, which shares all of its nodes.
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            len: self.len,
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default> Default for PHashMap<K, V, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Q, K, V, B> Index<&Q> for PHashMap<K, V, B>
+where
+    Q: Hash + Eq + ?Sized,
+    K: Hash + Eq + Borrow<Q>,
+    B: BuildHasher,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).ok_or(NoValueForKey).throw()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, B: BuildHasher> Extend<(K, V)> for PHashMap<K, V, B> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert_mut(key, value);
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, B: BuildHasher + Default> FromIterator<(K, V)>
+    for PHashMap<K, V, B>
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = PHashMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Hash + Eq, V: PartialEq, B: BuildHasher> PartialEq for PHashMap<K, V, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, B: BuildHasher> Eq for PHashMap<K, V, B> {}
+
+impl<K: Hash + Eq + Debug, V: Debug, B: BuildHasher> Debug for PHashMap<K, V, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
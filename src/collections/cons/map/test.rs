@@ -0,0 +1,154 @@
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+use super::*;
+use crate::util::panic::assert_panics;
+
+/// A key whose hash only depends on its value modulo 4, so that many keys have the same hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Colliding(u32);
+
+impl Hash for Colliding {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.0 % 4).hash(state);
+    }
+}
+
+// ============================================================================
+// PHashMap::new / get / insert
+// ============================================================================
+
+#[test]
+fn test_new_is_empty() {
+    let map: PHashMap<i32, i32> = PHashMap::new();
+    assert!(map.is_empty());
+    assert_eq!(map.get(&0), None);
+    assert_eq!(map.iter().next(), None);
+}
+
+#[test]
+fn test_insert_returns_new_map() {
+    let mut versions: Vec<PHashMap<u32, u32>> = vec![PHashMap::new()];
+    for i in 0..2000 {
+        let next = versions[i as usize].insert(i, i * 10);
+        versions.push(next);
+    }
+
+    for (len, version) in versions.iter().enumerate() {
+        assert_eq!(version.len(), len);
+        assert!((0..len as u32).all(|i| version.get(&i) == Some(&(i * 10))));
+        assert_eq!(version.get(&(len as u32)), None);
+    }
+
+    let map = &versions[2000];
+    let replaced = map.insert(5, 0);
+    assert_eq!(replaced.len(), 2000);
+    assert_eq!((map[&5], replaced[&5]), (50, 0));
+}
+
+#[test]
+fn test_borrowed_keys() {
+    let map: PHashMap<String, i32> = [("a".into(), 1), ("b".into(), 2)].into_iter().collect();
+    assert_eq!(map.get("a"), Some(&1));
+    assert_eq!(map.get_entry("b"), Some((&"b".to_string(), &2)));
+    assert!(!map.contains("c"));
+    assert_panics!({ let _ = map["c"]; });
+}
+
+// ============================================================================
+// Removal
+// ============================================================================
+
+#[test]
+fn test_remove_returns_new_map() {
+    let map: PHashMap<u32, u32> = (0..1000).map(|i| (i, i)).collect();
+    let removed = map.remove(&500);
+
+    assert_eq!((map.len(), removed.len()), (1000, 999));
+    assert_eq!(map.get(&500), Some(&500));
+    assert_eq!(removed.get(&500), None);
+    assert!((0..1000).filter(|i| *i != 500).all(|i| removed.get(&i) == Some(&i)));
+
+    // Removing a missing key shouldn't copy any nodes.
+    let unchanged = map.remove(&1000);
+    assert_eq!(map.root.as_ref().map(Rc::as_ptr), unchanged.root.as_ref().map(Rc::as_ptr));
+}
+
+#[test]
+fn test_remove_mut_to_empty() {
+    let mut map: PHashMap<u32, u32> = (0..1000).map(|i| (i, i)).collect();
+    let snapshot = map.clone();
+
+    for i in 0..1000 {
+        assert_eq!(map.remove_mut(&i), Some(i));
+        assert_eq!(map.remove_mut(&i), None);
+        assert_eq!(map.len(), 999 - i as usize);
+    }
+    assert!(map.root.is_none());
+    assert_eq!(snapshot.len(), 1000);
+    assert!((0..1000).all(|i| snapshot.get(&i) == Some(&i)));
+
+    map.insert_mut(1, 1);
+    assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&1, &1)]);
+}
+
+#[test]
+fn test_mut_only_copies_shared_nodes() {
+    let mut map: PHashMap<u32, u32> = (0..100).map(|i| (i, i)).collect();
+    let root = map.root.as_ref().map(Rc::as_ptr);
+
+    assert_eq!(map.insert_mut(5, 0), Some(5));
+    assert_eq!(map.remove_mut(&6), Some(6));
+    assert_eq!(map.root.as_ref().map(Rc::as_ptr), root, "Unique nodes shouldn't be copied.");
+
+    let snapshot = map.clone();
+    assert_eq!(map.insert_mut(100, 100), None);
+    assert_ne!(map.root.as_ref().map(Rc::as_ptr), root);
+    assert_eq!((snapshot.len(), map.len()), (99, 100));
+    assert!(!snapshot.contains(&100));
+}
+
+// ============================================================================
+// Collisions
+// ============================================================================
+
+#[test]
+fn test_colliding_keys() {
+    let mut map = PHashMap::<Colliding, u32>::new();
+    for i in 0..40 {
+        assert_eq!(map.insert_mut(Colliding(i), i), None);
+    }
+    assert_eq!(map.insert_mut(Colliding(7), 70), Some(7));
+    assert_eq!(map.len(), 40);
+    assert!((0..40).all(|i| map.contains(&Colliding(i))));
+    assert_eq!(map.get(&Colliding(7)), Some(&70));
+
+    let snapshot = map.clone();
+    for i in (0..40).rev() {
+        assert!(map.remove_mut(&Colliding(i)).is_some());
+        assert!(!map.contains(&Colliding(i)));
+        assert!((0..i).all(|j| map.contains(&Colliding(j))));
+    }
+    assert!(map.is_empty());
+    assert_eq!(snapshot.len(), 40);
+}
+
+// ============================================================================
+// Iteration and traits
+// ============================================================================
+
+#[test]
+fn test_iter_and_eq() {
+    let a: PHashMap<u32, u32> = (0..500).map(|i| (i, i * 2)).collect();
+    let b: PHashMap<u32, u32> = (0..500).rev().map(|i| (i, i * 2)).collect();
+
+    let mut pairs: Vec<_> = a.iter().map(|(k, v)| (*k, *v)).collect();
+    pairs.sort();
+    assert_eq!(pairs, (0..500).map(|i| (i, i * 2)).collect::<Vec<_>>());
+    assert_eq!(a.iter().len(), 500);
+
+    assert_eq!(a, b);
+    assert_ne!(a, b.insert(0, 1));
+    assert_ne!(a, b.remove(&0));
+    assert_eq!(format!("{:?}", PHashMap::<i32, i32>::new().insert(1, 2)), "{1: 2}");
+}
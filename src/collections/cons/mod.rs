@@ -1,6 +1,12 @@
 #![cfg(feature = "cons")]
 
+pub mod map;
 pub mod tree;
+pub mod vector;
 
 #[doc(inline)]
-pub use tree::ConsBranch;
\ No newline at end of file
+pub use map::PHashMap;
+#[doc(inline)]
+pub use tree::{ArcConsBranch, ConsBranch};
+#[doc(inline)]
+pub use vector::PVector;
\ No newline at end of file
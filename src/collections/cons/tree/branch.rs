@@ -1,6 +1,6 @@
-use std::{fmt::{self, Debug}, mem, ops::Deref, rc::Rc};
+use std::{fmt::{self, Debug}, hash::{Hash, Hasher}, mem, ops::Deref, rc::Rc, sync::Arc};
 
-use super::{Iter, OwnedIter, RcIter, UniqueIter};
+use super::{ArcKind, Iter, OwnedIter, PointerKind, RcIter, RcKind, UniqueIter};
 
 /// A references counted, linked list implemented similar to a cons list. This type is useful as an
 /// list of immutable items with cheap, shallow cloning, that can share nodes with other instances.
@@ -9,11 +9,39 @@ use super::{Iter, OwnedIter, RcIter, UniqueIter};
 /// included in both list. As a result, the data structure is only mutable from the head, where
 /// elements can be [`push`](Self::push)ed or [`pop`](Self::pop_to_owned)ped. This cheap cloning is
 /// helpful for implementing procedures such that include rollbacks or branching.
-#[derive(PartialEq, Eq, Hash)]
-pub struct ConsBranch<T> {
-    pub(crate) inner: Option<Rc<ConsNode<T>>>,
+///
+/// Nodes are shared using the pointer chosen by `P`, which is [`Rc`] by default. See
+/// [`ArcConsBranch`] for a version which can be shared between threads.
+pub struct ConsBranch<T, P: PointerKind = RcKind> {
+    pub(crate) inner: Option<P::Pointer<ConsNode<T, P>>>,
 }
 
+/// A [`ConsBranch`] which shares its nodes using [`Arc`] rather than [`Rc`], so that it is
+/// [`Send`] and [`Sync`] when `T` is. This is useful for branching work onto other threads, with
+/// each thread pushing onto its own clone of a shared branch.
+///
+/// # Examples
+/// ```
+/// # use std::thread;
+/// # use standard_lib::collections::cons::ArcConsBranch;
+/// let mut base = ArcConsBranch::empty();
+/// base.push(1);
+///
+/// thread::scope(|scope| {
+///     for i in 2..4 {
+///         let mut branch = base.clone();
+///         scope.spawn(move || {
+///             branch.push(i);
+///             assert!(branch.iter().eq(&[i, 1]));
+///         });
+///     }
+/// });
+/// ```
+pub type ArcConsBranch<T> = ConsBranch<T, ArcKind>;
+
+/// The node type of an [`ArcConsBranch`].
+pub type ArcConsNode<T> = ConsNode<T, ArcKind>;
+
 /// Largely intended as an internal type, these nodes are returned by [`ConsBranch::into_iter_rc`]
 /// because the interior of the [`Rc`] can't be unwrapped in place.
 ///
@@ -24,15 +52,24 @@ pub struct ConsBranch<T> {
 ///
 /// Note that cloning a `ConsNode` directly is _not_ cheap as it is with [`ConsBranch`] because
 /// the node contains the value (of type `T`) itself.
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct ConsNode<T> {
+pub struct ConsNode<T, P: PointerKind = RcKind> {
     pub(crate) value: T,
-    pub(crate) next: ConsBranch<T>,
+    pub(crate) next: ConsBranch<T, P>,
 }
 
 impl<T> ConsBranch<T> {
     /// Creates a new, empty `ConsBranch`.
+    ///
+    /// This is only defined for the default [`RcKind`], so that the pointer kind can be inferred.
+    /// Use [`empty`](Self::empty) to create a `ConsBranch` with any other [`PointerKind`].
     pub const fn new() -> ConsBranch<T> {
+        ConsBranch::empty()
+    }
+}
+
+impl<T, P: PointerKind> ConsBranch<T, P> {
+    /// Creates a new, empty `ConsBranch`, using the pointer kind `P`.
+    pub const fn empty() -> ConsBranch<T, P> {
         ConsBranch {
             inner: None
         }
@@ -43,7 +80,7 @@ impl<T> ConsBranch<T> {
     pub fn push(&mut self, value: T) {
         let old = mem::take(&mut self.inner);
 
-        self.inner = Some(Rc::new(ConsNode {
+        self.inner = Some(P::new(ConsNode {
             value,
             next: ConsBranch {
                 inner: old
@@ -63,19 +100,19 @@ impl<T> ConsBranch<T> {
 
     /// Produces a borrowed [`Iterator<Item = &T>`](Iter) over all elements in this list, both
     /// unique and shared.
-    pub fn iter(&self) -> Iter<'_, T> {
+    pub fn iter(&self) -> Iter<'_, T, P> {
         Iter {
             inner: self.inner.as_deref(),
         }
     }
 
     /// Produces an [`Iterator<Item = Rc<ConsNode<T>>>`](RcIter) over all of the underlying [`Rc`]
-    /// instances in this list.
+    /// instances in this list, or [`Arc`] instances for an [`ArcConsBranch`].
     ///
     /// Each referenced element is considered 'shared' for the lifetime of the [`Rc`] produced by
     /// this iterator. To return a `ConsBranch` to being unique, this iterator and all produced
     /// [`Rc`]s need to be dropped.
-    pub fn into_iter_rc(&self) -> RcIter<T> {
+    pub fn into_iter_rc(&self) -> RcIter<T, P> {
         RcIter {
             // We clone here because we are also cloning every step, there is no point taking an
             // owned self.
@@ -90,7 +127,7 @@ impl<T> ConsBranch<T> {
     pub fn is_unique(&self) -> bool {
         let mut next = &self.inner;
         while let Some(node) = next {
-            if !is_unqiue(node) {
+            if !P::is_unique(node) {
                 return false;
             }
             next = &node.next.inner;
@@ -101,60 +138,51 @@ impl<T> ConsBranch<T> {
     /// Returns `true` if the head element of this list is unique.
     pub fn is_head_unique(&self) -> bool {
         match &self.inner {
-            Some(node) => is_unqiue(node),
+            Some(node) => P::is_unique(node),
             None => true,
         }
     }
 
     /// Pops the head element of this list, if it is unique. Otherwise, `self` remains unchanged.
     pub fn pop_if_unique(&mut self) -> Option<T> {
-        if Rc::get_mut(self.inner.as_mut()?).is_none() {
-            return None;
-        }
-
-        // We've just confirmed that self.inner is Some and that it is unique.
-        let inner = mem::take(&mut self.inner).unwrap();
-        let ConsNode { value, next } = Rc::into_inner(inner).unwrap();
-        self.inner = next.inner;
+        let node = mem::take(&mut self.inner)?;
 
-        Some(value)
+        match P::try_unwrap(node) {
+            Ok(ConsNode { value, next }) => {
+                *self = next;
+                Some(value)
+            },
+            Err(node) => {
+                self.inner = Some(node);
+                None
+            },
+        }
     }
 
     /// Removes all unique items from this list and returns them as another `ConsBranch`.
-    pub fn split_off_unique(&mut self) -> ConsBranch<T> {
-        let mut node = match &mut self.inner {
-            Some(inner) => inner,
-            // The list is empty.
-            None => return ConsBranch::new(),
-        };
-
-        let mut node_mut = match Rc::get_mut(node) {
-            Some(node_mut) => node_mut,
-            // The list contains items, but none are uniquely referenced.
-            None => return ConsBranch::new(),
-        };
-
-        loop {
-            // We need to borrow the next node once once as a reference and then conditionally, as a
-            // mutable reference.
-            if let Some(true) = node_mut.next.inner.as_ref().map(is_unqiue) {
-                // We know that node_mut.next.inner is Some, but we couldn't borrow it as a
-                // mutable reference until we knew it was unique.
-                node = node_mut.next.inner.as_mut().unwrap();
-            } else {
-                // node_mut.next.inner is either None or a non unique node. For both cases, we take
-                // it as the shared head of the tree and replace it with a None.
-                let shared_head = mem::take(&mut node_mut.next.inner);
-                // We then replace the inner value with it, leaving self as entirely shared and
-                // returning the head of the unique portion.
-                return ConsBranch {
-                    inner: mem::replace(&mut self.inner, shared_head),
-                };
-            }
+    pub fn split_off_unique(&mut self) -> ConsBranch<T, P> {
+        // Walk past the unique nodes at the start of the list to the link which holds the shared
+        // head of the list. Uniqueness is checked with P::get_mut rather than P::is_unique, because
+        // with ArcKind another thread may briefly upgrade a weak pointer to a node while it is
+        // being checked. Once P::get_mut succeeds, there are no other pointers to the node which
+        // could create new ones, so calling it again straight away also succeeds.
+        let mut unique = 0;
+        let mut rest = &mut self.inner;
+        while rest.as_mut().is_some_and(|node| P::get_mut(node).is_some()) {
+            unique += 1;
+            let Some(node) = rest.as_mut().and_then(P::get_mut) else {
+                unreachable!("P::get_mut just succeeded on this node.");
+            };
+            rest = &mut node.next.inner;
+        }
 
-            // We've already checked that the node is unqiue, and diverged otherwise.
-            // Rc is !Sync, so we don't have to worry about TOCTOU.
-            node_mut = Rc::get_mut(node).unwrap();
+        if unique == 0 { return ConsBranch::empty(); }
+
+        // Take the shared portion from the last unique node, and then replace the head of self
+        // with it, leaving self as entirely shared and returning the head of the unique portion.
+        let shared_head = mem::take(rest);
+        ConsBranch {
+            inner: mem::replace(&mut self.inner, shared_head),
         }
     }
 
@@ -166,14 +194,14 @@ impl<T> ConsBranch<T> {
     ///
     /// If called on every clone of a single initial `ConsBranch`, every element of the tree will be
     /// returned by an iterator only once.
-    pub const fn into_iter_unique(self) -> UniqueIter<T> {
+    pub const fn into_iter_unique(self) -> UniqueIter<T, P> {
         UniqueIter {
             inner: self,
         }
     }
 }
 
-impl<T: Clone> ConsBranch<T> {
+impl<T: Clone, P: PointerKind> ConsBranch<T, P> {
     /// Pops the head element from this list, cloning if it is shared by another `ConsBranch`.
     /// Regardless of if a clone is required, the head of this list will be updated.
     pub fn pop_to_owned(&mut self) -> Option<T> {
@@ -181,8 +209,8 @@ impl<T: Clone> ConsBranch<T> {
 
         match inner {
             Some(node) => {
-                let ConsNode { value, next } = Rc::unwrap_or_clone(node);
-                self.inner = next.inner;
+                let ConsNode { value, next } = P::unwrap_or_clone(node);
+                *self = next;
                 Some(value)
             },
             None => {
@@ -194,7 +222,7 @@ impl<T: Clone> ConsBranch<T> {
 
     /// Produces an [`Iterator<Item = T>`](OwnedIter) over all elements in this list, returning
     /// owned items by cloning any shared elements.
-    pub const fn into_iter_owned(self) -> OwnedIter<T> {
+    pub const fn into_iter_owned(self) -> OwnedIter<T, P> {
         OwnedIter {
             inner: self,
         }
@@ -202,17 +230,28 @@ impl<T: Clone> ConsBranch<T> {
 
     /// Produces a deep clone of this `ConsBranch`. The result has a clone of every element in this
     /// list, without sharing any. The result is unique.
-    pub fn deep_clone(&self) -> ConsBranch<T> {
-        let refs: Vec<_> = self.iter().collect();
+    pub fn deep_clone(&self) -> ConsBranch<T, P> {
+        let mut res = ConsBranch::empty();
+
+        // Build the clone from front to back, by appending each node to the end of the last one.
+        // Every node in the clone is unique, so the last node can always be mutated.
+        let mut end = &mut res.inner;
+        for value in self.iter() {
+            let node = end.insert(P::new(ConsNode {
+                value: value.clone(),
+                next: ConsBranch::empty(),
+            }));
+            let Some(node) = P::get_mut(node) else {
+                unreachable!("The node was just created, so it is unique.");
+            };
+            end = &mut node.next.inner;
+        }
 
-        refs.into_iter()
-            .rev()
-            .cloned()
-            .collect()
+        res
     }
 }
 
-impl<T> Clone for ConsBranch<T> {
+impl<T, P: PointerKind> Clone for ConsBranch<T, P> {
     /// Creates a cheap (shallow) clone of this `ConsBranch`, with all the same underlying elements.
     /// After cloning, all elements of the list are considered 'shared' between the original list
     /// and the clone.
@@ -223,19 +262,69 @@ impl<T> Clone for ConsBranch<T> {
     }
 }
 
-fn is_unqiue<T>(value: &Rc<T>) -> bool {
-    Rc::strong_count(value) == 1
+impl<T: Clone, P: PointerKind> Clone for ConsNode<T, P> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            next: self.next.clone(),
+        }
+    }
+}
+
+impl<T, P: PointerKind> Drop for ConsBranch<T, P> {
+    fn drop(&mut self) {
+        // Dropping a node would drop the rest of the list through it, recursing once per node. To
+        // avoid overflowing the stack, detach the rest of the list from each unique node before it
+        // is dropped. The first shared node is only released, which ends the loop.
+        let mut next = self.inner.take();
+        while let Some(node) = next {
+            next = P::into_inner(node).and_then(|mut node| node.next.inner.take());
+        }
+    }
+}
+
+impl<T: PartialEq, P: PointerKind> PartialEq for ConsBranch<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, P: PointerKind> Eq for ConsBranch<T, P> {}
+
+impl<T: PartialEq, P: PointerKind> PartialEq for ConsNode<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.next == other.next
+    }
+}
+
+impl<T: Eq, P: PointerKind> Eq for ConsNode<T, P> {}
+
+impl<T: Hash, P: PointerKind> Hash for ConsBranch<T, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hashed as nested options of each node, without recursing through them.
+        for value in self.iter() {
+            Some(value).hash(state);
+        }
+        None::<&T>.hash(state);
+    }
+}
+
+impl<T: Hash, P: PointerKind> Hash for ConsNode<T, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+        self.next.hash(state);
+    }
 }
 
-impl<T> Default for ConsBranch<T> {
+impl<T, P: PointerKind> Default for ConsBranch<T, P> {
     fn default() -> Self {
-        Self::new()
+        Self::empty()
     }
 }
 
-impl<T> FromIterator<T> for ConsBranch<T> {
+impl<T, P: PointerKind> FromIterator<T> for ConsBranch<T, P> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut res = ConsBranch::new();
+        let mut res = ConsBranch::empty();
         for item in iter {
             res.push(item);
         }
@@ -251,7 +340,15 @@ impl<T> From<Rc<ConsNode<T>>> for ConsBranch<T> {
     }
 }
 
-impl<T> Deref for ConsNode<T> {
+impl<T> From<Arc<ArcConsNode<T>>> for ArcConsBranch<T> {
+    fn from(value: Arc<ArcConsNode<T>>) -> Self {
+        ConsBranch {
+            inner: Some(value),
+        }
+    }
+}
+
+impl<T, P: PointerKind> Deref for ConsNode<T, P> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -259,24 +356,24 @@ impl<T> Deref for ConsNode<T> {
     }
 }
 
-impl<T> AsRef<T> for ConsNode<T> {
+impl<T, P: PointerKind> AsRef<T> for ConsNode<T, P> {
     fn as_ref(&self) -> &T {
         self.deref()
     }
 }
 
-impl<T: Debug> Debug for ConsBranch<T> {
+impl<T: Debug, P: PointerKind> Debug for ConsBranch<T, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.inner {
+        match self.inner.as_deref() {
             Some(node) => write!(f, "{:?}", node),
             None => write!(f, "()"),
         }
     }
 }
 
-impl<T: Debug> Debug for ConsNode<T> {
+impl<T: Debug, P: PointerKind> Debug for ConsNode<T, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.next.inner {
+        match self.next.inner.as_deref() {
             Some(node) => write!(f, "({:?}->{:?})", self.value, node),
             None => write!(f, "({:?})", self.value),
         }
@@ -1,13 +1,13 @@
-use std::{mem, rc::Rc};
+use std::mem;
 
-use super::{ConsBranch, ConsNode};
+use super::{ConsBranch, ConsNode, PointerKind, RcKind};
 
 /// See [`ConsBranch::iter`].
-pub struct Iter<'a, T> {
-    pub(crate) inner: Option<&'a ConsNode<T>>,
+pub struct Iter<'a, T, P: PointerKind = RcKind> {
+    pub(crate) inner: Option<&'a ConsNode<T, P>>,
 }
 
-impl<'a, T> Iterator for Iter<'a, T> {
+impl<'a, T, P: PointerKind> Iterator for Iter<'a, T, P> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -17,7 +17,7 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
-impl<'a, T> Clone for Iter<'a, T> {
+impl<'a, T, P: PointerKind> Clone for Iter<'a, T, P> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
@@ -26,18 +26,18 @@ impl<'a, T> Clone for Iter<'a, T> {
 }
 
 /// See [`ConsBranch::into_iter_owned`].
-pub struct OwnedIter<T: Clone> {
-    pub(crate) inner: ConsBranch<T>,
+pub struct OwnedIter<T: Clone, P: PointerKind = RcKind> {
+    pub(crate) inner: ConsBranch<T, P>,
 }
 
-impl<T: Clone> OwnedIter<T> {
+impl<T: Clone, P: PointerKind> OwnedIter<T, P> {
     /// Returns all remaining elements of this iterator, as a [`ConsBranch`].
-    pub fn remainder(self) -> ConsBranch<T> {
+    pub fn remainder(self) -> ConsBranch<T, P> {
         self.inner
     }
 }
 
-impl<T: Clone> Iterator for OwnedIter<T> {
+impl<T: Clone, P: PointerKind> Iterator for OwnedIter<T, P> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -46,20 +46,20 @@ impl<T: Clone> Iterator for OwnedIter<T> {
 }
 
 /// See [`ConsBranch::into_iter_unique`].
-pub struct UniqueIter<T> {
-    pub(crate) inner: ConsBranch<T>,
+pub struct UniqueIter<T, P: PointerKind = RcKind> {
+    pub(crate) inner: ConsBranch<T, P>,
 }
 
-impl<T> UniqueIter<T> {
+impl<T, P: PointerKind> UniqueIter<T, P> {
     /// Returns all remaining elements of this iterator, as a [`ConsBranch`]. When used on an
     /// exhausted `UniqueIter`, the list returned will contain all the shared items (of which there
     /// may be none).
-    pub fn remainder(self) -> ConsBranch<T> {
+    pub fn remainder(self) -> ConsBranch<T, P> {
         self.inner
     }
 }
 
-impl<T> Iterator for UniqueIter<T> {
+impl<T, P: PointerKind> Iterator for UniqueIter<T, P> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -67,20 +67,21 @@ impl<T> Iterator for UniqueIter<T> {
     }
 }
 
-/// See [`ConsBranch::into_iter_rc`].
-pub struct RcIter<T> {
-    pub(crate) inner: ConsBranch<T>,
+/// See [`ConsBranch::into_iter_rc`]. Despite the name, this produces whichever pointer the branch
+/// uses, so [`Arc`](std::sync::Arc)s for an [`ArcConsBranch`](super::ArcConsBranch).
+pub struct RcIter<T, P: PointerKind = RcKind> {
+    pub(crate) inner: ConsBranch<T, P>,
 }
 
-impl<T> RcIter<T> {
+impl<T, P: PointerKind> RcIter<T, P> {
     /// Returns all remaining elements of this iterator, as a [`ConsBranch`].
-    pub fn remainder(self) -> ConsBranch<T> {
+    pub fn remainder(self) -> ConsBranch<T, P> {
         self.inner
     }
 }
 
-impl<T> Iterator for RcIter<T> {
-    type Item = Rc<ConsNode<T>>;
+impl<T, P: PointerKind> Iterator for RcIter<T, P> {
+    type Item = P::Pointer<ConsNode<T, P>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let inner = mem::take(&mut self.inner.inner);
@@ -1,8 +1,10 @@
 mod branch;
 mod iter;
+mod pointer;
 
 pub use branch::*;
 pub use iter::*;
+pub use pointer::*;
 
 #[cfg(test)]
-mod test;
\ No newline at end of file
+mod test;
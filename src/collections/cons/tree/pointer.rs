@@ -0,0 +1,105 @@
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// A kind of reference counted pointer, which a [`ConsBranch`](super::ConsBranch) uses to share
+/// nodes with its clones. All of the node sharing logic is written in terms of this trait, so that
+/// it works the same way regardless of the pointer used.
+///
+/// Implemented by [`RcKind`] for [`Rc`], which is the default, and by [`ArcKind`] for [`Arc`],
+/// which allows branches to be sent and shared between threads.
+pub trait PointerKind {
+    /// The pointer type itself, for example `Rc<T>`.
+    type Pointer<T>: Deref<Target = T> + Clone;
+
+    /// Moves `value` into a new pointer.
+    fn new<T>(value: T) -> Self::Pointer<T>;
+
+    /// Returns true if `ptr` is the only pointer to its value, including weak pointers.
+    fn is_unique<T>(ptr: &Self::Pointer<T>) -> bool;
+
+    /// Returns a mutable reference to the value if `ptr` is the only pointer to it.
+    fn get_mut<T>(ptr: &mut Self::Pointer<T>) -> Option<&mut T>;
+
+    /// Returns the value if `ptr` is the only strong pointer to it, otherwise returns `ptr`
+    /// unchanged.
+    ///
+    /// # Errors
+    /// Returns `ptr` as an [`Err`] if there are other strong pointers to the value.
+    fn try_unwrap<T>(ptr: Self::Pointer<T>) -> Result<T, Self::Pointer<T>>;
+
+    /// Returns the value if `ptr` is the only strong pointer to it, otherwise drops `ptr`. If
+    /// several pointers to a value are passed to this concurrently, exactly one returns the value.
+    fn into_inner<T>(ptr: Self::Pointer<T>) -> Option<T>;
+
+    /// Returns the value, cloning it if there are other pointers to it.
+    fn unwrap_or_clone<T: Clone>(ptr: Self::Pointer<T>) -> T;
+}
+
+/// The [`PointerKind`] for [`Rc`], used by [`ConsBranch`](super::ConsBranch) by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RcKind;
+
+impl PointerKind for RcKind {
+    type Pointer<T> = Rc<T>;
+
+    fn new<T>(value: T) -> Rc<T> {
+        Rc::new(value)
+    }
+
+    fn is_unique<T>(ptr: &Rc<T>) -> bool {
+        Rc::strong_count(ptr) == 1 && Rc::weak_count(ptr) == 0
+    }
+
+    fn get_mut<T>(ptr: &mut Rc<T>) -> Option<&mut T> {
+        Rc::get_mut(ptr)
+    }
+
+    fn try_unwrap<T>(ptr: Rc<T>) -> Result<T, Rc<T>> {
+        Rc::try_unwrap(ptr)
+    }
+
+    fn into_inner<T>(ptr: Rc<T>) -> Option<T> {
+        Rc::into_inner(ptr)
+    }
+
+    fn unwrap_or_clone<T: Clone>(ptr: Rc<T>) -> T {
+        Rc::unwrap_or_clone(ptr)
+    }
+}
+
+/// The [`PointerKind`] for [`Arc`], used by [`ArcConsBranch`](super::ArcConsBranch).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArcKind;
+
+impl PointerKind for ArcKind {
+    type Pointer<T> = Arc<T>;
+
+    fn new<T>(value: T) -> Arc<T> {
+        Arc::new(value)
+    }
+
+    fn is_unique<T>(ptr: &Arc<T>) -> bool {
+        // The two counts are loaded separately, so if another thread holds a weak pointer, it can
+        // upgrade and drop it between the loads, making this return true while another strong
+        // pointer briefly exists. The result is only a snapshot, so anything which relies on
+        // uniqueness to mutate the value must use get_mut instead.
+        Arc::strong_count(ptr) == 1 && Arc::weak_count(ptr) == 0
+    }
+
+    fn get_mut<T>(ptr: &mut Arc<T>) -> Option<&mut T> {
+        Arc::get_mut(ptr)
+    }
+
+    fn try_unwrap<T>(ptr: Arc<T>) -> Result<T, Arc<T>> {
+        Arc::try_unwrap(ptr)
+    }
+
+    fn into_inner<T>(ptr: Arc<T>) -> Option<T> {
+        Arc::into_inner(ptr)
+    }
+
+    fn unwrap_or_clone<T: Clone>(ptr: Arc<T>) -> T {
+        Arc::unwrap_or_clone(ptr)
+    }
+}
@@ -447,3 +447,123 @@ fn test_iter_clone_independence() {
 
     assert_eq!(iter1.copied().collect::<Vec<_>>(), iter2.copied().collect::<Vec<_>>());
 }
+
+#[test]
+fn test_keep_unique_with_weak_node() {
+    let mut tree: ConsBranch<i32> = vec![1, 2, 3].into_iter().collect();
+    let node = tree.into_iter_rc().nth(1).expect("The tree should have a second node.");
+    let _weak = std::rc::Rc::downgrade(&node);
+    drop(node);
+
+    // The second node has a weak pointer, so it can't be mutated and isn't unique.
+    let unique = tree.split_off_unique();
+    assert_eq!(unique.iter().copied().collect::<Vec<_>>(), vec![3]);
+    assert_eq!(tree.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+}
+
+// ============================================================================
+// ArcConsBranch
+// ============================================================================
+
+#[test]
+fn test_arc_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ArcConsBranch<i32>>();
+    assert_send_sync::<ArcConsNode<i32>>();
+}
+
+#[test]
+fn test_arc_shares_between_threads() {
+    let mut base = ArcConsBranch::empty();
+    base.push(1);
+    base.push(2);
+
+    let branches: Vec<_> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (3..6)
+            .map(|i| {
+                let mut branch = base.clone();
+                scope.spawn(move || {
+                    branch.push(i);
+                    branch
+                })
+            })
+            .collect();
+        handles.into_iter().map(|handle| handle.join().expect("Pushing shouldn't panic.")).collect()
+    });
+
+    for (i, branch) in (3..6).zip(&branches) {
+        assert_eq!(branch.iter().copied().collect::<Vec<_>>(), vec![i, 2, 1]);
+        assert!(branch.is_head_unique());
+        assert!(!branch.is_unique());
+    }
+}
+
+#[test]
+fn test_arc_unique_operations() {
+    let mut tree: ArcConsBranch<i32> = vec![1, 2].into_iter().collect();
+    let shared = tree.clone();
+    tree.push(3);
+    tree.push(4);
+
+    let unique = tree.clone().split_off_unique();
+    assert!(unique.is_empty());
+
+    let unique = tree.split_off_unique();
+    assert_eq!(unique.into_iter_unique().collect::<Vec<_>>(), vec![4, 3]);
+    assert_eq!(tree, shared);
+
+    assert_eq!(tree.pop_if_unique(), None);
+    assert_eq!(tree.pop_to_owned(), Some(2));
+    assert_eq!(shared.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+
+    let deep = shared.deep_clone();
+    assert!(deep.is_unique());
+    assert_eq!(deep, shared);
+}
+
+#[test]
+fn test_arc_into_iter_rc() {
+    let mut tree = ArcConsBranch::empty();
+    tree.push(1);
+    tree.push(2);
+
+    let nodes: Vec<std::sync::Arc<ArcConsNode<i32>>> = tree.into_iter_rc().collect();
+    assert_eq!(nodes.iter().map(|n| n.value).collect::<Vec<_>>(), vec![2, 1]);
+
+    let branch: ArcConsBranch<i32> = nodes[1].clone().into();
+    assert_eq!(branch.iter().copied().collect::<Vec<_>>(), vec![1]);
+}
+
+// ============================================================================
+// Long branches
+// ============================================================================
+
+#[test]
+fn test_long_branch_on_small_stack() {
+    use std::hash::{BuildHasher, RandomState};
+
+    // Each of these would recurse once per node if they weren't iterative, overflowing the stack.
+    let handle = std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            let mut tree = ConsBranch::new();
+            for i in 0..10_000_000 {
+                tree.push(i);
+            }
+            let shared = tree.clone();
+            tree.push(-1);
+            drop(shared);
+            drop(tree);
+
+            let mut tree: ConsBranch<i32> = (0..1_000_000).collect();
+            let deep = tree.deep_clone();
+            assert!(deep == tree);
+            let hasher = RandomState::new();
+            assert_eq!(hasher.hash_one(&deep), hasher.hash_one(&tree));
+
+            tree.push(-1);
+            assert!(deep != tree);
+        })
+        .expect("Spawning the test thread should succeed.");
+    handle.join().expect("The branch operations shouldn't overflow the stack.");
+}
@@ -0,0 +1,76 @@
+use std::iter::FusedIterator;
+
+use super::PVector;
+
+/// See [`PVector::iter`].
+pub struct Iter<'a, T> {
+    pub(crate) vec: &'a PVector<T>,
+    pub(crate) front: usize,
+    pub(crate) back: usize,
+    // The rest of the leaf containing front, and the start of the leaf containing back - 1. These
+    // can overlap when both ends are in the same leaf, but front and back prevent any overlapping
+    // values from being produced twice.
+    pub(crate) front_leaf: &'a [T],
+    pub(crate) back_leaf: &'a [T],
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None; }
+
+        if self.front_leaf.is_empty() {
+            self.front_leaf = self.vec.leaf(self.front).split_at(self.front % super::WIDTH).1;
+        }
+        let (value, rest) = self.front_leaf.split_first()?;
+        self.front_leaf = rest;
+        self.front += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None; }
+
+        if self.back_leaf.is_empty() {
+            let last = self.back - 1;
+            self.back_leaf = self.vec.leaf(last).split_at(last % super::WIDTH + 1).0;
+        }
+        let (value, rest) = self.back_leaf.split_last()?;
+        self.back_leaf = rest;
+        self.back -= 1;
+        Some(value)
+    }
+}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PVector<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            vec: self,
+            front: 0,
+            back: self.len,
+            front_leaf: &[],
+            back_leaf: &[],
+        }
+    }
+}
@@ -0,0 +1,10 @@
+mod iter;
+mod node;
+mod pvector;
+
+pub use iter::*;
+pub(crate) use node::*;
+pub use pvector::*;
+
+#[cfg(test)]
+mod test;
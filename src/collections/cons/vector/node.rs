@@ -0,0 +1,51 @@
+use std::rc::Rc;
+
+use crate::collections::contiguous::ArrayVec;
+
+/// The number of bits of an index which are used at each level of the trie.
+pub(crate) const BITS: u32 = 5;
+/// The maximum number of children of a branch, or values in a leaf.
+pub(crate) const WIDTH: usize = 1 << BITS;
+/// A mask for the bits of an index which are used at a single level of the trie.
+pub(crate) const MASK: usize = WIDTH - 1;
+
+/// A node of a [`PVector`](super::PVector)'s trie. Every leaf is at the same depth, and every node
+/// except for those along the right edge of the trie is full.
+///
+/// Nodes are always stored behind an [`Rc`], so the size of the larger variant isn't worth boxing.
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum Node<T> {
+    Branch(ArrayVec<Rc<Node<T>>, WIDTH>),
+    Leaf(ArrayVec<T, WIDTH>),
+}
+
+impl<T> Node<T> {
+    /// Creates a path of nodes down to a new leaf containing only `value`, with the top of the path
+    /// at the level `shift`.
+    pub(crate) fn path(shift: u32, value: T) -> Rc<Node<T>> {
+        let mut values = ArrayVec::new();
+        values.push(value);
+
+        let mut node = Rc::new(Node::Leaf(values));
+        for _ in 0..shift / BITS {
+            let mut children = ArrayVec::new();
+            children.push(node);
+            node = Rc::new(Node::Branch(children));
+        }
+        node
+    }
+}
+
+impl<T: Clone> Node<T> {
+    /// Returns the only value in a path of nodes, as created by [`path`](Node::path), cloning any
+    /// nodes that are shared.
+    pub(crate) fn into_only(mut node: Rc<Node<T>>) -> Option<T> {
+        loop {
+            match Rc::unwrap_or_clone(node) {
+                Node::Branch(mut children) => node = children.pop()?,
+                Node::Leaf(mut values) => return values.pop(),
+            }
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ops::Index;
+use std::rc::Rc;
+
+use super::{BITS, Iter, MASK, Node, WIDTH};
+use crate::collections::contiguous::ArrayVec;
+use crate::util::error::IndexOutOfBounds;
+use crate::util::result::ResultExtension;
+
+/// A persistent vector, implemented as a trie with 32 children per node. Like a
+/// [`ConsBranch`](crate::collections::cons::ConsBranch), cloning a `PVector` is cheap, with the
+/// clone sharing all of its nodes with the original. Unlike a `ConsBranch`, any element can be
+/// accessed in `O(log32 n)`, which is effectively constant.
+///
+/// Updates can be made in two ways:
+/// - [`set`](PVector::set), [`push_back`](PVector::push_back) and [`pop_back`](PVector::pop_back)
+///   leave the vector unchanged, returning a new version which shares all but the updated path of
+///   nodes with it.
+/// - [`set_mut`](PVector::set_mut), [`push_back_mut`](PVector::push_back_mut) and
+///   [`pop_back_mut`](PVector::pop_back_mut) update the vector in place. Nodes which are unique to
+///   this vector are modified directly, and only shared nodes are copied, so a series of updates to
+///   a vector with no clones doesn't copy anything.
+///
+/// # Time Complexity
+/// For this analysis of time complexity, variables are defined as follows:
+/// - `n`: The number of items in the PVector.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `clone` | `O(1)` |
+/// | `len` | `O(1)` |
+/// | `get` | `O(log32 n)` |
+/// | `set`, `set_mut` | `O(log32 n)` |
+/// | `push_back`, `push_back_mut` | `O(log32 n)` |
+/// | `pop_back`, `pop_back_mut` | `O(log32 n)` |
+///
+/// # Examples
+/// ```
+/// # use standard_lib::collections::cons::PVector;
+/// let v1: PVector<_> = (0..100).collect();
+/// let v2 = v1.set(50, -1).push_back(100);
+///
+/// assert_eq!(v1.get(50), Some(&50));
+/// assert_eq!(v2.get(50), Some(&-1));
+/// assert_eq!((v1.len(), v2.len()), (100, 101));
+/// ```
+pub struct PVector<T> {
+    pub(crate) root: Option<Rc<Node<T>>>,
+    pub(crate) len: usize,
+    // The number of bits that an index is shifted by to find the child of the root, which is a
+    // multiple of BITS and 0 when the root is a leaf.
+    pub(crate) shift: u32,
+}
+
+impl<T> PVector<T> {
+    /// Creates a new, empty PVector.
+    pub const fn new() -> PVector<T> {
+        PVector {
+            root: None,
+            len: 0,
+            shift: 0,
+        }
+    }
+
+    /// Returns the length of the PVector.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the PVector contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at `index`, or [`None`] if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len { return None; }
+        self.leaf(index).get(index & MASK)
+    }
+
+    /// Returns an iterator over references to the elements of the PVector.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.into_iter()
+    }
+}
+
+impl<T: Clone> PVector<T> {
+    /// Returns a new version of the PVector with the element at `index` replaced by `value`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set(&self, index: usize, value: T) -> PVector<T> {
+        self.try_set(index, value).throw()
+    }
+
+    /// Returns a new version of the PVector with the element at `index` replaced by `value`,
+    /// returning an [`Err`] rather than panicking if `index` is out of bounds.
+    pub fn try_set(&self, index: usize, value: T) -> Result<PVector<T>, IndexOutOfBounds> {
+        let mut res = self.clone();
+        res.try_set_mut(index, value)?;
+        Ok(res)
+    }
+
+    /// Returns a new version of the PVector with `value` added to the back.
+    pub fn push_back(&self, value: T) -> PVector<T> {
+        let mut res = self.clone();
+        res.push_back_mut(value);
+        res
+    }
+
+    /// Returns a new version of the PVector with the last element removed, along with that
+    /// element, or [`None`] if the PVector is empty.
+    pub fn pop_back(&self) -> Option<(PVector<T>, T)> {
+        let mut res = self.clone();
+        let value = res.pop_back_mut()?;
+        Some((res, value))
+    }
+
+    /// Returns a mutable reference to the element at `index`, or [`None`] if it is out of bounds.
+    /// Any shared nodes on the path to the element are copied first, so that other versions are
+    /// unaffected.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len { return None; }
+
+        let mut node = Rc::make_mut(self.root.as_mut()?);
+        let mut shift = self.shift;
+        loop {
+            match node {
+                Node::Branch(children) => {
+                    node = Rc::make_mut(children.get_mut((index >> shift) & MASK)?);
+                    shift -= BITS;
+                },
+                Node::Leaf(values) => return values.get_mut(index & MASK),
+            }
+        }
+    }
+
+    /// Replaces the element at `index` with `value` in place, returning the old element.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set_mut(&mut self, index: usize, value: T) -> T {
+        self.try_set_mut(index, value).throw()
+    }
+
+    /// Replaces the element at `index` with `value` in place, returning the old element, or an
+    /// [`Err`] rather than panicking if `index` is out of bounds.
+    pub fn try_set_mut(&mut self, index: usize, value: T) -> Result<T, IndexOutOfBounds> {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(old) => Ok(mem::replace(old, value)),
+            None => Err(IndexOutOfBounds { index, len }),
+        }
+    }
+
+    /// Adds `value` to the back of the PVector in place.
+    pub fn push_back_mut(&mut self, value: T) {
+        let index = self.len;
+        match &mut self.root {
+            None => self.root = Some(Node::path(0, value)),
+            // The trie is full, so add a level above the root with a new path beside it.
+            Some(_) if index == WIDTH << self.shift => {
+                let mut children = ArrayVec::new();
+                children.extend(self.root.take());
+                children.push(Node::path(self.shift, value));
+
+                self.root = Some(Rc::new(Node::Branch(children)));
+                self.shift += BITS;
+            },
+            Some(root) => {
+                let mut node = Rc::make_mut(root);
+                let mut shift = self.shift;
+                loop {
+                    match node {
+                        Node::Leaf(values) => break values.push(value),
+                        Node::Branch(children) => {
+                            let child = (index >> shift) & MASK;
+                            if child == children.len() {
+                                break children.push(Node::path(shift - BITS, value));
+                            }
+                            node = Rc::make_mut(&mut children[child]);
+                            shift -= BITS;
+                        },
+                    }
+                }
+            },
+        }
+        self.len += 1;
+    }
+
+    /// Removes the last element of the PVector in place and returns it, or [`None`] if the PVector
+    /// is empty.
+    pub fn pop_back_mut(&mut self) -> Option<T> {
+        let index = self.len.checked_sub(1)?;
+        let value = if index == 0 {
+            self.shift = 0;
+            Node::into_only(self.root.take()?)
+        } else {
+            let mut node = Rc::make_mut(self.root.as_mut()?);
+            let mut shift = self.shift;
+            loop {
+                match node {
+                    Node::Leaf(values) => break values.pop(),
+                    Node::Branch(children) => {
+                        // If the last element is the first in its child, the whole child goes.
+                        if index & ((1 << shift) - 1) == 0 {
+                            break children.pop().and_then(Node::into_only);
+                        }
+                        node = Rc::make_mut(children.last_mut()?);
+                        shift -= BITS;
+                    },
+                }
+            }
+        };
+        self.len = index;
+
+        // Remove any levels above the root which only have a single child.
+        while self.shift > 0 && self.len <= 1 << self.shift {
+            let Some(Node::Branch(children)) = self.root.as_deref() else { break };
+            self.root = children.first().cloned();
+            self.shift -= BITS;
+        }
+        value
+    }
+}
+
+impl<T> PVector<T> {
+    /// Returns the leaf which contains the element at `index`, which must be in bounds.
+    pub(crate) fn leaf(&self, index: usize) -> &[T] {
+        let mut node = self.root.as_deref();
+        let mut shift = self.shift;
+        while let Some(Node::Branch(children)) = node {
+            node = children.get((index >> shift) & MASK).map(Rc::as_ref);
+            shift = shift.saturating_sub(BITS);
+        }
+
+        match node {
+            Some(Node::Leaf(values)) => values,
+            _ => &[],
+        }
+    }
+}
+
+impl<T> Clone for PVector<T> {
+    /// Creates a cheap (shallow) clone of this `PVector`, which shares all of its nodes.
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            len: self.len,
+            shift: self.shift,
+        }
+    }
+}
+
+impl<T> Default for PVector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<usize> for PVector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).ok_or(IndexOutOfBounds { index, len: self.len }).throw()
+    }
+}
+
+impl<T: Clone> FromIterator<T> for PVector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut res = PVector::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<T: Clone> Extend<T> for PVector<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back_mut(value);
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for PVector<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for PVector<T> {}
+
+impl<T: Hash> Hash for PVector<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T: Debug> Debug for PVector<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
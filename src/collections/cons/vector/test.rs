@@ -0,0 +1,151 @@
+use std::mem;
+use std::rc::Rc;
+
+use super::*;
+use crate::util::panic::assert_panics;
+
+// ============================================================================
+// PVector::new / get / push_back_mut
+// ============================================================================
+
+#[test]
+fn test_new_is_empty() {
+    let vec: PVector<i32> = PVector::new();
+    assert!(vec.is_empty());
+    assert_eq!(vec.get(0), None);
+    assert_eq!(vec.iter().next(), None);
+}
+
+#[test]
+fn test_push_and_get_across_levels() {
+    // Enough elements for a trie with three levels of branches.
+    let vec: PVector<usize> = (0..40_000).collect();
+    assert_eq!(vec.len(), 40_000);
+    assert_eq!(vec.shift, 3 * BITS);
+    assert!((0..40_000).all(|i| vec.get(i) == Some(&i)));
+    assert_eq!(vec.get(40_000), None);
+    assert_eq!(vec[1234], 1234);
+}
+
+#[test]
+fn test_index_out_of_bounds() {
+    let vec: PVector<i32> = (0..10).collect();
+    assert!(vec.try_set(10, 0).is_err());
+    assert_panics!({ let _ = vec[10]; });
+    assert_panics!({ vec.set(10, 0); });
+}
+
+// ============================================================================
+// Persistent updates
+// ============================================================================
+
+#[test]
+fn test_set_returns_new_version() {
+    let v1: PVector<usize> = (0..2000).collect();
+    let v2 = v1.set(1500, 0);
+
+    assert_eq!(v1[1500], 1500);
+    assert_eq!(v2[1500], 0);
+    assert!(v1.iter().zip(&v2).filter(|(a, b)| a != b).count() == 1);
+
+    // Only the path to the updated element is copied.
+    let (Some(Node::Branch(a)), Some(Node::Branch(b))) = (v1.root.as_deref(), v2.root.as_deref())
+    else {
+        panic!("Expected both roots to be branches.");
+    };
+    assert!(Rc::ptr_eq(&a[0], &b[0]));
+    assert!(!Rc::ptr_eq(&a[1], &b[1]));
+}
+
+#[test]
+fn test_push_and_pop_return_new_versions() {
+    let mut versions = vec![PVector::new()];
+    for i in 0..1100 {
+        let next = versions[i].push_back(i);
+        versions.push(next);
+    }
+
+    for (len, version) in versions.iter().enumerate() {
+        assert_eq!(version.len(), len);
+        assert!(version.iter().copied().eq(0..len));
+    }
+
+    let last = &versions[1100];
+    assert_eq!(last.pop_back(), Some((versions[1099].clone(), 1099)));
+    assert_eq!(last.len(), 1100);
+}
+
+// ============================================================================
+// Transient updates
+// ============================================================================
+
+#[test]
+fn test_mut_only_copies_shared_nodes() {
+    let mut vec: PVector<usize> = (0..100).collect();
+    let root = vec.root.as_ref().map(Rc::as_ptr);
+
+    assert_eq!(vec.set_mut(5, 0), 5);
+    assert_eq!(vec.root.as_ref().map(Rc::as_ptr), root, "Unique nodes shouldn't be copied.");
+
+    let snapshot = vec.clone();
+    assert_eq!(vec.get_mut(50).map(|value| mem::replace(value, 0)), Some(50));
+    vec.push_back_mut(100);
+    assert_ne!(vec.root.as_ref().map(Rc::as_ptr), root);
+    assert_eq!(snapshot[50], 50);
+    assert_eq!(snapshot.len(), 100);
+    assert_eq!(vec[50], 0);
+}
+
+#[test]
+fn test_pop_back_mut_to_empty() {
+    let mut vec: PVector<usize> = (0..1100).collect();
+    let snapshot = vec.clone();
+
+    for i in (0..1100).rev() {
+        assert_eq!(vec.pop_back_mut(), Some(i));
+        assert_eq!(vec.len(), i);
+        assert_eq!(vec.get(i), None);
+    }
+    assert_eq!(vec.pop_back_mut(), None);
+    assert!(vec.root.is_none());
+    assert!(snapshot.iter().copied().eq(0..1100));
+
+    // The trie should shrink as it is popped, so it can be reused from empty.
+    vec.extend(0..40);
+    assert_eq!(vec.shift, BITS);
+    assert!(vec.iter().copied().eq(0..40));
+}
+
+// ============================================================================
+// Iteration and traits
+// ============================================================================
+
+#[test]
+fn test_iter_both_ends() {
+    let vec: PVector<usize> = (0..100).collect();
+    assert!(vec.iter().rev().copied().eq((0..100).rev()));
+
+    let mut iter = vec.iter();
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.next_back(), Some(&99));
+    assert_eq!(iter.len(), 98);
+    assert_eq!(iter.nth(90), Some(&91));
+    assert!(iter.clone().copied().eq(92..99));
+    assert!(iter.rev().copied().eq((92..99).rev()));
+}
+
+#[test]
+fn test_eq_and_hash() {
+    use std::hash::{BuildHasher, RandomState};
+
+    let a: PVector<i32> = (0..50).collect();
+    let b: PVector<i32> = (0..50).collect();
+    let c = a.set(10, -1);
+
+    let hasher = RandomState::new();
+    assert_eq!(a, b);
+    assert_eq!(hasher.hash_one(&a), hasher.hash_one(&b));
+    assert_ne!(a, c);
+    assert_ne!(Some(a.clone()), a.pop_back().map(|(popped, _)| popped));
+    assert_eq!(format!("{:?}", PVector::from_iter([1, 2])), "[1, 2]");
+}
@@ -1,5 +1,6 @@
-use std::alloc::{self, Layout};
+use std::alloc::{Allocator, Global, Layout};
 use std::borrow::{Borrow, BorrowMut};
+use std::cmp::Ordering;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::iter::{self, TrustedLen};
@@ -9,13 +10,11 @@ use std::ops::{Deref, DerefMut};
 use std::ptr::{self, NonNull};
 use std::slice;
 
-use crate::util::error::CapacityOverflow;
-use crate::util::result::ResultExtension;
+use crate::collections::contiguous::sort;
+use crate::util::error::{AllocError, CapacityOverflow, TryReserveError};
 
 const MAX_SIZE: usize = isize::MAX as usize;
 
-// TODO: Add try methods.
-
 /// An implementation of an array that has an fixed size at runtime. Similar to a
 /// [`Box<[T]>`](Box<T>) and not intended as an equivalent to Rust's primitive `[T; N]` type,
 /// which is sized at compile time.
@@ -31,11 +30,19 @@ const MAX_SIZE: usize = isize::MAX as usize;
 /// | `size` | `O(1)` |
 /// | `realloc` | `O(n)`*, `O(1)` |
 /// | `contains` | `O(n)` |
+/// | `sort`, `sort_unstable` | `O(n log n)` |
+/// | `binary_search`, `partition_point` | `O(log n)` |
 ///
 /// \* It might be possible to get an `O(1)` reallocation, but I don't believe it is very likely.
-pub struct Array<T> {
+///
+/// # Allocation
+/// All memory is provided by the allocator `A`, which defaults to the [`Global`] allocator. Methods
+/// ending in `_in` accept an allocator to be used instead, which is then used for the lifetime of
+/// the Array, including reallocation and deallocation.
+pub struct Array<T, A: Allocator = Global> {
     pub(crate) ptr: NonNull<T>,
     pub(crate) size: usize,
+    pub(crate) alloc: A,
     pub(crate) _phantom: PhantomData<T>,
 }
 
@@ -54,8 +61,7 @@ impl<T> Array<T> {
     /// assert_eq!(&*arr, &[]);
     /// ```
     pub fn new() -> Array<T> {
-        // SAFETY: There are no values, so they are all initialized.
-        unsafe { Self::new_uninit(0).assume_init() }
+        Array::new_in(Global)
     }
 
     /// Creates a new Array of [`MaybeUninit<T>`] with the provided `size`. All values are
@@ -72,14 +78,26 @@ impl<T> Array<T> {
     /// assert_eq!(arr.size(), 5);
     /// ```
     pub fn new_uninit(size: usize) -> Array<MaybeUninit<T>> {
-        let layout = Array::<MaybeUninit<T>>::make_layout(size);
-        let ptr = Array::<MaybeUninit<T>>::make_ptr(layout);
+        Array::new_uninit_in(size, Global)
+    }
 
-        Array {
-            ptr,
-            size,
-            _phantom: PhantomData,
-        }
+    /// Creates a new Array of [`MaybeUninit<T>`] with the provided `size`, returning an error
+    /// rather than panicking or aborting if the allocation fails. All values are uninitialized.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if memory layout size exceeds [`isize::MAX`] or if the
+    /// allocator fails to provide the memory.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Array;
+    /// # use std::mem::MaybeUninit;
+    /// let arr: Array<MaybeUninit<u8>> = Array::try_new_uninit(5).unwrap();
+    /// assert_eq!(arr.size(), 5);
+    /// assert!(Array::<u64>::try_new_uninit(usize::MAX / 4).is_err());
+    /// ```
+    pub fn try_new_uninit(size: usize) -> Result<Array<MaybeUninit<T>>, TryReserveError> {
+        Array::try_new_uninit_in(size, Global)
     }
 
     /// Decomposes an `Array<T>` into its raw components, a [`NonNull<T>`] pointer to the contained
@@ -122,9 +140,125 @@ impl<T> Array<T> {
     /// );
     /// ```
     pub const unsafe fn from_parts(ptr: NonNull<T>, size: usize) -> Array<T> {
+        // SAFETY: The caller upholds the same requirements, with the global allocator.
+        unsafe { Array::from_parts_in(ptr, size, Global) }
+    }
+
+    /// Creates an Array from a type which implements [`IntoIterator`] and creates an
+    /// [`ExactSizeIterator`].
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Array;
+    /// let arr = Array::from_iter_sized([1, 2, 3].into_iter());
+    /// assert_eq!(&*arr, [1, 2, 3]);
+    /// ```
+    pub fn from_iter_sized<I>(value: I) -> Array<T>
+    where
+        I: Iterator<Item = T> + ExactSizeIterator + TrustedLen,
+    {
+        Array::from_iter_sized_in(value, Global)
+    }
+
+    /// A helper function to create a [`Layout`] for use during allocation, containing `size` number
+    /// of elements of type `T`.
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    pub(crate) fn make_layout(size: usize) -> Layout {
+        Self::try_make_layout(size).unwrap_or_else(|error| error.handle())
+    }
+
+    /// A helper function to create a [`Layout`] for use during allocation, containing `size` number
+    /// of elements of type `T`.
+    ///
+    /// # Errors
+    /// Returns a [`CapacityOverflow`] if memory layout size exceeds [`isize::MAX`].
+    pub(crate) fn try_make_layout(size: usize) -> Result<Layout, TryReserveError> {
+        Ok(Layout::array::<T>(size).or(Err(CapacityOverflow))?)
+    }
+}
+
+impl<T, A: Allocator> Array<T, A> {
+    /// Creates a new Array with size 0, which will use the provided allocator.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(allocator_api)]
+    /// # use standard_lib::collections::contiguous::Array;
+    /// # use std::alloc::Global;
+    /// let arr: Array<u8> = Array::new_in(Global);
+    /// assert_eq!(arr.size(), 0);
+    /// ```
+    pub fn new_in(alloc: A) -> Array<T, A> {
+        // SAFETY: There are no values, so they are all initialized.
+        unsafe { Self::new_uninit_in(0, alloc).assume_init() }
+    }
+
+    /// Creates a new Array of [`MaybeUninit<T>`] with the provided `size`, allocated with the
+    /// provided allocator. All values are uninitialized.
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    pub fn new_uninit_in(size: usize, alloc: A) -> Array<MaybeUninit<T>, A> {
+        Self::try_new_uninit_in(size, alloc).unwrap_or_else(|error| error.handle())
+    }
+
+    /// Creates a new Array of [`MaybeUninit<T>`] with the provided `size`, allocated with the
+    /// provided allocator and returning an error rather than panicking or aborting if the
+    /// allocation fails. All values are uninitialized.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if memory layout size exceeds [`isize::MAX`] or if the
+    /// allocator fails to provide the memory.
+    pub fn try_new_uninit_in(
+        size: usize,
+        alloc: A,
+    ) -> Result<Array<MaybeUninit<T>, A>, TryReserveError> {
+        let layout = Array::<MaybeUninit<T>>::try_make_layout(size)?;
+        let ptr = Array::<MaybeUninit<T>, A>::try_make_ptr(layout, &alloc)?;
+
+        Ok(Array {
+            ptr,
+            size,
+            alloc,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Decomposes an `Array<T, A>` into its raw components, a [`NonNull<T>`] pointer to the
+    /// contained data, a [`usize`] representing the size and the allocator that owns the data.
+    ///
+    /// # Safety
+    /// After calling this function, the caller is responsible for the safety of the allocated data.
+    /// The parts can be used to reconstruct an Array with [`Array::from_parts_in`], allowing it to
+    /// be used again and dropped normally.
+    pub const fn into_parts_with_alloc(self) -> (NonNull<T>, usize, A) {
+        // SAFETY: self is forgotten immediately after, so the allocator isn't duplicated.
+        let alloc = unsafe { ptr::read(&self.alloc) };
+        let ret = (self.ptr, self.size, alloc);
+        mem::forget(self);
+        ret
+    }
+
+    /// Creates an `Array<T, A>` from its raw components, a [`NonNull<T>`] pointer to the contained
+    /// data, a [`usize`] representing the size and the allocator that owns the data.
+    ///
+    /// # Safety
+    /// This is extremely unsafe, nothing is checked during construction.
+    ///
+    /// For the produced value to be valid:
+    /// - `ptr` needs to be a currently and correctly allocated pointer within `alloc`.
+    /// - `ptr` needs to refer to `size` properly initialized values of `T`.
+    /// - `size` needs to be less than or equal to [`isize::MAX`] / `size_of::<T>()`.
+    pub const unsafe fn from_parts_in(ptr: NonNull<T>, size: usize, alloc: A) -> Array<T, A> {
         Array {
             ptr,
             size,
+            alloc,
             _phantom: PhantomData,
         }
     }
@@ -145,6 +279,11 @@ impl<T> Array<T> {
         self.ptr
     }
 
+    /// Returns a reference to the allocator used by the Array.
+    pub const fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
     /// Interprets self as an `Array<MaybeUninit<T>>`. Although it may not seem very useful by
     /// itself, this method acts as a counterpart to [`Array::assume_init`] and allows
     /// [`Array::realloc`] to be called on a previously initialized Array.
@@ -164,9 +303,11 @@ impl<T> Array<T> {
     ///
     /// assert_eq!(&*arr, &[1, 2, 3, 4]);
     /// ```
-    pub fn forget_init(self) -> Array<MaybeUninit<T>> {
-        // SAFETY: Array<T> has the same layout as Array<MaybeUninit<T>>.
-        unsafe { mem::transmute(self) }
+    pub fn forget_init(self) -> Array<MaybeUninit<T>, A> {
+        let (ptr, size, alloc) = self.into_parts_with_alloc();
+        // SAFETY: MaybeUninit<T> has the same layout as T, so the allocation remains valid for
+        // size values of MaybeUninit<T>, which are always initialized.
+        unsafe { Array::from_parts_in(ptr.cast(), size, alloc) }
     }
 
     /// Interprets &mut self as an `&mut Array<MaybeUninit<T>>`. See [`Array::forget_init`].
@@ -175,13 +316,13 @@ impl<T> Array<T> {
     /// When this mutable reference is dropped, self still need to be a valid and initialized
     /// `Array<T>`. Failing to do so is undefined behavior, as it is effectively the same as calling
     /// [`Array::assume_init`].
-    pub unsafe fn forget_init_mut(&mut self) -> &mut Array<MaybeUninit<T>> {
+    pub unsafe fn forget_init_mut(&mut self) -> &mut Array<MaybeUninit<T>, A> {
         // SAFETY: &mut Array<T> has the same layout as &mut Array<MaybeUninit<T>>.
         unsafe { mem::transmute(self) }
     }
 
     /// Interprets &self as an `&Array<MaybeUninit<T>>`. See [`Array::forget_init`].
-    pub fn forget_init_ref(&self) -> &Array<MaybeUninit<T>> {
+    pub fn forget_init_ref(&self) -> &Array<MaybeUninit<T>, A> {
         // SAFETY: &Array<T> has the same layout as &Array<MaybeUninit<T>>.
         unsafe { mem::transmute(self) }
     }
@@ -235,23 +376,16 @@ impl<T> Array<T> {
     }
 
     /// Creates an Array from a type which implements [`IntoIterator`] and creates an
-    /// [`ExactSizeIterator`].
+    /// [`ExactSizeIterator`], allocated with the provided allocator.
     ///
     /// # Panics
     /// Panics if memory layout size exceeds [`isize::MAX`].
-    ///
-    /// # Examples
-    /// ```
-    /// # use standard_lib::collections::contiguous::Array;
-    /// let arr = Array::from_iter_sized([1, 2, 3].into_iter());
-    /// assert_eq!(&*arr, [1, 2, 3]);
-    /// ```
-    pub fn from_iter_sized<I>(value: I) -> Array<T>
+    pub fn from_iter_sized_in<I>(value: I, alloc: A) -> Array<T, A>
     where
         I: Iterator<Item = T> + ExactSizeIterator + TrustedLen,
     {
         let size = value.len();
-        let arr = Self::new_uninit(size);
+        let arr = Self::new_uninit_in(size, alloc);
 
         for (index, item) in value.enumerate() {
             // SAFETY: size > isize::MAX / size_of::<T>() is already guarded against and all
@@ -265,29 +399,17 @@ impl<T> Array<T> {
         unsafe { arr.assume_init() }
     }
 
-    /// A helper function to create a [`Layout`] for use during allocation, containing `size` number
-    /// of elements of type `T`.
-    ///
-    /// # Panics
-    /// Panics if memory layout size exceeds [`isize::MAX`].
-    pub(crate) fn make_layout(size: usize) -> Layout {
-        Layout::array::<T>(size).or(Err(CapacityOverflow)).throw()
-    }
-
-    /// A helper function to create a [`NonNull`] for the provided [`Layout`]. Returns a dangling
-    /// pointer for a zero-sized layout.
+    /// A helper function to create a [`NonNull`] for the provided [`Layout`] using `alloc`.
+    /// Returns a dangling pointer for a zero-sized layout.
     ///
     /// # Errors
-    /// In the event of an allocation error, this method calls [`alloc::handle_alloc_error`] as
-    /// recommended, to avoid new allocations rather than panicking.
-    pub(crate) fn make_ptr(layout: Layout) -> NonNull<T> {
+    /// Returns an [`AllocError`] if the allocator fails to provide the memory, leaving the caller
+    /// to decide whether to call [`handle_alloc_error`](std::alloc::handle_alloc_error).
+    pub(crate) fn try_make_ptr(layout: Layout, alloc: &A) -> Result<NonNull<T>, TryReserveError> {
         if layout.size() == 0 {
-            NonNull::dangling()
+            Ok(NonNull::dangling())
         } else {
-            NonNull::new(
-                // SAFETY: Zero-sized layouts have been guarded against.
-                unsafe { alloc::alloc(layout).cast() }
-            ).unwrap_or_else(|| alloc::handle_alloc_error(layout))
+            Ok(alloc.allocate(layout).or(Err(AllocError { layout }))?.cast())
         }
     }
 }
@@ -306,7 +428,18 @@ impl<T: Copy> Array<T> {
     /// assert_eq!(&*arr, &[5, 5, 5]);
     /// ```
     pub fn repeat_item(item: T, count: usize) -> Array<T> {
-        Array::from_iter_sized(iter::repeat_n(item, count))
+        Array::repeat_item_in(item, count, Global)
+    }
+}
+
+impl<T: Copy, A: Allocator> Array<T, A> {
+    /// Creates a new `Array<T, A>` with `count` copies of `item`, allocated with the provided
+    /// allocator.
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    pub fn repeat_item_in(item: T, count: usize, alloc: A) -> Array<T, A> {
+        Array::from_iter_sized_in(iter::repeat_n(item, count), alloc)
     }
 
     /// Reallocate self with `new_size`, filling any extra elements with a copy of `item`.
@@ -325,7 +458,18 @@ impl<T: Default> Array<T> {
     /// # Panics
     /// Panics if memory layout size exceeds [`isize::MAX`].
     pub fn repeat_default(count: usize) -> Array<T> {
-        Array::from_iter_sized(iter::repeat_with(|| T::default()).take(count))
+        Array::repeat_default_in(count, Global)
+    }
+}
+
+impl<T: Default, A: Allocator> Array<T, A> {
+    /// Creates a new `Array<T, A>` by repeating the default value of `T` `count` times, allocated
+    /// with the provided allocator.
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    pub fn repeat_default_in(count: usize, alloc: A) -> Array<T, A> {
+        Array::from_iter_sized_in(iter::repeat_with(|| T::default()).take(count), alloc)
     }
 
     /// Reallocate self with `new_size`, filling any extra elements with the default value of `T`.
@@ -338,21 +482,23 @@ impl<T: Default> Array<T> {
     }
 }
 
-impl<T> Array<MaybeUninit<T>> {
+impl<T, A: Allocator> Array<MaybeUninit<T>, A> {
     /// Converts a `Array<MaybeUninit<T>>` to `MaybeUninit<Array<T>>`.
-    pub fn transpose(self) -> MaybeUninit<Array<T>> {
-        // SAFETY: Array<MaybeUninit<T>> has the same layout as MaybeUninit<Array<T>>.
-        unsafe { mem::transmute(self) }
+    pub fn transpose(self) -> MaybeUninit<Array<T, A>> {
+        let (ptr, size, alloc) = self.into_parts_with_alloc();
+        // SAFETY: MaybeUninit<T> has the same layout as T. The resulting Array is only accessible
+        // through a MaybeUninit, so its contents don't need to be initialized yet.
+        MaybeUninit::new(unsafe { Array::from_parts_in(ptr.cast(), size, alloc) })
     }
 
     /// Converts a `&mut Array<MaybeUninit<T>>` to `&mut MaybeUninit<Array<T>>`.
-    pub fn transpose_mut(&mut self) -> &mut MaybeUninit<Array<T>> {
+    pub fn transpose_mut(&mut self) -> &mut MaybeUninit<Array<T, A>> {
         // SAFETY: &mut Array<MaybeUninit<T>> has the same layout as &mut MaybeUninit<Array<T>>.
         unsafe { mem::transmute(self) }
     }
 
     /// Converts a `&Array<MaybeUninit<T>>` to `&MaybeUninit<Array<T>>`.
-    pub fn transpose_ref(&self) -> &MaybeUninit<Array<T>> {
+    pub fn transpose_ref(&self) -> &MaybeUninit<Array<T, A>> {
         // SAFETY: &Array<MaybeUninit<T>> has the same layout as &MaybeUninit<Array<T>>.
         unsafe { mem::transmute(self) }
     }
@@ -373,7 +519,7 @@ impl<T> Array<MaybeUninit<T>> {
     /// }
     /// assert_eq!(&*unsafe { arr.assume_init() }, &[0, 1, 2, 3, 4]);
     /// ```
-    pub unsafe fn assume_init(self) -> Array<T> {
+    pub unsafe fn assume_init(self) -> Array<T, A> {
         // SAFETY: There are no safety guarantees here, responsibility it passed to the caller.
         unsafe { self.transpose().assume_init() }
     }
@@ -383,7 +529,7 @@ impl<T> Array<MaybeUninit<T>> {
     /// # Safety
     /// It is up to the caller to guarantee that the Array is properly initialized. Failing to do so
     /// is undefined behavior.
-    pub unsafe fn assume_init_mut(&mut self) -> &mut Array<T> {
+    pub unsafe fn assume_init_mut(&mut self) -> &mut Array<T, A> {
         // SAFETY: There are no safety guarantees here, responsibility it passed to the caller.
         unsafe { self.transpose_mut().assume_init_mut() }
     }
@@ -393,7 +539,7 @@ impl<T> Array<MaybeUninit<T>> {
     /// # Safety
     /// It is up to the caller to guarantee that the Array is properly initialized. Failing to do so
     /// is undefined behavior.
-    pub unsafe fn assume_init_ref(&self) -> &Array<T> {
+    pub unsafe fn assume_init_ref(&self) -> &Array<T, A> {
         // SAFETY: There are no safety guarantees here, responsibility it passed to the caller.
         unsafe { self.transpose_ref().assume_init_ref() }
     }
@@ -405,6 +551,32 @@ impl<T> Array<MaybeUninit<T>> {
     /// Panics if the memory layout of the new allocation would have a size that exceeds
     /// [`isize::MAX`]. (`new_size * size_of::<T>() > isize::MAX`)
     pub fn realloc(&mut self, new_size: usize) {
+        self.try_realloc(new_size).unwrap_or_else(|error| error.handle())
+    }
+
+    /// Reallocate the Array to have size equal to new_size, with new locations uninitialized,
+    /// returning an error rather than panicking or aborting if the allocation fails. Several checks
+    /// are performed first to ensure that an allocation is actually required.
+    ///
+    /// If an error is returned, the Array is left unchanged.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the memory layout of the new allocation would have a size
+    /// that exceeds [`isize::MAX`] (`new_size * size_of::<T>() > isize::MAX`), or if the allocator
+    /// fails to provide the memory.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Array;
+    /// # use std::mem::MaybeUninit;
+    /// let mut arr: Array<MaybeUninit<u32>> = Array::new_uninit(2);
+    /// assert!(arr.try_realloc(4).is_ok());
+    /// assert_eq!(arr.size(), 4);
+    ///
+    /// assert!(arr.try_realloc(usize::MAX / 2).is_err());
+    /// assert_eq!(arr.size(), 4);
+    /// ```
+    pub fn try_realloc(&mut self, new_size: usize) -> Result<(), TryReserveError> {
         let new_ptr = match (self.size, new_size) {
             (_, _) if size_of::<T>() == 0 => {
                 // I didn't think that handling zero-sized types would be quite so easy. Turns out
@@ -419,65 +591,246 @@ impl<T> Array<MaybeUninit<T>> {
             },
             (old, new) if old == new => {
                 // The capacities are equal, do nothing there is no need to reallocate.
-                // SAFETY: Array<T> has the same layout as Array<MaybeUninit<T>>.
-                return;
+                return Ok(());
             },
             (_, 0) => {
                 // If the new size is zero, we just need to deallocate it and return a dangling
                 // pointer.
                 let layout = Array::<MaybeUninit<T>>::make_layout(self.size);
 
-                // SAFETY: ptr is always allocated in the global allocator and layout is the same as
-                // when allocated. Zero-sized layouts are guarded against by the first two branches.
-                unsafe { alloc::dealloc(self.ptr.as_ptr().cast(), layout); }
+                // SAFETY: ptr is always allocated by self.alloc and layout is the same as when
+                // allocated. Zero-sized layouts are guarded against by the first two branches.
+                unsafe { self.alloc.deallocate(self.ptr.cast(), layout); }
 
                 NonNull::dangling()
             },
             (_, new) if new.checked_mul(size_of::<T>())
                 .is_none_or(|size| size > MAX_SIZE) => {
-                // Throw an error if the new size would overflow.
-                Err(CapacityOverflow).throw()
+                // Return an error if the new size would overflow.
+                Err(CapacityOverflow)?
             },
             (0, _) => {
                 // If the Array previously had a capacity of zero, we need a new allocation.
-                let layout = Array::<MaybeUninit<T>>::make_layout(new_size);
+                let layout = Array::<MaybeUninit<T>>::try_make_layout(new_size)?;
 
-                // SAFETY: Layout will have non-zero size because both 0 capacity and zero-sized
-                // types are guarded against.
-                let raw_ptr: *mut MaybeUninit<T> = unsafe { alloc::alloc(layout).cast() };
-
-                NonNull::new(raw_ptr).unwrap_or_else(|| alloc::handle_alloc_error(layout))
+                // Layout will have non-zero size because both 0 capacity and zero-sized types are
+                // guarded against.
+                self.alloc.allocate(layout).or(Err(AllocError { layout }))?.cast()
             },
-            (_, _) => {
-                // Otherwise, use realloc to handle moving or in-place size changing.
+            (old, new) => {
+                // Otherwise, let the allocator handle moving or in-place size changing.
                 let layout = Array::<MaybeUninit<T>>::make_layout(self.size);
+                let new_layout = Array::<MaybeUninit<T>>::try_make_layout(new_size)?;
 
                 // SAFETY: The same layout and allocator are used for the allocation, and the new
-                // layout size is > 0 and <= isize::MAX.
-                let raw_ptr: *mut MaybeUninit<T> = unsafe {
-                    alloc::realloc(
-                        self.ptr.as_ptr().cast(),
-                        layout,
-                        new_size * size_of::<T>()
-                    ).cast()
+                // layout size is > 0 and <= isize::MAX. grow is only used when the new layout is
+                // larger and shrink when it is smaller.
+                let result = unsafe {
+                    if new > old {
+                        self.alloc.grow(self.ptr.cast(), layout, new_layout)
+                    } else {
+                        self.alloc.shrink(self.ptr.cast(), layout, new_layout)
+                    }
                 };
 
-                NonNull::new(raw_ptr).unwrap_or_else(|| alloc::handle_alloc_error(layout))
+                // If reallocation fails, the original allocation is left untouched, so self is
+                // still valid.
+                result.or(Err(AllocError { layout: new_layout }))?.cast()
             },
         };
 
         self.ptr = new_ptr;
         self.size = new_size;
+        Ok(())
+    }
+}
+
+impl<T, A: Allocator> Array<T, A> {
+    /// Sorts the Array in ascending order, with a stable merge sort.
+    ///
+    /// Equal elements are never reordered. A scratch buffer of half the length of the Array is
+    /// allocated while sorting.
+    ///
+    /// # Panics
+    /// Panics if allocating the scratch buffer fails, or if the implementation of [`Ord`] for `T`
+    /// panics. In the latter case, the Array still contains all of its elements, in an unspecified
+    /// order.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        sort::merge_sort(self, &mut T::lt);
+    }
+
+    /// Sorts the Array with a stable merge sort, using `compare` to order elements.
+    ///
+    /// # Panics
+    /// Panics if allocating the scratch buffer fails, or if `compare` panics. In the latter case,
+    /// the Array still contains all of its elements, in an unspecified order.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::merge_sort(self, &mut |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the Array with a stable merge sort, ordering elements by the key produced by `f`.
+    ///
+    /// `f` is called for every comparison, so [`sort_by_cached_key`](Array::sort_by_cached_key)
+    /// is likely to be faster if `f` is expensive.
+    ///
+    /// # Panics
+    /// Panics if allocating the scratch buffer fails, or if `f` panics. In the latter case, the
+    /// Array still contains all of its elements, in an unspecified order.
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        sort::merge_sort(self, &mut |a, b| f(a).lt(&f(b)));
+    }
+
+    /// Sorts the Array stably, ordering elements by the key produced by `f`, which is only called
+    /// once for each element.
+    ///
+    /// The keys are stored in an [`Array`] alongside each element's index while sorting.
+    ///
+    /// # Panics
+    /// Panics if allocating the keys fails, or if `f` or the implementation of [`Ord`] for `K`
+    /// panics. In the latter case, the Array is left unchanged.
+    pub fn sort_by_cached_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        sort::cached_key_sort(self, f);
+    }
+
+    /// Sorts the Array in ascending order, with an unstable pattern-defeating quicksort.
+    ///
+    /// Equal elements may be reordered, but no memory is allocated.
+    ///
+    /// # Panics
+    /// Panics if the implementation of [`Ord`] for `T` panics, in which case the Array still
+    /// contains all of its elements, in an unspecified order.
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        sort::quick_sort(self, &mut T::lt);
+    }
+
+    /// Sorts the Array with an unstable pattern-defeating quicksort, using `compare` to order
+    /// elements.
+    ///
+    /// # Panics
+    /// Panics if `compare` panics, in which case the Array still contains all of its elements, in
+    /// an unspecified order.
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::quick_sort(self, &mut |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the Array with an unstable pattern-defeating quicksort, ordering elements by the key
+    /// produced by `f`.
+    ///
+    /// # Panics
+    /// Panics if `f` panics, in which case the Array still contains all of its elements, in an
+    /// unspecified order.
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        sort::quick_sort(self, &mut |a, b| f(a).lt(&f(b)));
+    }
+
+    /// Searches the Array, which is assumed to be sorted, for `value` using a binary search.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the index where `value`
+    /// could be inserted while maintaining the sorted order. If there are multiple matches, any of
+    /// them may be returned.
+    pub fn binary_search(&self, value: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        sort::binary_search_by(self, |item| item.cmp(value))
+    }
+
+    /// Searches the Array, which is assumed to be sorted, using a binary search where `f` returns
+    /// the ordering of each element relative to the target.
+    ///
+    /// See [`binary_search`](Array::binary_search) for the meaning of the returned value.
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        sort::binary_search_by(self, f)
+    }
+
+    /// Searches the Array, which is assumed to be sorted by the key produced by `f`, for `key`
+    /// using a binary search.
+    ///
+    /// See [`binary_search`](Array::binary_search) for the meaning of the returned value.
+    pub fn binary_search_by_key<K, F>(&self, key: &K, mut f: F) -> Result<usize, usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        sort::binary_search_by(self, |item| f(item).cmp(key))
+    }
+
+    /// Returns the index of the first element for which `pred` returns false, assuming that the
+    /// Array is partitioned so that all elements which match `pred` come first.
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        sort::partition_point(self, pred)
+    }
+
+    /// Reorders the Array so that the element at `index` is in its sorted position, returning the
+    /// elements before it, a reference to it and the elements after it. All elements before
+    /// `index` are less than or equal to it and all elements after are greater than or equal to
+    /// it, but neither side is sorted.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds, or if the implementation of [`Ord`] for `T` panics. In
+    /// the latter case, the Array still contains all of its elements, in an unspecified order.
+    pub fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+    where
+        T: Ord,
+    {
+        sort::select_nth(self, index, &mut T::lt)
+    }
+
+    /// Reorders the Array so that the element at `index` is in its sorted position, like
+    /// [`select_nth_unstable`](Array::select_nth_unstable), using `compare` to order elements.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds, or if `compare` panics. In the latter case, the Array
+    /// still contains all of its elements, in an unspecified order.
+    pub fn select_nth_unstable_by<F>(
+        &mut self,
+        index: usize,
+        mut compare: F,
+    ) -> (&mut [T], &mut T, &mut [T])
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::select_nth(self, index, &mut |a, b| compare(a, b) == Ordering::Less)
     }
 }
 
-impl<T> Default for Array<T> {
+impl<T, A: Allocator + Default> Default for Array<T, A> {
     fn default() -> Self {
-        Self::new()
+        Self::new_in(A::default())
     }
 }
 
-impl<T> Drop for Array<T> {
+impl<T, A: Allocator> Drop for Array<T, A> {
     fn drop(&mut self) {
         for i in 0..self.size {
             // SAFETY: The pointer is nonnull, as well as properly aligned, initialized and
@@ -492,17 +845,16 @@ impl<T> Drop for Array<T> {
         let layout = Array::<T>::make_layout(self.size);
 
         if layout.size() != 0 {
-            // SAFETY: ptr is always allocated in the global allocator and layout is the same as
-            // when allocated. Zero-sized layouts aren't allocated and are guarded against
-            // deallocation.
+            // SAFETY: ptr is always allocated by self.alloc and layout is the same as when
+            // allocated. Zero-sized layouts aren't allocated and are guarded against deallocation.
             unsafe {
-                alloc::dealloc(self.ptr.as_ptr().cast(), layout)
+                self.alloc.deallocate(self.ptr.cast(), layout)
             }
         }
     }
 }
 
-impl<T> Deref for Array<T> {
+impl<T, A: Allocator> Deref for Array<T, A> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
@@ -516,7 +868,7 @@ impl<T> Deref for Array<T> {
     }
 }
 
-impl<T> DerefMut for Array<T> {
+impl<T, A: Allocator> DerefMut for Array<T, A> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         // SAFETY: The held data uses Layout::array(size) and is therefore valid and properly
         // aligned for (size * mem::size_of::<T>()) bytes. Data is properly initialized and has a
@@ -528,25 +880,25 @@ impl<T> DerefMut for Array<T> {
     }
 }
 
-impl<T> AsRef<[T]> for Array<T> {
+impl<T, A: Allocator> AsRef<[T]> for Array<T, A> {
     fn as_ref(&self) -> &[T] {
         self.deref()
     }
 }
 
-impl<T> AsMut<[T]> for Array<T> {
+impl<T, A: Allocator> AsMut<[T]> for Array<T, A> {
     fn as_mut(&mut self) -> &mut [T] {
         self.deref_mut()
     }
 }
 
-impl<T> Borrow<[T]> for Array<T> {
+impl<T, A: Allocator> Borrow<[T]> for Array<T, A> {
     fn borrow(&self) -> &[T] {
         self.as_ref()
     }
 }
 
-impl<T> BorrowMut<[T]> for Array<T> {
+impl<T, A: Allocator> BorrowMut<[T]> for Array<T, A> {
     fn borrow_mut(&mut self) -> &mut [T] {
         self.as_mut()
     }
@@ -554,32 +906,32 @@ impl<T> BorrowMut<[T]> for Array<T> {
 
 // SAFETY: Arrays, when used safely rely on unique pointers and are therefore safe for Send when T:
 // Send.
-unsafe impl<T: Send> Send for Array<T> {}
+unsafe impl<T: Send, A: Allocator + Send> Send for Array<T, A> {}
 // SAFETY: Array's safe API obeys all rules of the borrow checker, so no interior mutability occurs.
 // This means that Array<T> can safely implement Sync when T: Sync.
-unsafe impl<T: Sync> Sync for Array<T> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Array<T, A> {}
 
-impl<T: Clone> Clone for Array<T> {
+impl<T: Clone, A: Allocator + Clone> Clone for Array<T, A> {
     fn clone(&self) -> Self {
-        Array::from_iter_sized(self.iter().cloned())
+        Array::from_iter_sized_in(self.iter().cloned(), self.alloc.clone())
     }
 }
 
-impl<T: PartialEq> PartialEq for Array<T> {
+impl<T: PartialEq, A: Allocator> PartialEq for Array<T, A> {
     fn eq(&self, other: &Self) -> bool {
         **self == **other
     }
 }
 
-impl<T: Eq> Eq for Array<T> {}
+impl<T: Eq, A: Allocator> Eq for Array<T, A> {}
 
-impl<T: Hash> Hash for Array<T> {
+impl<T: Hash, A: Allocator> Hash for Array<T, A> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         (**self).hash(state);
     }
 }
 
-impl<T: Debug> Debug for Array<T> {
+impl<T: Debug, A: Allocator> Debug for Array<T, A> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("Array")
             .field_with("contents", |f| f.debug_list().entries(self.iter()).finish())
@@ -588,7 +940,7 @@ impl<T: Debug> Debug for Array<T> {
     }
 }
 
-impl<T: Debug> Display for Array<T> {
+impl<T: Debug, A: Allocator> Display for Array<T, A> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
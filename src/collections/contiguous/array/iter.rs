@@ -1,48 +1,48 @@
-use std::alloc;
+use std::alloc::{Allocator, Global};
 use std::iter::{FusedIterator, TrustedLen};
 use std::marker::PhantomData;
-use std::mem;
 use std::ptr::{self, NonNull};
 
 use super::Array;
 #[cfg(doc)]
 use crate::collections::contiguous::Vector;
 
-impl<T> IntoIterator for Array<T> {
+impl<T, A: Allocator> IntoIterator for Array<T, A> {
     type Item = T;
 
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let head = self.ptr.as_ptr().cast_const();
-        let result = IntoIter {
-            ptr: self.ptr,
-            size: self.size,
+        let (ptr, size, alloc) = self.into_parts_with_alloc();
+        let head = ptr.as_ptr().cast_const();
+        IntoIter {
+            ptr,
+            size,
+            alloc,
             head,
             // Don't subtract 1 for the tail.
             // SAFETY: Offset of one won't overflow isize::MAX. The memory range between head and
             // tail will be the same as that of the Array, where the initial value of tail is never
             // read.
-            tail: unsafe { head.add(self.size) },
+            tail: unsafe { head.add(size) },
             _phantom: PhantomData,
-        };
-        mem::forget(self);
-        result
+        }
     }
 }
 
 /// A type for owned iteration over an [`Array`] or [`Vector`]. Produces values of type `T`.
 ///
 /// See [`Array::into_iter`] and [`Vector::into_iter`].
-pub struct IntoIter<T> {
+pub struct IntoIter<T, A: Allocator = Global> {
     pub(crate) ptr: NonNull<T>,
     pub(crate) size: usize,
+    pub(crate) alloc: A,
     pub(crate) head: *const T, // Head points to the first element.
     pub(crate) tail: *const T, // Tail points one after the last element.
     pub(crate) _phantom: PhantomData<T>,
 }
 
-impl<T> Drop for IntoIter<T> {
+impl<T, A: Allocator> Drop for IntoIter<T, A> {
     fn drop(&mut self) {
         while self.head < self.tail {
             // SAFETY: The pointer is nonnull, properly aligned and valid for both reads and writes.
@@ -57,16 +57,17 @@ impl<T> Drop for IntoIter<T> {
         let layout = Array::<T>::make_layout(self.size);
 
         if layout.size() != 0 {
-            // SAFETY: ptr is ensured to be valid by the Array used to create this Iterator.
-            // Zero-sized layouts aren't allocated and are guarded against deallocation.
+            // SAFETY: ptr is ensured to be valid by the Array used to create this Iterator, which
+            // also provided the allocator. Zero-sized layouts aren't allocated and are guarded
+            // against deallocation.
             unsafe {
-                alloc::dealloc(self.ptr.as_ptr().cast(), layout)
+                self.alloc.deallocate(self.ptr.cast(), layout)
             }
         }
     }
 }
 
-impl<T> Iterator for IntoIter<T> {
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -89,7 +90,7 @@ impl<T> Iterator for IntoIter<T> {
     }
 }
 
-impl<T> DoubleEndedIterator for IntoIter<T> {
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.head < self.tail {
             // Tail sits one after the end, so we subtract first then read. Results in the same
@@ -106,9 +107,9 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
     }
 }
 
-impl<T> FusedIterator for IntoIter<T> {}
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
 
-impl<T> ExactSizeIterator for IntoIter<T> {
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {
     fn len(&self) -> usize {
         // SAFETY: Both pointers are derived from the original allocation and aligned to multiple of
         // size_of::<T>(). The memory range between them is contained within the initial allocation.
@@ -118,6 +119,6 @@ impl<T> ExactSizeIterator for IntoIter<T> {
 }
 
 // SAFETY: IntoIter::size_hint returns the exact length of the iterator.
-unsafe impl<T> TrustedLen for IntoIter<T> {}
+unsafe impl<T, A: Allocator> TrustedLen for IntoIter<T, A> {}
 
 // Just use the iter and iter_mut definitions provided by Deref<Target=[T]>.
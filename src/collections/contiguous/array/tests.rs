@@ -6,6 +6,7 @@ use std::iter;
 
 use super::*;
 use crate::util::alloc::{CountedDrop, ZeroSizedType};
+use crate::util::error::TryReserveError;
 use crate::util::panic::assert_panics;
 
 #[test]
@@ -170,3 +171,29 @@ fn test_iterators() {
         "Dropping an owned iterator should drop all elements."
     );
 }
+
+#[test]
+fn test_try_alloc() {
+    let arr = Array::<u64>::try_new_uninit(8);
+    assert!(arr.is_ok_and(|arr| arr.size() == 8), "Small allocations should succeed.");
+
+    assert!(
+        matches!(
+            Array::<u64>::try_new_uninit(usize::MAX / 4),
+            Err(TryReserveError::CapacityOverflow(_))
+        ),
+        "Allocations larger than isize::MAX bytes should be reported as overflow."
+    );
+
+    let mut arr = Array::<u64>::new_uninit(4);
+    let old_ptr = arr.ptr;
+    assert!(
+        matches!(arr.try_realloc(usize::MAX), Err(TryReserveError::CapacityOverflow(_))),
+        "Reallocating past isize::MAX bytes should be reported as overflow."
+    );
+    assert_eq!(arr.size(), 4, "A failed realloc should leave the Array unchanged.");
+    assert_eq!(arr.ptr, old_ptr, "A failed realloc should leave the Array unchanged.");
+
+    assert!(arr.try_realloc(16).is_ok());
+    assert_eq!(arr.size(), 16);
+}
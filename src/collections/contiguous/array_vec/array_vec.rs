@@ -0,0 +1,515 @@
+use std::borrow::{Borrow, BorrowMut};
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::mem::{self, MaybeUninit};
+use std::ops::{Deref, DerefMut, RangeBounds};
+use std::ptr;
+use std::slice;
+
+use super::Drain;
+use crate::collections::contiguous::Vector;
+use crate::collections::contiguous::drain::RawDrain;
+use crate::util::error::{CapacityError, CapacityOverflow, IndexOutOfBounds};
+use crate::util::range::resolve_range;
+use crate::util::result::ResultExtension;
+
+/// A variable size contiguous collection with a fixed capacity of `N`, which stores its items
+/// inline rather than on the heap.
+///
+/// An ArrayVec never allocates, so it can be used where allocation isn't possible, at the cost of
+/// never holding more than `N` items. Attempting to exceed this capacity is reported either as a
+/// [`CapacityError`] from the `try_*` methods, which hands back the rejected value, or as a panic
+/// with a [`CapacityOverflow`].
+///
+/// # Time Complexity
+/// For this analysis of time complexity, variables are defined as follows:
+/// - `n`: The number of items in the ArrayVec.
+/// - `i`: The index of the item in question.
+/// - `m`: The number of items in the drained range.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `get` | `O(1)` |
+/// | `len` | `O(1)` |
+/// | `push`, `try_push` | `O(1)` |
+/// | `pop` | `O(1)` |
+/// | `insert`, `try_insert` | `O(n-i)` |
+/// | `remove` | `O(n-i)` |
+/// | `swap_remove` | `O(1)` |
+/// | `replace` | `O(1)` |
+/// | `drain` | `O(n-i)` |
+/// | `contains` | `O(n)` |
+pub struct ArrayVec<T, const N: usize> {
+    pub(crate) buf: [MaybeUninit<T>; N],
+    pub(crate) len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    /// Creates a new, empty ArrayVec.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::ArrayVec;
+    /// let vec: ArrayVec<u8, 4> = ArrayVec::new();
+    /// assert_eq!(vec.len(), 0);
+    /// assert_eq!(vec.cap(), 4);
+    /// ```
+    pub const fn new() -> ArrayVec<T, N> {
+        ArrayVec {
+            buf: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the length of the ArrayVec.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the ArrayVec contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the capacity of the ArrayVec, which is always `N`.
+    pub const fn cap(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the ArrayVec contains `N` elements, so that no more can be added.
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Push the provided value onto the end of the ArrayVec.
+    ///
+    /// # Panics
+    /// Panics with a [`CapacityOverflow`] if the ArrayVec is already full.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::ArrayVec;
+    /// let mut vec = ArrayVec::<u8, 8>::new();
+    /// for i in 0..=5 {
+    ///     vec.push(i);
+    /// }
+    /// assert_eq!(&*vec, &[0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        self.try_push(value).or(Err(CapacityOverflow)).throw()
+    }
+
+    /// Push the provided value onto the end of the ArrayVec, returning an error rather than
+    /// panicking if it is already full.
+    ///
+    /// If an error is returned, the ArrayVec is left unchanged and `value` is handed back as part of
+    /// the error.
+    ///
+    /// # Errors
+    /// Returns a [`CapacityError`] containing `value` if the ArrayVec is already full.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::ArrayVec;
+    /// let mut vec = ArrayVec::<u8, 2>::new();
+    /// assert!(vec.try_push(1).is_ok());
+    /// assert!(vec.try_push(2).is_ok());
+    /// assert_eq!(vec.try_push(3).map_err(|error| error.into_inner()), Err(3));
+    /// assert_eq!(&*vec, &[1, 2]);
+    /// ```
+    pub const fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError { value });
+        }
+
+        // SAFETY: The ArrayVec isn't full, so there is space for the new item.
+        unsafe { self.push_unchecked(value) }
+        Ok(())
+    }
+
+    /// Push the provided value onto the end of the ArrayVec, assuming that it isn't full.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure that the ArrayVec isn't full. Using this method on a full
+    /// ArrayVec is undefined behavior.
+    pub const unsafe fn push_unchecked(&mut self, value: T) {
+        // SAFETY: It is up to the caller to ensure that len < N, so the write is in bounds.
+        unsafe { self.buf.as_mut_ptr().add(self.len).write(MaybeUninit::new(value)); }
+        self.len += 1;
+    }
+
+    /// Pops the last value off the end of the ArrayVec, returning an owned value if the ArrayVec
+    /// has length greater than 0.
+    pub const fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            // Decrement len before getting.
+            self.len -= 1;
+
+            // SAFETY: The value at the old end of the ArrayVec is initialized, and is now
+            // considered uninitialized because len has been decremented, so it is moved out rather
+            // than copied.
+            Some(unsafe { self.buf.as_ptr().add(self.len).read().assume_init() })
+        }
+    }
+
+    /// Inserts the provided value at the given index, moving all following items back by one.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds or with a [`CapacityOverflow`] if the
+    /// ArrayVec is already full.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::ArrayVec;
+    /// let mut vec: ArrayVec<_, 8> = (0..3).collect();
+    /// vec.insert(1, 100);
+    /// vec.insert(1, 200);
+    /// assert_eq!(&*vec, &[0, 200, 100, 1, 2]);
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.try_insert(index, value).or(Err(CapacityOverflow)).throw()
+    }
+
+    /// Inserts the provided value at the given index, moving all following items back by one and
+    /// returning an error rather than panicking if the ArrayVec is already full.
+    ///
+    /// If an error is returned, the ArrayVec is left unchanged and `value` is handed back as part of
+    /// the error.
+    ///
+    /// # Errors
+    /// Returns a [`CapacityError`] containing `value` if the ArrayVec is already full.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds.
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), CapacityError<T>> {
+        self.check_index(index);
+
+        if self.is_full() {
+            return Err(CapacityError { value });
+        }
+
+        let ptr = self.buf.as_mut_ptr();
+        // SAFETY: index < len < N, so both the shifted range and the written value are within the
+        // buffer.
+        unsafe {
+            ptr::copy(ptr.add(index), ptr.add(index + 1), self.len - index);
+            ptr.add(index).write(MaybeUninit::new(value));
+        }
+
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the element at the provided index, moving all following values to fill in the gap.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.check_index(index);
+
+        let ptr = self.buf.as_mut_ptr();
+        self.len -= 1;
+
+        // SAFETY: index < len, so the value there is initialized and is moved out before the
+        // following values are shifted over it. The shifted range ends at the old len, so it stays
+        // within the initialized part of the buffer.
+        unsafe {
+            let value = ptr.add(index).read().assume_init();
+            ptr::copy(ptr.add(index + 1), ptr.add(index), self.len - index);
+            value
+        }
+    }
+
+    /// Removes the element at the provided index, replacing it with the last element. This doesn't
+    /// preserve the order of the elements, but only moves one of them.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::ArrayVec;
+    /// let mut vec: ArrayVec<_, 4> = (0..4).collect();
+    /// assert_eq!(vec.swap_remove(1), 1);
+    /// assert_eq!(&*vec, &[0, 3, 2]);
+    /// ```
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.check_index(index);
+
+        let last = self.len - 1;
+        self.swap(index, last);
+        // SAFETY: The ArrayVec contains at least one element, because index is in bounds.
+        unsafe { self.pop().unwrap_unchecked() }
+    }
+
+    /// Replaces the element at the provided index with `new_value`, returning the old value.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds.
+    pub fn replace(&mut self, index: usize, new_value: T) -> T {
+        self.check_index(index);
+
+        mem::replace(&mut self[index], new_value)
+    }
+
+    /// Shortens the ArrayVec to the provided length, dropping all elements after it. If the
+    /// ArrayVec is no longer than `len`, nothing happens.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len { return; }
+
+        let removed = ptr::slice_from_raw_parts_mut(
+            // SAFETY: len < self.len, so the pointer is within the initialized part of the buffer.
+            unsafe { self.buf.as_mut_ptr().add(len).cast::<T>() },
+            self.len - len,
+        );
+
+        // Shorten the ArrayVec first, so that a panicking drop can only leak the removed values.
+        self.len = len;
+
+        // SAFETY: removed contains the initialized values after len, which are no longer accessible
+        // through the ArrayVec.
+        unsafe { ptr::drop_in_place(removed); }
+    }
+
+    /// Removes all elements in the provided range from the ArrayVec, returning them as an
+    /// iterator. Any elements that aren't produced by the iterator are dropped along with it, at
+    /// which point the remaining elements are moved to close the gap.
+    ///
+    /// # Panics
+    /// Panics if the range starts after it ends or ends after the length of the ArrayVec.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::ArrayVec;
+    /// let mut vec: ArrayVec<_, 8> = (0..6).collect();
+    /// assert!(vec.drain(1..4).eq([1, 2, 3]));
+    /// assert_eq!(&*vec, &[0, 4, 5]);
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, N> {
+        let range = resolve_range(range, self.len).throw();
+
+        Drain {
+            inner: RawDrain::new(self, range),
+        }
+    }
+
+    /// Removes and drops all elements in the ArrayVec.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Decomposes the ArrayVec into its buffer and length, without dropping any values.
+    pub(crate) const fn into_parts(self) -> ([MaybeUninit<T>; N], usize) {
+        // SAFETY: self is forgotten immediately afterwards, so the buffer is never used or dropped
+        // twice.
+        let buf = unsafe { ptr::read(&self.buf) };
+        let len = self.len;
+        mem::forget(self);
+        (buf, len)
+    }
+
+    /// Checks that the provided index is within the bounds of self.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds.
+    pub(crate) fn check_index(&self, index: usize) {
+        if index >= self.len {
+            Err(IndexOutOfBounds {
+                index,
+                len: self.len
+            }).throw()
+        }
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ArrayVec<T, N> {
+    /// Pushes each item produced by `iter` onto the ArrayVec.
+    ///
+    /// # Panics
+    /// Panics with a [`CapacityOverflow`] if `iter` produces more items than the ArrayVec has space
+    /// for. Items pushed before the panic are kept.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+
+    fn extend_one(&mut self, item: T) {
+        self.push(item);
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for ArrayVec<T, N> {
+    /// Creates an ArrayVec from the items produced by `iter`.
+    ///
+    /// # Panics
+    /// Panics with a [`CapacityOverflow`] if `iter` produces more than `N` items.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = ArrayVec::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: All values less than len are initialized and safe to drop.
+        unsafe { ptr::drop_in_place::<[T]>(self.deref_mut()); }
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The buffer is valid as a slice for len values, which are all initialized. The
+        // borrow checker enforces that self isn't mutated due to this function taking a &self.
+        unsafe {
+            slice::from_raw_parts(
+                // Reinterpret *const MaybeUninit<T> as *const T for all values < len.
+                self.buf.as_ptr().cast(),
+                self.len,
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: The buffer is valid as a slice for len values, which are all initialized. The
+        // borrow checker enforces that self isn't accessed due to this function taking a
+        // &mut self.
+        unsafe {
+            slice::from_raw_parts_mut(
+                // Reinterpret *mut MaybeUninit<T> as *mut T for all values < len.
+                self.buf.as_mut_ptr().cast(),
+                self.len,
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for ArrayVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.deref()
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for ArrayVec<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.deref_mut()
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for ArrayVec<T, N> {
+    fn borrow(&self) -> &[T] {
+        self.as_ref()
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for ArrayVec<T, N> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut vec = ArrayVec::new();
+
+        for value in self.iter() {
+            // SAFETY: vec has the same capacity as self, so it can hold a clone of every value.
+            unsafe { vec.push_unchecked(value.clone()); }
+        }
+
+        vec
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ArrayVec<T, N> {
+    fn from(value: [T; N]) -> Self {
+        ArrayVec {
+            buf: value.map(MaybeUninit::new),
+            len: N,
+        }
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vector<T>> for ArrayVec<T, N> {
+    type Error = CapacityError<Vector<T>>;
+
+    /// Moves all elements of the provided Vector into an ArrayVec.
+    ///
+    /// # Errors
+    /// Returns a [`CapacityError`] containing the Vector, untouched, if it contains more than `N`
+    /// elements.
+    fn try_from(mut value: Vector<T>) -> Result<Self, Self::Error> {
+        if value.len() > N {
+            return Err(CapacityError { value });
+        }
+
+        let mut vec = ArrayVec::new();
+        // SAFETY: The Vector's elements are initialized and len <= N, so they fit in the buffer.
+        // The two buffers are distinct, so they can't overlap.
+        unsafe {
+            ptr::copy_nonoverlapping(value.arr.ptr.as_ptr(), vec.buf.as_mut_ptr(), value.len);
+        }
+        vec.len = value.len;
+
+        // All values in the Vector have been moved, so dropping it only deallocates.
+        value.len = 0;
+        Ok(vec)
+    }
+}
+
+impl<T, const N: usize> From<ArrayVec<T, N>> for Vector<T> {
+    fn from(value: ArrayVec<T, N>) -> Self {
+        let (buf, len) = value.into_parts();
+
+        let mut vec = Vector::with_cap(len);
+        // SAFETY: The first len items are initialized and vec has capacity for exactly len values.
+        // The two buffers are distinct, so they can't overlap.
+        unsafe { ptr::copy_nonoverlapping(buf.as_ptr(), vec.arr.ptr.as_ptr(), len); }
+        vec.len = len;
+        vec
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T, N> {}
+
+impl<T: Hash, const N: usize> Hash for ArrayVec<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ArrayVec<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayVec")
+            .field_with("contents", |f| f.debug_list().entries(self.iter()).finish())
+            .field("len", &self.len)
+            .field("cap", &N)
+            .finish()
+    }
+}
+
+impl<T: Debug, const N: usize> Display for ArrayVec<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "!")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
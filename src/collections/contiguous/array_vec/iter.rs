@@ -0,0 +1,134 @@
+use std::iter::{FusedIterator, TrustedLen};
+use std::mem::MaybeUninit;
+use std::ptr;
+
+use super::ArrayVec;
+use crate::collections::contiguous::drain::{RawBuffer, RawDrain};
+
+impl<T, const N: usize> IntoIterator for ArrayVec<T, N> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (buf, len) = self.into_parts();
+        IntoIter {
+            buf,
+            head: 0,
+            tail: len,
+        }
+    }
+}
+
+/// A type for owned iteration over an [`ArrayVec`]. Produces values of type `T`.
+///
+/// See [`ArrayVec::into_iter`].
+pub struct IntoIter<T, const N: usize> {
+    pub(crate) buf: [MaybeUninit<T>; N],
+    pub(crate) head: usize, // Head is the index of the first element.
+    pub(crate) tail: usize, // Tail is the index one after the last element.
+}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: All values between head and tail are initialized and haven't been moved out yet.
+        // This method takes a mutable reference to self, so they can't be accessed elsewhere.
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.buf.as_mut_ptr().add(self.head).cast::<T>(),
+                self.tail - self.head,
+            ));
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.head < self.tail {
+            // SAFETY: head < tail, so the value is initialized. We will increment head next so that
+            // the value is effectively moved out of the buffer.
+            let value = unsafe { self.buf[self.head].assume_init_read() };
+            self.head += 1;
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.head < self.tail {
+            // Tail sits one after the end, so we decrement first then read.
+            self.tail -= 1;
+            // SAFETY: The value at tail was initialized and we've just decremented tail so that it
+            // is never read again, effectively moving it out of the buffer.
+            Some(unsafe { self.buf[self.tail].assume_init_read() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {
+    fn len(&self) -> usize {
+        self.tail - self.head
+    }
+}
+
+// SAFETY: IntoIter::size_hint returns the exact length of the iterator.
+unsafe impl<T, const N: usize> TrustedLen for IntoIter<T, N> {}
+
+/// A draining iterator over a range of elements in an [`ArrayVec`]. Produces values of type `T`.
+///
+/// Behaves like the [`Drain`](crate::collections::contiguous::vector::Drain) of a Vector, closing
+/// the gap left by the range when dropped.
+///
+/// See [`ArrayVec::drain`].
+pub struct Drain<'a, T, const N: usize> {
+    pub(crate) inner: RawDrain<'a, ArrayVec<T, N>>,
+}
+
+impl<T, const N: usize> RawBuffer for ArrayVec<T, N> {
+    type Item = T;
+
+    fn raw_parts(&mut self) -> (*mut T, &mut usize) {
+        (self.buf.as_mut_ptr().cast(), &mut self.len)
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for Drain<'a, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.inner.len(), Some(self.inner.len()))
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for Drain<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, T, const N: usize> FusedIterator for Drain<'a, T, N> {}
+
+impl<'a, T, const N: usize> ExactSizeIterator for Drain<'a, T, N> {}
+
+// SAFETY: Drain::size_hint returns the exact length of the iterator.
+unsafe impl<'a, T, const N: usize> TrustedLen for Drain<'a, T, N> {}
+
+// Just use the iter and iter_mut definitions provided by Deref<Target=[T]>.
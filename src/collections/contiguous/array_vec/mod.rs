@@ -0,0 +1,14 @@
+//! A module containing [`ArrayVec`] and associtated types.
+//!
+//! Other included types are [`IntoIter`] for owned iteration over an ArrayVec and [`Drain`] for
+//! removing a range of elements. [`IterMut`](std::slice::IterMut) and [`Iter`](std::slice::Iter)
+//! from [`std::slice`] are used for borrowed iteration.
+//!
+//! [`ArrayVec`] is also re-exported under the parent module.
+
+mod array_vec;
+mod iter;
+mod tests;
+
+pub use array_vec::*;
+pub use iter::*;
@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+use super::*;
+use crate::collections::contiguous::Vector;
+use crate::util::alloc::{CountedDrop, PanicOnDrop, ZeroSizedType, counted};
+use crate::util::panic::assert_panics;
+
+#[test]
+fn test_capacity_overflow() {
+    let mut vec: ArrayVec<u32, 3> = ArrayVec::new();
+    assert!(vec.try_push(0).is_ok());
+    assert!(vec.try_insert(0, 1).is_ok());
+    assert!(vec.try_push(2).is_ok());
+    assert!(vec.is_full());
+
+    assert!(vec.try_push(3).is_err(), "Pushing onto a full ArrayVec should fail.");
+    assert!(vec.try_insert(0, 3).is_err(), "Inserting into a full ArrayVec should fail.");
+    assert_eq!(*vec, [1, 0, 2], "A failed push or insert shouldn't modify the ArrayVec.");
+
+    let counter = CountedDrop::new(0);
+    let mut vec: ArrayVec<CountedDrop, 1> = ArrayVec::from([counter.clone()]);
+    let error = vec.try_push(counter.clone()).expect_err("The ArrayVec should be full.");
+    let error = vec.try_insert(0, error.into_inner()).expect_err("The ArrayVec should be full.");
+    let value = error.into_inner();
+    assert_eq!(*counter.borrow(), 0, "A failed push or insert should hand back the value.");
+    assert!(Rc::ptr_eq(&value, &counter), "The returned value should be the rejected one.");
+    drop((vec, value));
+    assert_eq!(counter.take(), 2);
+
+    assert_panics!({
+        let mut vec: ArrayVec<u32, 3> = ArrayVec::from([0, 1, 2]);
+        vec.push(3);
+    });
+    assert_panics!({
+        let _: ArrayVec<u32, 3> = (0..4).collect();
+    });
+
+    let mut vec: ArrayVec<u32, 0> = ArrayVec::new();
+    assert!(vec.try_push(0).is_err(), "An ArrayVec with N = 0 should always be full.");
+
+    let mut vec: ArrayVec<ZeroSizedType, 2> = ArrayVec::new();
+    vec.push(ZeroSizedType);
+    vec.push(ZeroSizedType);
+    assert!(vec.try_push(ZeroSizedType).is_err(), "ZSTs should still be limited to N items.");
+}
+
+#[test]
+fn test_remove() {
+    let mut vec: ArrayVec<_, 8> = (0..6).collect();
+    assert_eq!(vec.remove(1), 1);
+    assert_eq!(vec.swap_remove(0), 0);
+    assert_eq!(*vec, [5, 2, 3, 4], "swap_remove should replace the element with the last one.");
+    assert_eq!(vec.swap_remove(3), 4);
+    assert_eq!(vec.replace(0, 100), 5);
+    assert_eq!(vec.pop(), Some(3));
+    assert_eq!(*vec, [100, 2]);
+
+    assert_panics!({
+        let mut vec: ArrayVec<u32, 4> = (0..2).collect();
+        vec.swap_remove(2);
+    });
+}
+
+#[test]
+fn test_drain() {
+    let mut vec: ArrayVec<_, 8> = (0..8).collect();
+    assert!(vec.drain(2..5).eq([2, 3, 4]));
+    assert_eq!(*vec, [0, 1, 5, 6, 7], "The gap should be closed.");
+    assert!(vec.drain(3..).rev().eq([7, 6]));
+    assert_eq!(*vec, [0, 1, 5]);
+
+    let counter = CountedDrop::new(0);
+    let mut vec: ArrayVec<_, 8> = counted(&counter, 6);
+    let mut drain = vec.drain(1..4);
+    drop(drain.next());
+    assert_eq!(counter.take(), 1);
+    drop(drain);
+    assert_eq!(counter.take(), 2, "Elements which weren't produced should be dropped.");
+    assert_eq!(vec.len(), 3);
+
+    mem::forget(vec.drain(1..2));
+    assert_eq!(
+        vec.len(),
+        1,
+        "Leaking a Drain should leave the ArrayVec with the elements before it."
+    );
+    drop(vec);
+    assert_eq!(counter.take(), 1, "Leaking a Drain shouldn't cause any double drops.");
+
+    assert_panics!({
+        let mut vec: ArrayVec<u32, 4> = (0..3).collect();
+        vec.drain(2..4);
+    });
+}
+
+#[test]
+fn test_drain_panic_safety() {
+    let counter = CountedDrop::new(0);
+    let mut vec: ArrayVec<_, 6> = (0..6)
+        .map(|i| PanicOnDrop { _counter: counter.clone(), panics: i == 2 })
+        .collect();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| drop(vec.drain(1..4))));
+    assert!(result.is_err());
+    assert_eq!(counter.take(), 3, "The rest of the range should be dropped after a panic.");
+    assert_eq!(vec.len(), 3, "The gap should still be closed after a panic.");
+    drop(vec);
+    assert_eq!(counter.take(), 3);
+}
+
+#[test]
+fn test_vector_conversion() {
+    let vec: ArrayVec<_, 4> = Vector::from_iter_sized(0..3).try_into()
+        .expect("A Vector with len <= N should fit.");
+    assert_eq!(*vec, [0, 1, 2]);
+
+    let result: Result<ArrayVec<_, 2>, _> = Vector::from_iter_sized(0..3).try_into();
+    let vec = result.expect_err("A Vector with len > N shouldn't fit.").into_inner();
+    assert_eq!(*vec, [0, 1, 2], "The Vector should be returned intact.");
+
+    let counter = CountedDrop::new(0);
+    let result: Result<ArrayVec<_, 2>, _> = counted::<Vector<_>>(&counter, 3).try_into();
+    let vec = result.expect_err("A Vector with len > N shouldn't fit.").into_inner();
+    assert_eq!(counter.take(), 0, "A Vector which doesn't fit shouldn't be dropped.");
+    assert_eq!(vec.len(), 3);
+    drop(vec);
+    assert_eq!(counter.take(), 3);
+
+    let vec: Vector<_> = counted(&counter, 3);
+    let vec: ArrayVec<_, 4> = vec.try_into().expect("A Vector with len <= N should fit.");
+    assert_eq!(counter.take(), 0, "Converting from a Vector should move values, not drop them.");
+
+    let vec: Vector<_> = vec.into();
+    assert_eq!(counter.take(), 0, "Converting into a Vector should move values, not drop them.");
+    drop(vec);
+    assert_eq!(counter.take(), 3);
+}
+
+#[test]
+fn test_drop() {
+    let counter = CountedDrop::new(0);
+
+    let mut vec: ArrayVec<_, 4> = counted(&counter, 3);
+    vec.truncate(1);
+    assert_eq!(counter.take(), 2, "Truncating should drop the removed values.");
+    drop(vec);
+    assert_eq!(counter.take(), 1);
+
+    let mut iter = counted::<ArrayVec<_, 4>>(&counter, 4).into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(counter.take(), 2);
+    drop(iter);
+    assert_eq!(counter.take(), 2, "Dropping an IntoIter should drop the remaining values.");
+}
@@ -0,0 +1,686 @@
+use std::cmp;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::TrustedLen;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Index, IndexMut, RangeBounds};
+use std::ptr;
+use std::slice;
+
+use super::{Drain, Gap, Iter, IterMut};
+use crate::collections::contiguous::vector::{GROWTH_FACTOR, MAX_CAP, MIN_CAP};
+use crate::collections::contiguous::{Array, Vector};
+use crate::util::error::{CapacityOverflow, IndexOutOfBounds, TryReserveError};
+use crate::util::range::resolve_range;
+use crate::util::result::ResultExtension;
+
+/// A double-ended queue, implemented as a growable ring buffer based on [`Array<T>`].
+///
+/// Items are stored in a single allocation, starting at a head index and wrapping around to the
+/// start of the buffer once they reach the end. This allows items to be added or removed at either
+/// end without moving any others, while keeping them (mostly) contiguous in memory. Because the
+/// items may wrap around, they are exposed as a pair of slices with
+/// [`as_slices`](Deque::as_slices), or as a single slice after calling
+/// [`make_contiguous`](Deque::make_contiguous).
+///
+/// # Time Complexity
+/// For this analysis of time complexity, variables are defined as follows:
+/// - `n`: The number of items in the Deque.
+/// - `k`: The number of places to rotate by.
+/// - `i`: The index of the first item in the drained range.
+/// - `m`: The number of items in the drained range.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `get` | `O(1)` |
+/// | `len` | `O(1)` |
+/// | `front/back` | `O(1)` |
+/// | `push_front/back` | `O(1)`*, `O(n)` |
+/// | `pop_front/back` | `O(1)` |
+/// | `as_slices` | `O(1)` |
+/// | `make_contiguous` | `O(n)` |
+/// | `rotate` | `O(min(k, n-k))`** |
+/// | `drain` | `O(m + min(i, n-i-m))` |
+/// | `reserve`, `try_reserve` | `O(n)`***, `O(1)` |
+/// | `shrink_to_fit` | `O(n)` |
+/// | `contains` | `O(n)` |
+///
+/// \* If the Deque doesn't have enough capacity for the new element, `push_front/back` will take
+/// `O(n)`.
+///
+/// \** If the Deque is full, `rotate` only moves the head, taking `O(1)`.
+///
+/// \*** If the Deque has enough capacity for the additional items already, `reserve` is `O(1)`.
+pub struct Deque<T> {
+    pub(crate) arr: Array<MaybeUninit<T>>,
+    pub(crate) head: usize,
+    pub(crate) len: usize,
+}
+
+impl<T> Deque<T> {
+    /// Creates a new Deque with length and capacity 0. Memory will be allocated when the capacity
+    /// changes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Deque;
+    /// let deque: Deque<u8> = Deque::new();
+    /// assert_eq!(deque.len(), 0);
+    /// assert_eq!(deque.cap(), 0);
+    /// ```
+    pub fn new() -> Deque<T> {
+        Deque {
+            arr: Array::new(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Creates a new Deque with capacity exactly equal to the provided value, allowing values to
+    /// be added to either end without reallocation.
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    pub fn with_cap(cap: usize) -> Deque<T> {
+        Deque {
+            arr: Array::new_uninit(cap),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Creates a new Deque with capacity exactly equal to the provided value, returning an error
+    /// rather than panicking or aborting if the allocation fails.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if memory layout size exceeds [`isize::MAX`] or if the
+    /// allocator fails to provide the memory.
+    pub fn try_with_cap(cap: usize) -> Result<Deque<T>, TryReserveError> {
+        Ok(Deque {
+            arr: Array::try_new_uninit(cap)?,
+            head: 0,
+            len: 0,
+        })
+    }
+
+    /// Creates a Deque from a type which implements [`IntoIterator`] and creates an
+    /// [`ExactSizeIterator`].
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    pub fn from_iter_sized<I>(value: I) -> Self
+    where
+        I: Iterator<Item = T> + ExactSizeIterator + TrustedLen,
+    {
+        let iter = value.into_iter();
+        let mut deque = Deque::with_cap(iter.len());
+
+        for item in iter {
+            deque.push_back(item);
+        }
+
+        deque
+    }
+
+    /// Returns the length of the Deque.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the Deque contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the current capacity of the Deque.
+    pub const fn cap(&self) -> usize {
+        self.arr.size()
+    }
+
+    /// Returns a reference to the item at the provided index, counting from the front, if it
+    /// exists.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            // SAFETY: index < len, so the item at the corresponding position is initialized.
+            Some(unsafe { self.arr[self.physical(index)].assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the item at the provided index, counting from the front, if
+    /// it exists.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            let physical = self.physical(index);
+            // SAFETY: index < len, so the item at the corresponding position is initialized.
+            Some(unsafe { self.arr[physical].assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the item at the front of the Deque, if it isn't empty.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a mutable reference to the item at the front of the Deque, if it isn't empty.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns a reference to the item at the back of the Deque, if it isn't empty.
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    /// Returns a mutable reference to the item at the back of the Deque, if it isn't empty.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.checked_sub(1)?)
+    }
+
+    /// Pushes the provided value onto the back of the Deque, increasing the capacity if required.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the Deque would have a size that exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Deque;
+    /// let mut deque = Deque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// deque.push_front(0);
+    /// assert_eq!(deque.as_slices(), (&[0][..], &[1, 2][..]));
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        if self.len == self.cap() {
+            self.grow();
+        }
+
+        let physical = self.physical(self.len);
+        self.arr[physical].write(value);
+        self.len += 1;
+    }
+
+    /// Pushes the provided value onto the front of the Deque, increasing the capacity if required.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the Deque would have a size that exceeds [`isize::MAX`].
+    pub fn push_front(&mut self, value: T) {
+        if self.len == self.cap() {
+            self.grow();
+        }
+
+        self.head = self.physical(self.cap() - 1);
+        self.arr[self.head].write(value);
+        self.len += 1;
+    }
+
+    /// Pops the value off the back of the Deque, returning it if the Deque isn't empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        // SAFETY: The item at the old back of the Deque is initialized, and is now considered
+        // uninitialized because len has been decremented, so it is moved out rather than copied.
+        Some(unsafe { self.arr[self.physical(self.len)].assume_init_read() })
+    }
+
+    /// Pops the value off the front of the Deque, returning it if the Deque isn't empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Deque;
+    /// let mut deque = Deque::from_iter_sized(0..3);
+    /// assert_eq!(deque.pop_front(), Some(0));
+    /// assert_eq!(deque.pop_back(), Some(2));
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: The item at head is initialized, and is considered uninitialized once head is
+        // moved past it, so it is moved out rather than copied.
+        let value = unsafe { self.arr[self.head].assume_init_read() };
+        self.head = self.physical(1);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns true if the Deque contains an item equal to the one provided.
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let (front, back) = self.as_slices();
+        front.contains(value) || back.contains(value)
+    }
+
+    /// Returns a pair of slices containing all items in the Deque, in order. The second slice is
+    /// only non-empty when the items wrap around the end of the buffer.
+    pub const fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.slice_lens();
+        let ptr = self.arr.ptr.as_ptr().cast::<T>();
+
+        // SAFETY: The front slice starts at head and the back slice starts at the beginning of the
+        // buffer. slice_lens ensures that both contain only initialized items and that they don't
+        // overlap. The borrow checker enforces that self isn't mutated while they exist.
+        unsafe {
+            (
+                slice::from_raw_parts(ptr.add(self.head), front),
+                slice::from_raw_parts(ptr, back),
+            )
+        }
+    }
+
+    /// Returns a pair of mutable slices containing all items in the Deque, in order. The second
+    /// slice is only non-empty when the items wrap around the end of the buffer.
+    pub const fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.slice_lens();
+        let ptr = self.arr.ptr.as_ptr().cast::<T>();
+
+        // SAFETY: The front slice starts at head and the back slice starts at the beginning of the
+        // buffer. slice_lens ensures that both contain only initialized items and that they don't
+        // overlap, so they can be borrowed mutably at the same time. The borrow checker enforces
+        // that self isn't accessed while they exist.
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr.add(self.head), front),
+                slice::from_raw_parts_mut(ptr, back),
+            )
+        }
+    }
+
+    /// Rearranges the items of the Deque so that they are stored contiguously, returning them as a
+    /// single mutable slice. If the items don't wrap around the end of the buffer, nothing is
+    /// moved.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Deque;
+    /// let mut deque = Deque::with_cap(4);
+    /// deque.extend([2, 3]);
+    /// deque.push_front(1);
+    /// deque.push_front(0);
+    /// assert_eq!(deque.as_slices(), (&[0, 1][..], &[2, 3][..]));
+    /// assert_eq!(deque.make_contiguous(), &[0, 1, 2, 3]);
+    /// assert_eq!(deque.as_slices(), (&[0, 1, 2, 3][..], &[][..]));
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.is_wrapped() {
+            self.move_to_start();
+        }
+
+        self.as_mut_slices().0
+    }
+
+    /// Rotates the Deque in place by `offset` places, such that the item at index `offset` becomes
+    /// the front. A negative offset rotates in the other direction, so that the last `-offset`
+    /// items are moved to the front. Offsets larger than the length wrap around.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Deque;
+    /// let mut deque = Deque::from_iter_sized(0..5);
+    /// deque.rotate(2);
+    /// assert_eq!(deque.make_contiguous(), &[2, 3, 4, 0, 1]);
+    /// deque.rotate(-3);
+    /// assert_eq!(deque.make_contiguous(), &[4, 0, 1, 2, 3]);
+    /// ```
+    pub fn rotate(&mut self, offset: isize) {
+        if self.len == 0 {
+            return;
+        }
+
+        // len <= isize::MAX, so this can't overflow.
+        let places = offset.rem_euclid(self.len as isize) as usize;
+
+        if self.len == self.cap() {
+            // When full, the buffer is a complete cycle, so moving head is enough.
+            self.head = self.physical(places);
+        } else if places <= self.len - places {
+            for _ in 0..places {
+                // SAFETY: The Deque isn't empty and popping creates space for the push.
+                let item = unsafe { self.pop_front().unwrap_unchecked() };
+                self.push_back(item);
+            }
+        } else {
+            for _ in places..self.len {
+                // SAFETY: The Deque isn't empty and popping creates space for the push.
+                let item = unsafe { self.pop_back().unwrap_unchecked() };
+                self.push_front(item);
+            }
+        }
+    }
+
+    /// Removes all items within the provided range from the Deque, returning them as an iterator.
+    /// Any items that aren't produced by the iterator are dropped along with it, at which point
+    /// the remaining items are moved to close the gap.
+    ///
+    /// If the iterator is leaked, the Deque may lose all items from the start of the range onwards.
+    ///
+    /// # Panics
+    /// Panics if the range starts after it ends or ends after the length of the Deque.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Deque;
+    /// let mut deque = Deque::from_iter_sized(0..6);
+    /// let drained: Vec<_> = deque.drain(1..4).collect();
+    /// assert_eq!(drained, [1, 2, 3]);
+    /// assert_eq!(deque.make_contiguous(), &[0, 4, 5]);
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let range = resolve_range(range, self.len).throw();
+        let len = self.len;
+
+        // Hide the drained items and everything after them, until the Drain is dropped.
+        self.len = range.start;
+
+        Drain {
+            gap: Gap {
+                deque: self,
+                start: range.start,
+                end: range.end,
+                len,
+            },
+            front: range.start,
+            back: range.end,
+        }
+    }
+
+    /// Removes and drops all items in the Deque, without changing the capacity.
+    pub fn clear(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        let (front, back) = (ptr::from_mut(front), ptr::from_mut(back));
+        self.len = 0;
+        self.head = 0;
+
+        // SAFETY: The slices contain all initialized items, which are no longer accessible through
+        // self because len has been reset.
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+
+    /// Ensures that the Deque has capacity to hold an additional `extra` elements. After invoking
+    /// this method, the capacity will be >= len + extra.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the Deque would have a size that exceeds [`isize::MAX`].
+    pub fn reserve(&mut self, extra: usize) {
+        self.try_reserve(extra).unwrap_or_else(|error| error.handle())
+    }
+
+    /// Ensures that the Deque has capacity to hold an additional `extra` elements, returning an
+    /// error rather than panicking or aborting if the allocation fails. After a successful
+    /// invocation, the capacity will be >= len + extra.
+    ///
+    /// If an error is returned, the Deque is left unchanged.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the memory layout of the Deque would have a size that
+    /// exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    pub fn try_reserve(&mut self, extra: usize) -> Result<(), TryReserveError> {
+        let new_cap = self.len.checked_add(extra).ok_or(CapacityOverflow)?;
+
+        if new_cap <= self.cap() { return Ok(()); }
+
+        self.try_grow_to(new_cap)
+    }
+
+    /// Shrinks the Deque so that its capacity is equal to its length, moving its items to the
+    /// start of the buffer first.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the Deque would have a size that exceeds [`isize::MAX`].
+    pub fn shrink_to_fit(&mut self) {
+        self.move_to_start();
+        self.arr.realloc(self.len);
+    }
+
+    /// Returns an iterator over all items in the Deque, as references, from front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.into_iter()
+    }
+
+    /// Returns an iterator over all items in the Deque, as mutable references, from front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.into_iter()
+    }
+
+    /// Grows the buffer to allow for the insertion of additional elements. After calling this, the
+    /// Deque can take at least one more element.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the Deque would have a size that exceeds [`isize::MAX`].
+    pub(crate) fn grow(&mut self) {
+        // SAFETY: old_cap < isize::MAX, so old_cap * 2 can't overflow. Can still exceed isize::MAX.
+        let mut new_cap = cmp::max(self.cap() * GROWTH_FACTOR, MIN_CAP);
+
+        // If we would grow past maximum capacity, instead use the maximum if it represents growth.
+        if (new_cap * size_of::<T>() > MAX_CAP) && (MAX_CAP > self.cap() * size_of::<T>()) {
+            new_cap = MAX_CAP;
+        }
+
+        self.try_grow_to(new_cap).unwrap_or_else(|error| error.handle())
+    }
+
+    /// Reallocates the buffer with the provided capacity, which must be larger than the current
+    /// one, and moves the front of the Deque to the end of the new buffer if the items wrap around.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the memory layout of the Deque would have a size that
+    /// exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    fn try_grow_to(&mut self, new_cap: usize) -> Result<(), TryReserveError> {
+        let old_cap = self.cap();
+        let wrapped = self.is_wrapped();
+        self.arr.try_realloc(new_cap)?;
+
+        if wrapped {
+            let front = old_cap - self.head;
+            let new_head = new_cap - front;
+            let ptr = self.arr.ptr.as_ptr();
+
+            // SAFETY: The front of the Deque occupies head..old_cap, which is moved to the end of
+            // the new buffer, new_head..new_cap. ptr::copy handles any overlap between the two.
+            unsafe { ptr::copy(ptr.add(self.head), ptr.add(new_head), front); }
+            self.head = new_head;
+        }
+
+        Ok(())
+    }
+
+    /// Moves all items so that the front of the Deque is at the start of the buffer.
+    pub(crate) fn move_to_start(&mut self) {
+        if self.is_wrapped() {
+            // Rotating the whole buffer preserves the cyclic order of the items.
+            self.arr.rotate_left(self.head);
+        } else {
+            let ptr = self.arr.ptr.as_ptr();
+            // SAFETY: The items occupy head..head + len, which is within the buffer. ptr::copy
+            // handles any overlap with 0..len.
+            unsafe { ptr::copy(ptr.add(self.head), ptr, self.len); }
+        }
+
+        self.head = 0;
+    }
+
+    /// Converts a logical index, relative to the front of the Deque, into an index in the buffer.
+    /// `index` must be no greater than the capacity.
+    pub(crate) const fn physical(&self, index: usize) -> usize {
+        // head < cap and index <= cap <= isize::MAX, so this can't overflow.
+        let physical = self.head + index;
+
+        if physical >= self.cap() {
+            physical - self.cap()
+        } else {
+            physical
+        }
+    }
+
+    /// Returns true if the items of the Deque wrap around the end of the buffer.
+    pub(crate) const fn is_wrapped(&self) -> bool {
+        self.head + self.len > self.cap()
+    }
+
+    /// Returns the lengths of the slices returned by [`as_slices`](Deque::as_slices).
+    const fn slice_lens(&self) -> (usize, usize) {
+        if self.is_wrapped() {
+            let front = self.cap() - self.head;
+            (front, self.len - front)
+        } else {
+            (self.len, 0)
+        }
+    }
+}
+
+impl<T> Index<usize> for Deque<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index)
+            .ok_or(IndexOutOfBounds {
+                index,
+                len: self.len,
+            })
+            .throw()
+    }
+}
+
+impl<T> IndexMut<usize> for Deque<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        self.get_mut(index)
+            .ok_or(IndexOutOfBounds {
+                index,
+                len,
+            })
+            .throw()
+    }
+}
+
+impl<T> Extend<T> for Deque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+
+    fn extend_one(&mut self, item: T) {
+        self.push_back(item);
+    }
+
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+}
+
+impl<T> FromIterator<T> for Deque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(value: I) -> Self {
+        let iter = value.into_iter();
+        let mut deque = Deque::with_cap(iter.size_hint().0);
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for Deque<T> {
+    fn drop(&mut self) {
+        self.clear();
+
+        // Implicitly drop self.arr, containing only MaybeUninit values without a no-op drop.
+        // Doing so also deallocates the owned memory.
+    }
+}
+
+// SAFETY: Deques, when used safely rely on unique pointers and are therefore safe for Send when T:
+// Send.
+unsafe impl<T: Send> Send for Deque<T> {}
+// SAFETY: Deque's safe API obeys all rules of the borrow checker, so no interior mutability
+// occurs. This means that Deque<T> can safely implement Sync when T: Sync.
+unsafe impl<T: Sync> Sync for Deque<T> {}
+
+impl<T: Clone> Clone for Deque<T> {
+    fn clone(&self) -> Self {
+        let mut deque = Deque::with_cap(self.len);
+        deque.extend(self.iter().cloned());
+        deque
+    }
+}
+
+impl<T> From<Vector<T>> for Deque<T> {
+    /// Converts a Vector into a Deque, taking ownership of its allocation rather than copying any
+    /// items.
+    fn from(value: Vector<T>) -> Self {
+        let (ptr, len, cap) = value.into_parts();
+
+        Deque {
+            // SAFETY: The parts were just taken from a valid Vector, so they describe an
+            // allocation of cap values from the global allocator.
+            arr: unsafe { Array::from_parts(ptr, cap) },
+            head: 0,
+            len,
+        }
+    }
+}
+
+impl<T> From<Deque<T>> for Vector<T> {
+    /// Converts a Deque into a Vector, reusing its allocation. The items are moved to the start of
+    /// the buffer first, which takes `O(n)` unless they are there already.
+    fn from(mut value: Deque<T>) -> Self {
+        value.move_to_start();
+
+        Vector {
+            arr: mem::replace(&mut value.arr, Array::new()),
+            len: mem::take(&mut value.len),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Deque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Deque<T> {}
+
+impl<T: Hash> Hash for Deque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T: Debug> Debug for Deque<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Deque")
+            .field_with("contents", |f| f.debug_list().entries(self.iter()).finish())
+            .field("len", &self.len)
+            .field("cap", &self.cap())
+            .finish()
+    }
+}
+
+impl<T: Debug> Display for Deque<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
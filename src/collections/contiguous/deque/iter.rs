@@ -0,0 +1,283 @@
+use std::iter::{FusedIterator, TrustedLen};
+use std::ptr;
+use std::slice;
+
+use super::Deque;
+
+impl<T> IntoIterator for Deque<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+/// A type for owned iteration over a [`Deque`]. Produces values of type `T`.
+///
+/// See [`Deque::into_iter`].
+pub struct IntoIter<T>(pub(crate) Deque<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+// SAFETY: IntoIter::size_hint returns the exact length of the iterator.
+unsafe impl<T> TrustedLen for IntoIter<T> {}
+
+impl<'a, T> IntoIterator for &'a Deque<T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (front, back) = self.as_slices();
+        Iter {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+}
+
+/// A type for borrowed iteration over a [`Deque`]. Produces values of type `&T`, by iterating over
+/// each of the slices returned by [`Deque::as_slices`] in turn.
+///
+/// See [`Deque::iter`].
+pub struct Iter<'a, T> {
+    pub(crate) front: slice::Iter<'a, T>,
+    pub(crate) back: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+// SAFETY: Iter::size_hint returns the exact length of the iterator.
+unsafe impl<'a, T> TrustedLen for Iter<'a, T> {}
+
+impl<'a, T> IntoIterator for &'a mut Deque<T> {
+    type Item = &'a mut T;
+
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (front, back) = self.as_mut_slices();
+        IterMut {
+            front: front.iter_mut(),
+            back: back.iter_mut(),
+        }
+    }
+}
+
+/// A type for mutable iteration over a [`Deque`]. Produces values of type `&mut T`, by iterating
+/// over each of the slices returned by [`Deque::as_mut_slices`] in turn.
+///
+/// See [`Deque::iter_mut`].
+pub struct IterMut<'a, T> {
+    pub(crate) front: slice::IterMut<'a, T>,
+    pub(crate) back: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+// SAFETY: IterMut::size_hint returns the exact length of the iterator.
+unsafe impl<'a, T> TrustedLen for IterMut<'a, T> {}
+
+/// A draining iterator over a range of items in a [`Deque`]. Produces values of type `T`.
+///
+/// While this iterator exists, the Deque only considers the items before the range to be part of
+/// it. When dropped, any items in the range which haven't been produced are dropped and the
+/// remaining items are moved to close the gap, moving whichever side of the range is shorter. This
+/// happens even if dropping one of the items panics.
+///
+/// See [`Deque::drain`].
+pub struct Drain<'a, T> {
+    pub(crate) gap: Gap<'a, T>,
+    pub(crate) front: usize, // The index of the next item to produce from the front.
+    pub(crate) back: usize, // The index one after the next item to produce from the back.
+}
+
+/// The range of a [`Deque`] which is being drained. When dropped, the items on the shorter side of
+/// the range are moved to close the gap and the Deque's length is restored to include them.
+pub(crate) struct Gap<'a, T> {
+    pub(crate) deque: &'a mut Deque<T>,
+    pub(crate) start: usize, // The index of the first item in the range.
+    pub(crate) end: usize, // The index one after the last item in the range.
+    pub(crate) len: usize, // The length of the Deque before draining.
+}
+
+impl<'a, T> Gap<'a, T> {
+    /// Moves the item at the logical index `from` to `to`, leaving `from` uninitialized.
+    ///
+    /// # Safety
+    /// `from` must be initialized and `to` must be uninitialized, with both less than the capacity.
+    const unsafe fn move_item(&mut self, from: usize, to: usize) {
+        let ptr = self.deque.arr.ptr.as_ptr();
+        let (from, to) = (self.deque.physical(from), self.deque.physical(to));
+        // SAFETY: Both indices are within the buffer, and from != to because one is initialized
+        // and the other isn't. Gap::drop never moves items when the range is empty.
+        unsafe { ptr::copy_nonoverlapping(ptr.add(from), ptr.add(to), 1); }
+    }
+}
+
+impl<'a, T> Drop for Gap<'a, T> {
+    fn drop(&mut self) {
+        let count = self.end - self.start;
+        if count == 0 {
+            // Nothing was drained, so every item is still in place.
+            self.deque.len = self.len;
+            return;
+        }
+        let after = self.len - self.end;
+
+        if self.start <= after {
+            // Move the items before the range towards the back.
+            for index in (0..self.start).rev() {
+                // SAFETY: The item at index is initialized and index + count is either in the
+                // drained range or has already been moved.
+                unsafe { self.move_item(index, index + count); }
+            }
+            self.deque.head = self.deque.physical(count);
+        } else {
+            // Move the items after the range towards the front.
+            for index in self.end..self.len {
+                // SAFETY: The item at index is initialized and index - count is either in the
+                // drained range or has already been moved.
+                unsafe { self.move_item(index, index - count); }
+            }
+        }
+
+        self.deque.len = self.len - count;
+    }
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let physical = self.gap.deque.physical(self.front);
+            self.front += 1;
+            // SAFETY: Items between front and back are initialized and haven't been produced yet.
+            // front has been incremented, so this item won't be read again.
+            Some(unsafe { self.gap.deque.arr[physical].assume_init_read() })
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            let physical = self.gap.deque.physical(self.back);
+            // SAFETY: Items between front and back are initialized and haven't been produced yet.
+            // back has been decremented, so this item won't be read again.
+            Some(unsafe { self.gap.deque.arr[physical].assume_init_read() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> FusedIterator for Drain<'a, T> {}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {}
+
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        // Drops the second slice if dropping the first one panics.
+        struct DropSlice<T>(*mut [T]);
+
+        impl<T> Drop for DropSlice<T> {
+            fn drop(&mut self) {
+                // SAFETY: DropSlice is only constructed for initialized items which won't be
+                // accessed again.
+                unsafe { ptr::drop_in_place(self.0); }
+            }
+        }
+
+        let deque = &mut *self.gap.deque;
+        let count = self.back - self.front;
+        let start = deque.physical(self.front);
+        // The remaining items wrap around the end of the buffer if they don't fit after start.
+        let first = count.min(deque.cap() - start);
+        let ptr = deque.arr.ptr.as_ptr().cast::<T>();
+        self.front = self.back;
+
+        // SAFETY: The remaining items run from start towards the end of the buffer, with any
+        // others at the beginning of it. They won't be produced, because front has been moved to
+        // back. If dropping one of them panics, the other slice is still dropped by DropSlice and
+        // the Gap still closes when it is dropped as a field of self.
+        unsafe {
+            let back = DropSlice(ptr::slice_from_raw_parts_mut(ptr, count - first));
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr.add(start), first));
+            drop(back);
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! A module containing [`Deque`] and associtated types.
+//!
+//! Included types are [`IntoIter`] for owned iteration, [`Iter`] and [`IterMut`] for borrowed
+//! iteration and [`Drain`] for removing a range of items.
+//!
+//! [`Deque`] is also re-exported under the parent module.
+
+mod deque;
+mod iter;
+mod tests;
+
+pub use deque::*;
+pub use iter::*;
@@ -0,0 +1,196 @@
+#![cfg(test)]
+
+use std::panic::{self, AssertUnwindSafe};
+
+use super::*;
+use crate::collections::contiguous::Vector;
+use crate::util::alloc::{CountedDrop, ZeroSizedType};
+use crate::util::panic::assert_panics;
+
+/// Creates a Deque with capacity 8, containing 0..8 but starting at index 5 of the buffer, so that
+/// the items wrap around.
+fn wrapped() -> Deque<usize> {
+    let mut deque = Deque::with_cap(8);
+    deque.extend(3..8);
+    for i in (0..3).rev() {
+        deque.push_front(i);
+    }
+    deque
+}
+
+#[test]
+fn test_push_and_pop() {
+    let mut deque = Deque::new();
+    for i in 0..10 {
+        deque.push_back(i);
+        deque.push_front(-i);
+    }
+    assert_eq!(deque.len(), 20);
+    assert_eq!(deque.front(), Some(&-9));
+    assert_eq!(deque.back(), Some(&9));
+    assert_eq!(deque[10], 0, "Indexing should be relative to the front.");
+
+    for i in (0..10).rev() {
+        assert_eq!(deque.pop_back(), Some(i));
+        assert_eq!(deque.pop_front(), Some(-i));
+    }
+    assert!(deque.is_empty());
+    assert_eq!(deque.pop_front(), None);
+
+    assert_panics!({
+        let deque = Deque::from_iter_sized(0..3);
+        let _ = deque[3];
+    });
+}
+
+#[test]
+fn test_growth_while_wrapped() {
+    let mut deque = wrapped();
+    assert_eq!(deque.head, 5);
+    assert_eq!(deque.as_slices(), (&[0, 1, 2][..], &[3, 4, 5, 6, 7][..]));
+
+    deque.push_back(8);
+    assert_eq!(deque.cap(), 16);
+    assert!(
+        deque.iter().copied().eq(0..9),
+        "Items should remain in order after growing while wrapped."
+    );
+
+    let mut deque = wrapped();
+    deque.reserve(20);
+    assert!(deque.cap() >= 28);
+    assert!(deque.iter().copied().eq(0..8));
+}
+
+#[test]
+fn test_make_contiguous_and_rotate() {
+    let mut deque = wrapped();
+    assert_eq!(deque.make_contiguous(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(deque.head, 0);
+
+    let mut deque = wrapped();
+    deque.rotate(3);
+    assert_eq!(deque.head, 0, "Rotating a full Deque should only move the head.");
+    assert!(deque.iter().copied().eq((3..8).chain(0..3)));
+    deque.rotate(-11);
+    assert!(deque.iter().copied().eq(0..8), "Offsets larger than len should wrap around.");
+
+    let mut deque = Deque::with_cap(10);
+    deque.extend(0..6);
+    deque.rotate(5);
+    assert!(deque.iter().copied().eq([5, 0, 1, 2, 3, 4]));
+    deque.rotate(1);
+    assert!(deque.iter().copied().eq(0..6));
+    assert_eq!(deque.cap(), 10, "Rotating shouldn't grow the Deque.");
+}
+
+#[test]
+fn test_drain() {
+    let mut deque = wrapped();
+    assert!(deque.drain(1..3).eq([1, 2]));
+    assert!(deque.iter().copied().eq([0, 3, 4, 5, 6, 7]), "The front should close the gap.");
+
+    let mut deque = wrapped();
+    assert!(deque.drain(4..7).rev().eq([6, 5, 4]));
+    assert!(deque.iter().copied().eq([0, 1, 2, 3, 7]), "The back should close the gap.");
+
+    let mut deque = wrapped();
+    assert_eq!(deque.drain(..).count(), 8);
+    assert!(deque.is_empty());
+
+    let counter = CountedDrop::new(0);
+    let mut deque = Deque::new();
+    deque.extend((0..6).map(|_| counter.clone()));
+    let mut drain = deque.drain(2..5);
+    drop(drain.next());
+    assert_eq!(counter.take(), 1);
+    drop(drain);
+    assert_eq!(counter.take(), 2, "Items that weren't produced should be dropped.");
+    assert_eq!(deque.len(), 3);
+    drop(deque);
+    assert_eq!(counter.take(), 3);
+
+    assert_panics!({
+        let mut deque = wrapped();
+        deque.drain(3..9);
+    });
+    assert_panics!({
+        let mut deque = wrapped();
+        #[allow(clippy::reversed_empty_ranges)]
+        deque.drain(4..2);
+    });
+}
+
+#[test]
+fn test_drain_empty_range() {
+    for i in [0, 2, 5] {
+        let mut deque = Deque::new();
+        deque.extend(0..5_usize);
+        assert_eq!(deque.drain(i..i).count(), 0);
+        assert!(deque.iter().copied().eq(0..5), "Draining {i}..{i} shouldn't move any items.");
+    }
+
+    for i in [0, 4, 8] {
+        let mut deque = wrapped();
+        assert_eq!(deque.drain(i..i).count(), 0);
+        assert!(deque.iter().copied().eq(0..8), "Draining {i}..{i} shouldn't move any items.");
+        deque.push_back(8);
+        deque.push_front(100);
+        assert_eq!(deque.front(), Some(&100));
+        assert_eq!(deque.back(), Some(&8));
+    }
+}
+
+#[test]
+fn test_drain_panic_safety() {
+    struct PanicOnDrop {
+        _counter: CountedDrop,
+        value: usize,
+    }
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            if self.value == 2 {
+                panic!("PanicOnDrop");
+            }
+        }
+    }
+
+    let counter = CountedDrop::new(0);
+    let item = |value| PanicOnDrop { _counter: counter.clone(), value };
+    let mut deque = Deque::with_cap(8);
+    deque.extend((3..8).map(item));
+    for value in (0..3).rev() {
+        deque.push_front(item(value));
+    }
+
+    // The range wraps around the end of the buffer, and the panic happens in the first part of it.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| drop(deque.drain(1..5))));
+    assert!(result.is_err());
+    assert_eq!(counter.take(), 4, "The rest of the range should be dropped after a panic.");
+    assert!(
+        deque.iter().map(|item| item.value).eq([0, 5, 6, 7]),
+        "The gap should still be closed after a panic."
+    );
+    drop(deque);
+    assert_eq!(counter.take(), 4);
+}
+
+#[test]
+fn test_vector_conversions() {
+    let vec = Vector::from_iter_sized(0..8_usize);
+    let ptr = vec.as_ptr();
+    let mut deque = Deque::from(vec);
+    assert_eq!(deque.as_slices().0.as_ptr(), ptr, "Converting from a Vector shouldn't copy.");
+
+    deque.rotate(3);
+    deque.push_front(100);
+    let vec = Vector::from(deque);
+    assert_eq!(*vec, [100, 3, 4, 5, 6, 7, 0, 1, 2]);
+
+    let mut deque = Deque::new();
+    deque.extend([ZeroSizedType; 4]);
+    deque.push_front(ZeroSizedType);
+    assert_eq!(deque.drain(1..3).count(), 2);
+    assert_eq!(Vector::from(deque).len(), 3);
+}
@@ -0,0 +1,123 @@
+use std::ops::Range;
+use std::ptr;
+
+/// A collection which stores its elements contiguously at the start of a buffer, followed by its
+/// spare capacity, such as [`Vector`](super::Vector) or [`ArrayVec`](super::ArrayVec). This allows
+/// ranges to be drained from it with a [`RawDrain`].
+pub(crate) trait RawBuffer {
+    type Item;
+
+    /// Returns a pointer to the start of the buffer and a mutable reference to the length of the
+    /// collection.
+    fn raw_parts(&mut self) -> (*mut Self::Item, &mut usize);
+}
+
+/// The elements of a [`RawBuffer`] after a range which has been removed. When dropped, they are
+/// moved back to close the gap left by the range and the collection's length is restored to
+/// include them.
+///
+/// The collection's length marks the start of the gap, so that the gap can be partially filled
+/// before the Gap is dropped.
+pub(crate) struct Gap<'a, V: RawBuffer> {
+    pub(crate) vec: &'a mut V,
+    pub(crate) tail_start: usize,
+    pub(crate) tail_len: usize,
+}
+
+impl<'a, V: RawBuffer> Drop for Gap<'a, V> {
+    fn drop(&mut self) {
+        let (ptr, len) = self.vec.raw_parts();
+        let start = *len;
+
+        if start != self.tail_start {
+            // SAFETY: The tail is initialized and both ranges are within the buffer. ptr::copy
+            // handles any overlap.
+            unsafe { ptr::copy(ptr.add(self.tail_start), ptr.add(start), self.tail_len); }
+        }
+
+        *len = start + self.tail_len;
+    }
+}
+
+/// The shared implementation of the draining iterators of [`RawBuffer`]s, which produces the
+/// elements in a range from either end.
+///
+/// While a RawDrain exists, the collection only considers the elements before the range to be part
+/// of it. When dropped, any elements in the range which haven't been produced are dropped and the
+/// elements after the range are moved back to close the gap. This happens even if dropping one of
+/// the elements panics.
+pub(crate) struct RawDrain<'a, V: RawBuffer> {
+    pub(crate) gap: Gap<'a, V>,
+    pub(crate) front: usize, // The index of the next element to produce from the front.
+    pub(crate) back: usize, // The index one after the next element to produce from the back.
+}
+
+impl<'a, V: RawBuffer> RawDrain<'a, V> {
+    /// Creates a RawDrain over the provided `range` of `vec`, which must be within its length.
+    pub(crate) fn new(vec: &'a mut V, range: Range<usize>) -> RawDrain<'a, V> {
+        let (_, len) = vec.raw_parts();
+        let tail_len = *len - range.end;
+
+        // Hide the drained elements and everything after them, until the RawDrain is dropped.
+        *len = range.start;
+
+        RawDrain {
+            gap: Gap {
+                vec,
+                tail_start: range.end,
+                tail_len,
+            },
+            front: range.start,
+            back: range.end,
+        }
+    }
+
+    /// Returns the number of elements which haven't been produced yet.
+    pub(crate) const fn len(&self) -> usize {
+        self.back - self.front
+    }
+
+    /// Removes and returns the next element from the front of the range, if there is one.
+    pub(crate) fn next(&mut self) -> Option<V::Item> {
+        if self.front < self.back {
+            let index = self.front;
+            self.front += 1;
+            let (ptr, _) = self.gap.vec.raw_parts();
+            // SAFETY: Elements between front and back are initialized and haven't been produced
+            // yet. front has been incremented, so this element won't be read again.
+            Some(unsafe { ptr.add(index).read() })
+        } else {
+            None
+        }
+    }
+
+    /// Removes and returns the next element from the back of the range, if there is one.
+    pub(crate) fn next_back(&mut self) -> Option<V::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            let (ptr, _) = self.gap.vec.raw_parts();
+            // SAFETY: Elements between front and back are initialized and haven't been produced
+            // yet. back has been decremented, so this element won't be read again.
+            Some(unsafe { ptr.add(self.back).read() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, V: RawBuffer> Drop for RawDrain<'a, V> {
+    fn drop(&mut self) {
+        let (ptr, _) = self.gap.vec.raw_parts();
+        let remaining = ptr::slice_from_raw_parts_mut(
+            // SAFETY: front is within the range being drained, so it is within the buffer.
+            unsafe { ptr.add(self.front) },
+            self.len(),
+        );
+        self.front = self.back;
+
+        // SAFETY: The remaining elements are initialized and won't be produced, because front has
+        // been moved to back. If one of them panics, the rest are still dropped and the Gap still
+        // closes when it is dropped as a field of self.
+        unsafe { ptr::drop_in_place(remaining); }
+    }
+}
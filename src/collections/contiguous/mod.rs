@@ -1,12 +1,29 @@
 //! Contiguous collection types. Namely [`Array`] and [`Vector`] for contiguous collections that
-//! vary in size at runtime.
+//! vary in size at runtime, as well as [`SmallVector`], which avoids allocating for small
+//! collections, [`ArrayVec`], which never allocates, and [`Deque`], a double-ended queue.
 #![cfg(feature = "contiguous")]
 #![warn(missing_docs)]
 
 pub mod array;
+pub mod array_vec;
+pub mod deque;
+pub mod small_vector;
 pub mod vector;
 
+mod drain;
+mod sort;
+
 #[doc(inline)]
 pub use array::Array;
 #[doc(inline)]
+pub use array_vec::ArrayVec;
+#[doc(inline)]
+pub use deque::Deque;
+#[doc(inline)]
+pub use small_vector::SmallVector;
+#[doc(inline)]
 pub use vector::Vector;
+
+pub use crate::util::error::{
+    AllocError, CapacityError, CapacityOverflow, TryPushError, TryReserveError,
+};
@@ -0,0 +1,94 @@
+use std::iter::{FusedIterator, TrustedLen};
+use std::ptr;
+
+use super::{Buffer, SmallVector};
+
+impl<T, const N: usize> IntoIterator for SmallVector<T, N> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (buf, len) = self.into_parts();
+        IntoIter {
+            buf,
+            head: 0,
+            tail: len,
+        }
+    }
+}
+
+/// A type for owned iteration over a [`SmallVector`]. Produces values of type `T`.
+///
+/// The SmallVector's buffer is moved into the iterator as is, so iterating over an inline
+/// SmallVector doesn't allocate either.
+///
+/// See [`SmallVector::into_iter`].
+pub struct IntoIter<T, const N: usize> {
+    pub(crate) buf: Buffer<T, N>,
+    pub(crate) head: usize, // Head is the index of the first element.
+    pub(crate) tail: usize, // Tail is the index one after the last element.
+}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: All values between head and tail are initialized and haven't been moved out yet.
+        // This method takes a mutable reference to self, so they can't be accessed elsewhere.
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.buf.as_mut_ptr().add(self.head).cast::<T>(),
+                self.tail - self.head,
+            ));
+        }
+
+        // Implicitly drop self.buf, which only deallocates it (if required).
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.head < self.tail {
+            // SAFETY: head < tail, so the value is initialized. We will increment head next so that
+            // the value is effectively moved out of the buffer.
+            let value = unsafe { self.buf.as_ptr().add(self.head).read().assume_init() };
+            self.head += 1;
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.head < self.tail {
+            // Tail sits one after the end, so we decrement first then read.
+            self.tail -= 1;
+            // SAFETY: The value at tail was initialized and we've just decremented tail so that it
+            // is never read again, effectively moving it out of the buffer.
+            Some(unsafe { self.buf.as_ptr().add(self.tail).read().assume_init() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {
+    fn len(&self) -> usize {
+        self.tail - self.head
+    }
+}
+
+// SAFETY: IntoIter::size_hint returns the exact length of the iterator.
+unsafe impl<T, const N: usize> TrustedLen for IntoIter<T, N> {}
+
+// Just use the iter and iter_mut definitions provided by Deref<Target=[T]>.
@@ -0,0 +1,14 @@
+//! A module containing [`SmallVector`] and associtated types.
+//!
+//! Currently, the only other included type is [`IntoIter`] for owned iteration over a
+//! SmallVector. [`IterMut`](std::slice::IterMut) and [`Iter`](std::slice::Iter) from
+//! [`std::slice`] are used for borrowed iteration.
+//!
+//! [`SmallVector`] is also re-exported under the parent module.
+
+mod iter;
+mod small_vector;
+mod tests;
+
+pub use iter::*;
+pub use small_vector::*;
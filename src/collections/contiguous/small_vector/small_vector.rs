@@ -0,0 +1,768 @@
+use std::borrow::{Borrow, BorrowMut};
+use std::cmp;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::TrustedLen;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::slice;
+
+use crate::collections::contiguous::vector::{GROWTH_FACTOR, MAX_CAP, MIN_CAP};
+use crate::collections::contiguous::{Array, Vector};
+use crate::util::error::{CapacityOverflow, IndexOutOfBounds, TryPushError, TryReserveError};
+use crate::util::result::ResultExtension;
+
+/// A variable size contiguous collection, which stores up to `N` items inline before spilling onto
+/// the heap.
+///
+/// While it has no more than `N` items, a SmallVector keeps them in a buffer within the struct
+/// itself, so short-lived collections of a few items can be built without allocating. Once it grows
+/// past `N` items, the contents are moved into an [`Array`] on the heap and it behaves like a
+/// [`Vector`] from then on. A SmallVector only moves back inline when
+/// [`shrink_to_fit`](SmallVector::shrink_to_fit) is called.
+///
+/// Converting to and from a Vector reuses the heap allocation, if there is one, so only an inline
+/// SmallVector needs to copy its items.
+///
+/// # Time Complexity
+/// For this analysis of time complexity, variables are defined as follows:
+/// - `n`: The number of items in the SmallVector.
+/// - `i`: The index of the item in question.
+/// - `m`: The number of items in the second SmallVector.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `get` | `O(1)` |
+/// | `len` | `O(1)` |
+/// | `push`, `try_push` | `O(1)`*, `O(n)` |
+/// | `push_unchecked` | `O(1)` |
+/// | `pop` | `O(1)` |
+/// | `insert`, `try_insert` | `O(n-i)` |
+/// | `remove` | `O(n-i)` |
+/// | `replace` | `O(1)` |
+/// | `reserve`, `try_reserve` | `O(n)`**, `O(1)` |
+/// | `shrink_to_fit` | `O(n)` |
+/// | `append` | `O(n+m)` |
+/// | `contains` | `O(n)` |
+///
+/// \* If the SmallVector doesn't have enough capacity for the new element, `push` will take `O(n)`.
+///
+/// \** If the SmallVector has enough capacity for the additional items already, `reserve` is
+/// `O(1)`.
+pub struct SmallVector<T, const N: usize> {
+    pub(crate) buf: Buffer<T, N>,
+    pub(crate) len: usize,
+}
+
+/// The storage behind a [`SmallVector`], which starts inline and moves to the heap once it runs out
+/// of space. Only the first `len` items of either buffer are initialized.
+pub(crate) enum Buffer<T, const N: usize> {
+    Inline([MaybeUninit<T>; N]),
+    Heap(Array<MaybeUninit<T>>),
+}
+
+impl<T, const N: usize> Buffer<T, N> {
+    /// Returns the number of items that the Buffer can hold.
+    pub(crate) const fn cap(&self) -> usize {
+        match self {
+            Buffer::Inline(_) => N,
+            Buffer::Heap(arr) => arr.size(),
+        }
+    }
+
+    /// Returns a pointer to the first item in the Buffer.
+    pub(crate) const fn as_ptr(&self) -> *const MaybeUninit<T> {
+        match self {
+            Buffer::Inline(items) => items.as_ptr(),
+            Buffer::Heap(arr) => arr.ptr.as_ptr().cast_const(),
+        }
+    }
+
+    /// Returns a mutable pointer to the first item in the Buffer.
+    pub(crate) const fn as_mut_ptr(&mut self) -> *mut MaybeUninit<T> {
+        match self {
+            Buffer::Inline(items) => items.as_mut_ptr(),
+            Buffer::Heap(arr) => arr.ptr.as_ptr(),
+        }
+    }
+}
+
+impl<T, const N: usize> SmallVector<T, N> {
+    /// Creates a new, empty SmallVector, which can hold `N` items before allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::SmallVector;
+    /// let vec: SmallVector<u8, 4> = SmallVector::new();
+    /// assert_eq!(vec.len(), 0);
+    /// assert_eq!(vec.cap(), 4);
+    /// assert!(vec.is_inline());
+    /// ```
+    pub const fn new() -> SmallVector<T, N> {
+        SmallVector {
+            buf: Buffer::Inline([const { MaybeUninit::uninit() }; N]),
+            len: 0,
+        }
+    }
+
+    /// Creates a new SmallVector with capacity for at least `cap` items. If `cap` is no more than
+    /// `N`, nothing is allocated, otherwise the capacity is exactly `cap`.
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::SmallVector;
+    /// let vec: SmallVector<u8, 4> = SmallVector::with_cap(2);
+    /// assert_eq!(vec.cap(), 4);
+    /// let vec: SmallVector<u8, 4> = SmallVector::with_cap(10);
+    /// assert_eq!(vec.cap(), 10);
+    /// ```
+    pub fn with_cap(cap: usize) -> SmallVector<T, N> {
+        SmallVector::try_with_cap(cap).unwrap_or_else(|error| error.handle())
+    }
+
+    /// Creates a new SmallVector with capacity for at least `cap` items, returning an error rather
+    /// than panicking or aborting if the allocation fails.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if memory layout size exceeds [`isize::MAX`] or if the
+    /// allocator fails to provide the memory.
+    pub fn try_with_cap(cap: usize) -> Result<SmallVector<T, N>, TryReserveError> {
+        let mut vec = SmallVector::new();
+        vec.try_realloc(cap)?;
+        Ok(vec)
+    }
+
+    /// Creates a SmallVector from a type which implements [`IntoIterator`] and creates an
+    /// [`ExactSizeIterator`].
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::SmallVector;
+    /// let vec: SmallVector<_, 4> = SmallVector::from_iter_sized(1_u8..=3);
+    /// assert_eq!(&*vec, &[1, 2, 3]);
+    /// assert!(vec.is_inline());
+    /// ```
+    pub fn from_iter_sized<I>(value: I) -> Self
+    where
+        I: Iterator<Item = T> + ExactSizeIterator + TrustedLen,
+    {
+        let iter = value.into_iter();
+        let mut vec = SmallVector::with_cap(iter.len());
+
+        for item in iter {
+            // SAFETY: vec has been created with the right capacity.
+            unsafe { vec.push_unchecked(item); }
+        }
+
+        vec
+    }
+
+    /// Returns the length of the SmallVector.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the SmallVector contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the current capacity of the SmallVector, which is `N` while its items are stored
+    /// inline.
+    pub const fn cap(&self) -> usize {
+        self.buf.cap()
+    }
+
+    /// Returns true if the SmallVector's items are stored inline, rather than on the heap.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::SmallVector;
+    /// let mut vec: SmallVector<u8, 2> = SmallVector::new();
+    /// vec.extend([1, 2]);
+    /// assert!(vec.is_inline());
+    /// vec.push(3);
+    /// assert!(!vec.is_inline());
+    /// ```
+    pub const fn is_inline(&self) -> bool {
+        matches!(self.buf, Buffer::Inline(_))
+    }
+
+    /// Push the provided value onto the end of the SmallVector, moving to the heap or increasing
+    /// the capacity if required.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the SmallVector would have a size that exceeds
+    /// [`isize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::SmallVector;
+    /// let mut vec = SmallVector::<u8, 2>::new();
+    /// for i in 0..=5 {
+    ///     vec.push(i);
+    /// }
+    /// assert_eq!(&*vec, &[0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        if self.len == self.cap() {
+            self.grow();
+        }
+        // SAFETY: The capacity has just been adjusted to support the addition of the new item.
+        unsafe { self.push_unchecked(value) }
+    }
+
+    /// Push the provided value onto the end of the SmallVector, moving to the heap or increasing
+    /// the capacity if required and returning an error rather than panicking or aborting if the
+    /// allocation fails.
+    ///
+    /// If an error is returned, the SmallVector is left unchanged and `value` is handed back as
+    /// part of the error.
+    ///
+    /// # Errors
+    /// Returns a [`TryPushError`] containing `value` if the memory layout of the SmallVector would
+    /// have a size that exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    pub fn try_push(&mut self, value: T) -> Result<(), TryPushError<T>> {
+        if self.len == self.cap()
+            && let Err(error) = self.try_grow()
+        {
+            return Err(TryPushError { error, value });
+        }
+        // SAFETY: The capacity has just been adjusted to support the addition of the new item.
+        unsafe { self.push_unchecked(value) }
+        Ok(())
+    }
+
+    /// Push the provided value onto the end of the SmallVector, assuming that there is enough
+    /// capacity to do so.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure that the SmallVector has enough capacity to add the
+    /// provided value, using methods like [`reserve`](SmallVector::reserve) or
+    /// [`with_cap`](SmallVector::with_cap) to do so. Using this method on a SmallVector without
+    /// enough capacity is undefined behavior.
+    pub const unsafe fn push_unchecked(&mut self, value: T) {
+        // SAFETY: It is up to the caller to ensure that the SmallVector has enough capacity for
+        // this push, leading to the pointer write being in bounds of the buffer.
+        unsafe { self.buf.as_mut_ptr().add(self.len).write(MaybeUninit::new(value)); }
+        self.len += 1;
+    }
+
+    /// Pops the last value off the end of the SmallVector, returning an owned value if the
+    /// SmallVector has length greater than 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::SmallVector;
+    /// let mut vec: SmallVector<_, 4> = SmallVector::from_iter_sized(0..5);
+    /// for i in (0..vec.len()).rev() {
+    ///     assert_eq!(vec.pop(), Some(i));
+    /// }
+    /// assert_eq!(vec.pop(), None);
+    /// ```
+    pub const fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            // Decrement len before getting.
+            self.len -= 1;
+
+            // SAFETY: len has just been decremented and is within the capacity of the buffer. All
+            // values < len are initialized, and the value at len is now considered uninitialized,
+            // so it is moved out rather than copied.
+            let value = unsafe {
+                self.buf.as_ptr().add(self.len).read().assume_init()
+            };
+            Some(value)
+        }
+    }
+
+    /// Inserts the provided value at the given index, growing and moving items as necessary.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::SmallVector;
+    /// let mut vec: SmallVector<_, 4> = SmallVector::from_iter_sized(0..3);
+    /// vec.insert(1, 100);
+    /// vec.insert(1, 200);
+    /// vec.insert(3, 300);
+    /// assert_eq!(&*vec, &[0, 200, 100, 300, 1, 2]);
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.check_index(index);
+
+        if self.len == self.cap() {
+            self.grow()
+        }
+
+        // SAFETY: The index has been checked and the capacity adjusted for the new item.
+        unsafe { self.insert_unchecked(index, value) }
+    }
+
+    /// Inserts the provided value at the given index, growing and moving items as necessary and
+    /// returning an error rather than panicking or aborting if the allocation fails.
+    ///
+    /// If an error is returned, the SmallVector is left unchanged and `value` is handed back as
+    /// part of the error.
+    ///
+    /// # Errors
+    /// Returns a [`TryPushError`] containing `value` if the memory layout of the SmallVector would
+    /// have a size that exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds.
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), TryPushError<T>> {
+        self.check_index(index);
+
+        if self.len == self.cap()
+            && let Err(error) = self.try_grow()
+        {
+            return Err(TryPushError { error, value });
+        }
+
+        // SAFETY: The index has been checked and the capacity adjusted for the new item.
+        unsafe { self.insert_unchecked(index, value) }
+        Ok(())
+    }
+
+    /// Removes the element at the provided index, moving all following values to fill in the gap.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::SmallVector;
+    /// let mut vec: SmallVector<_, 16> = "Hello world!".chars().collect();
+    /// assert_eq!(vec.remove(1), 'e');
+    /// assert_eq!(vec.remove(4), ' ');
+    /// assert_eq!(vec, "Hlloworld!".chars().collect());
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        self.check_index(index);
+
+        let ptr = self.buf.as_mut_ptr();
+        self.len -= 1;
+
+        // SAFETY: index < len, so the value there is initialized and is moved out before the
+        // following values are shifted over it. The shifted range ends at the old len, so it stays
+        // within the initialized part of the buffer.
+        unsafe {
+            let value = ptr.add(index).read().assume_init();
+            ptr::copy(ptr.add(index + 1), ptr.add(index), self.len - index);
+            value
+        }
+    }
+
+    /// Replaces the element at the provided index with `new_value`, returning the old value.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds.
+    pub fn replace(&mut self, index: usize, new_value: T) -> T {
+        self.check_index(index);
+
+        mem::replace(&mut self[index], new_value)
+    }
+
+    /// Ensures that the SmallVector has capacity to hold an additional `extra` elements. After
+    /// invoking this method, the capacity will be >= len + extra.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the SmallVector would have a size that exceeds
+    /// [`isize::MAX`].
+    pub fn reserve(&mut self, extra: usize) {
+        self.try_reserve(extra).unwrap_or_else(|error| error.handle())
+    }
+
+    /// Ensures that the SmallVector has capacity to hold an additional `extra` elements, returning
+    /// an error rather than panicking or aborting if the allocation fails. After a successful
+    /// invocation, the capacity will be >= len + extra.
+    ///
+    /// If an error is returned, the SmallVector is left unchanged.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the memory layout of the SmallVector would have a size that
+    /// exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    pub fn try_reserve(&mut self, extra: usize) -> Result<(), TryReserveError> {
+        let new_cap = self.len.checked_add(extra).ok_or(CapacityOverflow)?;
+
+        if new_cap <= self.cap() { return Ok(()); }
+
+        self.try_realloc(new_cap)
+    }
+
+    /// Shrinks the SmallVector so that its capacity is equal to its length, moving its items back
+    /// inline if there are no more than `N` of them.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the SmallVector would have a size that exceeds
+    /// [`isize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::SmallVector;
+    /// let mut vec: SmallVector<_, 4> = SmallVector::from_iter_sized(0..8);
+    /// for _ in 0..6 {
+    ///     vec.pop();
+    /// }
+    /// vec.shrink_to_fit();
+    /// assert!(vec.is_inline());
+    /// assert_eq!(&*vec, &[0, 1]);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        let Buffer::Heap(arr) = &mut self.buf else { return; };
+
+        if self.len <= N {
+            let mut items = [const { MaybeUninit::uninit() }; N];
+            // SAFETY: The first len values of arr are initialized and len <= N, so they fit in
+            // items. The two buffers are distinct, so they can't overlap.
+            unsafe { ptr::copy_nonoverlapping(arr.ptr.as_ptr(), items.as_mut_ptr(), self.len); }
+
+            // Dropping arr only deallocates it, because it contains MaybeUninit values.
+            self.buf = Buffer::Inline(items);
+        } else {
+            arr.realloc(self.len);
+        }
+    }
+
+    /// Appends all elements from `other` to self. `other` may store a different number of items
+    /// inline.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the SmallVector would have a size that exceeds
+    /// [`isize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::SmallVector;
+    /// let mut vec: SmallVector<_, 2> = SmallVector::from_iter_sized(0..2);
+    /// vec.append(SmallVector::<_, 8>::from_iter_sized(2..5));
+    /// assert_eq!(&*vec, &[0, 1, 2, 3, 4]);
+    /// ```
+    pub fn append<const M: usize>(&mut self, other: SmallVector<T, M>) {
+        self.reserve(other.len);
+
+        let (buf, len) = other.into_parts();
+        // SAFETY: self has capacity for len more values after its own len and the first len values
+        // of buf are initialized. Both are properly aligned and don't overlap.
+        unsafe {
+            ptr::copy_nonoverlapping(buf.as_ptr(), self.buf.as_mut_ptr().add(self.len), len);
+        }
+        self.len += len;
+
+        // Dropping buf only deallocates it (if required), because all values have been moved.
+    }
+
+    /// Grows the buffer to allow for the insertion of additional elements. After calling this, the
+    /// SmallVector can take at least one more element.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the SmallVector would have a size that exceeds
+    /// [`isize::MAX`].
+    pub(crate) fn grow(&mut self) {
+        self.try_grow().unwrap_or_else(|error| error.handle())
+    }
+
+    /// Grows the buffer to allow for the insertion of additional elements, returning an error if
+    /// the allocation fails. After a successful call, the SmallVector can take at least one more
+    /// element.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the memory layout of the SmallVector would have a size that
+    /// exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    pub(crate) fn try_grow(&mut self) -> Result<(), TryReserveError> {
+        // SAFETY: old_cap < isize::MAX, so old_cap * 2 can't overflow. Can still exceed isize::MAX.
+        let mut new_cap = cmp::max(self.cap() * GROWTH_FACTOR, MIN_CAP);
+
+        // If we would grow past maximum capacity, instead use the maximum if it represents growth.
+        if (new_cap * size_of::<T>() > MAX_CAP) && (MAX_CAP > self.cap() * size_of::<T>()) {
+            new_cap = MAX_CAP;
+        }
+
+        self.try_realloc(new_cap)
+    }
+
+    /// Reallocates the buffer with the provided capacity, moving it to the heap if it is inline and
+    /// `new_cap` exceeds `N`. An inline buffer is never shrunk.
+    ///
+    /// The caller must ensure that `new_cap` is no less than len, otherwise values will be leaked.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the memory layout of the SmallVector would have a size that
+    /// exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    fn try_realloc(&mut self, new_cap: usize) -> Result<(), TryReserveError> {
+        match &mut self.buf {
+            Buffer::Heap(arr) => arr.try_realloc(new_cap),
+            Buffer::Inline(_) if new_cap <= N => Ok(()),
+            Buffer::Inline(items) => {
+                let arr = Array::<T>::try_new_uninit(new_cap)?;
+                // SAFETY: The first len values of items are initialized and new_cap > N >= len, so
+                // they fit in arr. The two buffers are distinct, so they can't overlap.
+                unsafe { ptr::copy_nonoverlapping(items.as_ptr(), arr.ptr.as_ptr(), self.len); }
+
+                self.buf = Buffer::Heap(arr);
+                Ok(())
+            },
+        }
+    }
+
+    /// Inserts the provided value at the given index, moving all following items back by one.
+    ///
+    /// # Safety
+    /// The caller must ensure that `index` is in bounds and that the SmallVector has capacity for
+    /// at least one more element.
+    const unsafe fn insert_unchecked(&mut self, index: usize, value: T) {
+        let ptr = self.buf.as_mut_ptr();
+
+        // SAFETY: index <= len and there is capacity for len + 1 values, so both the shifted range
+        // and the written value are within the buffer.
+        unsafe {
+            ptr::copy(ptr.add(index), ptr.add(index + 1), self.len - index);
+            ptr.add(index).write(MaybeUninit::new(value));
+        }
+
+        self.len += 1;
+    }
+
+    /// Decomposes the SmallVector into its buffer and length, without dropping any values.
+    pub(crate) const fn into_parts(self) -> (Buffer<T, N>, usize) {
+        // SAFETY: self is forgotten immediately afterwards, so the buffer is never used or dropped
+        // twice.
+        let buf = unsafe { ptr::read(&self.buf) };
+        let len = self.len;
+        mem::forget(self);
+        (buf, len)
+    }
+
+    /// Checks that the provided index is within the bounds of self.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds.
+    pub(crate) fn check_index(&self, index: usize) {
+        if index >= self.len {
+            Err(IndexOutOfBounds {
+                index,
+                len: self.len
+            }).throw()
+        }
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SmallVector<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+
+    fn extend_one(&mut self, item: T) {
+        self.push(item);
+    }
+
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    unsafe fn extend_one_unchecked(&mut self, item: T)
+    where
+        Self: Sized,
+    {
+        // SAFETY: extend_reserve is implemented correctly, so all other safety requirements are the
+        // responsibility of the caller.
+        unsafe { self.push_unchecked(item); }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallVector<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(value: I) -> Self {
+        let iter = value.into_iter();
+        let mut vec = SmallVector::with_cap(iter.size_hint().0);
+
+        for item in iter {
+            vec.push(item);
+        }
+
+        vec
+    }
+}
+
+impl<T, const N: usize> Default for SmallVector<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for SmallVector<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: All values less than len are initialized and safe to drop.
+        unsafe { ptr::drop_in_place::<[T]>(self.deref_mut()); }
+
+        // Implicitly drop self.buf, containing only MaybeUninit values without a no-op drop.
+        // Doing so also deallocates any owned memory.
+    }
+}
+
+impl<T, const N: usize> Deref for SmallVector<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The buffer is valid as a slice for len values, which are all initialized. The
+        // pointer is nonnull, properly aligned and the range entirely contained within the buffer.
+        // The borrow checker enforces that self isn't mutated due to this function taking a &self.
+        unsafe {
+            slice::from_raw_parts(
+                // Reinterpret *const MaybeUninit<T> as *const T for all values < len.
+                self.buf.as_ptr().cast(),
+                self.len,
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> DerefMut for SmallVector<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: The buffer is valid as a slice for len values, which are all initialized. The
+        // pointer is nonnull, properly aligned and the range entirely contained within the buffer.
+        // The borrow checker enforces that self isn't accessed due to this function taking a
+        // &mut self.
+        unsafe {
+            slice::from_raw_parts_mut(
+                // Reinterpret *mut MaybeUninit<T> as *mut T for all values < len.
+                self.buf.as_mut_ptr().cast(),
+                self.len,
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for SmallVector<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.deref()
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for SmallVector<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.deref_mut()
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for SmallVector<T, N> {
+    fn borrow(&self) -> &[T] {
+        self.as_ref()
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for SmallVector<T, N> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for SmallVector<T, N> {
+    fn clone(&self) -> Self {
+        let mut vec = Self::with_cap(self.cap());
+
+        for value in self.iter() {
+            vec.push(value.clone());
+        }
+
+        vec
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for SmallVector<T, N> {
+    fn from(value: [T; N]) -> Self {
+        SmallVector {
+            buf: Buffer::Inline(value.map(MaybeUninit::new)),
+            len: N,
+        }
+    }
+}
+
+impl<T, const N: usize> From<Vector<T>> for SmallVector<T, N> {
+    /// Converts a Vector into a SmallVector, taking ownership of its allocation rather than copying
+    /// any items. Vectors without an allocation produce an inline SmallVector.
+    fn from(value: Vector<T>) -> Self {
+        if value.cap() == 0 {
+            return SmallVector::new();
+        }
+
+        let (ptr, len, cap) = value.into_parts();
+        SmallVector {
+            // SAFETY: The parts were just taken from a valid Vector, so they describe an
+            // allocation of cap values from the global allocator.
+            buf: Buffer::Heap(unsafe { Array::from_parts(ptr, cap) }),
+            len,
+        }
+    }
+}
+
+impl<T, const N: usize> From<SmallVector<T, N>> for Vector<T> {
+    /// Converts a SmallVector into a Vector, reusing its allocation if it has one. Inline items are
+    /// copied into a new allocation of exactly the right size.
+    fn from(value: SmallVector<T, N>) -> Self {
+        let (buf, len) = value.into_parts();
+
+        match buf {
+            Buffer::Heap(arr) => Vector { arr, len },
+            Buffer::Inline(items) => {
+                let mut vec = Vector::with_cap(len);
+                // SAFETY: The first len items are initialized and vec has capacity for exactly len
+                // values. The two buffers are distinct, so they can't overlap.
+                unsafe { ptr::copy_nonoverlapping(items.as_ptr(), vec.arr.ptr.as_ptr(), len); }
+                vec.len = len;
+                vec
+            },
+        }
+    }
+}
+
+// SAFETY: SmallVectors own their items and, when used safely, rely on unique pointers to any heap
+// allocation. They are therefore safe for Send when T: Send.
+unsafe impl<T: Send, const N: usize> Send for SmallVector<T, N> {}
+// SAFETY: SmallVector's safe API obeys all rules of the borrow checker, so no interior mutability
+// occurs. This means that SmallVector<T, N> can safely implement Sync when T: Sync.
+unsafe impl<T: Sync, const N: usize> Sync for SmallVector<T, N> {}
+
+impl<T: PartialEq, const N: usize> PartialEq for SmallVector<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallVector<T, N> {}
+
+impl<T: Hash, const N: usize> Hash for SmallVector<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for SmallVector<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmallVector")
+            .field_with("contents", |f| f.debug_list().entries(self.iter()).finish())
+            .field("len", &self.len)
+            .field("cap", &self.cap())
+            .field("inline", &self.is_inline())
+            .finish()
+    }
+}
+
+impl<T: Debug, const N: usize> Display for SmallVector<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "!")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+use super::*;
+use crate::collections::contiguous::Vector;
+use crate::util::alloc::{CountedDrop, ZeroSizedType};
+use crate::util::panic::assert_panics;
+
+#[test]
+fn test_spill_to_heap() {
+    let mut vec: SmallVector<u32, 4> = SmallVector::new();
+    for i in 0..4 {
+        vec.push(i);
+    }
+    assert!(vec.is_inline(), "Up to N items should be stored inline.");
+    assert_eq!(vec.cap(), 4);
+
+    vec.push(4);
+    assert!(!vec.is_inline(), "Pushing past N items should move to the heap.");
+    assert_eq!(*vec, [0, 1, 2, 3, 4], "Items should be preserved when moving to the heap.");
+
+    vec.try_push(5).expect("Growing a small SmallVector should succeed.");
+    vec.try_insert(0, 100).expect("Inserting into a small SmallVector should succeed.");
+    assert_eq!(vec.pop(), Some(5));
+    assert_eq!(vec.remove(2), 1);
+    assert_eq!(vec.replace(0, 200), 100);
+    assert_eq!(*vec, [200, 0, 2, 3, 4]);
+
+    while vec.len() > 2 {
+        vec.pop();
+    }
+    vec.shrink_to_fit();
+    assert!(vec.is_inline(), "Shrinking to N items or less should move back inline.");
+    assert_eq!(*vec, [200, 0], "Items should be preserved when moving back inline.");
+
+    let mut vec: SmallVector<u32, 0> = SmallVector::new();
+    vec.extend(0..10);
+    assert!(!vec.is_inline(), "A SmallVector with N = 0 should always allocate.");
+    assert_eq!(vec.iter().sum::<u32>(), 45);
+
+    assert_panics!({
+        let mut vec: SmallVector<u32, 2> = SmallVector::from([0, 1]);
+        vec.remove(2);
+    });
+}
+
+#[test]
+fn test_drop() {
+    let counter = CountedDrop::new(0);
+
+    let mut vec: SmallVector<_, 2> = SmallVector::new();
+    vec.push(counter.clone());
+    drop(vec);
+    assert_eq!(counter.take(), 1, "Inline values should be dropped.");
+
+    let mut vec: SmallVector<_, 2> = SmallVector::new();
+    vec.extend([counter.clone(), counter.clone(), counter.clone()]);
+    assert_eq!(counter.take(), 0, "Moving to the heap shouldn't drop any values.");
+    drop(vec);
+    assert_eq!(counter.take(), 3, "Values on the heap should be dropped.");
+
+    let mut vec: SmallVector<_, 4> = SmallVector::new();
+    vec.extend([counter.clone(), counter.clone(), counter.clone()]);
+    let mut iter = vec.into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(counter.take(), 2);
+    drop(iter);
+    assert_eq!(counter.take(), 1, "Remaining values should be dropped with the IntoIter.");
+
+    let mut vec: SmallVector<_, 1> = SmallVector::new();
+    vec.extend([counter.clone(), counter.clone()]);
+    vec.append(SmallVector::<_, 2>::from([counter.clone(), counter.clone()]));
+    assert_eq!(counter.take(), 0, "Appending shouldn't drop any values.");
+    drop(vec);
+    assert_eq!(counter.take(), 4);
+
+    let mut vec: SmallVector<_, 4> = SmallVector::new();
+    vec.extend([ZeroSizedType; 8]);
+    assert_eq!(vec.len(), 8);
+    assert_eq!(vec.into_iter().count(), 8);
+}
+
+#[test]
+fn test_vector_conversions() {
+    let vec = Vector::from_iter_sized(0..8);
+    let ptr = vec.as_ptr();
+
+    let small: SmallVector<_, 4> = vec.into();
+    assert!(!small.is_inline());
+    assert_eq!(small.as_ptr(), ptr, "Converting from a Vector should reuse its allocation.");
+
+    let vec = Vector::from(small);
+    assert_eq!(vec.as_ptr(), ptr, "Converting to a Vector should reuse the allocation.");
+    assert_eq!(*vec, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    let small: SmallVector<_, 4> = Vector::<u8>::new().into();
+    assert!(small.is_inline(), "Converting an unallocated Vector shouldn't require the heap.");
+
+    let small = SmallVector::from([1, 2, 3]);
+    let vec = Vector::from(small);
+    assert_eq!(*vec, [1, 2, 3], "Inline items should be copied into the Vector.");
+    assert_eq!(vec.cap(), 3);
+}
@@ -0,0 +1,47 @@
+/// Sorts `v` with a stable insertion sort, using `is_less` to compare items.
+pub(crate) fn insertion_sort<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    for end in 1..v.len() {
+        insert_tail(&mut v[..=end], is_less);
+    }
+}
+
+/// Moves the last item of `v` towards the front until it is in order, assuming that the rest of
+/// `v` is already sorted. Equal items are never moved past each other.
+///
+/// Items are only moved once the destination has been found, so `v` is left unchanged if
+/// `is_less` panics.
+pub(crate) fn insert_tail<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let Some(last) = v.len().checked_sub(1) else { return };
+
+    let mut dest = last;
+    while dest > 0 && is_less(&v[last], &v[dest - 1]) {
+        dest -= 1;
+    }
+
+    v[dest..].rotate_right(1);
+}
+
+/// Moves the first item of `v` towards the back until it is in order, assuming that the rest of
+/// `v` is already sorted. Equal items are never moved past each other.
+///
+/// Items are only moved once the destination has been found, so `v` is left unchanged if
+/// `is_less` panics.
+pub(crate) fn insert_head<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    if v.is_empty() { return; }
+
+    let mut dest = 0;
+    while dest + 1 < v.len() && is_less(&v[dest + 1], &v[0]) {
+        dest += 1;
+    }
+
+    v[..=dest].rotate_left(1);
+}
@@ -0,0 +1,34 @@
+use super::quick::quick_sort;
+use crate::collections::contiguous::Array;
+
+/// Sorts `v` stably by the keys produced by `f`, calling `f` exactly once for each item.
+///
+/// Keys are stored alongside the original index of each item in an [`Array`], which is sorted
+/// instead of `v`. Because no two indices are equal, an unstable sort still produces a stable
+/// order. The resulting permutation is then applied to `v` with at most one swap per item.
+///
+/// # Panics
+/// Panics if allocating the keys fails.
+pub(crate) fn cached_key_sort<T, K, F>(v: &mut [T], f: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    if v.len() < 2 { return; }
+
+    let mut keys: Array<(K, usize)> = Array::from_iter_sized(
+        v.iter().map(f).enumerate().map(|(index, key)| (key, index))
+    );
+    quick_sort(&mut keys, &mut |a, b| a.lt(b));
+
+    for i in 0..v.len() {
+        // The item that belongs at i may have already been swapped elsewhere, so follow the swaps
+        // until reaching its current position.
+        let mut index = keys[i].1;
+        while index < i {
+            index = keys[index].1;
+        }
+        keys[i].1 = index;
+        v.swap(i, index);
+    }
+}
@@ -0,0 +1,120 @@
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+
+use super::INSERTION_LEN;
+use super::insertion::insertion_sort;
+use crate::collections::contiguous::Array;
+
+/// Sorts `v` with a stable merge sort, using `is_less` to compare items.
+///
+/// A scratch buffer of half the length of `v` is allocated to hold the first of each pair of runs
+/// while they are merged. If `is_less` panics, `v` still contains each of its original items
+/// exactly once, in an unspecified order.
+///
+/// # Panics
+/// Panics if allocating the scratch buffer fails.
+pub(crate) fn merge_sort<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    // ZSTs are indistinguishable, so any order is a sorted order.
+    if mem::size_of::<T>() == 0 { return; }
+
+    if v.len() <= INSERTION_LEN {
+        insertion_sort(v, is_less);
+        return;
+    }
+
+    let mut buf: Array<MaybeUninit<T>> = Array::new_uninit(v.len() / 2);
+    // SAFETY: buf is a new allocation which can hold v.len() / 2 items, so it doesn't overlap v.
+    unsafe { merge_sort_with(v, buf.as_mut_ptr().cast(), is_less); }
+}
+
+/// Sorts `v` by recursively sorting and then merging each half, using `buf` as scratch space.
+///
+/// # Safety
+/// `buf` must be valid for writes of `v.len() / 2` items and mustn't overlap `v`.
+unsafe fn merge_sort_with<T, F>(v: &mut [T], buf: *mut T, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+    if len <= INSERTION_LEN {
+        insertion_sort(v, is_less);
+        return;
+    }
+
+    let mid = len / 2;
+    // SAFETY: Each half is at most len - len / 2 long, so neither needs more than len / 2 items of
+    // scratch space.
+    unsafe {
+        merge_sort_with(&mut v[..mid], buf, is_less);
+        merge_sort_with(&mut v[mid..], buf, is_less);
+    }
+
+    // If the halves are already in order, there is nothing to merge.
+    if !is_less(&v[mid], &v[mid - 1]) { return; }
+
+    // SAFETY: 0 < mid < len and buf can hold len / 2 = mid items.
+    unsafe { merge(v, mid, buf, is_less); }
+}
+
+/// Merges the sorted runs `v[..mid]` and `v[mid..]`, by moving the first run into `buf` and then
+/// repeatedly moving the lesser of the two run's heads back into `v`.
+///
+/// # Safety
+/// `mid` must be less than `v.len()` and `buf` must be valid for writes of `mid` items and mustn't
+/// overlap `v`.
+unsafe fn merge<T, F>(v: &mut [T], mid: usize, buf: *mut T, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let ptr = v.as_mut_ptr();
+
+    // SAFETY: All pointers stay within v or the first mid items of buf. At any point, the gap
+    // between run.dest and right is exactly as long as the remainder of the left run, so items are
+    // never overwritten before they are moved. If is_less panics, run is dropped and moves the
+    // remainder of the left run into the gap, so v contains each item exactly once.
+    unsafe {
+        ptr::copy_nonoverlapping(ptr, buf, mid);
+
+        let mut run = MergeRun {
+            start: buf,
+            end: buf.add(mid),
+            dest: ptr,
+        };
+        let mut right = ptr.add(mid);
+        let end = ptr.add(v.len());
+
+        while run.start < run.end && right < end {
+            // Only take from the right run if it is strictly less, so that the sort is stable.
+            if is_less(&*right, &*run.start) {
+                ptr::copy_nonoverlapping(right, run.dest, 1);
+                right = right.add(1);
+            } else {
+                ptr::copy_nonoverlapping(run.start, run.dest, 1);
+                run.start = run.start.add(1);
+            }
+            run.dest = run.dest.add(1);
+        }
+    }
+}
+
+/// The part of a run which has been moved into a scratch buffer but not yet merged back. When
+/// dropped, the remaining items are moved to `dest`, even if a comparison panics during the merge.
+struct MergeRun<T> {
+    start: *mut T,
+    end: *mut T,
+    dest: *mut T,
+}
+
+impl<T> Drop for MergeRun<T> {
+    fn drop(&mut self) {
+        // SAFETY: start and end are within the same scratch buffer, with start <= end. The items
+        // between them are initialized and dest has space for all of them, which don't overlap.
+        unsafe {
+            let len = self.end.offset_from_unsigned(self.start);
+            ptr::copy_nonoverlapping(self.start, self.dest, len);
+        }
+    }
+}
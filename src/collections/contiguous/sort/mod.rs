@@ -0,0 +1,22 @@
+//! Sorting and searching algorithms for slices, which back the inherent sorting methods on
+//! [`Array`](super::Array) and [`Vector`](super::Vector).
+//!
+//! Stable sorting is done with a merge sort, using an `Array<MaybeUninit<T>>` as scratch space,
+//! while unstable sorting and selection use a pattern-defeating quicksort that works entirely in
+//! place. All of the sorts leave the slice as a permutation of its original items if a comparison
+//! panics.
+
+mod insertion;
+mod key;
+mod merge;
+mod quick;
+mod search;
+mod tests;
+
+pub(crate) use key::*;
+pub(crate) use merge::*;
+pub(crate) use quick::*;
+pub(crate) use search::*;
+
+/// Slices of this length or shorter are sorted with insertion sort rather than being split further.
+const INSERTION_LEN: usize = 20;
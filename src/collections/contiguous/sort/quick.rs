@@ -0,0 +1,418 @@
+use std::mem;
+
+use super::INSERTION_LEN;
+use super::insertion::{insert_head, insert_tail, insertion_sort};
+use crate::util::error::IndexOutOfBounds;
+use crate::util::result::ResultExtension;
+
+/// Slices of this length or longer use the median of three medians to choose a pivot.
+const NINTHER_LEN: usize = 50;
+
+/// Sorts `v` with an unstable pattern-defeating quicksort, using `is_less` to compare items.
+///
+/// Sorting is done in place, by swapping items, so `v` is always a permutation of its original
+/// items, even if `is_less` panics. Sorted and reversed inputs, or those with many equal items, are
+/// detected and handled in linear time. If too many partitions are unbalanced, the slice is heap
+/// sorted instead, which bounds the worst case at `O(n log n)`.
+pub(crate) fn quick_sort<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    // ZSTs are indistinguishable, so any order is a sorted order.
+    if mem::size_of::<T>() == 0 { return; }
+
+    let limit = usize::BITS - v.len().leading_zeros();
+    recurse(v, is_less, None, limit);
+}
+
+/// Reorders `v` so that the item at `index` is in its sorted position, with all items before it
+/// less than or equal to it and all items after it greater than or equal to it. Returns the items
+/// before `index`, the item at `index` and the items after it.
+///
+/// Like [`quick_sort`], items are only ever swapped and falling back to a heap sort bounds the
+/// worst case at `O(n log n)`, although it is expected to be `O(n)`.
+///
+/// # Panics
+/// Panics if `index` is out of bounds.
+pub(crate) fn select_nth<'a, T, F>(
+    v: &'a mut [T],
+    index: usize,
+    is_less: &mut F,
+) -> (&'a mut [T], &'a mut T, &'a mut [T])
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    if index >= v.len() {
+        Err(IndexOutOfBounds {
+            index,
+            len: v.len()
+        }).throw()
+    }
+
+    // ZSTs are indistinguishable, so any order is a sorted order.
+    if mem::size_of::<T>() != 0 {
+        select_in_place(v, index, is_less);
+    }
+
+    let (before, rest) = v.split_at_mut(index);
+    let (nth, after) = rest.split_at_mut(1);
+    (before, &mut nth[0], after)
+}
+
+/// Performs selection for [`select_nth`], assuming that `index` is in bounds.
+fn select_in_place<T, F>(mut v: &mut [T], mut index: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut limit = usize::BITS - v.len().leading_zeros();
+    let mut pred = None;
+
+    loop {
+        if v.len() <= INSERTION_LEN {
+            insertion_sort(v, is_less);
+            return;
+        }
+
+        // Too many bad pivots have been chosen, so fall back to a guaranteed O(n log n) sort.
+        if limit == 0 {
+            heap_sort(v, is_less);
+            return;
+        }
+
+        let (pivot, _) = choose_pivot(v, is_less);
+
+        if let Some(pred) = pred && !is_less(pred, &v[pivot]) {
+            let mid = partition_equal(v, pivot, is_less);
+            if index < mid { return; }
+
+            v = &mut mem::take(&mut v)[mid..];
+            index -= mid;
+            continue;
+        }
+
+        let len = v.len();
+        let (mid, _) = partition(v, pivot, is_less);
+        if mid.min(len - mid) < len / 8 {
+            limit -= 1;
+        }
+
+        let (left, right) = mem::take(&mut v).split_at_mut(mid);
+        let (pivot, right) = right.split_at_mut(1);
+
+        if index < mid {
+            v = left;
+        } else if index == mid {
+            return;
+        } else {
+            v = right;
+            index -= mid + 1;
+            pred = Some(&pivot[0]);
+        }
+    }
+}
+
+/// Sorts `v` by partitioning it around a pivot, recursing into the shorter side and looping on the
+/// longer side. `pred` is the pivot immediately before `v`, if there is one, which is less than or
+/// equal to every item in `v`.
+fn recurse<'a, T, F>(mut v: &'a mut [T], is_less: &mut F, mut pred: Option<&'a T>, mut limit: u32)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut was_balanced = true;
+    let mut was_partitioned = true;
+
+    loop {
+        let len = v.len();
+
+        if len <= INSERTION_LEN {
+            insertion_sort(v, is_less);
+            return;
+        }
+
+        // Too many bad pivots have been chosen, so fall back to a guaranteed O(n log n) sort.
+        if limit == 0 {
+            heap_sort(v, is_less);
+            return;
+        }
+
+        // If the last partition was unbalanced, shuffle some items to avoid repeating it.
+        if !was_balanced {
+            break_patterns(v);
+            limit -= 1;
+        }
+
+        let (pivot, likely_sorted) = choose_pivot(v, is_less);
+
+        if was_balanced && was_partitioned && likely_sorted && partial_insertion_sort(v, is_less) {
+            return;
+        }
+
+        // If the pivot is equal to the predecessor, it is the smallest item in v. Move all of the
+        // items equal to it to the front and skip them, because they're already sorted.
+        if let Some(pred) = pred && !is_less(pred, &v[pivot]) {
+            let mid = partition_equal(v, pivot, is_less);
+            v = &mut mem::take(&mut v)[mid..];
+            continue;
+        }
+
+        let (mid, partitioned) = partition(v, pivot, is_less);
+        was_balanced = mid.min(len - mid) >= len / 8;
+        was_partitioned = partitioned;
+
+        let (left, right) = mem::take(&mut v).split_at_mut(mid);
+        let (pivot, right) = right.split_at_mut(1);
+        let pivot = &pivot[0];
+
+        if left.len() < right.len() {
+            recurse(left, is_less, pred, limit);
+            v = right;
+            pred = Some(pivot);
+        } else {
+            recurse(right, is_less, Some(pivot), limit);
+            v = left;
+        }
+    }
+}
+
+/// Partitions `v` around the item at `pivot`, returning the pivot's new index and whether `v` was
+/// already partitioned. All items before the pivot are less than it and all items after it are
+/// greater than or equal to it.
+fn partition<T, F>(v: &mut [T], pivot: usize, is_less: &mut F) -> (usize, bool)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    v.swap(0, pivot);
+
+    let (mid, was_partitioned) = {
+        let (pivot, rest) = v.split_at_mut(1);
+        let pivot = &pivot[0];
+
+        let mut left = 0;
+        let mut right = rest.len();
+
+        // Skip the items which are already in place, to detect if rest is already partitioned.
+        while left < right && is_less(&rest[left], pivot) {
+            left += 1;
+        }
+        while left < right && !is_less(&rest[right - 1], pivot) {
+            right -= 1;
+        }
+        let was_partitioned = left >= right;
+
+        // Everything before left is less than the pivot and everything from right onwards isn't.
+        while left < right {
+            right -= 1;
+            rest.swap(left, right);
+            left += 1;
+
+            while left < right && is_less(&rest[left], pivot) {
+                left += 1;
+            }
+            while left < right && !is_less(&rest[right - 1], pivot) {
+                right -= 1;
+            }
+        }
+
+        (left, was_partitioned)
+    };
+
+    // Move the pivot between the two partitions.
+    v.swap(0, mid);
+    (mid, was_partitioned)
+}
+
+/// Partitions `v` into items equal to the item at `pivot` followed by items greater than it,
+/// assuming that no items in `v` are less than the pivot. Returns the number of equal items.
+fn partition_equal<T, F>(v: &mut [T], pivot: usize, is_less: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    v.swap(0, pivot);
+
+    let (pivot, rest) = v.split_at_mut(1);
+    let pivot = &pivot[0];
+
+    let mut left = 0;
+    let mut right = rest.len();
+
+    loop {
+        while left < right && !is_less(pivot, &rest[left]) {
+            left += 1;
+        }
+        while left < right && is_less(pivot, &rest[right - 1]) {
+            right -= 1;
+        }
+
+        if left >= right { break; }
+
+        right -= 1;
+        rest.swap(left, right);
+        left += 1;
+    }
+
+    // Include the pivot itself, which is still at the start of v.
+    left + 1
+}
+
+/// Chooses the index of a pivot in `v`, which is longer than [`INSERTION_LEN`], and returns whether
+/// `v` is likely to already be sorted. If `v` appears to be reversed, it is reversed back first.
+fn choose_pivot<T, F>(v: &mut [T], is_less: &mut F) -> (usize, bool)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    const MAX_SWAPS: usize = 4 * 3;
+
+    let len = v.len();
+    let mut chooser = PivotChooser {
+        v: &*v,
+        is_less,
+        swaps: 0,
+    };
+
+    let mut a = len / 4;
+    let mut b = len / 4 * 2;
+    let mut c = len / 4 * 3;
+
+    if len >= NINTHER_LEN {
+        chooser.sort_adjacent(&mut a);
+        chooser.sort_adjacent(&mut b);
+        chooser.sort_adjacent(&mut c);
+    }
+    chooser.sort3(&mut a, &mut b, &mut c);
+
+    let swaps = chooser.swaps;
+    if swaps < MAX_SWAPS {
+        (b, swaps == 0)
+    } else {
+        // Every comparison found the items in descending order, so v is probably reversed.
+        v.reverse();
+        (len - 1 - b, true)
+    }
+}
+
+/// A helper for sorting the indices of pivot candidates by the items they refer to, while counting
+/// how many times they had to be swapped.
+struct PivotChooser<'a, T, F> {
+    v: &'a [T],
+    is_less: &'a mut F,
+    swaps: usize,
+}
+
+impl<'a, T, F> PivotChooser<'a, T, F>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    fn sort2(&mut self, a: &mut usize, b: &mut usize) {
+        if (self.is_less)(&self.v[*b], &self.v[*a]) {
+            mem::swap(a, b);
+            self.swaps += 1;
+        }
+    }
+
+    fn sort3(&mut self, a: &mut usize, b: &mut usize, c: &mut usize) {
+        self.sort2(a, b);
+        self.sort2(b, c);
+        self.sort2(a, b);
+    }
+
+    /// Replaces `a` with the index of the median of the items at `a - 1`, `a` and `a + 1`.
+    fn sort_adjacent(&mut self, a: &mut usize) {
+        let mut before = *a - 1;
+        let mut after = *a + 1;
+        self.sort3(&mut before, a, &mut after);
+    }
+}
+
+/// Attempts to sort `v` by moving a few out of order items, returning true if it is sorted.
+///
+/// Gives up after moving a fixed number of items, or immediately if `v` is short, because the cost
+/// of trying would outweigh the benefits.
+fn partial_insertion_sort<T, F>(v: &mut [T], is_less: &mut F) -> bool
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    const MAX_STEPS: usize = 5;
+    const SHORTEST_SHIFTING: usize = 50;
+
+    let len = v.len();
+    let mut i = 1;
+
+    for _ in 0..MAX_STEPS {
+        while i < len && !is_less(&v[i], &v[i - 1]) {
+            i += 1;
+        }
+
+        if i == len { return true; }
+        if len < SHORTEST_SHIFTING { return false; }
+
+        // Swap the out of order pair and move each of them into place.
+        v.swap(i - 1, i);
+        insert_tail(&mut v[..i], is_less);
+        insert_head(&mut v[i..], is_less);
+    }
+
+    false
+}
+
+/// Swaps some items around the middle of `v` with pseudo-random positions, to break up patterns
+/// that would otherwise cause the same unbalanced partition to be repeated.
+fn break_patterns<T>(v: &mut [T]) {
+    let len = v.len();
+
+    // A simple xorshift generator, seeded with the length so that sorting is deterministic.
+    let mut random = len as u64;
+    let mut next = || {
+        random ^= random << 13;
+        random ^= random >> 7;
+        random ^= random << 17;
+        random as usize
+    };
+
+    let mask = len.next_power_of_two() - 1;
+    let pos = len / 4 * 2;
+
+    for i in 0..3 {
+        let mut other = next() & mask;
+        if other >= len {
+            other -= len;
+        }
+        v.swap(pos - 1 + i, other);
+    }
+}
+
+/// Sorts `v` with an unstable heap sort, using `is_less` to compare items.
+fn heap_sort<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+
+    for node in (0..len / 2).rev() {
+        sift_down(v, node, is_less);
+    }
+
+    for end in (1..len).rev() {
+        v.swap(0, end);
+        sift_down(&mut v[..end], 0, is_less);
+    }
+}
+
+/// Moves the item at `node` down the max-heap `v` until it is greater than both of its children.
+fn sift_down<T, F>(v: &mut [T], mut node: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= v.len() { break; }
+
+        if child + 1 < v.len() && is_less(&v[child], &v[child + 1]) {
+            child += 1;
+        }
+
+        if !is_less(&v[node], &v[child]) { break; }
+
+        v.swap(node, child);
+        node = child;
+    }
+}
@@ -0,0 +1,40 @@
+use std::cmp::Ordering;
+
+/// Performs a binary search over `v`, which is assumed to be sorted, using `f` to compare each item
+/// with the target.
+///
+/// Returns `Ok` with the index of a matching item, or `Err` with the index where a matching item
+/// could be inserted while maintaining the sorted order. If there are multiple matches, any of them
+/// may be returned.
+pub(crate) fn binary_search_by<T, F>(v: &[T], mut f: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let mut low = 0;
+    let mut high = v.len();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+
+        match f(&v[mid]) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+
+    Err(low)
+}
+
+/// Returns the index of the first item in `v` for which `pred` returns false, assuming that `v` is
+/// partitioned so that all items which match `pred` come first.
+pub(crate) fn partition_point<T, P>(v: &[T], mut pred: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    binary_search_by(v, |item| if pred(item) {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }).unwrap_or_else(|index| index)
+}
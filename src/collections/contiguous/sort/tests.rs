@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+use std::cmp::Ordering;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::collections::contiguous::{Array, Vector};
+use crate::util::alloc::CountedDrop;
+use crate::util::panic::assert_panics;
+use crate::util::random::random;
+
+/// Creates Vectors of various lengths and patterns which are likely to exercise different paths
+/// through the sorting algorithms.
+fn inputs() -> Vector<Vector<u64>> {
+    let mut inputs = Vector::new();
+
+    for len in [0, 1, 2, 7, 20, 21, 50, 51, 100, 1000, 5000] {
+        inputs.push(random(len, u64::MAX));
+        inputs.push(random(len, 4));
+        inputs.push((0..len as u64).collect());
+        inputs.push((0..len as u64).rev().collect());
+        inputs.push((0..len as u64).map(|i| i % 16).collect());
+        inputs.push((0..len as u64).map(|i| if i == len as u64 / 2 { 0 } else { i }).collect());
+    }
+
+    inputs
+}
+
+/// Returns a sorted copy of `v`, using the sort from `std` as a reference.
+fn reference_sort(v: &[u64]) -> Vector<u64> {
+    let mut sorted = Vector::from_iter_sized(v.iter().copied());
+    <[u64]>::sort(&mut sorted);
+    sorted
+}
+
+#[test]
+fn test_sort() {
+    for input in inputs() {
+        let expected = reference_sort(&input);
+
+        let mut vec = input.clone();
+        vec.sort();
+        assert_eq!(vec, expected, "Stable sort failed for {input:?}.");
+
+        let mut vec = input.clone();
+        vec.sort_unstable();
+        assert_eq!(vec, expected, "Unstable sort failed for {input:?}.");
+
+        let mut arr = Array::from(input.clone());
+        arr.sort_unstable_by(|a, b| b.cmp(a));
+        assert!(arr.iter().rev().eq(expected.iter()), "Reversed sort failed for {input:?}.");
+    }
+
+    let mut vec = Vector::from_iter_sized([(); 30].into_iter());
+    vec.sort_unstable();
+    vec.sort();
+    assert_eq!(vec.len(), 30);
+}
+
+#[test]
+fn test_stability() {
+    for input in inputs() {
+        // Pair each key with its original index, which should stay in ascending order for each key.
+        let pairs = Vector::from_iter_sized(input.iter().map(|i| i % 8).enumerate());
+        let is_stable = |v: &[(usize, u64)]| v.is_sorted_by_key(|(index, key)| (*key, *index));
+
+        let mut vec = pairs.clone();
+        vec.sort_by_key(|(_, key)| *key);
+        assert!(is_stable(&vec), "sort_by_key should be stable.");
+
+        let mut vec = pairs.clone();
+        vec.sort_by_cached_key(|(_, key)| *key);
+        assert!(is_stable(&vec), "sort_by_cached_key should be stable.");
+    }
+
+    let mut calls = 0;
+    let mut vec: Vector<_> = random(100, 10);
+    vec.sort_by_cached_key(|i| {
+        calls += 1;
+        *i
+    });
+    assert_eq!(calls, 100, "sort_by_cached_key should only compute each key once.");
+}
+
+#[test]
+fn test_select_nth_unstable() {
+    for input in inputs() {
+        let expected = reference_sort(&input);
+
+        for index in [0, input.len() / 3, input.len() / 2, input.len().saturating_sub(1)] {
+            if index >= input.len() { continue; }
+
+            let mut vec = input.clone();
+            let (before, nth, after) = vec.select_nth_unstable(index);
+            assert_eq!(*nth, expected[index], "The nth element should be in its sorted position.");
+            assert!(before.iter().all(|i| i <= nth), "Elements before n shouldn't be greater.");
+            assert!(after.iter().all(|i| i >= nth), "Elements after n shouldn't be less.");
+        }
+    }
+
+    assert_panics!({
+        let mut vec = Vector::from_iter_sized(0..3);
+        vec.select_nth_unstable(3);
+    });
+}
+
+#[test]
+fn test_binary_search() {
+    let vec = Vector::from_iter_sized([1, 3, 3, 5, 7].into_iter());
+    assert_eq!(vec.binary_search(&1), Ok(0));
+    assert_eq!(vec.binary_search(&7), Ok(4));
+    assert!(matches!(vec.binary_search(&3), Ok(1 | 2)));
+    assert_eq!(vec.binary_search(&0), Err(0));
+    assert_eq!(vec.binary_search(&4), Err(3));
+    assert_eq!(vec.binary_search(&8), Err(5));
+    assert_eq!(vec.binary_search_by_key(&10, |i| i * 2), Ok(3));
+    assert_eq!(vec.binary_search_by(|i| i.cmp(&6)), Err(4));
+
+    assert_eq!(vec.partition_point(|i| *i < 3), 1);
+    assert_eq!(vec.partition_point(|i| *i <= 3), 3);
+    assert_eq!(vec.partition_point(|_| true), 5);
+    assert_eq!(Vector::<u8>::new().partition_point(|_| true), 0);
+}
+
+#[test]
+fn test_comparator_panic_safety() {
+    struct Item {
+        value: u64,
+        _counter: CountedDrop,
+    }
+
+    type Sort = fn(&mut Vector<Item>, &mut dyn FnMut(&Item, &Item) -> Ordering);
+    let sorts: [(&str, Sort); 3] = [
+        ("sort_by", |vec, compare| vec.sort_by(compare)),
+        ("sort_unstable_by", |vec, compare| vec.sort_unstable_by(compare)),
+        ("select_nth_unstable_by", |vec, compare| {
+            vec.select_nth_unstable_by(vec.len() / 2, compare);
+        }),
+    ];
+
+    let counter = CountedDrop::new(0);
+
+    for (name, sort) in sorts {
+        for len in [10, 100, 1000] {
+            for panic_after in [0, len / 2, len] {
+                let input: Vector<_> = random(len, 50);
+                let mut vec: Vector<_> = input.iter()
+                    .map(|value| Item { value: *value, _counter: counter.clone() })
+                    .collect();
+
+                let mut comparisons = 0;
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    sort(&mut vec, &mut |a, b| {
+                        comparisons += 1;
+                        if comparisons > panic_after {
+                            panic!("Comparison limit reached");
+                        }
+                        a.value.cmp(&b.value)
+                    });
+                }));
+                assert!(
+                    result.is_err(),
+                    "{name} should have made more than {panic_after} comparisons."
+                );
+                assert_eq!(counter.take(), 0, "{name} shouldn't drop any elements when panicking.");
+
+                let values = Vector::from_iter_sized(vec.iter().map(|item| item.value));
+                assert_eq!(
+                    reference_sort(&values), reference_sort(&input),
+                    "{name} should leave every element in the Vector exactly once after a panic."
+                );
+
+                drop(vec);
+                assert_eq!(counter.take(), len, "Each element should be dropped exactly once.");
+            }
+        }
+    }
+}
@@ -1,14 +1,140 @@
+use std::alloc::{Allocator, Global};
+use std::iter::{FusedIterator, TrustedLen};
+use std::ptr;
+
 use super::Vector;
 use crate::collections::contiguous::Array;
+use crate::collections::contiguous::drain::{RawBuffer, RawDrain};
 #[doc(inline)]
 pub use crate::collections::contiguous::array::IntoIter;
 
-impl<T> IntoIterator for Vector<T> {
+impl<T, A: Allocator> IntoIterator for Vector<T, A> {
     type Item = T;
 
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         Array::from(self).into_iter()
     }
 }
+
+/// A draining iterator over a range of elements in a [`Vector`]. Produces values of type `T`.
+///
+/// While this iterator exists, the Vector only considers the elements before the range to be part
+/// of it. When dropped, any elements in the range which haven't been produced are dropped and the
+/// elements after the range are moved back to close the gap. This happens even if dropping one of
+/// the elements panics.
+///
+/// See [`Vector::drain`].
+pub struct Drain<'a, T, A: Allocator = Global> {
+    pub(crate) inner: RawDrain<'a, Vector<T, A>>,
+}
+
+impl<T, A: Allocator> RawBuffer for Vector<T, A> {
+    type Item = T;
+
+    fn raw_parts(&mut self) -> (*mut T, &mut usize) {
+        (self.arr.ptr.as_ptr().cast(), &mut self.len)
+    }
+}
+
+impl<'a, T, A: Allocator> Iterator for Drain<'a, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.inner.len(), Some(self.inner.len()))
+    }
+}
+
+impl<'a, T, A: Allocator> DoubleEndedIterator for Drain<'a, T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, T, A: Allocator> FusedIterator for Drain<'a, T, A> {}
+
+impl<'a, T, A: Allocator> ExactSizeIterator for Drain<'a, T, A> {}
+
+// SAFETY: Drain::size_hint returns the exact length of the iterator.
+unsafe impl<'a, T, A: Allocator> TrustedLen for Drain<'a, T, A> {}
+
+/// A splicing iterator, which replaces a range of elements in a [`Vector`]. Produces the removed
+/// values, of type `T`.
+///
+/// When dropped, any removed elements which haven't been produced are dropped and the gap is
+/// filled with the items from the replacement iterator. If the replacement iterator panics, the
+/// items it has already produced are kept and the Vector is left valid.
+///
+/// See [`Vector::splice`].
+pub struct Splice<'a, I: Iterator, A: Allocator = Global> {
+    pub(crate) drain: Drain<'a, I::Item, A>,
+    pub(crate) replace_with: I,
+}
+
+impl<'a, I: Iterator, A: Allocator> Iterator for Splice<'a, I, A> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<'a, I: Iterator, A: Allocator> DoubleEndedIterator for Splice<'a, I, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl<'a, I: Iterator, A: Allocator> FusedIterator for Splice<'a, I, A> {}
+
+impl<'a, I: Iterator, A: Allocator> ExactSizeIterator for Splice<'a, I, A> {}
+
+impl<'a, I: Iterator, A: Allocator> Drop for Splice<'a, I, A> {
+    fn drop(&mut self) {
+        // Drop any removed elements which haven't been produced.
+        self.drain.by_ref().for_each(drop);
+
+        // Fill the gap in place for as long as the replacement iterator produces items.
+        let gap = &mut self.drain.inner.gap;
+        while gap.vec.len < gap.tail_start {
+            let Some(item) = self.replace_with.next() else { return; };
+
+            // SAFETY: The Vector's length is before tail_start, so the value is written into the
+            // gap, which is uninitialized.
+            unsafe { gap.vec.push_unchecked(item); }
+        }
+
+        // Collect everything else so that the tail only needs to be moved once.
+        let mut rest: Vector<I::Item> = self.replace_with.by_ref().collect();
+        if rest.is_empty() { return; }
+
+        // The Vector's length is tail_start, so this reserves space for both the tail and rest.
+        gap.vec.reserve(gap.tail_len + rest.len);
+
+        let ptr = gap.vec.arr.ptr.as_ptr();
+        // SAFETY: The capacity has just been adjusted so that the tail can be moved back by
+        // rest.len, leaving space for all of rest in between. rest is a separate allocation, so
+        // it can't overlap with the Vector. ptr::copy handles any overlap when moving the tail.
+        unsafe {
+            ptr::copy(ptr.add(gap.tail_start), ptr.add(gap.tail_start + rest.len), gap.tail_len);
+            ptr::copy_nonoverlapping(rest.arr.ptr.as_ptr(), ptr.add(gap.tail_start), rest.len);
+        }
+
+        gap.tail_start += rest.len;
+        gap.vec.len += rest.len;
+        // All values in rest have been moved, so dropping it only deallocates.
+        rest.len = 0;
+
+        // The Gap is dropped next, which sees that the tail is already in place and restores the
+        // Vector's length.
+    }
+}
@@ -0,0 +1,235 @@
+#![cfg(test)]
+
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+
+use super::*;
+use crate::util::alloc::{CountedDrop, PanicOnDrop, counted};
+use crate::util::error::TryReserveError;
+use crate::util::panic::assert_panics;
+
+#[test]
+fn test_try_methods() {
+    let mut vec = Vector::<u64>::try_with_cap(4).expect("Small allocations should succeed.");
+    assert_eq!(vec.cap(), 4);
+
+    for i in 0..10 {
+        vec.try_push(i).expect("Growing a small Vector should succeed.");
+    }
+    vec.try_insert(0, 100).expect("Inserting into a small Vector should succeed.");
+    assert_eq!(*vec, [100, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    vec.try_reserve(20).expect("Reserving a small amount should succeed.");
+    assert!(vec.cap() >= 31, "Capacity should be at least len + extra.");
+
+    assert!(
+        matches!(
+            Vector::<u64>::try_with_cap(usize::MAX / 4),
+            Err(TryReserveError::CapacityOverflow(_))
+        ),
+        "Allocations larger than isize::MAX bytes should be reported as overflow."
+    );
+}
+
+#[test]
+fn test_try_reserve_failure() {
+    let mut vec = Vector::from_iter_sized(0_u32..5);
+    let cap = vec.cap();
+
+    assert!(
+        matches!(vec.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow(_))),
+        "len + extra overflowing usize should be reported as overflow."
+    );
+    assert!(
+        matches!(vec.try_reserve(isize::MAX as usize), Err(TryReserveError::CapacityOverflow(_))),
+        "Layouts larger than isize::MAX bytes should be reported as overflow."
+    );
+    assert_eq!(vec.cap(), cap, "A failed reserve should leave the capacity unchanged.");
+    assert_eq!(*vec, [0, 1, 2, 3, 4], "A failed reserve should leave the contents unchanged.");
+
+    let counter = CountedDrop::new(0);
+    let mut vec = Vector::with_cap(2);
+    vec.try_push(counter.clone()).expect("Small allocations should succeed.");
+    vec.try_push(counter.clone()).expect("Small allocations should succeed.");
+    vec.try_insert(1, counter.clone()).expect("Small allocations should succeed.");
+    drop(vec);
+    assert_eq!(counter.take(), 3, "Values added with try methods should be dropped.");
+}
+
+#[test]
+fn test_drain() {
+    let mut vec = Vector::from_iter_sized(0..8);
+    assert_eq!(vec.drain(2..5).collect::<Vector<_>>(), Vector::from_iter_sized(2..5));
+    assert_eq!(*vec, [0, 1, 5, 6, 7], "The gap should be closed.");
+    assert!(vec.drain(3..).rev().eq([7, 6]));
+    assert!(vec.drain(..0).eq([]));
+    assert_eq!(*vec, [0, 1, 5]);
+
+    let counter = CountedDrop::new(0);
+    let mut vec: Vector<_> = counted(&counter, 6);
+    let mut drain = vec.drain(1..4);
+    drop(drain.next());
+    assert_eq!(counter.take(), 1);
+    drop(drain);
+    assert_eq!(counter.take(), 2, "Elements which weren't produced should be dropped.");
+    assert_eq!(vec.len(), 3);
+    drop(vec);
+    assert_eq!(counter.take(), 3, "Each element should be dropped exactly once.");
+
+    let mut vec: Vector<_> = counted(&counter, 6);
+    mem::forget(vec.drain(2..4));
+    assert_eq!(
+        vec.len(),
+        2,
+        "Leaking a Drain should leave the Vector with the elements before it."
+    );
+    drop(vec);
+    assert_eq!(counter.take(), 2, "Leaking a Drain shouldn't cause any double drops.");
+
+    assert_panics!({
+        let mut vec = Vector::from_iter_sized(0..3);
+        vec.drain(2..4);
+    });
+}
+
+#[test]
+fn test_drain_panic_safety() {
+    let counter = CountedDrop::new(0);
+    let mut vec: Vector<_> = (0..6)
+        .map(|i| PanicOnDrop { _counter: counter.clone(), panics: i == 2 })
+        .collect();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| drop(vec.drain(1..4))));
+    assert!(result.is_err());
+    assert_eq!(counter.take(), 3, "The rest of the range should be dropped after a panic.");
+    assert_eq!(vec.len(), 3, "The gap should still be closed after a panic.");
+    drop(vec);
+    assert_eq!(counter.take(), 3);
+}
+
+#[test]
+fn test_splice() {
+    let mut vec = Vector::from_iter_sized(0..6);
+    assert!(vec.splice(1..3, [10, 20, 30, 40]).eq([1, 2]));
+    assert_eq!(*vec, [0, 10, 20, 30, 40, 3, 4, 5], "Extra items should move the tail back.");
+    assert!(vec.splice(1..5, [50]).eq([10, 20, 30, 40]));
+    assert_eq!(*vec, [0, 50, 3, 4, 5], "Fewer items should move the tail forwards.");
+    drop(vec.splice(5.., 6..8));
+    assert_eq!(*vec, [0, 50, 3, 4, 5, 6, 7]);
+
+    let counter = CountedDrop::new(0);
+    let mut vec: Vector<_> = counted(&counter, 5);
+    let removed = vec.splice(1..3, counted::<Vector<_>>(&counter, 4)).count();
+    assert_eq!(removed, 2);
+    assert_eq!(counter.take(), 2, "Only the removed elements should be dropped.");
+    assert_eq!(vec.len(), 7);
+    drop(vec);
+    assert_eq!(counter.take(), 7);
+
+    let mut vec: Vector<_> = counted(&counter, 5);
+    let replacement: Vector<_> = counted(&counter, 4);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let items = replacement.into_iter().enumerate().map(|(i, item)| {
+            assert!(i < 1, "Replacement panicked");
+            item
+        });
+        drop(vec.splice(1..4, items));
+    }));
+    assert!(result.is_err());
+    assert_eq!(
+        counter.take(),
+        6,
+        "The removed elements and unused replacements should be dropped."
+    );
+    assert_eq!(vec.len(), 3, "The Vector should keep the replacements produced before a panic.");
+    drop(vec);
+    assert_eq!(counter.take(), 3);
+}
+
+#[test]
+fn test_split_off_and_truncate() {
+    let counter = CountedDrop::new(0);
+    let mut vec: Vector<_> = counted(&counter, 6);
+    let cap = vec.cap();
+
+    let tail = vec.split_off(4);
+    assert_eq!((vec.len(), tail.len()), (4, 2));
+    assert_eq!(vec.cap(), cap, "Splitting shouldn't change the original capacity.");
+    drop(tail);
+    assert_eq!(counter.take(), 2);
+
+    vec.truncate(1);
+    assert_eq!(counter.take(), 3, "Truncating should drop the removed elements.");
+    vec.truncate(5);
+    assert_eq!(vec.len(), 1);
+
+    vec.extend(counted::<Vector<_>>(&counter, 3));
+    vec.adjust_cap(2);
+    assert_eq!(counter.take(), 2, "Shrinking the capacity should only drop elements past it.");
+    assert_eq!((vec.len(), vec.cap()), (2, 2));
+    drop(vec);
+    assert_eq!(counter.take(), 2);
+
+    assert_panics!({
+        let mut vec = Vector::from_iter_sized(0..3);
+        vec.split_off(4);
+    });
+}
+
+#[test]
+fn test_retain_and_dedup() {
+    let mut vec = Vector::from_iter_sized(0..10);
+    vec.retain(|i| i % 2 == 1);
+    assert_eq!(*vec, [1, 3, 5, 7, 9]);
+
+    let mut vec = Vector::from_iter_sized([1, 1, 2, 3, 3, 3, 1].into_iter());
+    vec.dedup_by_key(|i| *i);
+    assert_eq!(*vec, [1, 2, 3, 1]);
+
+    let counter = CountedDrop::new(0);
+    let mut vec: Vector<_> = counted(&counter, 6);
+    let mut index = 0;
+    vec.retain_mut(|_| {
+        index += 1;
+        index % 3 == 0
+    });
+    assert_eq!(counter.take(), 4);
+    assert_eq!(vec.len(), 2);
+
+    let mut vec: Vector<_> = counted(&counter, 6);
+    let mut index = 0;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        vec.retain(|_| {
+            index += 1;
+            assert!(index < 4, "Predicate panicked");
+            index % 2 == 0
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(counter.take(), 2, "Elements removed before a panic should be dropped.");
+    assert_eq!(vec.len(), 4, "Elements from the panic onwards should be kept.");
+    drop(vec);
+    assert_eq!(counter.take(), 4);
+
+    let mut vec: Vector<_> = counted(&counter, 5);
+    vec.dedup_by_key(|_| ());
+    assert_eq!(counter.take(), 4);
+    assert_eq!(vec.len(), 1);
+    drop(vec);
+    assert_eq!(counter.take(), 1);
+}
+
+#[test]
+fn test_append_and_extend_from_slice() {
+    let counter = CountedDrop::new(0);
+    let mut vec: Vector<_> = counted(&counter, 2);
+    vec.append(counted(&counter, 3));
+    assert_eq!(counter.take(), 0, "Appending shouldn't drop any elements.");
+    assert_eq!(vec.len(), 5);
+
+    let mut vec = Vector::from_iter_sized(0..2);
+    vec.append(Vector::from_iter_sized(2..5));
+    assert_eq!(*vec, [0, 1, 2, 3, 4]);
+    vec.extend_from_slice(&[5, 6]);
+    assert_eq!(*vec, [0, 1, 2, 3, 4, 5, 6]);
+}
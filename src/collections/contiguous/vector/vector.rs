@@ -1,23 +1,25 @@
+use std::alloc::{Allocator, Global};
 use std::borrow::{Borrow, BorrowMut};
-use std::cmp;
+use std::cmp::{self, Ordering};
 use std::fmt::{self, Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::iter::TrustedLen;
 use std::mem::{self, MaybeUninit};
-use std::ops::{Deref, DerefMut};
+use std::ops::{Deref, DerefMut, RangeBounds};
 use std::ptr::{self, NonNull};
 use std::slice;
 
-use crate::collections::contiguous::Array;
-use crate::util::error::IndexOutOfBounds;
+use super::{Drain, Splice};
+use crate::collections::contiguous::{Array, sort};
+use crate::collections::contiguous::drain::RawDrain;
+use crate::util::error::{CapacityOverflow, IndexOutOfBounds, TryPushError, TryReserveError};
+use crate::util::range::resolve_range;
 use crate::util::result::ResultExtension;
 
-const MIN_CAP: usize = 2;
-const MAX_CAP: usize = isize::MAX as usize;
+pub(crate) const MIN_CAP: usize = 2;
+pub(crate) const MAX_CAP: usize = isize::MAX as usize;
 
-const GROWTH_FACTOR: usize = 2;
-
-// TODO: Add try methods.
+pub(crate) const GROWTH_FACTOR: usize = 2;
 
 /// A variable size contiguous collection, based on [`Array<T>`].
 ///
@@ -31,23 +33,34 @@ const GROWTH_FACTOR: usize = 2;
 /// |-|-|
 /// | `get` | `O(1)` |
 /// | `len` | `O(1)` |
-/// | `push` | `O(1)`*, `O(n)` |
+/// | `push`, `try_push` | `O(1)`*, `O(n)` |
 /// | `push_unchecked` | `O(1)` |
 /// | `pop` | `O(1)` |
-/// | `insert` | `O(n-i)` |
+/// | `insert`, `try_insert` | `O(n-i)` |
 /// | `remove` | `O(n-i)` |
 /// | `replace` | `O(1)` |
-/// | `reserve` | `O(n)`**, `O(1)` |
+/// | `reserve`, `try_reserve` | `O(n)`**, `O(1)` |
 /// | `shrink_to_fit` | `O(n)` |
 /// | `adjust_cap` | `O(n)` |
 /// | `append` | `O(n+m)` |
 /// | `contains` | `O(n)` |
+/// | `sort`, `sort_unstable` | `O(n log n)` |
+/// | `select_nth_unstable` | `O(n)`*** |
+/// | `binary_search`, `partition_point` | `O(log n)` |
 ///
 /// \* If the Vector doesn't have enough capacity for the new element, `push` will take `O(n)`.
 ///
 /// \** If the Vector has enough capacity for the additional items already, `reserve` is `O(1)`.
-pub struct Vector<T> {
-    pub(crate) arr: Array<MaybeUninit<T>>,
+///
+/// \*** Selection is `O(n)` on average, but falls back to sorting, `O(n log n)`, if it repeatedly
+/// chooses bad pivots.
+///
+/// # Allocation
+/// Like [`Array`], a Vector stores its items in memory provided by the allocator `A`, which
+/// defaults to the [`Global`] allocator. Constructors ending in `_in` accept another allocator,
+/// which the Vector then uses whenever it grows or shrinks.
+pub struct Vector<T, A: Allocator = Global> {
+    pub(crate) arr: Array<MaybeUninit<T>, A>,
     pub(crate) len: usize,
 }
 
@@ -63,10 +76,7 @@ impl<T> Vector<T> {
     /// assert_eq!(vec.cap(), 0);
     /// ```
     pub fn new() -> Vector<T> {
-        Vector {
-            arr: Array::new(),
-            len: 0,
-        }
+        Vector::new_in(Global)
     }
 
     /// Creates a new Vector with capacity exactly equal to the provided value, allowing values to
@@ -84,12 +94,116 @@ impl<T> Vector<T> {
     /// assert_eq!(vec.cap(), 5);
     /// ```
     pub fn with_cap(cap: usize) -> Vector<T> {
+        Vector::with_cap_in(cap, Global)
+    }
+
+    /// Creates a new Vector with capacity exactly equal to the provided value, returning an error
+    /// rather than panicking or aborting if the allocation fails.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if memory layout size exceeds [`isize::MAX`] or if the
+    /// allocator fails to provide the memory.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let vec: Vector<u8> = Vector::try_with_cap(5).unwrap();
+    /// assert_eq!(vec.cap(), 5);
+    /// assert!(Vector::<u64>::try_with_cap(usize::MAX).is_err());
+    /// ```
+    pub fn try_with_cap(cap: usize) -> Result<Vector<T>, TryReserveError> {
+        Vector::try_with_cap_in(cap, Global)
+    }
+
+    pub const fn into_parts(self) -> (NonNull<MaybeUninit<T>>, usize, usize) {
+        let ret = (self.arr.ptr, self.len, self.arr.size);
+        mem::forget(self);
+        ret
+    }
+
+    pub const unsafe fn from_parts(
+        ptr: NonNull<MaybeUninit<T>>,
+        len: usize,
+        cap: usize,
+    ) -> Vector<T> {
         Vector {
-            arr: Array::new_uninit(cap),
+            arr: unsafe { Array::from_parts(ptr, cap) },
+            len,
+        }
+    }
+
+    /// Creates an Vector from a type which implements [`IntoIterator`] and creates an
+    /// [`ExactSizeIterator`].
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    pub fn from_iter_sized<I>(value: I) -> Self
+    where
+        I: Iterator<Item = T> + ExactSizeIterator + TrustedLen,
+    {
+        let iter = value.into_iter();
+        let mut vec = Vector::with_cap(iter.len());
+
+        for item in iter {
+            // SAFETY: vec has been created with the right capacity.
+            unsafe { vec.push_unchecked(item); }
+        }
+
+        vec
+    }
+}
+
+impl<T, A: Allocator> Vector<T, A> {
+    /// Creates a new Vector with length and capacity 0, which will use the provided allocator.
+    /// Memory will be allocated when the capacity changes.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(allocator_api)]
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// # use std::alloc::Global;
+    /// let mut vec = Vector::new_in(Global);
+    /// vec.push(1_u8);
+    /// assert_eq!(&*vec, &[1]);
+    /// ```
+    pub fn new_in(alloc: A) -> Vector<T, A> {
+        Vector {
+            arr: Array::new_in(alloc),
             len: 0,
         }
     }
 
+    /// Creates a new Vector with capacity exactly equal to the provided value, allocated with the
+    /// provided allocator.
+    ///
+    /// # Panics
+    /// Panics if memory layout size exceeds [`isize::MAX`].
+    pub fn with_cap_in(cap: usize, alloc: A) -> Vector<T, A> {
+        Vector {
+            arr: Array::new_uninit_in(cap, alloc),
+            len: 0,
+        }
+    }
+
+    /// Creates a new Vector with capacity exactly equal to the provided value, allocated with the
+    /// provided allocator and returning an error rather than panicking or aborting if the
+    /// allocation fails.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if memory layout size exceeds [`isize::MAX`] or if the
+    /// allocator fails to provide the memory.
+    pub fn try_with_cap_in(cap: usize, alloc: A) -> Result<Vector<T, A>, TryReserveError> {
+        Ok(Vector {
+            arr: Array::try_new_uninit_in(cap, alloc)?,
+            len: 0,
+        })
+    }
+
+    /// Returns a reference to the allocator used by the Vector.
+    pub const fn allocator(&self) -> &A {
+        self.arr.allocator()
+    }
+
     /// Returns the length of the Vector.
     ///
     /// # Examples
@@ -151,6 +265,36 @@ impl<T> Vector<T> {
         unsafe { self.push_unchecked(value) }
     }
 
+    /// Push the provided value onto the end of the Vector, increasing the capacity if required and
+    /// returning an error rather than panicking or aborting if the allocation fails.
+    ///
+    /// If an error is returned, the Vector is left unchanged and `value` is handed back as part of
+    /// the error.
+    ///
+    /// # Errors
+    /// Returns a [`TryPushError`] containing `value` if the memory layout of the Vector would have
+    /// a size that exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::<u8>::new();
+    /// for i in 0..=5 {
+    ///     vec.try_push(i).unwrap();
+    /// }
+    /// assert_eq!(&*vec, &[0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), TryPushError<T>> {
+        if self.len == self.cap()
+            && let Err(error) = self.try_grow()
+        {
+            return Err(TryPushError { error, value });
+        }
+        // SAFETY: The capacity has just been adjusted to support the addition of the new item.
+        unsafe { self.push_unchecked(value) }
+        Ok(())
+    }
+
     /// Push the provided value onto the end of the Vector, assuming that there is enough capacity
     /// to do so.
     ///
@@ -231,12 +375,42 @@ impl<T> Vector<T> {
             self.grow()
         }
 
-        let mut prev = MaybeUninit::new(value);
-        for i in index..=self.len {
-            prev = mem::replace(&mut self.arr[i], prev);
+        // SAFETY: The index has been checked and the capacity adjusted for the new item.
+        unsafe { self.insert_unchecked(index, value) }
+    }
+
+    /// Inserts the provided value at the given index, growing and moving items as necessary and
+    /// returning an error rather than panicking or aborting if the allocation fails.
+    ///
+    /// If an error is returned, the Vector is left unchanged and `value` is handed back as part of
+    /// the error.
+    ///
+    /// # Errors
+    /// Returns a [`TryPushError`] containing `value` if the memory layout of the Vector would have
+    /// a size that exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    ///
+    /// # Panics
+    /// Panics if the provided index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized(0..3);
+    /// vec.try_insert(1, 100).unwrap();
+    /// assert_eq!(&*vec, &[0, 100, 1, 2]);
+    /// ```
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), TryPushError<T>> {
+        self.check_index(index);
+
+        if self.len == self.cap()
+            && let Err(error) = self.try_grow()
+        {
+            return Err(TryPushError { error, value });
         }
 
-        self.len += 1;
+        // SAFETY: The index has been checked and the capacity adjusted for the new item.
+        unsafe { self.insert_unchecked(index, value) }
+        Ok(())
     }
 
     /// Removes the element at the provided index, moving all following values to fill in the gap.
@@ -296,6 +470,38 @@ impl<T> Vector<T> {
         self.realloc_with_cap(new_cap);
     }
 
+    /// Ensures that the Vector has capacity to hold an additional `extra` elements, returning an
+    /// error rather than panicking or aborting if the allocation fails. After a successful
+    /// invocation, the capacity will be >= len + extra.
+    ///
+    /// If an error is returned, the Vector is left unchanged.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the memory layout of the Vector would have a size that
+    /// exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// # use standard_lib::collections::contiguous::TryReserveError;
+    /// let mut vec: Vector<u32> = Vector::from_iter_sized(0..3);
+    /// assert!(vec.try_reserve(10).is_ok());
+    /// assert!(vec.cap() >= 13);
+    ///
+    /// assert!(matches!(
+    ///     vec.try_reserve(usize::MAX),
+    ///     Err(TryReserveError::CapacityOverflow(_))
+    /// ));
+    /// assert_eq!(&*vec, &[0, 1, 2]);
+    /// ```
+    pub fn try_reserve(&mut self, extra: usize) -> Result<(), TryReserveError> {
+        let new_cap = self.len.checked_add(extra).ok_or(CapacityOverflow)?;
+
+        if new_cap <= self.cap() { return Ok(()); }
+
+        self.arr.try_realloc(new_cap)
+    }
+
     /// Shrinks the Vector so that its capacity is equal to its length.
     ///
     /// # Panics
@@ -309,20 +515,8 @@ impl<T> Vector<T> {
     /// # Panics
     /// Panics if the memory layout of the Vector would have a size that exceeds [`isize::MAX`].
     pub fn adjust_cap(&mut self, new_cap: usize) {
-        if new_cap < self.cap() {
-            // Drop the values that are about to be deallocated.
-            for i in new_cap..self.cap() {
-                // SAFETY: The pointer is nonnull, as well as properly aligned, initialized and
-                // ready to drop. count > isize::MAX is already guarded against and all possible
-                // values are within the allocated range of the Array.
-                unsafe {
-                    ptr::drop_in_place(
-                        self.arr.ptr.add(i).as_ptr()
-                    );
-                }
-                self.len -= 1;
-            }
-        }
+        // Drop the values that are about to be deallocated.
+        self.truncate(new_cap);
 
         self.realloc_with_cap(new_cap);
     }
@@ -331,7 +525,7 @@ impl<T> Vector<T> {
     ///
     /// # Panics
     /// Panics if the memory layout of the Vector would have a size that exceeds [`isize::MAX`].
-    pub fn append(&mut self, other: Vector<T>) {
+    pub fn append(&mut self, mut other: Vector<T, A>) {
         let initial_len = self.len;
         self.reserve(other.len);
 
@@ -340,53 +534,237 @@ impl<T> Vector<T> {
         unsafe {
             // Reduce iteration by copying one slice into the other.
             ptr::copy_nonoverlapping(
-                self.arr.ptr.add(initial_len).as_ptr().cast_const(),
-                other.arr.ptr.as_ptr(),
+                other.arr.ptr.as_ptr().cast_const(),
+                self.arr.ptr.add(initial_len).as_ptr(),
                 other.len,
             );
         }
 
         self.len += other.len;
 
-        // Forget about other because we have copied all values.
-        mem::forget(other);
+        // Empty other because we have moved all values, so that dropping it only deallocates.
+        other.len = 0;
     }
 
-    pub const fn into_parts(self) -> (NonNull<MaybeUninit<T>>, usize, usize) {
-        let ret = (self.arr.ptr, self.len, self.arr.size);
-        mem::forget(self);
-        ret
+    /// Appends clones of all elements in the provided slice to self.
+    ///
+    /// # Panics
+    /// Panics if the memory layout of the Vector would have a size that exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized(0..2);
+    /// vec.extend_from_slice(&[2, 3, 4]);
+    /// assert_eq!(&*vec, &[0, 1, 2, 3, 4]);
+    /// ```
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+
+        for item in other {
+            // SAFETY: Capacity for all items in other has just been reserved.
+            unsafe { self.push_unchecked(item.clone()); }
+        }
     }
 
-    pub const unsafe fn from_parts(
-        ptr: NonNull<MaybeUninit<T>>,
-        len: usize,
-        cap: usize,
-    ) -> Vector<T> {
-        Vector {
-            arr: unsafe { Array::from_parts(ptr, cap) },
-            len,
+    /// Shortens the Vector to the provided length, dropping all elements after it. If the Vector
+    /// is no longer than `len`, nothing happens. The capacity is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized(0..5);
+    /// vec.truncate(2);
+    /// assert_eq!(&*vec, &[0, 1]);
+    /// vec.truncate(3);
+    /// assert_eq!(&*vec, &[0, 1]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len { return; }
+
+        let removed = ptr::slice_from_raw_parts_mut(
+            // SAFETY: len < self.len, so the pointer is within the initialized part of the Array.
+            unsafe { self.arr.ptr.add(len).as_ptr().cast::<T>() },
+            self.len - len,
+        );
+
+        // Shorten the Vector first, so that a panicking drop can only leak the removed values.
+        self.len = len;
+
+        // SAFETY: removed contains the initialized values after len, which are no longer accessible
+        // through the Vector.
+        unsafe { ptr::drop_in_place(removed); }
+    }
+
+    /// Splits the Vector in two at the provided index, returning a new Vector containing all
+    /// elements from `at` onwards. self is left containing the elements before `at`, with its
+    /// capacity unchanged.
+    ///
+    /// # Panics
+    /// Panics if `at` is greater than the length of the Vector, or if the memory layout of the new
+    /// Vector would have a size that exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized(0..5);
+    /// let tail = vec.split_off(3);
+    /// assert_eq!(&*vec, &[0, 1, 2]);
+    /// assert_eq!(&*tail, &[3, 4]);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Vector<T, A>
+    where
+        A: Clone,
+    {
+        if at > self.len {
+            Err(IndexOutOfBounds {
+                index: at,
+                len: self.len,
+            }).throw()
         }
+
+        let count = self.len - at;
+        let mut other = Vector::with_cap_in(count, self.allocator().clone());
+
+        // SAFETY: self is valid from at to len and other has capacity for count values. Both are
+        // properly aligned and don't overlap.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self.arr.ptr.add(at).as_ptr().cast_const(),
+                other.arr.ptr.as_ptr(),
+                count,
+            );
+        }
+
+        self.len = at;
+        other.len = count;
+        other
     }
 
-    /// Creates an Vector from a type which implements [`IntoIterator`] and creates an
-    /// [`ExactSizeIterator`].
+    /// Removes all elements in the provided range from the Vector, returning them as an iterator.
+    /// Any elements that aren't produced by the iterator are dropped along with it, at which point
+    /// the remaining elements are moved to close the gap.
+    ///
+    /// If the iterator is leaked, the Vector may lose all elements from the start of the range
+    /// onwards, but no element is dropped twice.
     ///
     /// # Panics
-    /// Panics if memory layout size exceeds [`isize::MAX`].
-    pub fn from_iter_sized<I>(value: I) -> Self
+    /// Panics if the range starts after it ends or ends after the length of the Vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized(0..6);
+    /// let drained: Vector<_> = vec.drain(1..4).collect();
+    /// assert_eq!(&*drained, &[1, 2, 3]);
+    /// assert_eq!(&*vec, &[0, 4, 5]);
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, A> {
+        let range = resolve_range(range, self.len).throw();
+
+        Drain {
+            inner: RawDrain::new(self, range),
+        }
+    }
+
+    /// Replaces all elements in the provided range with the items produced by `replace_with`,
+    /// returning the removed elements as an iterator. The replacement happens when the iterator is
+    /// dropped, after dropping any removed elements that it didn't produce.
+    ///
+    /// `replace_with` doesn't need to produce the same number of items as the range contains.
+    /// Items that don't fit in the range are collected into a temporary Vector before the elements
+    /// after the range are moved to make room for them.
+    ///
+    /// # Panics
+    /// Panics if the range starts after it ends or ends after the length of the Vector, or if the
+    /// memory layout of the Vector would have a size that exceeds [`isize::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized(0..5);
+    /// let removed: Vector<_> = vec.splice(1..3, [10, 20, 30]).collect();
+    /// assert_eq!(&*removed, &[1, 2]);
+    /// assert_eq!(&*vec, &[0, 10, 20, 30, 3, 4]);
+    /// ```
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter, A>
     where
-        I: Iterator<Item = T> + ExactSizeIterator + TrustedLen,
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
     {
-        let iter = value.into_iter();
-        let mut vec = Vector::with_cap(iter.len());
+        Splice {
+            drain: self.drain(range),
+            replace_with: replace_with.into_iter(),
+        }
+    }
 
-        for item in iter {
-            // SAFETY: vec has been created with the right capacity.
-            unsafe { vec.push_unchecked(item); }
+    /// Retains only the elements for which `f` returns true, dropping all others in place and
+    /// preserving the order of those that remain.
+    ///
+    /// If `f` panics, the element it was called with and all elements after it are kept.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized(0..10);
+    /// vec.retain(|i| i % 3 == 0);
+    /// assert_eq!(&*vec, &[0, 3, 6, 9]);
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|item| f(item));
+    }
+
+    /// Retains only the elements for which `f` returns true, like [`retain`](Vector::retain),
+    /// except that `f` is provided with a mutable reference to each element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized(0..6);
+    /// vec.retain_mut(|i| {
+    ///     *i *= 10;
+    ///     *i < 30
+    /// });
+    /// assert_eq!(&*vec, &[0, 10, 20]);
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let mut compactor = Compactor::new(self, 0);
+
+        while let Some(item) = compactor.current() {
+            let keep = f(item);
+            compactor.advance(keep);
         }
+    }
 
-        vec
+    /// Removes all but the first of each run of consecutive elements which produce equal keys,
+    /// dropping the removed elements in place.
+    ///
+    /// If `key` panics, the element it was called with and all elements after it are kept.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized([10, 11, 20, 21, 22, 10, 30].into_iter());
+    /// vec.dedup_by_key(|i| *i / 10);
+    /// assert_eq!(&*vec, &[10, 20, 10, 30]);
+    /// ```
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        // The first element is always kept, so start comparing from the second.
+        let mut compactor = Compactor::new(self, 1);
+
+        while let Some(item) = compactor.current() {
+            let current = key(item);
+            // SAFETY: At least one element (the first) has been kept, before the current one.
+            let last = key(unsafe { compactor.last_kept() });
+            compactor.advance(current != last);
+        }
     }
 
     /// Reallocates the internal Array with the provided capacity.
@@ -403,6 +781,17 @@ impl<T> Vector<T> {
     /// # Panics
     /// Panics if the memory layout of the Vector would have a size that exceeds [`isize::MAX`].
     pub(crate) fn grow(&mut self) {
+        self.try_grow().unwrap_or_else(|error| error.handle())
+    }
+
+    /// Grows the internal Array to allow for the insertion of additional elements, returning an
+    /// error if the allocation fails. After a successful call, the Vector can take at least one
+    /// more element.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the memory layout of the Vector would have a size that
+    /// exceeds [`isize::MAX`] or if the allocator fails to provide the memory.
+    pub(crate) fn try_grow(&mut self) -> Result<(), TryReserveError> {
         // SAFETY: old_cap < isize::MAX, so old_cap * 2 can't overflow. Can still exceed isize::MAX.
         let mut new_cap = cmp::max(self.cap() * GROWTH_FACTOR, MIN_CAP);
 
@@ -411,7 +800,21 @@ impl<T> Vector<T> {
             new_cap = MAX_CAP;
         }
 
-        self.realloc_with_cap(new_cap);
+        self.arr.try_realloc(new_cap)
+    }
+
+    /// Inserts the provided value at the given index, moving all following items back by one.
+    ///
+    /// # Safety
+    /// The caller must ensure that `index` is in bounds and that the Vector has capacity for at
+    /// least one more element.
+    unsafe fn insert_unchecked(&mut self, index: usize, value: T) {
+        let mut prev = MaybeUninit::new(value);
+        for i in index..=self.len {
+            prev = mem::replace(&mut self.arr[i], prev);
+        }
+
+        self.len += 1;
     }
 
     /// Checks that the provided index is within the bounds of self.
@@ -428,8 +831,337 @@ impl<T> Vector<T> {
     }
 }
 
-impl<T> Extend<T> for Vector<T> {
-    fn extend<A: IntoIterator<Item = T>>(&mut self, iter: A) {
+/// A guard used to remove elements from a [`Vector`] in place, by visiting each element in turn and
+/// moving those that are kept back over those that have been removed.
+///
+/// While the Compactor exists, the Vector's length is set to 0, so that it is left valid (if
+/// shorter) if the Compactor is leaked. When dropped, including during a panic, any elements which
+/// haven't been visited are moved back to close the gap and the length is restored.
+struct Compactor<'a, T, A: Allocator> {
+    vec: &'a mut Vector<T, A>,
+    processed: usize,
+    removed: usize,
+    len: usize,
+}
+
+impl<'a, T, A: Allocator> Compactor<'a, T, A> {
+    /// Creates a Compactor for the provided Vector, treating the first `skip` elements as already
+    /// kept.
+    fn new(vec: &'a mut Vector<T, A>, skip: usize) -> Compactor<'a, T, A> {
+        let len = vec.len;
+        vec.len = 0;
+
+        Compactor {
+            vec,
+            processed: cmp::min(skip, len),
+            removed: 0,
+            len,
+        }
+    }
+
+    /// Returns a mutable reference to the element currently being visited, if any remain.
+    fn current(&mut self) -> Option<&mut T> {
+        if self.processed < self.len {
+            // SAFETY: Elements from processed to len haven't been visited, so they are initialized
+            // and in their original positions.
+            Some(unsafe { self.vec.arr[self.processed].assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the last element which has been kept.
+    ///
+    /// # Safety
+    /// At least one element must have been kept.
+    unsafe fn last_kept(&mut self) -> &mut T {
+        let index = self.processed - self.removed - 1;
+        // SAFETY: All kept elements are initialized and moved to before processed - removed.
+        unsafe { self.vec.arr[index].assume_init_mut() }
+    }
+
+    /// Moves on from the current element, either keeping it by moving it back over any removed
+    /// elements or dropping it in place.
+    fn advance(&mut self, keep: bool) {
+        let ptr = self.vec.arr.ptr.as_ptr();
+        let index = self.processed;
+        self.processed += 1;
+
+        if keep {
+            if self.removed > 0 {
+                // SAFETY: The current element is initialized and the destination is the position
+                // of a removed element, so they don't overlap.
+                unsafe {
+                    ptr::copy_nonoverlapping(ptr.add(index), ptr.add(index - self.removed), 1);
+                }
+            }
+        } else {
+            self.removed += 1;
+            // SAFETY: The current element is initialized and is no longer considered part of the
+            // Vector, because processed has already been incremented.
+            unsafe { (*ptr.add(index)).assume_init_drop(); }
+        }
+    }
+}
+
+impl<'a, T, A: Allocator> Drop for Compactor<'a, T, A> {
+    fn drop(&mut self) {
+        let ptr = self.vec.arr.ptr.as_ptr();
+        let unvisited = self.len - self.processed;
+
+        if self.removed > 0 {
+            // SAFETY: Elements from processed to len are initialized and are moved back to follow
+            // the kept elements. ptr::copy handles any overlap.
+            unsafe {
+                ptr::copy(
+                    ptr.add(self.processed),
+                    ptr.add(self.processed - self.removed),
+                    unvisited,
+                );
+            }
+        }
+
+        self.vec.len = self.len - self.removed;
+    }
+}
+
+impl<T, A: Allocator> Vector<T, A> {
+    /// Sorts the Vector in ascending order, with a stable merge sort.
+    ///
+    /// Equal elements are never reordered. A scratch buffer of half the length of the Vector is
+    /// allocated while sorting.
+    ///
+    /// # Panics
+    /// Panics if allocating the scratch buffer fails, or if the implementation of [`Ord`] for `T`
+    /// panics. In the latter case, the Vector still contains all of its elements, in an unspecified
+    /// order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized([5, 2, 4, 1, 3].into_iter());
+    /// vec.sort();
+    /// assert_eq!(&*vec, &[1, 2, 3, 4, 5]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        sort::merge_sort(self, &mut T::lt);
+    }
+
+    /// Sorts the Vector with a stable merge sort, using `compare` to order elements.
+    ///
+    /// # Panics
+    /// Panics if allocating the scratch buffer fails, or if `compare` panics. In the latter case,
+    /// the Vector still contains all of its elements, in an unspecified order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized([5, 2, 4, 1, 3].into_iter());
+    /// vec.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(&*vec, &[5, 4, 3, 2, 1]);
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::merge_sort(self, &mut |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the Vector with a stable merge sort, ordering elements by the key produced by `f`.
+    ///
+    /// `f` is called for every comparison, so [`sort_by_cached_key`](Vector::sort_by_cached_key)
+    /// is likely to be faster if `f` is expensive.
+    ///
+    /// # Panics
+    /// Panics if allocating the scratch buffer fails, or if `f` panics. In the latter case, the
+    /// Vector still contains all of its elements, in an unspecified order.
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        sort::merge_sort(self, &mut |a, b| f(a).lt(&f(b)));
+    }
+
+    /// Sorts the Vector stably, ordering elements by the key produced by `f`, which is only called
+    /// once for each element.
+    ///
+    /// The keys are stored in an [`Array`] alongside each element's index while sorting.
+    ///
+    /// # Panics
+    /// Panics if allocating the keys fails, or if `f` or the implementation of [`Ord`] for `K`
+    /// panics. In the latter case, the Vector is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized([-3, 1, -2, 2].into_iter());
+    /// vec.sort_by_cached_key(|i: &i32| i.abs());
+    /// assert_eq!(&*vec, &[1, -2, 2, -3]);
+    /// ```
+    pub fn sort_by_cached_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        sort::cached_key_sort(self, f);
+    }
+
+    /// Sorts the Vector in ascending order, with an unstable pattern-defeating quicksort.
+    ///
+    /// Equal elements may be reordered, but no memory is allocated.
+    ///
+    /// # Panics
+    /// Panics if the implementation of [`Ord`] for `T` panics, in which case the Vector still
+    /// contains all of its elements, in an unspecified order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized([5, 2, 4, 1, 3].into_iter());
+    /// vec.sort_unstable();
+    /// assert_eq!(&*vec, &[1, 2, 3, 4, 5]);
+    /// ```
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        sort::quick_sort(self, &mut T::lt);
+    }
+
+    /// Sorts the Vector with an unstable pattern-defeating quicksort, using `compare` to order
+    /// elements.
+    ///
+    /// # Panics
+    /// Panics if `compare` panics, in which case the Vector still contains all of its elements, in
+    /// an unspecified order.
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::quick_sort(self, &mut |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the Vector with an unstable pattern-defeating quicksort, ordering elements by the key
+    /// produced by `f`.
+    ///
+    /// # Panics
+    /// Panics if `f` panics, in which case the Vector still contains all of its elements, in an
+    /// unspecified order.
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        sort::quick_sort(self, &mut |a, b| f(a).lt(&f(b)));
+    }
+
+    /// Searches the Vector, which is assumed to be sorted, for `value` using a binary search.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the index where `value`
+    /// could be inserted while maintaining the sorted order. If there are multiple matches, any of
+    /// them may be returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let vec = Vector::from_iter_sized([1, 3, 5, 7].into_iter());
+    /// assert_eq!(vec.binary_search(&5), Ok(2));
+    /// assert_eq!(vec.binary_search(&4), Err(2));
+    /// ```
+    pub fn binary_search(&self, value: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        sort::binary_search_by(self, |item| item.cmp(value))
+    }
+
+    /// Searches the Vector, which is assumed to be sorted, using a binary search where `f` returns
+    /// the ordering of each element relative to the target.
+    ///
+    /// See [`binary_search`](Vector::binary_search) for the meaning of the returned value.
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        sort::binary_search_by(self, f)
+    }
+
+    /// Searches the Vector, which is assumed to be sorted by the key produced by `f`, for `key`
+    /// using a binary search.
+    ///
+    /// See [`binary_search`](Vector::binary_search) for the meaning of the returned value.
+    pub fn binary_search_by_key<K, F>(&self, key: &K, mut f: F) -> Result<usize, usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        sort::binary_search_by(self, |item| f(item).cmp(key))
+    }
+
+    /// Returns the index of the first element for which `pred` returns false, assuming that the
+    /// Vector is partitioned so that all elements which match `pred` come first.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let vec = Vector::from_iter_sized([1, 2, 3, 10, 20].into_iter());
+    /// assert_eq!(vec.partition_point(|i| *i < 5), 3);
+    /// ```
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        sort::partition_point(self, pred)
+    }
+
+    /// Reorders the Vector so that the element at `index` is in its sorted position, returning the
+    /// elements before it, a reference to it and the elements after it. All elements before
+    /// `index` are less than or equal to it and all elements after are greater than or equal to
+    /// it, but neither side is sorted.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds, or if the implementation of [`Ord`] for `T` panics. In
+    /// the latter case, the Vector still contains all of its elements, in an unspecified order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::contiguous::Vector;
+    /// let mut vec = Vector::from_iter_sized([5, 2, 4, 1, 3].into_iter());
+    /// let (before, median, after) = vec.select_nth_unstable(2);
+    /// assert_eq!(*median, 3);
+    /// assert!(before.iter().all(|i| *i < 3) && after.iter().all(|i| *i > 3));
+    /// ```
+    pub fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+    where
+        T: Ord,
+    {
+        sort::select_nth(self, index, &mut T::lt)
+    }
+
+    /// Reorders the Vector so that the element at `index` is in its sorted position, like
+    /// [`select_nth_unstable`](Vector::select_nth_unstable), using `compare` to order elements.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds, or if `compare` panics. In the latter case, the Vector
+    /// still contains all of its elements, in an unspecified order.
+    pub fn select_nth_unstable_by<F>(
+        &mut self,
+        index: usize,
+        mut compare: F,
+    ) -> (&mut [T], &mut T, &mut [T])
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::select_nth(self, index, &mut |a, b| compare(a, b) == Ordering::Less)
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for Vector<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         for item in iter {
             self.push(item);
         }
@@ -483,13 +1215,13 @@ impl<T> FromIterator<T> for Vector<T> {
     }
 }
 
-impl<T> Default for Vector<T> {
+impl<T, A: Allocator + Default> Default for Vector<T, A> {
     fn default() -> Self {
-        Self::new()
+        Self::new_in(A::default())
     }
 }
 
-impl<T> Drop for Vector<T> {
+impl<T, A: Allocator> Drop for Vector<T, A> {
     fn drop(&mut self) {
         // Call drop on all initialized values in place.
         for i in 0..self.len {
@@ -502,7 +1234,7 @@ impl<T> Drop for Vector<T> {
     }
 }
 
-impl<T> Deref for Vector<T> {
+impl<T, A: Allocator> Deref for Vector<T, A> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
@@ -520,7 +1252,7 @@ impl<T> Deref for Vector<T> {
     }
 }
 
-impl<T> DerefMut for Vector<T> {
+impl<T, A: Allocator> DerefMut for Vector<T, A> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         // SAFETY: Vector is valid as a slice for len values, which are all initialized. The pointer
         // is nonnull, properly aligned and the range entirely contained within this Vector.
@@ -536,25 +1268,25 @@ impl<T> DerefMut for Vector<T> {
     }
 }
 
-impl<T> AsRef<[T]> for Vector<T> {
+impl<T, A: Allocator> AsRef<[T]> for Vector<T, A> {
     fn as_ref(&self) -> &[T] {
         self.deref()
     }
 }
 
-impl<T> AsMut<[T]> for Vector<T> {
+impl<T, A: Allocator> AsMut<[T]> for Vector<T, A> {
     fn as_mut(&mut self) -> &mut [T] {
         self.deref_mut()
     }
 }
 
-impl<T> Borrow<[T]> for Vector<T> {
+impl<T, A: Allocator> Borrow<[T]> for Vector<T, A> {
     fn borrow(&self) -> &[T] {
         self.as_ref()
     }
 }
 
-impl<T> BorrowMut<[T]> for Vector<T> {
+impl<T, A: Allocator> BorrowMut<[T]> for Vector<T, A> {
     fn borrow_mut(&mut self) -> &mut [T] {
         self.as_mut()
     }
@@ -562,14 +1294,14 @@ impl<T> BorrowMut<[T]> for Vector<T> {
 
 // SAFETY: Vectors, when used safely rely on unique pointers and are therefore safe for Send when T:
 // Send.
-unsafe impl<T: Send> Send for Vector<T> {}
+unsafe impl<T: Send, A: Allocator + Send> Send for Vector<T, A> {}
 // SAFETY: Vector's safe API obeys all rules of the borrow checker, so no interior mutability
 // occurs. This means that Vector<T> can safely implement Sync when T: Sync.
-unsafe impl<T: Sync> Sync for Vector<T> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Vector<T, A> {}
 
-impl<T: Clone> Clone for Vector<T> {
+impl<T: Clone, A: Allocator + Clone> Clone for Vector<T, A> {
     fn clone(&self) -> Self {
-        let mut vec = Self::with_cap(self.cap());
+        let mut vec = Self::with_cap_in(self.cap(), self.allocator().clone());
 
         for value in self.iter() {
             vec.push(value.clone());
@@ -579,8 +1311,8 @@ impl<T: Clone> Clone for Vector<T> {
     }
 }
 
-impl<T> From<Vector<T>> for Array<T> {
-    fn from(mut value: Vector<T>) -> Self {
+impl<T, A: Allocator> From<Vector<T, A>> for Array<T, A> {
+    fn from(mut value: Vector<T, A>) -> Self {
         // Dealloc all uninit values > len.
         value.shrink_to_fit();
 
@@ -592,8 +1324,8 @@ impl<T> From<Vector<T>> for Array<T> {
     }
 }
 
-impl<T> From<Array<T>> for Vector<T> {
-    fn from(value: Array<T>) -> Self {
+impl<T, A: Allocator> From<Array<T, A>> for Vector<T, A> {
+    fn from(value: Array<T, A>) -> Self {
         let len = value.size();
         Vector {
             arr: value.forget_init(),
@@ -630,21 +1362,21 @@ impl TryFrom<Vector<u8>> for String {
     }
 }
 
-impl<T: PartialEq> PartialEq for Vector<T> {
+impl<T: PartialEq, A: Allocator> PartialEq for Vector<T, A> {
     fn eq(&self, other: &Self) -> bool {
         **self == **other
     }
 }
 
-impl<T: Eq> Eq for Vector<T> {}
+impl<T: Eq, A: Allocator> Eq for Vector<T, A> {}
 
-impl<T: Hash> Hash for Vector<T> {
+impl<T: Hash, A: Allocator> Hash for Vector<T, A> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         (**self).hash(state);
     }
 }
 
-impl<T: Debug> Debug for Vector<T> {
+impl<T: Debug, A: Allocator> Debug for Vector<T, A> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("Vector")
             .field_with("contents", |f| f.debug_list().entries(self.iter()).finish())
@@ -654,7 +1386,7 @@ impl<T: Debug> Debug for Vector<T> {
     }
 }
 
-impl<T: Debug> Display for Vector<T> {
+impl<T: Debug, A: Allocator> Display for Vector<T, A> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "!")?;
         f.debug_list().entries(self.iter()).finish()
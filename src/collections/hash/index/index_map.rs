@@ -0,0 +1,512 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{BuildHasher, Hash, RandomState};
+use std::mem;
+use std::ops::Index;
+
+use super::{IntoKeys, IntoValues, Iter, Keys, Values, ValuesMut};
+use crate::collections::contiguous::Vector;
+use crate::collections::hash::HashMap;
+use crate::collections::hash::map::RawEntryMut;
+use crate::util::error::{IndexOutOfBounds, NoValueForKey};
+use crate::util::result::ResultExtension;
+
+/// A map of keys to values which relies on the keys implementing [`Hash`], while preserving the
+/// order in which entries are inserted.
+///
+/// Entries are stored contiguously in a [`Vector`], in order, which allows them to be accessed by
+/// position as well as by key. A separate [`HashMap`] acts as a hash index, mapping the hash of
+/// each key to the position of its entry. The index only ever accesses its own entries by their
+/// stored hash, so keys are only hashed when they are inserted or looked up, and when the entries
+/// are reordered by sorting.
+///
+/// Iteration always follows the order of the entries, which is insertion order unless the entries
+/// are reordered by [`swap_remove`](IndexMap::swap_remove), [`move_index`](IndexMap::move_index)
+/// or sorting.
+///
+/// It is a logic error for keys in an IndexMap to be manipulated in a way that changes their hash.
+/// Because of this, IndexMap's API prevents mutable access to its keys.
+///
+/// # Time Complexity
+/// For this analysis of time complexity, variables are defined as follows:
+/// - `n`: The number of items in the IndexMap.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `len` | `O(1)` |
+/// | `insert` | `O(1)`**, `O(n)` |
+/// | `get` | `O(1)`* |
+/// | `get_index` | `O(1)` |
+/// | `swap_remove` | `O(1)`* |
+/// | `shift_remove` | `O(n)` |
+/// | `move_index` | `O(n)` |
+/// | `sort_by` | `O(n*log(n))` |
+/// | `contains` | `O(1)`* |
+///
+/// \* In the event of a hash collision, these functions will take additional time, while a valid
+/// / correct location is found.
+///
+/// \** If the IndexMap doesn't have enough capacity for the new element, `insert` will take `O(n)`.
+/// \* applies as well.
+pub struct IndexMap<K: Hash + Eq, V, B: BuildHasher = RandomState> {
+    pub(crate) entries: Vector<(K, V)>,
+    /// The hash index, with the positions of entries as keys. The hasher of this HashMap is used to
+    /// hash the keys of the IndexMap, never the positions themselves.
+    pub(crate) indices: HashMap<usize, (), B>,
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default> IndexMap<K, V, B> {
+    /// Creates a new IndexMap with capacity 0 and the default value for `B`. Memory will be
+    /// allocated when the capacity changes.
+    pub fn new() -> IndexMap<K, V, B> {
+        IndexMap {
+            entries: Vector::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    /// Creates a new IndexMap with enough capacity to hold `cap` entries and the default value for
+    /// `B`.
+    pub fn with_cap(cap: usize) -> IndexMap<K, V, B> {
+        IndexMap::with_cap_and_hasher(cap, B::default())
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher> IndexMap<K, V, B> {
+    /// Creates a new IndexMap with capacity 0 and the provided `hasher`.
+    pub fn with_hasher(hasher: B) -> IndexMap<K, V, B> {
+        IndexMap {
+            entries: Vector::new(),
+            indices: HashMap::with_hasher(hasher),
+        }
+    }
+
+    /// Creates a new IndexMap with enough capacity to hold `cap` entries and the provided `hasher`.
+    pub fn with_cap_and_hasher(cap: usize, hasher: B) -> IndexMap<K, V, B> {
+        let mut map = IndexMap {
+            entries: Vector::with_cap(cap),
+            indices: HashMap::with_hasher(hasher),
+        };
+        map.indices.reserve(cap);
+        map
+    }
+
+    /// Returns the length of the IndexMap (the number of entries it contains).
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the IndexMap contains no entries.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the IndexMap's hasher.
+    pub const fn hasher(&self) -> &B {
+        self.indices.hasher()
+    }
+
+    /// Inserts the provided `key`-`value` pair into the IndexMap, increasing its capacity if
+    /// required. If the key was already associated with a value, the previous value is returned
+    /// and the entry keeps its position. Otherwise, the new entry is appended to the end.
+    ///
+    /// As with the standard library, the key isn't changed if it already exists.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert_full(key, value).1
+    }
+
+    /// Inserts the provided `key`-`value` pair into the IndexMap, in the same manner as
+    /// [`insert`](IndexMap::insert), returning the position of the entry along with the previous
+    /// value, if any.
+    pub fn insert_full(&mut self, key: K, value: V) -> (usize, Option<V>) {
+        let hash = self.indices.hasher().hash_one(&key);
+        let entries = &mut self.entries;
+
+        match self.indices.raw_entry_mut().from_hash(hash, |&pos| entries[pos].0 == key) {
+            RawEntryMut::Occupied(occupied) => {
+                let pos = *occupied.key();
+                (pos, Some(mem::replace(&mut entries[pos].1, value)))
+            },
+            RawEntryMut::Vacant(vacant) => {
+                let pos = entries.len();
+                vacant.insert(pos, ());
+                entries.push((key, value));
+                (pos, None)
+            },
+        }
+    }
+
+    /// Returns the position of the entry for the provided `key`, or None if there is no entry.
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.indices.hasher().hash_one(key);
+        self.indices.raw_entry()
+            .from_hash(hash, |&pos| self.entries[pos].0.borrow() == key)
+            .map(|(&pos, _)| pos)
+    }
+
+    /// Returns the entry for the provided `key` as a key-value pair or None if there is no entry.
+    pub fn get_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_index(self.get_index_of(key)?)
+    }
+
+    /// Returns a reference to the value associated with the provided `key` or None if the map
+    /// contains no values for `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_entry(key).map(|(_, value)| value)
+    }
+
+    /// Returns a mutable reference to the value associated with the provided `key` or None if the
+    /// map contains no values for `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let pos = self.get_index_of(key)?;
+        Some(&mut self.entries[pos].1)
+    }
+
+    /// Returns the entry at the provided `index` as a key-value pair, or None if `index` is out of
+    /// bounds.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries.get(index).map(|(key, value)| (key, value))
+    }
+
+    /// Returns the entry at the provided `index` as a key-value pair with a mutable value, or None
+    /// if `index` is out of bounds.
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.entries.get_mut(index).map(|(key, value)| (&*key, value))
+    }
+
+    /// Returns true if the IndexMap contains `key`.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_index_of(key).is_some()
+    }
+
+    /// Removes the entry associated with `key` by swapping it with the last entry, returning the
+    /// value if it exists. This doesn't preserve the order of the remaining entries, but takes
+    /// `O(1)` time.
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.swap_remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes the entry associated with `key` by swapping it with the last entry, returning it if
+    /// it exists. See [`IndexMap::swap_remove`].
+    pub fn swap_remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.swap_remove_index(self.get_index_of(key)?)
+    }
+
+    /// Removes the entry at the provided `index` by swapping it with the last entry, returning it
+    /// if `index` is in bounds. See [`IndexMap::swap_remove`].
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let last = self.len().checked_sub(1)?;
+        if index > last {
+            return None;
+        }
+
+        self.remove_position(index);
+        if index != last {
+            let hash = self.indices.hasher().hash_one(&self.entries[last].0);
+            self.set_position(hash, last, index);
+            self.entries.swap(index, last);
+        }
+
+        self.entries.pop()
+    }
+
+    /// Removes the entry associated with `key` by shifting all following entries, returning the
+    /// value if it exists. This preserves the order of the remaining entries, but takes `O(n)`
+    /// time.
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.shift_remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes the entry associated with `key` by shifting all following entries, returning it if
+    /// it exists. See [`IndexMap::shift_remove`].
+    pub fn shift_remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.shift_remove_index(self.get_index_of(key)?)
+    }
+
+    /// Removes the entry at the provided `index` by shifting all following entries, returning it
+    /// if `index` is in bounds. See [`IndexMap::shift_remove`].
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        if index >= self.len() {
+            return None;
+        }
+
+        self.remove_position(index);
+        self.map_positions(|pos| if pos > index { pos - 1 } else { pos });
+        Some(self.entries.remove(index))
+    }
+
+    /// Moves the entry at index `from` to index `to`, shifting all entries in between to fill the
+    /// gap.
+    ///
+    /// # Panics
+    /// Panics if either `from` or `to` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::IndexMap;
+    /// let mut map: IndexMap<char, usize> = "abcd".chars().zip(0..).collect();
+    /// map.move_index(0, 2);
+    /// assert_eq!(map.keys().collect::<String>(), "bcad");
+    /// assert_eq!(map.get_index_of(&'a'), Some(2));
+    /// ```
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        self.check_index(from);
+        self.check_index(to);
+
+        match from.cmp(&to) {
+            Ordering::Less => {
+                self.map_positions(|pos| match pos {
+                    _ if pos == from => to,
+                    _ if pos > from && pos <= to => pos - 1,
+                    _ => pos,
+                });
+                self.entries[from..=to].rotate_left(1);
+            },
+            Ordering::Greater => {
+                self.map_positions(|pos| match pos {
+                    _ if pos == from => to,
+                    _ if pos >= to && pos < from => pos + 1,
+                    _ => pos,
+                });
+                self.entries[to..=from].rotate_right(1);
+            },
+            Ordering::Equal => (),
+        }
+    }
+
+    /// Sorts the entries of the IndexMap in place, using `compare` to compare the key-value pairs.
+    /// The sort is stable.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::IndexMap;
+    /// let mut map: IndexMap<&str, usize> = [("b", 2), ("c", 1), ("a", 3)].into_iter().collect();
+    /// map.sort_by(|_, v1, _, v2| v1.cmp(v2));
+    /// assert_eq!(map.keys().copied().collect::<String>(), "cba");
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        let guard = Reindexer { map: self };
+        guard.map.entries.sort_by(|(k1, v1), (k2, v2)| compare(k1, v1, k2, v2));
+    }
+
+    /// Sorts the entries of the IndexMap in place, by their keys. The sort is stable.
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.sort_by(|k1, _, k2, _| k1.cmp(k2));
+    }
+
+    /// Increases the capacity of the IndexMap to ensure that len + `extra` entries will fit.
+    pub fn reserve(&mut self, extra: usize) {
+        self.entries.reserve(extra);
+        self.indices.reserve(extra);
+    }
+
+    /// Returns an iterator over all key-value pairs in the IndexMap, as references, in order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.into_iter()
+    }
+
+    /// Returns an iterator over all keys in the IndexMap, in order.
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        IntoKeys(self.into_iter())
+    }
+
+    /// Returns an iterator over all keys in the IndexMap, as references, in order.
+    pub fn keys<'a>(&'a self) -> Keys<'a, K, V> {
+        Keys(self.iter())
+    }
+
+    /// Returns an iterator over all values in the IndexMap, in order.
+    pub fn into_values(self) -> IntoValues<K, V> {
+        IntoValues(self.into_iter())
+    }
+
+    /// Returns an iterator over all values in the IndexMap, as mutable references, in order.
+    pub fn values_mut<'a>(&'a mut self) -> ValuesMut<'a, K, V> {
+        ValuesMut(self.entries.iter_mut())
+    }
+
+    /// Returns an iterator over all values in the IndexMap, as references, in order.
+    pub fn values<'a>(&'a self) -> Values<'a, K, V> {
+        Values(self.iter())
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher> IndexMap<K, V, B> {
+    /// Panics if the provided index is out of bounds.
+    pub(crate) fn check_index(&self, index: usize) {
+        if index >= self.len() {
+            Err(IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }).throw()
+        }
+    }
+
+    /// Removes the hash index entry for the entry at `pos`, without modifying the entries.
+    pub(crate) fn remove_position(&mut self, pos: usize) {
+        let hash = self.indices.hasher().hash_one(&self.entries[pos].0);
+
+        if let Some(Ok(index)) = self.indices.probe(hash, |&existing| existing == pos) {
+            self.indices.remove_at(index);
+        }
+    }
+
+    /// Updates the hash index entry for the entry at `old`, which has the provided `hash`, so that
+    /// it points to `new` instead.
+    pub(crate) fn set_position(&mut self, hash: u64, old: usize, new: usize) {
+        if let Some(Ok(index)) = self.indices.probe(hash, |&existing| existing == old)
+            && let Some(pair) = &mut self.indices.arr[index]
+        {
+            // The position is only ever compared through closures, never hashed, so it can be
+            // modified in place.
+            pair.key = new;
+        }
+    }
+
+    /// Updates the position of every hash index entry using the provided mapping function.
+    pub(crate) fn map_positions<F: FnMut(usize) -> usize>(&mut self, mut f: F) {
+        for pair in self.indices.arr.iter_mut().flatten() {
+            pair.key = f(pair.key);
+        }
+    }
+
+    /// Rebuilds the hash index from scratch, after the entries have been reordered. The capacity of
+    /// the index is retained.
+    pub(crate) fn rebuild_indices(&mut self) {
+        drop(self.indices.drain());
+
+        for (pos, (key, _)) in self.entries.iter().enumerate() {
+            let hash = self.indices.hasher().hash_one(key);
+            self.indices.insert_with_hash(hash, pos, ());
+        }
+    }
+}
+
+/// A guard used to reorder the entries of an [`IndexMap`] with a user-provided function. When
+/// dropped, including during a panic, the hash index is rebuilt to match the new order of the
+/// entries.
+struct Reindexer<'a, K: Hash + Eq, V, B: BuildHasher> {
+    map: &'a mut IndexMap<K, V, B>,
+}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher> Drop for Reindexer<'a, K, V, B> {
+    fn drop(&mut self) {
+        self.map.rebuild_indices();
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default> Default for IndexMap<K, V, B> {
+    fn default() -> Self {
+        IndexMap::new()
+    }
+}
+
+impl<Q, K, V> Index<&Q> for IndexMap<K, V>
+where
+    Q: Hash + Eq + ?Sized,
+    K: Hash + Eq + Borrow<Q>,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).ok_or(NoValueForKey).throw()
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default> Extend<(K, V)> for IndexMap<K, V, B> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+
+    fn extend_one(&mut self, item: (K, V)) {
+        self.insert(item.0, item.1);
+    }
+
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default> FromIterator<(K, V)> for IndexMap<K, V, B> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(value: I) -> Self {
+        let iter = value.into_iter();
+        let mut map = IndexMap::with_cap(iter.size_hint().0);
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+impl<K: Hash + Eq, V: PartialEq, B: BuildHasher> PartialEq for IndexMap<K, V, B> {
+    /// Two IndexMaps are considered equal if they contain exactly the same entries, regardless of
+    /// their order.
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, B: BuildHasher> Eq for IndexMap<K, V, B> {}
+
+impl<K: Hash + Eq + Debug, V: Debug, B: BuildHasher + Debug> Debug for IndexMap<K, V, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexMap")
+            .field_with("entries", |f| f.debug_map().entries(self.iter()).finish())
+            .field("len", &self.len())
+            .field("hasher", self.hasher())
+            .finish()
+    }
+}
+
+impl<K: Hash + Eq + Debug, V: Debug, B: BuildHasher> Display for IndexMap<K, V, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "#")?;
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,286 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{BuildHasher, Hash, RandomState};
+
+use super::{IndexMap, SetIter};
+#[doc(inline)]
+pub use crate::collections::traits::set::{SetInterface, SetIterator};
+
+/// A set of values that prevents duplicates with the help of the [`Hash`] trait, while preserving
+/// the order in which elements are inserted.
+///
+/// Relies on [`IndexMap`] internally, see documentation there for additional details.
+///
+/// It is a logic error for elements in an IndexSet to be manipulated in a way that changes their
+/// hash. Because of this, IndexSet's API prevents mutable access to its elements.
+///
+/// # Time Complexity
+/// See [`IndexMap`]. Set operations provided by [`SetIterator`] have the same complexity as those
+/// of [`HashSet`](crate::collections::hash::HashSet).
+pub struct IndexSet<T: Hash + Eq, B: BuildHasher = RandomState> {
+    pub(crate) inner: IndexMap<T, (), B>,
+}
+
+impl<T: Hash + Eq, B: BuildHasher + Default> IndexSet<T, B> {
+    /// Creates a new IndexSet with capacity 0 and the default value for `B`. Memory will be
+    /// allocated when the capacity changes.
+    pub fn new() -> IndexSet<T, B> {
+        IndexSet {
+            inner: IndexMap::new(),
+        }
+    }
+
+    /// Creates a new IndexSet with enough capacity to hold `cap` elements and the default value for
+    /// `B`.
+    pub fn with_cap(cap: usize) -> IndexSet<T, B> {
+        IndexSet {
+            inner: IndexMap::with_cap(cap),
+        }
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> IndexSet<T, B> {
+    /// Creates a new IndexSet with capacity 0 and the provided `hasher`.
+    pub fn with_hasher(hasher: B) -> IndexSet<T, B> {
+        IndexSet {
+            inner: IndexMap::with_hasher(hasher),
+        }
+    }
+
+    /// Creates a new IndexSet with enough capacity to hold `cap` elements and the provided
+    /// `hasher`.
+    pub fn with_cap_and_hasher(cap: usize, hasher: B) -> IndexSet<T, B> {
+        IndexSet {
+            inner: IndexMap::with_cap_and_hasher(cap, hasher),
+        }
+    }
+
+    /// Returns the length of the IndexSet (the number of elements it contains).
+    pub const fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the IndexSet contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Inserts the provided item at the end of the IndexSet, increasing its capacity if required.
+    /// If the item was already included, no change is made (the existing element keeps its
+    /// position) and the method returns false.
+    pub fn insert(&mut self, item: T) -> bool {
+        self.inner.insert(item, ()).is_none()
+    }
+
+    /// Inserts the provided item in the same manner as [`insert`](IndexSet::insert), returning the
+    /// position of the element and whether the item was newly inserted.
+    pub fn insert_full(&mut self, item: T) -> (usize, bool) {
+        let (index, existing) = self.inner.insert_full(item, ());
+        (index, existing.is_none())
+    }
+
+    /// Returns true if the IndexSet contains `item`.
+    #[inline(always)]
+    pub fn contains<Q>(&self, item: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        <IndexSet<T, B> as SetInterface<T, Q>>::contains(self, item)
+    }
+
+    /// Returns a reference to the contained element equal to the provided `item` or None if there
+    /// isn't one.
+    #[inline(always)]
+    pub fn get<Q>(&self, item: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        <IndexSet<T, B> as SetInterface<T, Q>>::get(self, item)
+    }
+
+    /// Removes `item` from the IndexSet by swapping it with the last element, returning it if it
+    /// exists. This is the same as [`swap_remove`](IndexSet::swap_remove).
+    #[inline(always)]
+    pub fn remove<Q>(&mut self, item: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        <IndexSet<T, B> as SetInterface<T, Q>>::remove(self, item)
+    }
+
+    /// Returns the position of the element equal to `item`, or None if there isn't one.
+    pub fn get_index_of<Q>(&self, item: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.inner.get_index_of(item)
+    }
+
+    /// Returns the element at the provided `index`, or None if `index` is out of bounds.
+    pub fn get_index(&self, index: usize) -> Option<&T> {
+        self.inner.get_index(index).map(|(item, _)| item)
+    }
+
+    /// Removes `item` from the IndexSet by swapping it with the last element, returning it if it
+    /// exists. This doesn't preserve the order of the remaining elements, but takes `O(1)` time.
+    pub fn swap_remove<Q>(&mut self, item: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.inner.swap_remove_entry(item).map(|(item, _)| item)
+    }
+
+    /// Removes the element at the provided `index` by swapping it with the last element, returning
+    /// it if `index` is in bounds.
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<T> {
+        self.inner.swap_remove_index(index).map(|(item, _)| item)
+    }
+
+    /// Removes `item` from the IndexSet by shifting all following elements, returning it if it
+    /// exists. This preserves the order of the remaining elements, but takes `O(n)` time.
+    pub fn shift_remove<Q>(&mut self, item: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.inner.shift_remove_entry(item).map(|(item, _)| item)
+    }
+
+    /// Removes the element at the provided `index` by shifting all following elements, returning it
+    /// if `index` is in bounds.
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<T> {
+        self.inner.shift_remove_index(index).map(|(item, _)| item)
+    }
+
+    /// Moves the element at index `from` to index `to`, shifting all elements in between to fill
+    /// the gap.
+    ///
+    /// # Panics
+    /// Panics if either `from` or `to` is out of bounds.
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        self.inner.move_index(from, to)
+    }
+
+    /// Sorts the elements of the IndexSet in place, using `compare`. The sort is stable.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.inner.sort_by(|a, _, b, _| compare(a, b))
+    }
+
+    /// Sorts the elements of the IndexSet in place. The sort is stable.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.inner.sort_keys()
+    }
+
+    /// Increases the capacity of the IndexSet to ensure that len + `extra` elements will fit.
+    pub fn reserve(&mut self, extra: usize) {
+        self.inner.reserve(extra)
+    }
+
+    /// Returns an iterator over all elements in the IndexSet, as references, in order.
+    #[inline(always)]
+    pub fn iter<'a>(&'a self) -> SetIter<'a, T> {
+        <IndexSet<T, B> as SetIterator<T>>::iter(self)
+    }
+}
+
+impl<T, B, Q> SetInterface<T, Q> for IndexSet<T, B>
+where
+    T: Hash + Eq + Borrow<Q>,
+    B: BuildHasher,
+    Q: Hash + Eq + ?Sized,
+{
+    fn contains(&self, item: &Q) -> bool {
+        self.inner.contains(item)
+    }
+
+    fn get(&self, item: &Q) -> Option<&T> {
+        self.inner.get_entry(item).map(|(k, _)| k)
+    }
+
+    fn remove(&mut self, item: &Q) -> Option<T> {
+        self.swap_remove(item)
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> SetIterator<T> for IndexSet<T, B> {
+    type Iter<'a> = SetIter<'a, T> where Self: 'a;
+
+    fn iter<'a>(&'a self) -> Self::Iter<'a> {
+        self.into_iter()
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher + Default> Default for IndexSet<T, B> {
+    fn default() -> Self {
+        IndexSet::new()
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> Extend<T> for IndexSet<T, B> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+
+    fn extend_one(&mut self, item: T) {
+        self.insert(item);
+    }
+
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher + Default> FromIterator<T> for IndexSet<T, B> {
+    fn from_iter<I: IntoIterator<Item = T>>(value: I) -> Self {
+        let iter = value.into_iter();
+        let mut set = IndexSet::with_cap(iter.size_hint().0);
+
+        for item in iter {
+            set.insert(item);
+        }
+
+        set
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> PartialEq for IndexSet<T, B> {
+    /// Two IndexSets are considered equal if they contain exactly the same elements, regardless of
+    /// their order.
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self.is_subset(other)
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> Eq for IndexSet<T, B> {}
+
+impl<T: Hash + Eq + Debug, B: BuildHasher + Debug> Debug for IndexSet<T, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexSet")
+            .field_with("elements", |f| f.debug_list().entries(self.iter()).finish())
+            .field("len", &self.len())
+            .field("hasher", self.inner.hasher())
+            .finish()
+    }
+}
+
+impl<T: Hash + Eq + Debug, B: BuildHasher> Display for IndexSet<T, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "#")?;
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,297 @@
+use std::hash::{BuildHasher, Hash};
+use std::iter::FusedIterator;
+use std::slice::Iter as ArrIter;
+use std::slice::IterMut as ArrIterMut;
+
+use super::{IndexMap, IndexSet};
+use crate::collections::contiguous::vector::IntoIter as VecIntoIter;
+#[cfg(doc)]
+use crate::collections::traits::set::SetIterator;
+
+impl<K: Hash + Eq, V, B: BuildHasher> IntoIterator for IndexMap<K, V, B> {
+    type Item = (K, V);
+
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.entries.into_iter())
+    }
+}
+
+/// A type for owned iteration over an [`IndexMap`], in order. Produces values of type `(K, V)`.
+///
+/// See [`IndexMap::into_iter`].
+pub struct IntoIter<K, V>(pub(crate) VecIntoIter<(K, V)>);
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IntoIter<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K, V> FusedIterator for IntoIter<K, V> {}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher> IntoIterator for &'a IndexMap<K, V, B> {
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter(self.entries.iter())
+    }
+}
+
+/// A type for borrowed iteration over an [`IndexMap`], in order. Produces values of type
+/// `(&K, &V)`.
+///
+/// See [`IndexMap::iter`].
+pub struct Iter<'a, K, V>(pub(crate) ArrIter<'a, (K, V)>);
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(k, v)| (k, v))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V> FusedIterator for Iter<'a, K, V> {}
+
+/// A type for owned iteration over an [`IndexMap`]'s keys, in order. Produces values of type `K`.
+///
+/// See [`IndexMap::into_keys`].
+pub struct IntoKeys<K, V>(pub(crate) IntoIter<K, V>);
+
+impl<K, V> Iterator for IntoKeys<K, V> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, _)| k)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IntoKeys<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(k, _)| k)
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoKeys<K, V> {}
+
+impl<K, V> FusedIterator for IntoKeys<K, V> {}
+
+/// A type for borrowed iteration over an [`IndexMap`]'s keys, in order. Produces values of type
+/// `&K`.
+///
+/// See [`IndexMap::keys`].
+pub struct Keys<'a, K, V>(pub(crate) Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, _)| k)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Keys<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(k, _)| k)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Keys<'a, K, V> {}
+
+impl<'a, K, V> FusedIterator for Keys<'a, K, V> {}
+
+/// A type for owned iteration over an [`IndexMap`]'s values, in order. Produces values of type
+/// `V`.
+///
+/// See [`IndexMap::into_values`].
+pub struct IntoValues<K, V>(pub(crate) IntoIter<K, V>);
+
+impl<K, V> Iterator for IntoValues<K, V> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IntoValues<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, v)| v)
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoValues<K, V> {}
+
+impl<K, V> FusedIterator for IntoValues<K, V> {}
+
+/// A type for mutable iteration over an [`IndexMap`]'s values, in order. Produces values of type
+/// `&mut V`.
+///
+/// See [`IndexMap::values_mut`].
+pub struct ValuesMut<'a, K, V>(pub(crate) ArrIterMut<'a, (K, V)>);
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, v)| v)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for ValuesMut<'a, K, V> {}
+
+impl<'a, K, V> FusedIterator for ValuesMut<'a, K, V> {}
+
+/// A type for borrowed iteration over an [`IndexMap`]'s values, in order. Produces values of type
+/// `&V`.
+///
+/// See [`IndexMap::values`].
+pub struct Values<'a, K, V>(pub(crate) Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Values<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, v)| v)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Values<'a, K, V> {}
+
+impl<'a, K, V> FusedIterator for Values<'a, K, V> {}
+
+impl<T: Hash + Eq, B: BuildHasher> IntoIterator for IndexSet<T, B> {
+    type Item = T;
+
+    type IntoIter = SetIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SetIntoIter(self.inner.into_keys())
+    }
+}
+
+/// A type for owned iteration over an [`IndexSet`], in order. Produces values of type `T`.
+///
+/// See [`IndexSet::into_iter`].
+pub struct SetIntoIter<T>(pub(crate) IntoKeys<T, ()>);
+
+impl<T> Iterator for SetIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for SetIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for SetIntoIter<T> {}
+
+impl<T> FusedIterator for SetIntoIter<T> {}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> IntoIterator for &'a IndexSet<T, B> {
+    type Item = &'a T;
+
+    type IntoIter = SetIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SetIter(self.inner.keys())
+    }
+}
+
+/// A type for borrowed iteration over an [`IndexSet`], in order. Produces values of type `&T`.
+///
+/// See [`IndexSet::iter`] and [`SetIterator::iter`].
+pub struct SetIter<'a, T>(pub(crate) Keys<'a, T, ()>);
+
+impl<'a, T> Iterator for SetIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for SetIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for SetIter<'a, T> {}
+
+impl<'a, T> FusedIterator for SetIter<'a, T> {}
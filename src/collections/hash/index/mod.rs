@@ -0,0 +1,16 @@
+//! A module containing [`IndexMap`] and [`IndexSet`] and associated types.
+//!
+//! These types preserve the order in which entries are inserted, storing them contiguously
+//! alongside a hash index. The other included types provide owned and borrowed iteration over
+//! entries, keys or values in a map and elements in a set, always in order.
+//!
+//! [`IndexMap`] and [`IndexSet`] are also re-exported under the parent module.
+
+mod index_map;
+mod index_set;
+mod iter;
+mod tests;
+
+pub use index_map::*;
+pub use index_set::*;
+pub use iter::*;
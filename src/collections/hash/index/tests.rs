@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+use std::hash::{BuildHasher, Hash};
+use std::panic::{self, AssertUnwindSafe};
+
+use super::*;
+use crate::collections::contiguous::Vector;
+use crate::util::alloc::CountedDrop;
+use crate::util::hash::{BadHasherBuilder, ManualHash};
+
+fn assert_consistent<K: Hash + Eq, V, B: BuildHasher>(map: &IndexMap<K, V, B>) {
+    for (index, (key, _)) in map.iter().enumerate() {
+        assert_eq!(
+            map.get_index_of(key),
+            Some(index),
+            "Every key's stored position should match its position in the entries."
+        );
+    }
+}
+
+#[test]
+fn test_insertion_order() {
+    let mut map: IndexMap<&str, usize> = IndexMap::new();
+    for (value, key) in ["c", "a", "d", "b"].into_iter().enumerate() {
+        assert_eq!(map.insert_full(key, value), (value, None));
+    }
+
+    assert_eq!(
+        map.insert_full("a", 10),
+        (1, Some(1)),
+        "Replacing a value should keep its position."
+    );
+    assert_eq!(*map.keys().copied().collect::<Vector<_>>(), ["c", "a", "d", "b"]);
+    assert_eq!(*map.values().copied().collect::<Vector<_>>(), [0, 10, 2, 3]);
+    assert_eq!(map.get_index(2), Some((&"d", &2)));
+    assert_eq!(map.get_index(4), None);
+    assert_eq!(map["b"], 3);
+    assert_consistent(&map);
+}
+
+#[test]
+fn test_removal() {
+    let mut map: IndexMap<usize, usize> = (0..10).map(|i| (i, i * 2)).collect();
+
+    assert_eq!(map.swap_remove(&2), Some(4));
+    assert_eq!(*map.keys().copied().collect::<Vector<_>>(), [0, 1, 9, 3, 4, 5, 6, 7, 8]);
+    assert_consistent(&map);
+
+    assert_eq!(map.shift_remove(&1), Some(2));
+    assert_eq!(*map.keys().copied().collect::<Vector<_>>(), [0, 9, 3, 4, 5, 6, 7, 8]);
+    assert_consistent(&map);
+
+    assert_eq!(map.swap_remove_index(7), Some((8, 16)), "Removing the last entry needs no swap.");
+    assert_eq!(map.shift_remove_index(0), Some((0, 0)));
+    assert_eq!(map.shift_remove_index(10), None);
+    assert_eq!(map.swap_remove(&0), None);
+    assert_eq!(*map.keys().copied().collect::<Vector<_>>(), [9, 3, 4, 5, 6, 7]);
+    assert_consistent(&map);
+
+    while let Some((key, _)) = map.get_index(0).map(|(k, v)| (*k, *v)) {
+        assert_eq!(map.shift_remove_entry(&key), Some((key, key * 2)));
+        assert_consistent(&map);
+    }
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_move_index() {
+    let mut map: IndexMap<usize, ()> = (0..6).map(|i| (i, ())).collect();
+
+    map.move_index(1, 4);
+    assert_eq!(*map.keys().copied().collect::<Vector<_>>(), [0, 2, 3, 4, 1, 5]);
+    assert_consistent(&map);
+
+    map.move_index(5, 0);
+    assert_eq!(*map.keys().copied().collect::<Vector<_>>(), [5, 0, 2, 3, 4, 1]);
+    assert_consistent(&map);
+
+    map.move_index(3, 3);
+    assert_eq!(*map.keys().copied().collect::<Vector<_>>(), [5, 0, 2, 3, 4, 1]);
+}
+
+#[test]
+#[should_panic]
+fn test_move_index_out_of_bounds() {
+    let mut map: IndexMap<usize, ()> = (0..6).map(|i| (i, ())).collect();
+    map.move_index(0, 6);
+}
+
+#[test]
+fn test_sort() {
+    let mut map: IndexMap<usize, usize> = IndexMap::new();
+    for key in [7, 3, 9, 1, 5, 8, 2] {
+        map.insert(key, key % 3);
+    }
+
+    map.sort_by(|_, a, _, b| a.cmp(b));
+    assert_eq!(
+        *map.keys().copied().collect::<Vector<_>>(),
+        [3, 9, 7, 1, 5, 8, 2],
+        "sort_by should be stable."
+    );
+    assert_consistent(&map);
+
+    map.sort_keys();
+    assert_eq!(*map.keys().copied().collect::<Vector<_>>(), [1, 2, 3, 5, 7, 8, 9]);
+    assert_consistent(&map);
+}
+
+#[test]
+fn test_sort_panic_safety() {
+    for panic_after in [0, 10, 100] {
+        let mut map: IndexMap<usize, usize> = (0..50).map(|key| (key * 37 % 50, key)).collect();
+
+        let mut comparisons = 0;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            map.sort_by(|k1, _, k2, _| {
+                comparisons += 1;
+                if comparisons > panic_after {
+                    panic!("Comparison limit reached");
+                }
+                k1.cmp(k2)
+            });
+        }));
+        assert!(result.is_err(), "sort_by should have made more than {panic_after} comparisons.");
+        assert_consistent(&map);
+
+        assert!((0..50).all(|key| map.contains(&key)), "No keys should be lost by panicking.");
+        map.insert(0, 100);
+        assert_eq!(map.len(), 50, "Inserting an existing key shouldn't add an entry.");
+    }
+}
+
+#[test]
+fn test_collisions() {
+    let mut map = IndexMap::with_hasher(BadHasherBuilder);
+    for value in 0..50 {
+        map.insert(ManualHash::new(0, value), value);
+    }
+
+    map.shift_remove(&ManualHash::new(0, 10));
+    map.swap_remove(&ManualHash::new(0, 20));
+    map.move_index(0, 40);
+    map.sort_by(|_, a, _, b| b.cmp(a));
+
+    assert_eq!(map.len(), 48);
+    assert_consistent(&map);
+    assert_eq!(map.get_index(0), Some((&ManualHash::new(0, 49), &49)));
+    assert!(
+        (0..50).all(|value| {
+            map.contains(&ManualHash::new(0, value)) == (value != 10 && value != 20)
+        }),
+        "Colliding keys should remain reachable after positions change."
+    );
+}
+
+#[test]
+fn test_drops() {
+    let counter = CountedDrop::new(0);
+    let mut map: IndexMap<usize, CountedDrop> = IndexMap::new();
+    for key in 0..20 {
+        map.insert(key, counter.clone());
+    }
+
+    map.insert(0, counter.clone());
+    map.swap_remove(&1);
+    map.shift_remove(&2);
+    assert_eq!(*counter.borrow(), 3);
+
+    drop(map);
+    assert_eq!(*counter.borrow(), 21, "Every value should be dropped exactly once.");
+}
+
+#[test]
+fn test_set() {
+    let mut set: IndexSet<usize> = IndexSet::new();
+    for item in [4, 1, 3, 1, 2] {
+        set.insert(item);
+    }
+    assert_eq!(*set.iter().copied().collect::<Vector<_>>(), [4, 1, 3, 2]);
+    assert_eq!(set.insert_full(3), (2, false));
+    assert_eq!(set.get_index(0), Some(&4));
+
+    let other: IndexSet<usize> = [2, 3, 5].into_iter().collect();
+    assert_eq!(*set.intersection(&other).copied().collect::<Vector<_>>(), [3, 2]);
+    assert_eq!(*set.difference(&other).copied().collect::<Vector<_>>(), [4, 1]);
+    assert_eq!(set.union(&other).count(), 5);
+    assert!(!set.is_subset(&other));
+
+    assert_eq!(set.remove(&4), Some(4), "remove should behave like swap_remove.");
+    assert_eq!(*set.iter().copied().collect::<Vector<_>>(), [2, 1, 3]);
+
+    set.sort();
+    assert_eq!(*set.iter().copied().collect::<Vector<_>>(), [1, 2, 3]);
+    assert_eq!(set, [3, 2, 1].into_iter().collect(), "Equality should ignore order.");
+    assert_eq!(set.into_iter().rev().collect::<Vector<_>>(), [3, 2, 1].into_iter().collect());
+}
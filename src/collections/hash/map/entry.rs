@@ -0,0 +1,249 @@
+use std::alloc::{Allocator, Global};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+
+use super::{HashMap, HashedPair};
+
+/// A view into a single bucket of a [`HashMap`], which is either occupied or vacant. Because the
+/// bucket is located when the Entry is created, the key is only hashed once, regardless of how many
+/// operations are then performed on it.
+///
+/// See [`HashMap::entry`].
+pub enum Entry<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    /// An Entry for a key which is already associated with a value in the HashMap.
+    Occupied(OccupiedEntry<'a, K, V, B, A>),
+    /// An Entry for a key which isn't associated with any value in the HashMap.
+    Vacant(VacantEntry<'a, K, V, B, A>),
+}
+
+use Entry::*;
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> Entry<'a, K, V, B, A> {
+    /// Returns a reference to the key of this Entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Occupied(entry) => entry.key(),
+            Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Ensures that the Entry has a value by inserting `default` if it is vacant. Returns a mutable
+    /// reference to the value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures that the Entry has a value by inserting the result of `default` if it is vacant.
+    /// Returns a mutable reference to the value.
+    ///
+    /// Unlike [`or_insert`](Entry::or_insert), `default` is only invoked if a value is required.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Ensures that the Entry has a value by inserting the result of `default` if it is vacant.
+    /// `default` is passed a reference to the key, so that the value can be derived from it.
+    /// Returns a mutable reference to the value.
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            },
+        }
+    }
+
+    /// Invokes `f` with a mutable reference to the value if the Entry is occupied. Vacant entries
+    /// are left unchanged. Returns self, so that the Entry can be used again.
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Entry<'a, K, V, B, A> {
+        match self {
+            Occupied(mut entry) => {
+                f(entry.get_mut());
+                Occupied(entry)
+            },
+            Vacant(entry) => Vacant(entry),
+        }
+    }
+
+    /// Sets the value of the Entry, replacing any existing value, and returns it as an
+    /// [`OccupiedEntry`].
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, B, A> {
+        match self {
+            Occupied(mut entry) => {
+                entry.insert(value);
+                entry
+            },
+            Vacant(entry) => entry.insert_entry(value),
+        }
+    }
+}
+
+impl<'a, K: Hash + Eq, V: Default, B: BuildHasher, A: Allocator + Clone> Entry<'a, K, V, B, A> {
+    /// Ensures that the Entry has a value by inserting the default value of `V` if it is vacant.
+    /// Returns a mutable reference to the value.
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, K, V, B, A> Debug for Entry<'a, K, V, B, A>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Occupied(entry) => f.debug_tuple("Entry").field(entry).finish(),
+            Vacant(entry) => f.debug_tuple("Entry").field(entry).finish(),
+        }
+    }
+}
+
+/// A view into an occupied bucket of a [`HashMap`]. Part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a mut HashMap<K, V, B, A>,
+    pub(crate) index: usize,
+}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> OccupiedEntry<'a, K, V, B, A> {
+    /// Returns a reference to the bucket's contents.
+    fn pair(&self) -> &HashedPair<K, V> {
+        match &self.map.arr[self.index] {
+            Some(pair) => pair,
+            // An OccupiedEntry is only ever constructed for an occupied bucket.
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns a mutable reference to the bucket's contents.
+    fn pair_mut(&mut self) -> &mut HashedPair<K, V> {
+        match &mut self.map.arr[self.index] {
+            Some(pair) => pair,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns a reference to the key of this Entry.
+    pub fn key(&self) -> &K {
+        &self.pair().key
+    }
+
+    /// Returns a reference to the value of this Entry.
+    pub fn get(&self) -> &V {
+        &self.pair().value
+    }
+
+    /// Returns a mutable reference to the value of this Entry. The reference is only valid for the
+    /// lifetime of the Entry, see [`OccupiedEntry::into_mut`] for a longer lived reference.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.pair_mut().value
+    }
+
+    /// Converts the Entry into a mutable reference to its value, with the same lifetime as the
+    /// borrow of the HashMap.
+    pub fn into_mut(self) -> &'a mut V {
+        match &mut self.map.arr[self.index] {
+            Some(pair) => &mut pair.value,
+            None => unreachable!(),
+        }
+    }
+
+    /// Replaces the value of this Entry with `value`, returning the previous value.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes this Entry from the HashMap, returning the contained key-value pair.
+    pub fn remove_entry(self) -> (K, V) {
+        let removed = self.map.remove_at(self.index);
+        self.map.shrink_if_sparse();
+        removed
+    }
+
+    /// Removes this Entry from the HashMap, returning the contained value.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
+
+impl<'a, K, V, B, A> Debug for OccupiedEntry<'a, K, V, B, A>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+/// A view into a vacant bucket of a [`HashMap`]. Part of the [`Entry`] enum.
+pub struct VacantEntry<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a mut HashMap<K, V, B, A>,
+    pub(crate) index: usize,
+    pub(crate) hash: u64,
+    pub(crate) key: K,
+}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> VacantEntry<'a, K, V, B, A> {
+    /// Returns a reference to the key that would be used when inserting a value through this Entry.
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Takes ownership of the key, leaving the HashMap unchanged.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Inserts `value` into the HashMap with this Entry's key, returning a mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.insert_entry(value).into_mut()
+    }
+
+    /// Inserts `value` into the HashMap with this Entry's key, returning an [`OccupiedEntry`].
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, B, A> {
+        let VacantEntry { map, index, hash, key } = self;
+
+        // The index was returned by probing when this Entry was created, and the HashMap has been
+        // mutably borrowed since then, so the new pair will be placed at index.
+        map.insert_at(index, HashedPair {
+            hash,
+            key,
+            value,
+        });
+        map.len += 1;
+
+        OccupiedEntry {
+            map,
+            index,
+        }
+    }
+}
+
+impl<'a, K, V, B, A> Debug for VacantEntry<'a, K, V, B, A>
+where
+    K: Hash + Eq + Debug,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VacantEntry")
+            .field(self.key())
+            .finish()
+    }
+}
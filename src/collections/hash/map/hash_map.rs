@@ -1,3 +1,4 @@
+use std::alloc::{Allocator, Global};
 use std::borrow::Borrow;
 use std::cmp;
 use std::fmt;
@@ -7,22 +8,24 @@ use std::iter::TrustedLen;
 use std::mem;
 use std::ops::Index;
 
-use super::{IndexNoCap, IntoKeys, IntoValues, Iter, Keys, Values, ValuesMut};
-use crate::collections::contiguous::{Array, Vector};
+use super::{
+    Drain, Entry, ExtractIf, ExtractState, HashPolicy, IndexNoCap, IntoKeys, IntoValues, Iter, Keys,
+    OccupiedEntry, RawEntryBuilder, RawEntryBuilderMut, VacantEntry, Values, ValuesMut,
+};
+use crate::collections::contiguous::Array;
 use crate::util::error::NoValueForKey;
 use crate::util::fmt::DebugRaw;
 use crate::util::result::ResultExtension;
 
-const MIN_ALLOCATED_CAP: usize = 2;
-
-const GROWTH_FACTOR: usize = 2;
-
-const LOAD_FACTOR_NUMERATOR: usize = 4;
-const LOAD_FACTOR_DENOMINATOR: usize = 5;
-
 /// A map of keys to values which relies on the keys implementing [`Hash`].
 ///
-/// A custom load factor is not supported at this point, with the default being 4/5.
+/// The load factor, growth factor and minimum capacity can be configured with a [`HashPolicy`],
+/// with the default load factor being 4/5.
+///
+/// Collisions are resolved with open addressing, using Robin Hood hashing: when inserting, an entry
+/// will take the place of any entry that is closer to its ideal bucket, which keeps the variance of
+/// probe lengths low. Removal uses backward-shift deletion rather than tombstones, so lookups don't
+/// degrade as the map churns.
 ///
 /// It is a logic error for keys in a HashMap to be manipulated in a way that changes their hash.
 /// Because of this, HashMap's API prevents mutable access to its keys.
@@ -36,69 +39,153 @@ const LOAD_FACTOR_DENOMINATOR: usize = 5;
 /// | `len` | `O(1)` |
 /// | `insert` | `O(1)`**, `O(n)` |
 /// | `insert_unchecked` | `O(1)`* |
+/// | `entry` | `O(1)`**, `O(n)` |
+/// | `insert_with_hash` | `O(1)`**, `O(n)` |
+/// | `raw_entry` | `O(1)`* |
+/// | `raw_entry_mut` | `O(1)`**, `O(n)` |
 /// | `get` | `O(1)`* |
 /// | `remove` | `O(1)`* |
 /// | `contains` | `O(1)`* |
 /// | `reserve` | `O(n)`***, `O(1)` |
+/// | `shrink_to_fit` | `O(n)` |
+/// | `shrink_to` | `O(n)` |
+/// | `retain` | `O(n)` |
+/// | `drain` | `O(n)`**** |
+/// | `extract_if` | `O(n)`**** |
 ///
 /// \* In the event of a has collision, these functions will take additional time, while a valid
-/// / correct location is found. This additional time is kept at a minimum and hash collisions are
-/// unlikely especially with a large capacity.
+/// / correct location is found. This additional time is kept at a minimum by Robin Hood hashing
+/// and hash collisions are unlikely especially with a large capacity.
 ///
-/// \** If the HashMap doesn't have enough capacity for the new element, `insert` will take `O(n)`.
+/// \** If the HashMap doesn't have enough capacity for the new element, `insert` and `entry` will
+/// take `O(n)`.
 /// \* applies as well.
 ///
 /// \*** If the HashMap has enough capacity for the additional items already, `reserve` is `O(1)`.
-pub struct HashMap<K: Hash + Eq, V, B: BuildHasher = RandomState> {
-    pub(crate) arr: Array<Bucket<K, V>>,
+///
+/// \**** When exhausted.
+///
+/// If automatic shrinking is enabled through the HashMap's [`HashPolicy`], `remove` will take
+/// `O(n)` when it causes the HashMap to shrink.
+pub struct HashMap<K: Hash + Eq, V, B: BuildHasher = RandomState, A: Allocator + Clone = Global> {
+    pub(crate) arr: Array<Bucket<K, V>, A>,
     pub(crate) len: usize,
     pub(crate) hasher: B,
+    pub(crate) policy: HashPolicy,
 }
 
-pub(crate) type Bucket<K, V> = Option<(K, V)>;
+pub(crate) type Bucket<K, V> = Option<HashedPair<K, V>>;
+
+/// A key-value pair stored alongside the hash of its key, so that the probe distance of an occupied
+/// bucket can be calculated without hashing the key again.
+pub(crate) struct HashedPair<K, V> {
+    pub(crate) hash: u64,
+    pub(crate) key: K,
+    pub(crate) value: V,
+}
+
+/// Calculates the distance between `index` and the ideal index for `hash`, in a HashMap with the
+/// provided `cap`acity, wrapping at the end of the buckets.
+pub(crate) const fn probe_distance(hash: u64, index: usize, cap: usize) -> usize {
+    let ideal = (hash % cap as u64) as usize;
+    // index and ideal are both < cap <= isize::MAX, so this can't overflow.
+    (index + cap - ideal) % cap
+}
 
 impl<K: Hash + Eq, V, B: BuildHasher + Default> HashMap<K, V, B> {
     /// Creates a new HashMap with capacity 0 and the default value for `B`. Memory will be
     /// allocated when the capacity changes.
     pub fn new() -> HashMap<K, V, B> {
-        HashMap {
-            arr: Array::new(),
-            len: 0,
-            hasher: B::default(),
-        }
+        HashMap::new_in(Global)
     }
 
     /// Creates a new HashMap with the provided `cap`acity, allowing insertions without
     /// reallocation. The default hasher will be used.
     pub fn with_cap(cap: usize) -> HashMap<K, V, B> {
+        HashMap::with_cap_in(cap, Global)
+    }
+
+    /// Creates a new HashMap with capacity 0, the provided `policy` and the default value for `B`.
+    /// Memory will be allocated according to `policy` when the capacity changes.
+    pub fn with_policy(policy: HashPolicy) -> HashMap<K, V, B> {
+        HashMap::with_cap_hasher_and_policy_in(0, B::default(), policy, Global)
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default, A: Allocator + Clone> HashMap<K, V, B, A> {
+    /// Creates a new HashMap with capacity 0 and the default value for `B`, which will use the
+    /// provided allocator. Memory will be allocated when the capacity changes.
+    ///
+    /// # Examples
+    /// ```
+    /// #![feature(allocator_api)]
+    /// # use standard_lib::collections::hash::HashMap;
+    /// # use std::alloc::Global;
+    /// # use std::hash::RandomState;
+    /// let mut map: HashMap<&str, u8, RandomState, _> = HashMap::new_in(Global);
+    /// map.insert("a", 1);
+    /// assert_eq!(map.get("a"), Some(&1));
+    /// ```
+    pub fn new_in(alloc: A) -> HashMap<K, V, B, A> {
+        HashMap::with_hasher_in(B::default(), alloc)
+    }
+
+    /// Creates a new HashMap with the provided `cap`acity, allowing insertions without
+    /// reallocation. The default hasher and the provided allocator will be used.
+    pub fn with_cap_in(cap: usize, alloc: A) -> HashMap<K, V, B, A> {
         // TODO: Adjust this to prevent reallocation during `cap` insertions.
-        HashMap {
-            arr: Array::repeat_default(cap),
-            len: 0,
-            hasher: B::default(),
-        }
+        HashMap::with_cap_hasher_and_policy_in(cap, B::default(), HashPolicy::DEFAULT, alloc)
     }
 }
 
 impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
     /// Creates a new HashMap with capacity 0 and the provided `hasher`.
     pub fn with_hasher(hasher: B) -> HashMap<K, V, B> {
-        HashMap {
-            arr: Array::new(),
-            len: 0,
-            hasher,
-        }
+        HashMap::with_hasher_in(hasher, Global)
     }
 
     /// Creates a new HashMap with the provided `cap`acity and `hasher`.
     pub fn with_cap_and_hasher(cap: usize, hasher: B) -> HashMap<K, V, B> {
+        HashMap::with_cap_hasher_and_policy_in(cap, hasher, HashPolicy::DEFAULT, Global)
+    }
+
+    /// Creates a new HashMap with the provided `cap`acity, `hasher` and `policy`.
+    pub fn with_cap_hasher_and_policy(
+        cap: usize,
+        hasher: B,
+        policy: HashPolicy,
+    ) -> HashMap<K, V, B> {
+        HashMap::with_cap_hasher_and_policy_in(cap, hasher, policy, Global)
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> HashMap<K, V, B, A> {
+    /// Creates a new HashMap with capacity 0, the provided `hasher` and the provided allocator.
+    pub fn with_hasher_in(hasher: B, alloc: A) -> HashMap<K, V, B, A> {
+        HashMap::with_cap_hasher_and_policy_in(0, hasher, HashPolicy::DEFAULT, alloc)
+    }
+
+    /// Creates a new HashMap with the provided `cap`acity, `hasher`, `policy` and allocator. All
+    /// allocations made by the HashMap, including those made while growing, will use `alloc`.
+    pub fn with_cap_hasher_and_policy_in(
+        cap: usize,
+        hasher: B,
+        policy: HashPolicy,
+        alloc: A,
+    ) -> HashMap<K, V, B, A> {
         HashMap {
-            arr: Array::repeat_default(cap),
+            arr: Array::repeat_default_in(cap, alloc),
             len: 0,
             hasher,
+            policy,
         }
     }
 
+    /// Returns a reference to the allocator used by the HashMap.
+    pub const fn allocator(&self) -> &A {
+        self.arr.allocator()
+    }
+
     /// Returns the length of the HashMap (the number of entries it contains).
     pub const fn len(&self) -> usize {
         self.len
@@ -114,6 +201,17 @@ impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
         self.arr.size
     }
 
+    /// Returns the [`HashPolicy`] used by the HashMap to determine when and how to allocate.
+    pub const fn policy(&self) -> HashPolicy {
+        self.policy
+    }
+
+    /// Returns a reference to the HashMap's hasher, which can be used to precompute hashes for
+    /// [`insert_with_hash`](HashMap::insert_with_hash) and [`raw_entry`](HashMap::raw_entry).
+    pub const fn hasher(&self) -> &B {
+        &self.hasher
+    }
+
     /// Inserts the provided `key`-`value` pair into the HashMap, increasing its capacity if
     /// required. If the key was already associated with a value, the previous value is returned.
     ///
@@ -124,24 +222,7 @@ impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
         }
 
         // SAFETY: We've just grown if necessary.
-        let index = unsafe { self.find_index_for_key(&key).unwrap_unchecked() };
-
-        // The bucket at index is either empty or contains an equal key.
-        match &mut self.arr[index] {
-            Some(existing) => {
-                // Replace the value with the provided one.
-                Some(mem::replace(
-                    &mut existing.1,
-                    value
-                ))
-            },
-            None => {
-                // Create a new bucket with the provided values.
-                self.arr[index] = Some((key, value));
-                self.len += 1;
-                None
-            },
-        }
+        unsafe { self.insert_unchecked(key, value) }
     }
 
     /// Inserts the provided `key`-`value` pair without checking if the HashMap has enough capacity.
@@ -158,26 +239,151 @@ impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
     /// Panics if the HashMap has a capacity of 0, as it isn't possible to find a bucket associated
     /// with the key.
     pub unsafe fn insert_unchecked(&mut self, key: K, value: V) -> Option<V> {
-        let index = self.find_index_for_key(&key).ok_or(IndexNoCap).throw();
+        let hash = self.hasher.hash_one(&key);
+        self.insert_hashed(hash, key, value)
+    }
 
-        // The bucket at index is either empty or contains an equal key.
-        match &mut self.arr[index] {
-            Some(existing) => {
+    /// Inserts the provided `key`-`value` pair using a precomputed `hash`, rather than hashing the
+    /// key with the HashMap's hasher. The HashMap's capacity is increased if required. If the key
+    /// was already associated with a value, the previous value is returned.
+    ///
+    /// Because the hash is stored alongside each entry, the HashMap can still be resized without
+    /// rehashing. However, it is a logic error for `hash` to differ from the hash that the
+    /// HashMap's [`hasher`](HashMap::hasher) produces for `key`, unless the key is only ever
+    /// accessed through methods that take a precomputed hash, such as
+    /// [`raw_entry`](HashMap::raw_entry).
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::HashMap;
+    /// # use std::hash::BuildHasher;
+    /// let mut map: HashMap<&str, usize> = HashMap::new();
+    /// let hash = map.hasher().hash_one("key");
+    ///
+    /// map.insert_with_hash(hash, "key", 1);
+    /// assert_eq!(map.get("key"), Some(&1));
+    /// ```
+    pub fn insert_with_hash(&mut self, hash: u64, key: K, value: V) -> Option<V> {
+        if self.should_grow() {
+            self.grow()
+        }
+
+        self.insert_hashed(hash, key, value)
+    }
+
+    /// Inserts the provided `key`-`value` pair using a precomputed `hash`, without checking if the
+    /// HashMap has enough capacity.
+    ///
+    /// # Panics
+    /// Panics if the HashMap has a capacity of 0.
+    pub(crate) fn insert_hashed(&mut self, hash: u64, key: K, value: V) -> Option<V> {
+        match self.probe(hash, |existing| *existing == key).ok_or(IndexNoCap).throw() {
+            Ok(index) => {
                 // Replace the value with the provided one.
-                Some(mem::replace(
-                    &mut existing.1,
-                    value
-                ))
+                self.arr[index].as_mut().map(|existing| mem::replace(&mut existing.value, value))
             },
-            None => {
-                // Create a new bucket with the provided values.
-                self.arr[index] = Some((key, value));
+            Err(index) => {
+                // Create a new bucket with the provided values, displacing others if required.
+                self.insert_at(index, HashedPair {
+                    hash,
+                    key,
+                    value,
+                });
                 self.len += 1;
                 None
             },
         }
     }
 
+    /// Returns the [`Entry`] associated with the provided `key`, which can be used to inspect or
+    /// manipulate the bucket in place. The key is only hashed once, regardless of how the Entry is
+    /// used.
+    ///
+    /// As with [`insert`](HashMap::insert), the HashMap's capacity is increased if required, so
+    /// that a vacant Entry can always be filled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::HashMap;
+    /// let mut counts: HashMap<char, usize> = HashMap::new();
+    /// for c in "hello".chars() {
+    ///     *counts.entry(c).or_default() += 1;
+    /// }
+    /// assert_eq!(counts.get(&'l'), Some(&2));
+    /// assert_eq!(counts.get(&'o'), Some(&1));
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, B, A> {
+        if self.should_grow() {
+            self.grow()
+        }
+
+        let hash = self.hasher.hash_one(&key);
+
+        // SAFETY: We've just grown if necessary, so the capacity isn't 0.
+        match unsafe { self.probe(hash, |existing| *existing == key).unwrap_unchecked() } {
+            Ok(index) => Entry::Occupied(OccupiedEntry {
+                map: self,
+                index,
+            }),
+            Err(index) => Entry::Vacant(VacantEntry {
+                map: self,
+                index,
+                hash,
+                key,
+            }),
+        }
+    }
+
+    /// Returns a [`RawEntryBuilder`], for looking up an entry with a precomputed hash and a custom
+    /// equality closure. This avoids hashing the key again and doesn't require the lookup key to
+    /// implement [`Borrow`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::HashMap;
+    /// # use std::hash::BuildHasher;
+    /// let mut map: HashMap<String, usize> = HashMap::new();
+    /// map.insert(String::from("key"), 1);
+    ///
+    /// let hash = map.hasher().hash_one("key");
+    /// assert_eq!(
+    ///     map.raw_entry().from_hash(hash, |k| k == "key"),
+    ///     Some((&String::from("key"), &1)),
+    /// );
+    /// ```
+    pub const fn raw_entry(&self) -> RawEntryBuilder<'_, K, V, B, A> {
+        RawEntryBuilder {
+            map: self,
+        }
+    }
+
+    /// Returns a [`RawEntryBuilderMut`], for locating a bucket with a precomputed hash and a custom
+    /// equality closure, which can then be inspected or manipulated in place.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::HashMap;
+    /// # use standard_lib::collections::hash::map::RawEntryMut;
+    /// # use std::hash::BuildHasher;
+    /// let mut map: HashMap<&str, usize> = HashMap::new();
+    /// let hash = map.hasher().hash_one("key");
+    ///
+    /// for _ in 0..3 {
+    ///     match map.raw_entry_mut().from_hash(hash, |k| *k == "key") {
+    ///         RawEntryMut::Occupied(mut entry) => *entry.get_mut() += 1,
+    ///         RawEntryMut::Vacant(entry) => {
+    ///             entry.insert("key", 1);
+    ///         },
+    ///     }
+    /// }
+    /// assert_eq!(map.get("key"), Some(&3));
+    /// ```
+    pub const fn raw_entry_mut(&mut self) -> RawEntryBuilderMut<'_, K, V, B, A> {
+        RawEntryBuilderMut {
+            map: self,
+        }
+    }
+
     /// Returns the entry for the provided `key` as a key-value pair or None if there is no entry.
     pub fn get_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
     where
@@ -188,11 +394,7 @@ impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
     {
         let index = self.find_index_for_key(key)?;
 
-        // If the bucket at index is empty, the map doesn't contain the key.
-        match &self.arr[index] {
-            Some(existing) => Some((&existing.0, &existing.1)),
-            None => None,
-        }
+        self.arr[index].as_ref().map(|existing| (&existing.key, &existing.value))
     }
 
     /// Returns a reference to the value associated with the provided `key` or None if the map
@@ -204,11 +406,7 @@ impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
     {
         let index = self.find_index_for_key(key)?;
 
-        // If the bucket at index is empty, the map doesn't contain the key.
-        match &self.arr[index] {
-            Some(existing) => Some(&existing.1),
-            None => None,
-        }
+        self.arr[index].as_ref().map(|existing| &existing.value)
     }
 
     /// Returns a mutable reference to the value associated with the provided `key` or None if the
@@ -220,11 +418,7 @@ impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
     {
         let index = self.find_index_for_key(key)?;
 
-        // If the bucket at index is empty, the map doesn't contain the key.
-        match &mut self.arr[index] {
-            Some(existing) => Some(&mut existing.1),
-            None => None,
-        }
+        self.arr[index].as_mut().map(|existing| &mut existing.value)
     }
 
     /// Removes the entry associated with `key`, returning it if it exists.
@@ -233,49 +427,10 @@ impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
         K: Borrow<Q>,
         Q: Hash + Eq + ?Sized,
     {
-        let start = self.find_index_for_key(key)?;
-
-        // If the bucket at index is empty, the map doesn't contain the key.
-        let removed = match mem::take(&mut self.arr[start]) {
-            Some(entry) => {
-                self.len -= 1;
-                entry
-            },
-            None => None?,
-        };
-
-        let mut potential_collisions = Vector::new();
-
-        // UNCHECKED: find_index_for_key returned some, so the cap is not 0.
-        let mut index = (start + 1) % self.cap();
-
-        while self.arr[index].is_some() {
-            // SAFETY: We know that the value at index is some given the loop condition.
-            let entry = unsafe { mem::take(&mut self.arr[index]).unwrap_unchecked() };
-            potential_collisions.push((
-                // SAFETY: We've already propagated a None from find_index_for_key, so
-                // index_from_key will return Some.
-                unsafe { self.index_from_key(&entry.0).unwrap_unchecked() },
-                entry
-            ));
-            index = (index + 1) % self.cap();
-        }
-
-        // Sort by distance to the right from the starting index.
-        potential_collisions.sort_by_key(|(ideal, _)| (*ideal as isize - start as isize).rem_euclid(self.cap() as isize));
-
-        for (ideal, entry) in potential_collisions {
-            let mut index = ideal;
-
-            // Find the closest index on the right of the ideal one, remaining within the original
-            // block naturally.
-            while self.arr[index].is_some() {
-                index = (index + 1) % self.cap();
-            }
-
-            self.arr[index] = Some(entry);
-        }
+        let index = self.find_index_for_key(key)?;
 
+        let removed = self.remove_at(index);
+        self.shrink_if_sparse();
         Some(removed)
     }
 
@@ -294,30 +449,103 @@ impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
         K: Borrow<Q>,
         Q: Hash + Eq + ?Sized,
     {
-        let index = self.find_index_for_key(key);
-
-        match index {
-            Some(i) => self.arr[i].is_some(),
-            None => false,
-        }
+        self.find_index_for_key(key).is_some()
     }
 
     /// Increases the capacity of the HashMap to ensure that len + `extra` entries will fit without
     /// exceeding the load factor.
     pub fn reserve(&mut self, extra: usize) {
-        let new_cap = self.len.strict_add(extra) * LOAD_FACTOR_DENOMINATOR / LOAD_FACTOR_NUMERATOR;
+        let new_cap = self.policy.min_cap_for_len(self.len.strict_add(extra));
         if new_cap <= self.cap() { return; }
 
         self.realloc_with_cap(new_cap);
     }
 
+    /// Shrinks the HashMap to the smallest capacity that can hold its current entries without
+    /// exceeding the load factor. An empty HashMap is deallocated entirely.
+    pub fn shrink_to_fit(&mut self) {
+        self.realloc_with_cap(self.policy.min_cap_for_len(self.len));
+    }
+
+    /// Shrinks the HashMap so that its capacity is no less than `min_cap` and no less than the
+    /// smallest capacity that can hold its current entries without exceeding the load factor. If
+    /// the current capacity is already below this, no change is made.
+    pub fn shrink_to(&mut self, min_cap: usize) {
+        let new_cap = cmp::max(min_cap, self.policy.min_cap_for_len(self.len));
+        if new_cap >= self.cap() { return; }
+
+        self.realloc_with_cap(new_cap);
+    }
+
+    /// Retains only the entries for which `f` returns true, removing all others in place. `f` is
+    /// invoked exactly once for each entry, in an unspecified order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::HashMap;
+    /// let mut map: HashMap<usize, usize> = (0..10).map(|i| (i, i * 10)).collect();
+    /// map.retain(|k, v| {
+    ///     *v += 1;
+    ///     k % 2 == 0
+    /// });
+    /// assert_eq!(map.len(), 5);
+    /// assert_eq!(map.get(&4), Some(&41));
+    /// assert_eq!(map.get(&5), None);
+    /// ```
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.extract_if(|k, v| !f(k, v)).for_each(drop);
+    }
+
+    /// Removes all entries from the HashMap, returning them as an iterator. The capacity of the
+    /// HashMap is retained, unless automatic shrinking is enabled by its [`HashPolicy`].
+    ///
+    /// If the returned iterator is dropped before being exhausted, all remaining entries are
+    /// dropped as well.
+    pub fn drain(&mut self) -> Drain<'_, K, V, B, A> {
+        let len = mem::replace(&mut self.len, 0);
+        let empty = Array::new_in(self.allocator().clone());
+
+        Drain {
+            arr: mem::replace(&mut self.arr, empty),
+            map: self,
+            index: 0,
+            len,
+        }
+    }
+
+    /// Returns an iterator which removes and produces every entry for which `pred` returns true,
+    /// in an unspecified order. `pred` is invoked at most once for each entry.
+    ///
+    /// If the returned iterator is dropped before being exhausted, the remaining entries are
+    /// retained, leaving the HashMap valid.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::HashMap;
+    /// let mut map: HashMap<usize, usize> = (0..10).map(|i| (i, i)).collect();
+    /// let mut odd: Vec<_> = map.extract_if(|k, _| k % 2 == 1).map(|(k, _)| k).collect();
+    /// odd.sort();
+    /// assert_eq!(odd, [1, 3, 5, 7, 9]);
+    /// assert_eq!(map.len(), 5);
+    /// ```
+    pub fn extract_if<F: FnMut(&K, &mut V) -> bool>(
+        &mut self,
+        pred: F,
+    ) -> ExtractIf<'_, K, V, F, B, A> {
+        ExtractIf {
+            state: ExtractState::new(self),
+            map: self,
+            pred,
+        }
+    }
+
     /// Returns an iterator over all key-value pairs in the HashMap, as references.
     pub fn iter(&self) -> Iter<'_, K, V> {
         self.into_iter()
     }
 
     /// Consumes self and returns an iterator over all contained keys.
-    pub fn into_keys(self) -> IntoKeys<K, V> {
+    pub fn into_keys(self) -> IntoKeys<K, V, A> {
         IntoKeys(self.into_iter())
     }
 
@@ -327,7 +555,7 @@ impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
     }
 
     /// Consumes self and returns an iterator over all contained values.
-    pub fn into_values(self) -> IntoValues<K, V> {
+    pub fn into_values(self) -> IntoValues<K, V, A> {
         IntoValues(self.into_iter())
     }
 
@@ -345,51 +573,61 @@ impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
     }
 }
 
-impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
+impl<K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> HashMap<K, V, B, A> {
     /// Determines whether the HashMap's length exceeds the load capacity, suggesting that it should
     /// grow before inserting new entries.
     pub(crate) const fn should_grow(&self) -> bool {
-        self.len >= self.arr.size * LOAD_FACTOR_NUMERATOR / LOAD_FACTOR_DENOMINATOR
+        self.len >= self.policy.max_len_for_cap(self.arr.size)
     }
 
     /// Grows the HashMap by the growth factor, ensuring that it can hold additional entries.
     pub(crate) fn grow(&mut self) {
-        let new_cap = cmp::max(self.cap() * GROWTH_FACTOR, MIN_ALLOCATED_CAP);
+        let new_cap = self.policy.grown_cap(self.cap(), self.len);
 
         self.realloc_with_cap(new_cap)
     }
 
     /// Reallocates the HashMap to have capacity equal to `new_cap`, if doing so wouldn't cause the
-    /// map to overload. (There isn't a logical way for the map to shrink and drop entries, so this
-    /// isn't allowed.)
+    /// map to overload. Shrinking is allowed as long as all entries still fit within the load
+    /// factor. (There isn't a logical way for the map to drop entries while shrinking, so
+    /// reallocating below that isn't allowed.)
     pub(crate) fn realloc_with_cap(&mut self, new_cap: usize) {
-        // Can't handle dropping values at this point.
-        if new_cap * LOAD_FACTOR_NUMERATOR / LOAD_FACTOR_DENOMINATOR < self.len { return; }
+        if new_cap == self.cap() || self.policy.max_len_for_cap(new_cap) < self.len { return; }
 
         // Replace the Array first so that we can consume the old Array.
-        let old_arr = mem::replace(&mut self.arr, Array::repeat_default(new_cap));
+        let new_arr = Array::repeat_default_in(new_cap, self.allocator().clone());
+        let old_arr = mem::replace(&mut self.arr, new_arr);
 
-        for entry in old_arr.into_iter().flatten() {
+        for pair in old_arr.into_iter().flatten() {
             // SAFETY: If the new capacity is 0, the old_arr has no items and we can't enter
             // this loop.
-            let index = unsafe { self.find_index_for_key(&entry.0).unwrap_unchecked() };
+            let ideal = unsafe { self.index_from_hash(pair.hash).unwrap_unchecked() };
 
-            // Move the bucket into the new Array.
-            self.arr[index] = Some(entry);
+            // Move the pair into the new Array, using the stored hash rather than rehashing.
+            self.insert_at(ideal, pair);
         }
     }
 
-    /// Calculates the ideal index of a bucket for the provided `hashable` (or None if the HashMap
-    /// has 0 capacity). This method doesn't consider hash collisions, see
-    /// [`HashMap::find_index_for_key`] for that functionality.
-    pub(crate) fn index_from_key<H: Hash + ?Sized>(&self, hashable: &H) -> Option<usize> {
-        let key_hash = self.hasher.hash_one(hashable);
-        key_hash.checked_rem(self.cap() as u64).map(|i| i as usize)
+    /// Shrinks the HashMap if its length has fallen below the shrink threshold of its policy. This
+    /// is called after removals, rather than from within [`HashMap::remove_at`], so that indices
+    /// remain valid while removing multiple entries.
+    pub(crate) fn shrink_if_sparse(&mut self) {
+        if let Some(new_cap) = self.policy.shrunk_cap(self.cap(), self.len) {
+            self.realloc_with_cap(new_cap);
+        }
     }
 
-    /// Finds the first valid index for the provided `key` (or None if the HashMap has 0 capacity).
-    /// This is done by calculating the ideal index and then iterating until a bucket is found that
-    /// is empty or has an equal key.
+    /// Calculates the ideal index of a bucket for the provided `hash` (or None if the HashMap has 0
+    /// capacity). This method doesn't consider hash collisions, see [`HashMap::probe`] for that
+    /// functionality.
+    pub(crate) const fn index_from_hash(&self, hash: u64) -> Option<usize> {
+        match hash.checked_rem(self.cap() as u64) {
+            Some(index) => Some(index as usize),
+            None => None,
+        }
+    }
+
+    /// Finds the index of the bucket containing `key`, or None if the HashMap doesn't contain it.
     pub(crate) fn find_index_for_key<Q>(&self, key: &Q) -> Option<usize>
     where
         // We're introducing a new type parameter here, Q which represents a borrowed version of K
@@ -397,26 +635,112 @@ impl<K: Hash + Eq, V, B: BuildHasher> HashMap<K, V, B> {
         K: Borrow<Q>,
         Q: Hash + Eq + ?Sized,
     {
-        let mut index = self.index_from_key(&key)?;
+        self.probe(self.hasher.hash_one(key), |existing| existing.borrow() == key)?.ok()
+    }
+
+    /// Probes the buckets for a key with the provided `hash` (or returns None if the HashMap has 0
+    /// capacity), for which `is_match` returns true. Returns `Ok` with the index of the bucket
+    /// containing a matching key, or `Err` with the index at which the key should be inserted.
+    ///
+    /// Because of the Robin Hood invariant, probing can stop as soon as a bucket is found that is
+    /// closer to its ideal index than the key would be, without reaching an empty bucket.
+    pub(crate) fn probe<F>(&self, hash: u64, mut is_match: F) -> Option<Result<usize, usize>>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        let mut index = self.index_from_hash(hash)?;
+        let mut distance = 0;
+
+        // While there is a value at the current index, but the key doesn't match, increment the
+        // index (wrapping at the capacity) and check again. Comparing the stored hash first avoids
+        // most calls to is_match. Can't loop forever unless the load factor is 100%.
+        while let Some(existing) = &self.arr[index] {
+            if existing.hash == hash && is_match(&existing.key) {
+                return Some(Ok(index));
+            }
+
+            if probe_distance(existing.hash, index, self.cap()) < distance {
+                break;
+            }
+
+            // UNCHECKED: index_from_hash returned some, so the cap is not 0.
+            index = (index + 1) % self.cap();
+            distance += 1;
+        }
+
+        // After that loop, index is either empty or contains an entry closer to its ideal index.
+        Some(Err(index))
+    }
+
+    /// Inserts `pair` into the HashMap, starting at the bucket at `start` and displacing any
+    /// entries which are closer to their ideal index than the carried entry. This method doesn't
+    /// check for an equal key or update the length.
+    ///
+    /// If `start` was returned by [`HashMap::probe`], `pair` is placed in the bucket at `start`.
+    pub(crate) fn insert_at(&mut self, start: usize, pair: HashedPair<K, V>) {
+        let cap = self.cap();
+        let mut carried = pair;
+        let mut distance = probe_distance(carried.hash, start, cap);
+        let mut index = start;
+
+        loop {
+            match &mut self.arr[index] {
+                Some(existing) => {
+                    let existing_distance = probe_distance(existing.hash, index, cap);
+
+                    // Take from the rich and give to the poor: swap the carried entry into this
+                    // bucket and continue with the entry that was closer to its ideal index.
+                    if existing_distance < distance {
+                        mem::swap(existing, &mut carried);
+                        distance = existing_distance;
+                    }
+                },
+                empty @ None => {
+                    *empty = Some(carried);
+                    return;
+                },
+            }
+
+            index = (index + 1) % cap;
+            distance += 1;
+        }
+    }
+
+    /// Removes the entry stored in the bucket at `start`, shifting any following entries back
+    /// towards their ideal index to fill the gap. Because of this, no tombstones are required.
+    ///
+    /// # Panics
+    /// Panics if the bucket at `start` is empty.
+    pub(crate) fn remove_at(&mut self, start: usize) -> (K, V) {
+        let removed = self.arr[start].take().expect("bucket to remove should be occupied");
+        self.len -= 1;
+
+        // UNCHECKED: The bucket at start was occupied, so the cap is not 0.
+        let mut hole = start;
+        let mut index = (start + 1) % self.cap();
 
-        // This is where Eq comes in: while there is a value at the current index, but the key
-        // isn't equal, increment the index (wrapping at the capacity) and check again.
-        // Can't enter recursion unless the load factor is 100%.
+        // Shift each following entry back by one, until an empty bucket or an entry which is
+        // already in its ideal bucket is found.
         while let Some(existing) = &self.arr[index]
-            && existing.0.borrow() != key
+            && probe_distance(existing.hash, index, self.cap()) > 0
         {
-            // UNCHECKED: find_index_for_key returned some, so the cap is not 0.
+            self.arr.swap(hole, index);
+            hole = index;
             index = (index + 1) % self.cap();
         }
 
-        // After that loop, index is either empty or contains an equal key.
-        Some(index)
+        (removed.key, removed.value)
     }
 }
 
-impl<K: Hash + Eq, V, B: BuildHasher + Default> Default for HashMap<K, V, B> {
+impl<K, V, B, A> Default for HashMap<K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher + Default,
+    A: Allocator + Clone + Default,
+{
     fn default() -> Self {
-        HashMap::new()
+        HashMap::new_in(A::default())
     }
 }
 
@@ -428,16 +752,16 @@ where
     type Output = V;
 
     fn index(&self, key: &Q) -> &Self::Output {
-        let index = self.find_index_for_key(key).ok_or(NoValueForKey).throw();
-
-        match &self.arr[index] {
-            Some((_, v)) => v,
-            None => Err(NoValueForKey).throw(),
-        }
+        self.get(key).ok_or(NoValueForKey).throw()
     }
 }
 
-impl<K: Hash + Eq, V, B: BuildHasher + Default> Extend<(K, V)> for HashMap<K, V, B> {
+impl<K, V, B, A> Extend<(K, V)> for HashMap<K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher + Default,
+    A: Allocator + Clone,
+{
     fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
         for (key, value) in iter {
             self.insert(key, value);
@@ -494,24 +818,37 @@ impl<K: Hash + Eq, V, B: BuildHasher + Default> FromIterator<(K, V)> for HashMap
 
 // TODO: impl PartialEq and Eq
 
-impl<K: Hash + Eq + Debug, V: Debug, B: BuildHasher + Debug> Debug for HashMap<K, V, B> {
+impl<K, V, B, A> Debug for HashMap<K, V, B, A>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    B: BuildHasher + Debug,
+    A: Allocator + Clone,
+{
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("HashMap")
             .field_with("buckets", |f| f.debug_list().entries(
                 self.arr.iter()
                     .map(|o| DebugRaw(match o {
-                        Some((k, v)) => format!("({k:?}: {v:?})"),
+                        Some(pair) => format!("({:?}: {:?})", pair.key, pair.value),
                         None => "_".into(),
                     }))
             ).finish())
             .field("len", &self.len)
             .field("cap", &self.cap())
             .field("hasher", &self.hasher)
+            .field("policy", &self.policy)
             .finish()
     }
 }
 
-impl<K: Hash + Eq + Debug, V: Debug, B: BuildHasher> Display for HashMap<K, V, B> {
+impl<K, V, B, A> Display for HashMap<K, V, B, A>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "#")?;
         f.debug_map().entries(self.iter()).finish()
@@ -1,15 +1,18 @@
-use std::hash::{BuildHasher, Hash};
+use std::alloc::{Allocator, Global};
+use std::hash::{BuildHasher, Hash, RandomState};
 use std::iter::FusedIterator;
+use std::mem;
 use std::slice::Iter as ArrIter;
 use std::slice::IterMut as ArrIterMut;
 
-use super::{Bucket, HashMap};
+use super::{Bucket, HashMap, probe_distance};
+use crate::collections::contiguous::Array;
 use crate::collections::contiguous::array::IntoIter as ArrIntoIter;
 
-impl<K: Hash + Eq, V, B: BuildHasher> IntoIterator for HashMap<K, V, B> {
+impl<K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> IntoIterator for HashMap<K, V, B, A> {
     type Item = (K, V);
 
-    type IntoIter = IntoIter<K, V>;
+    type IntoIter = IntoIter<K, V, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter {
@@ -22,12 +25,12 @@ impl<K: Hash + Eq, V, B: BuildHasher> IntoIterator for HashMap<K, V, B> {
 /// A type for owned iteration over a [`HashMap`]. Produces values of type `(K, V)`.
 ///
 /// See [`HashMap::into_iter`].
-pub struct IntoIter<K, V> {
-    pub(crate) inner: ArrIntoIter<Bucket<K, V>>,
+pub struct IntoIter<K, V, A: Allocator = Global> {
+    pub(crate) inner: ArrIntoIter<Bucket<K, V>, A>,
     pub(crate) len: usize,
 }
 
-impl<K: Hash + Eq, V> Iterator for IntoIter<K, V> {
+impl<K: Hash + Eq, V, A: Allocator> Iterator for IntoIter<K, V, A> {
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -36,7 +39,7 @@ impl<K: Hash + Eq, V> Iterator for IntoIter<K, V> {
             next = self.inner.next();
         }
 
-        next.flatten()
+        next.flatten().map(|pair| (pair.key, pair.value))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -44,9 +47,14 @@ impl<K: Hash + Eq, V> Iterator for IntoIter<K, V> {
     }
 }
 
-impl<K: Hash + Eq, V> FusedIterator for IntoIter<K, V> {}
+impl<K: Hash + Eq, V, A: Allocator> FusedIterator for IntoIter<K, V, A> {}
 
-impl<'a, K: Hash + Eq, V, B: BuildHasher> IntoIterator for &'a HashMap<K, V, B> {
+impl<'a, K, V, B, A> IntoIterator for &'a HashMap<K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
     type Item = (&'a K, &'a V);
 
     type IntoIter = Iter<'a, K, V>;
@@ -76,8 +84,8 @@ impl<'a, K: Hash + Eq, V> Iterator for Iter<'a, K, V> {
             next = self.inner.next();
         }
 
-        // Convert &'a (K, V) to (&'a K, &'a V) to avoid promising the internal use of a tuple.
-        next.and_then(|i| i.as_ref().map(|(k, v)| (k, v)))
+        // Convert &'a HashedPair<K, V> to (&'a K, &'a V) to avoid exposing the internal type.
+        next.and_then(|i| i.as_ref().map(|pair| (&pair.key, &pair.value)))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -90,9 +98,9 @@ impl<'a, K: Hash + Eq, V> FusedIterator for Iter<'a, K, V> {}
 /// A type for owned iteration over a [`HashMap`]'s keys. Produces values of type `K`.
 ///
 /// See [`HashMap::into_keys`].
-pub struct IntoKeys<K, V>(pub(crate) IntoIter<K, V>);
+pub struct IntoKeys<K, V, A: Allocator = Global>(pub(crate) IntoIter<K, V, A>);
 
-impl<K: Hash + Eq, V> Iterator for IntoKeys<K, V> {
+impl<K: Hash + Eq, V, A: Allocator> Iterator for IntoKeys<K, V, A> {
     type Item = K;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -104,7 +112,7 @@ impl<K: Hash + Eq, V> Iterator for IntoKeys<K, V> {
     }
 }
 
-impl<K: Hash + Eq, V> FusedIterator for IntoKeys<K, V> {}
+impl<K: Hash + Eq, V, A: Allocator> FusedIterator for IntoKeys<K, V, A> {}
 
 /// A type for borrowed iteration over a [`HashMap`]'s keys. Produces values of type `&K`.
 ///
@@ -128,9 +136,9 @@ impl<'a, K: Hash + Eq, V> FusedIterator for Keys<'a, K, V> {}
 /// A type for owned iteration over a [`HashMap`]'s values. Produces values of type `V`.
 ///
 /// See [`HashMap::into_values`].
-pub struct IntoValues<K, V>(pub(crate) IntoIter<K, V>);
+pub struct IntoValues<K, V, A: Allocator = Global>(pub(crate) IntoIter<K, V, A>);
 
-impl<K: Hash + Eq, V> Iterator for IntoValues<K, V> {
+impl<K: Hash + Eq, V, A: Allocator> Iterator for IntoValues<K, V, A> {
     type Item = V;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -142,7 +150,7 @@ impl<K: Hash + Eq, V> Iterator for IntoValues<K, V> {
     }
 }
 
-impl<K: Hash + Eq, V> FusedIterator for IntoValues<K, V> {}
+impl<K: Hash + Eq, V, A: Allocator> FusedIterator for IntoValues<K, V, A> {}
 
 /// A type for mutable iteration over a [`HashMap`]'s values. Produces values of type `&mut V`.
 ///
@@ -161,7 +169,7 @@ impl<'a, K: Hash + Eq, V> Iterator for ValuesMut<'a, K, V> {
             next = self.inner.next();
         }
 
-        next.and_then(|i| i.as_mut().map(|(_, v)| v))
+        next.and_then(|i| i.as_mut().map(|pair| &mut pair.value))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -189,3 +197,192 @@ impl<'a, K: Hash + Eq, V> Iterator for Values<'a, K, V> {
 }
 
 impl<'a, K: Hash + Eq, V> FusedIterator for Values<'a, K, V> {}
+
+/// A type for draining iteration over a [`HashMap`], which removes all entries while keeping the
+/// allocated capacity. Produces values of type `(K, V)`.
+///
+/// The buckets are moved out of the HashMap when this iterator is created, so the HashMap remains
+/// valid (and empty) even if this iterator is leaked. Any entries which haven't been produced are
+/// dropped along with the iterator.
+///
+/// See [`HashMap::drain`].
+pub struct Drain<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a mut HashMap<K, V, B, A>,
+    pub(crate) arr: Array<Bucket<K, V>, A>,
+    pub(crate) index: usize,
+    pub(crate) len: usize,
+}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> Iterator for Drain<'a, K, V, B, A> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.arr.size() {
+            let bucket = self.arr[self.index].take();
+            self.index += 1;
+
+            if let Some(pair) = bucket {
+                self.len -= 1;
+                return Some((pair.key, pair.value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K, V, B, A> ExactSizeIterator for Drain<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{}
+
+impl<'a, K, V, B, A> FusedIterator for Drain<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> Drop for Drain<'a, K, V, B, A> {
+    fn drop(&mut self) {
+        // Drop any entries that haven't been produced, before returning the empty buckets to the
+        // HashMap so that the allocation can be reused.
+        for bucket in &mut self.arr[self.index..] {
+            *bucket = None;
+        }
+
+        mem::swap(&mut self.map.arr, &mut self.arr);
+        self.map.shrink_if_sparse();
+    }
+}
+
+/// The state of an in-place extraction from a [`HashMap`], shared between the extracting iterators
+/// of HashMap and [`HashSet`](crate::collections::hash::HashSet).
+pub(crate) struct ExtractState {
+    pub(crate) index: usize,
+    pub(crate) remaining: usize,
+}
+
+impl ExtractState {
+    /// Creates a new ExtractState for the provided `map`, starting at the beginning of a cluster of
+    /// occupied buckets.
+    ///
+    /// Removal shifts the following entries in a cluster back by one bucket, so by starting at the
+    /// beginning of a cluster, the entries being shifted are never ones that have already been
+    /// visited. This ensures that the predicate is invoked exactly once for each entry.
+    pub(crate) fn new<K, V, B, A>(map: &HashMap<K, V, B, A>) -> ExtractState
+    where
+        K: Hash + Eq,
+        B: BuildHasher,
+        A: Allocator + Clone,
+    {
+        let cap = map.cap();
+        let index = map.arr.iter()
+            .enumerate()
+            .position(|(index, bucket)| match bucket {
+                Some(pair) => probe_distance(pair.hash, index, cap) == 0,
+                None => true,
+            })
+            .unwrap_or(0);
+
+        ExtractState {
+            index,
+            remaining: cap,
+        }
+    }
+
+    /// Removes and returns the next entry of `map` for which `pred` returns true, or None if every
+    /// bucket has been visited. The HashMap remains valid between calls.
+    pub(crate) fn next<K, V, B, A, F>(
+        &mut self,
+        map: &mut HashMap<K, V, B, A>,
+        mut pred: F,
+    ) -> Option<(K, V)>
+    where
+        K: Hash + Eq,
+        B: BuildHasher,
+        A: Allocator + Clone,
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        while self.remaining > 0 {
+            if let Some(pair) = &mut map.arr[self.index]
+                && pred(&pair.key, &mut pair.value)
+            {
+                // The following entry (if any) is shifted back into this bucket, so the index
+                // isn't advanced.
+                return Some(map.remove_at(self.index));
+            }
+
+            // UNCHECKED: remaining is only positive if the cap isn't 0.
+            self.index = (self.index + 1) % map.cap();
+            self.remaining -= 1;
+        }
+
+        None
+    }
+}
+
+/// A type for in-place extraction from a [`HashMap`], removing and producing every entry for which
+/// the predicate returns true. Produces values of type `(K, V)`.
+///
+/// If this iterator is dropped before being exhausted, the remaining entries are left in the
+/// HashMap.
+///
+/// See [`HashMap::extract_if`].
+pub struct ExtractIf<
+    'a,
+    K: Hash + Eq,
+    V,
+    F: FnMut(&K, &mut V) -> bool,
+    B: BuildHasher = RandomState,
+    A: Allocator + Clone = Global,
+> {
+    pub(crate) map: &'a mut HashMap<K, V, B, A>,
+    pub(crate) state: ExtractState,
+    pub(crate) pred: F,
+}
+
+impl<'a, K, V, F, B, A> Iterator for ExtractIf<'a, K, V, F, B, A>
+where
+    K: Hash + Eq,
+    F: FnMut(&K, &mut V) -> bool,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next(self.map, &mut self.pred)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.map.len()))
+    }
+}
+
+impl<'a, K, V, F, B, A> FusedIterator for ExtractIf<'a, K, V, F, B, A>
+where
+    K: Hash + Eq,
+    F: FnMut(&K, &mut V) -> bool,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{}
+
+impl<'a, K, V, F, B, A> Drop for ExtractIf<'a, K, V, F, B, A>
+where
+    K: Hash + Eq,
+    F: FnMut(&K, &mut V) -> bool,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn drop(&mut self) {
+        // Shrinking is deferred until extraction is complete, so that indices remain valid.
+        self.map.shrink_if_sparse();
+    }
+}
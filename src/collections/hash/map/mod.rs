@@ -1,17 +1,27 @@
 //! A module containing [`HashMap`] and associtated types.
 //!
-//! Currently, the only other included types are for iteration, providing owned and borrowed
-//! iteration over entries, keys or values in a map.
+//! The other included types are [`Entry`] and its variants, for in-place manipulation of a single
+//! bucket, [`RawEntryMut`] and its builders, for doing the same with a precomputed hash,
+//! [`HashPolicy`] for configuring allocation, as well as types for iteration, providing owned and
+//! borrowed iteration over entries, keys or values in a map, and removing iteration through
+//! [`Drain`] and [`ExtractIf`].
 //!
 //! As a note, there is no mutable iterator over entries or keys because mutating the keys of a
 //! HashMap in place would cause a logic error.
 //!
 //! [`HashMap`] is also re-exported under the parent module.
 
+mod entry;
 mod error;
 mod hash_map;
 mod iter;
+mod policy;
+mod raw_entry;
+mod tests;
 
+pub use entry::*;
 pub use error::*;
 pub use hash_map::*;
 pub use iter::*;
+pub use policy::*;
+pub use raw_entry::*;
@@ -0,0 +1,214 @@
+use std::cmp;
+
+#[cfg(doc)]
+use super::HashMap;
+#[cfg(doc)]
+use crate::collections::hash::SwissMap;
+
+const DEFAULT_MIN_CAP: usize = 2;
+
+const DEFAULT_GROWTH_FACTOR: (usize, usize) = (2, 1);
+
+const DEFAULT_LOAD_FACTOR: (usize, usize) = (4, 5);
+
+/// A policy determining when and how a [`HashMap`] (or a type built on it) or a [`SwissMap`]
+/// allocates memory. The load factor, growth factor and minimum allocated capacity are each
+/// configurable, with factors represented as a ratio of a numerator and denominator to avoid
+/// floating point arithmetic.
+///
+/// A lower load factor (e.g. 1/2) trades memory for shorter probe sequences, while a higher one
+/// (e.g. 19/20) keeps the map compact at the expense of slower lookups.
+///
+/// Optionally, a shrink threshold can be set, so that a map automatically shrinks when its length
+/// falls below that fraction of its capacity. Automatic shrinking is disabled by default.
+///
+/// # Examples
+/// ```
+/// # use standard_lib::collections::hash::{HashMap, HashPolicy};
+/// let policy = HashPolicy::new()
+///     .with_load_factor(19, 20)
+///     .with_growth_factor(3, 2)
+///     .with_min_cap(16);
+///
+/// let mut map: HashMap<u8, u8> = HashMap::with_policy(policy);
+/// map.insert(1, 1);
+/// assert_eq!(map.cap(), 16);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HashPolicy {
+    pub(crate) load_numerator: usize,
+    pub(crate) load_denominator: usize,
+    pub(crate) growth_numerator: usize,
+    pub(crate) growth_denominator: usize,
+    pub(crate) min_cap: usize,
+    pub(crate) shrink_threshold: Option<(usize, usize)>,
+}
+
+impl HashPolicy {
+    /// The policy used when none is provided, with a load factor of 4/5, a growth factor of 2, a
+    /// minimum allocated capacity of 2 and no automatic shrinking.
+    pub const DEFAULT: HashPolicy = HashPolicy {
+        load_numerator: DEFAULT_LOAD_FACTOR.0,
+        load_denominator: DEFAULT_LOAD_FACTOR.1,
+        growth_numerator: DEFAULT_GROWTH_FACTOR.0,
+        growth_denominator: DEFAULT_GROWTH_FACTOR.1,
+        min_cap: DEFAULT_MIN_CAP,
+        shrink_threshold: None,
+    };
+
+    /// Creates a new HashPolicy, equal to [`HashPolicy::DEFAULT`].
+    pub const fn new() -> HashPolicy {
+        HashPolicy::DEFAULT
+    }
+
+    /// Sets the load factor to `numerator / denominator`. A map will grow before an insertion
+    /// would cause its length to exceed this fraction of its capacity.
+    ///
+    /// # Panics
+    /// Panics if the load factor isn't strictly between 0 and 1, because open addressing requires
+    /// at least one empty bucket.
+    pub const fn with_load_factor(self, numerator: usize, denominator: usize) -> HashPolicy {
+        assert!(
+            0 < numerator && numerator < denominator,
+            "load factor should be strictly between 0 and 1"
+        );
+
+        HashPolicy {
+            load_numerator: numerator,
+            load_denominator: denominator,
+            ..self
+        }
+    }
+
+    /// Sets the growth factor to `numerator / denominator`. When a map grows, its capacity is
+    /// multiplied by this value.
+    ///
+    /// # Panics
+    /// Panics if the growth factor isn't greater than 1.
+    pub const fn with_growth_factor(self, numerator: usize, denominator: usize) -> HashPolicy {
+        assert!(
+            0 < denominator && denominator < numerator,
+            "growth factor should be greater than 1"
+        );
+
+        HashPolicy {
+            growth_numerator: numerator,
+            growth_denominator: denominator,
+            ..self
+        }
+    }
+
+    /// Sets the minimum capacity that a map will allocate when it grows from being empty.
+    ///
+    /// # Panics
+    /// Panics if `min_cap` is 0.
+    pub const fn with_min_cap(self, min_cap: usize) -> HashPolicy {
+        assert!(min_cap > 0, "minimum capacity should be greater than 0");
+
+        HashPolicy {
+            min_cap,
+            ..self
+        }
+    }
+
+    /// Enables automatic shrinking, so that a map shrinks after a removal which causes its length
+    /// to fall below `numerator / denominator` of its capacity. When shrinking automatically, the
+    /// new capacity is chosen as if the map had grown once from the smallest capacity that fits its
+    /// entries, so that subsequent insertions don't immediately cause it to grow again.
+    ///
+    /// For this to be effective, the threshold should be well below the load factor divided by the
+    /// growth factor.
+    ///
+    /// # Panics
+    /// Panics if the threshold isn't strictly between 0 and 1.
+    pub const fn with_shrink_threshold(self, numerator: usize, denominator: usize) -> HashPolicy {
+        assert!(
+            0 < numerator && numerator < denominator,
+            "shrink threshold should be strictly between 0 and 1"
+        );
+
+        HashPolicy {
+            shrink_threshold: Some((numerator, denominator)),
+            ..self
+        }
+    }
+
+    /// Disables automatic shrinking, which is the default behavior.
+    pub const fn without_shrink_threshold(self) -> HashPolicy {
+        HashPolicy {
+            shrink_threshold: None,
+            ..self
+        }
+    }
+
+    /// Returns the load factor as a `(numerator, denominator)` pair.
+    pub const fn load_factor(&self) -> (usize, usize) {
+        (self.load_numerator, self.load_denominator)
+    }
+
+    /// Returns the growth factor as a `(numerator, denominator)` pair.
+    pub const fn growth_factor(&self) -> (usize, usize) {
+        (self.growth_numerator, self.growth_denominator)
+    }
+
+    /// Returns the minimum capacity that a map will allocate when it grows from being empty.
+    pub const fn min_cap(&self) -> usize {
+        self.min_cap
+    }
+
+    /// Returns the shrink threshold as a `(numerator, denominator)` pair, or None if automatic
+    /// shrinking is disabled.
+    pub const fn shrink_threshold(&self) -> Option<(usize, usize)> {
+        self.shrink_threshold
+    }
+
+    /// Calculates the maximum number of entries that a map with the provided `cap`acity can hold
+    /// without exceeding the load factor.
+    pub(crate) const fn max_len_for_cap(&self, cap: usize) -> usize {
+        cap * self.load_numerator / self.load_denominator
+    }
+
+    /// Calculates the smallest capacity that can hold `len` entries without exceeding the load
+    /// factor.
+    pub(crate) const fn min_cap_for_len(&self, len: usize) -> usize {
+        len.strict_mul(self.load_denominator).div_ceil(self.load_numerator)
+    }
+
+    /// Calculates the capacity that a map with the provided `cap`acity and `len`gth should grow to,
+    /// ensuring that it is able to hold at least one additional entry.
+    pub(crate) fn grown_cap(&self, cap: usize, len: usize) -> usize {
+        let grown = cap.strict_mul(self.growth_numerator) / self.growth_denominator;
+
+        cmp::max(
+            cmp::max(grown, self.min_cap),
+            // Small capacities may not change when multiplied by a fractional growth factor, and a
+            // low load factor may need more than one step of growth.
+            cmp::max(cap + 1, self.min_cap_for_len(len + 1)),
+        )
+    }
+
+    /// Calculates the capacity that a map with the provided `cap`acity and `len`gth should
+    /// automatically shrink to, or None if it shouldn't shrink.
+    pub(crate) fn shrunk_cap(&self, cap: usize, len: usize) -> Option<usize> {
+        let (numerator, denominator) = self.shrink_threshold?;
+
+        if len.strict_mul(denominator) >= cap.strict_mul(numerator) {
+            return None;
+        }
+
+        let fitted = self.min_cap_for_len(len);
+        let new_cap = cmp::max(
+            fitted.strict_mul(self.growth_numerator) / self.growth_denominator,
+            self.min_cap,
+        );
+
+        // Only shrink if doing so actually reduces the capacity.
+        (new_cap < cap).then_some(new_cap)
+    }
+}
+
+impl Default for HashPolicy {
+    fn default() -> Self {
+        HashPolicy::DEFAULT
+    }
+}
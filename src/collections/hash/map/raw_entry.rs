@@ -0,0 +1,301 @@
+use std::alloc::{Allocator, Global};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+
+use super::{HashMap, HashedPair};
+
+/// A builder for immutable lookups in a [`HashMap`] using a precomputed hash and a custom equality
+/// closure, rather than the HashMap's hasher and [`Eq`].
+///
+/// See [`HashMap::raw_entry`].
+pub struct RawEntryBuilder<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a HashMap<K, V, B, A>,
+}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> RawEntryBuilder<'a, K, V, B, A> {
+    /// Finds the entry with the provided `hash` for which `is_match` returns true, returning the
+    /// key-value pair or None if there is no such entry.
+    ///
+    /// `is_match` is only invoked for keys with an equal stored hash.
+    pub fn from_hash<F>(self, hash: u64, is_match: F) -> Option<(&'a K, &'a V)>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        let map = self.map;
+        let index = map.probe(hash, is_match)?.ok()?;
+        map.arr[index].as_ref().map(|pair| (&pair.key, &pair.value))
+    }
+}
+
+impl<'a, K, V, B, A> Debug for RawEntryBuilder<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawEntryBuilder").finish_non_exhaustive()
+    }
+}
+
+/// A builder for mutable lookups in a [`HashMap`] using a precomputed hash and a custom equality
+/// closure, producing a [`RawEntryMut`].
+///
+/// See [`HashMap::raw_entry_mut`].
+pub struct RawEntryBuilderMut<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a mut HashMap<K, V, B, A>,
+}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> RawEntryBuilderMut<'a, K, V, B, A> {
+    /// Finds the bucket for an entry with the provided `hash` for which `is_match` returns true,
+    /// returning it as a [`RawEntryMut`]. The HashMap's capacity is increased if required, so that
+    /// a vacant entry can always be filled.
+    ///
+    /// `is_match` is only invoked for keys with an equal stored hash.
+    pub fn from_hash<F>(self, hash: u64, is_match: F) -> RawEntryMut<'a, K, V, B, A>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        let map = self.map;
+        if map.should_grow() {
+            map.grow()
+        }
+
+        // SAFETY: We've just grown if necessary, so the capacity isn't 0.
+        match unsafe { map.probe(hash, is_match).unwrap_unchecked() } {
+            Ok(index) => RawEntryMut::Occupied(RawOccupiedEntryMut {
+                map,
+                index,
+            }),
+            Err(index) => RawEntryMut::Vacant(RawVacantEntryMut {
+                map,
+                index,
+                hash,
+            }),
+        }
+    }
+}
+
+impl<'a, K, V, B, A> Debug for RawEntryBuilderMut<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawEntryBuilderMut").finish_non_exhaustive()
+    }
+}
+
+/// A view into a single bucket of a [`HashMap`], located with a precomputed hash, which is either
+/// occupied or vacant.
+///
+/// See [`HashMap::raw_entry_mut`].
+pub enum RawEntryMut<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    /// A RawEntryMut for a key which is already associated with a value in the HashMap.
+    Occupied(RawOccupiedEntryMut<'a, K, V, B, A>),
+    /// A RawEntryMut for a key which isn't associated with any value in the HashMap.
+    Vacant(RawVacantEntryMut<'a, K, V, B, A>),
+}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> RawEntryMut<'a, K, V, B, A> {
+    /// Ensures that the entry has a value by inserting the provided `key` and `value` if it is
+    /// vacant. Returns references to the key and value.
+    ///
+    /// It is a logic error for `key` to not match the hash and equality closure used to locate
+    /// this entry.
+    pub fn or_insert(self, key: K, value: V) -> (&'a K, &'a mut V) {
+        match self {
+            RawEntryMut::Occupied(entry) => entry.into_key_value(),
+            RawEntryMut::Vacant(entry) => entry.insert(key, value),
+        }
+    }
+
+    /// Ensures that the entry has a value by inserting the key-value pair returned by `default` if
+    /// it is vacant. Returns references to the key and value.
+    pub fn or_insert_with<F: FnOnce() -> (K, V)>(self, default: F) -> (&'a K, &'a mut V) {
+        match self {
+            RawEntryMut::Occupied(entry) => entry.into_key_value(),
+            RawEntryMut::Vacant(entry) => {
+                let (key, value) = default();
+                entry.insert(key, value)
+            },
+        }
+    }
+
+    /// Invokes `f` with references to the key and value if the entry is occupied. Vacant entries
+    /// are left unchanged. Returns self, so that the entry can be used again.
+    pub fn and_modify<F: FnOnce(&K, &mut V)>(self, f: F) -> RawEntryMut<'a, K, V, B, A> {
+        match self {
+            RawEntryMut::Occupied(mut entry) => {
+                let (key, value) = entry.pair_mut();
+                f(key, value);
+                RawEntryMut::Occupied(entry)
+            },
+            RawEntryMut::Vacant(entry) => RawEntryMut::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K, V, B, A> Debug for RawEntryMut<'a, K, V, B, A>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RawEntryMut::Occupied(entry) => f.debug_tuple("RawEntryMut").field(entry).finish(),
+            RawEntryMut::Vacant(entry) => f.debug_tuple("RawEntryMut").field(entry).finish(),
+        }
+    }
+}
+
+/// A view into an occupied bucket of a [`HashMap`]. Part of the [`RawEntryMut`] enum.
+pub struct RawOccupiedEntryMut<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a mut HashMap<K, V, B, A>,
+    pub(crate) index: usize,
+}
+
+impl<'a, K, V, B, A> RawOccupiedEntryMut<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    /// Returns a reference to the bucket's contents.
+    fn pair(&self) -> &HashedPair<K, V> {
+        match &self.map.arr[self.index] {
+            Some(pair) => pair,
+            // A RawOccupiedEntryMut is only ever constructed for an occupied bucket.
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns references to the key and value in the bucket, with only the value being mutable.
+    fn pair_mut(&mut self) -> (&K, &mut V) {
+        match &mut self.map.arr[self.index] {
+            Some(pair) => (&pair.key, &mut pair.value),
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns a reference to the key of this entry.
+    pub fn key(&self) -> &K {
+        &self.pair().key
+    }
+
+    /// Returns the hash stored alongside the key of this entry.
+    pub fn hash(&self) -> u64 {
+        self.pair().hash
+    }
+
+    /// Returns a reference to the value of this entry.
+    pub fn get(&self) -> &V {
+        &self.pair().value
+    }
+
+    /// Returns a mutable reference to the value of this entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        self.pair_mut().1
+    }
+
+    /// Converts the entry into a mutable reference to its value, with the same lifetime as the
+    /// borrow of the HashMap.
+    pub fn into_mut(self) -> &'a mut V {
+        self.into_key_value().1
+    }
+
+    /// Converts the entry into references to its key and value, with the same lifetime as the
+    /// borrow of the HashMap.
+    pub fn into_key_value(self) -> (&'a K, &'a mut V) {
+        match &mut self.map.arr[self.index] {
+            Some(pair) => (&pair.key, &mut pair.value),
+            None => unreachable!(),
+        }
+    }
+
+    /// Replaces the value of this entry with `value`, returning the previous value.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes this entry from the HashMap, returning the contained key-value pair.
+    pub fn remove_entry(self) -> (K, V) {
+        let removed = self.map.remove_at(self.index);
+        self.map.shrink_if_sparse();
+        removed
+    }
+
+    /// Removes this entry from the HashMap, returning the contained value.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
+
+impl<'a, K, V, B, A> Debug for RawOccupiedEntryMut<'a, K, V, B, A>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawOccupiedEntryMut")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+/// A view into a vacant bucket of a [`HashMap`]. Part of the [`RawEntryMut`] enum.
+pub struct RawVacantEntryMut<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a mut HashMap<K, V, B, A>,
+    pub(crate) index: usize,
+    pub(crate) hash: u64,
+}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> RawVacantEntryMut<'a, K, V, B, A> {
+    /// Returns the hash that would be stored when inserting through this entry.
+    pub const fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Inserts the provided `key` and `value` into the HashMap, storing them with the hash used to
+    /// locate this entry. Returns references to the key and value.
+    ///
+    /// It is a logic error for `key` to not match the hash and equality closure used to locate
+    /// this entry.
+    pub fn insert(self, key: K, value: V) -> (&'a K, &'a mut V) {
+        let RawVacantEntryMut { map, index, hash } = self;
+
+        // The index was returned by probing when this entry was created, and the HashMap has been
+        // mutably borrowed since then, so the new pair will be placed at index.
+        map.insert_at(index, HashedPair {
+            hash,
+            key,
+            value,
+        });
+        map.len += 1;
+
+        RawOccupiedEntryMut {
+            map,
+            index,
+        }.into_key_value()
+    }
+}
+
+impl<'a, K, V, B, A> Debug for RawVacantEntryMut<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawVacantEntryMut")
+            .field("hash", &self.hash)
+            .finish()
+    }
+}
@@ -0,0 +1,341 @@
+#![cfg(test)]
+
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+
+use super::*;
+use crate::collections::contiguous::Vector;
+use crate::util::alloc::CountedDrop;
+use crate::util::hash::{BadHasherBuilder, ManualHash};
+
+#[test]
+fn test_entry() {
+    let mut map: HashMap<&str, usize> = HashMap::new();
+
+    *map.entry("one").or_insert(0) += 1;
+    *map.entry("one").or_insert(0) += 1;
+    assert_eq!(map.get("one"), Some(&2), "An occupied Entry should be modified in place.");
+
+    map.entry("two").and_modify(|v| *v = 100).or_insert_with(|| 2);
+    map.entry("two").and_modify(|v| *v += 20).or_default();
+    assert_eq!(map.get("two"), Some(&22), "and_modify should only apply to occupied entries.");
+
+    assert_eq!(*map.entry("three").or_default(), 0);
+    assert_eq!(map.len(), 3);
+
+    let entry = map.entry("four").insert_entry(4);
+    assert_eq!((entry.key(), entry.get()), (&"four", &4));
+
+    match map.entry("one") {
+        Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), ("one", 2)),
+        Entry::Vacant(_) => panic!("Entry for an existing key should be occupied."),
+    }
+    assert!(!map.contains("one"), "Removed entries should no longer be contained.");
+    assert_eq!(map.len(), 3);
+
+    match map.entry("one") {
+        Entry::Occupied(_) => panic!("Entry for a removed key should be vacant."),
+        Entry::Vacant(entry) => assert_eq!(entry.into_key(), "one"),
+    }
+    assert_eq!(map.len(), 3, "Dropping a vacant Entry shouldn't change the HashMap.");
+}
+
+#[test]
+fn test_entry_collisions() {
+    let mut map = HashMap::with_hasher(BadHasherBuilder);
+    map.insert(ManualHash::new(0, "zero"), 0);
+    map.insert(ManualHash::new(0, "one"), 1);
+    map.insert(ManualHash::new(0, "two"), 2);
+
+    match map.entry(ManualHash::new(0, "one")) {
+        Entry::Occupied(entry) => assert_eq!(entry.remove(), 1),
+        Entry::Vacant(_) => panic!("Entry for an existing key should be occupied."),
+    }
+
+    assert_eq!(
+        map.get(&ManualHash::new(0, "two")),
+        Some(&2),
+        "Removing through an Entry should handle hash collisions."
+    );
+    assert_eq!(*map.entry(ManualHash::new(0, "three")).or_insert(3), 3);
+    assert_eq!(map.len(), 3);
+}
+
+/// Returns the longest distance between any entry in `map` and its ideal bucket.
+fn max_probe_distance<K: Hash + Eq, V, B: BuildHasher>(map: &HashMap<K, V, B>) -> usize {
+    map.arr.iter()
+        .enumerate()
+        .filter_map(|(index, bucket)| bucket.as_ref().map(|pair| {
+            probe_distance(pair.hash, index, map.cap())
+        }))
+        .max()
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_probe_length_under_churn() {
+    const LIVE: usize = 100_000;
+    const ROUNDS: usize = 20;
+
+    let mut map: HashMap<usize, usize> = HashMap::new();
+    for key in 0..LIVE {
+        map.insert(key, key);
+    }
+
+    let cap = map.cap();
+
+    // Slide a window of live keys forwards, inserting and removing two million keys in total.
+    for round in 0..ROUNDS {
+        for offset in 0..LIVE {
+            let old = round * LIVE + offset;
+            assert_eq!(map.remove(&old), Some(old));
+            map.insert(old + LIVE, old + LIVE);
+        }
+
+        assert_eq!(map.len(), LIVE);
+        assert_eq!(map.cap(), cap, "Churn shouldn't cause the HashMap to grow.");
+
+        let max = max_probe_distance(&map);
+        assert!(max <= 64, "Maximum probe distance should remain bounded, but was {max}.");
+    }
+
+    for key in ROUNDS * LIVE..(ROUNDS + 1) * LIVE {
+        assert_eq!(map.get(&key), Some(&key), "All live keys should still be found.");
+    }
+}
+
+#[test]
+fn test_policy() {
+    let sparse = HashPolicy::new().with_load_factor(1, 2);
+    let mut map: HashMap<usize, usize> = HashMap::with_policy(sparse);
+    for key in 0..1000 {
+        map.insert(key, key);
+        assert!(map.len() * 2 <= map.cap(), "The load factor of 1/2 shouldn't be exceeded.");
+    }
+
+    let dense = HashPolicy::new()
+        .with_load_factor(19, 20)
+        .with_growth_factor(3, 2)
+        .with_min_cap(1);
+    let mut map: HashMap<usize, usize> = HashMap::with_policy(dense);
+    map.insert(0, 0);
+    assert_eq!(map.cap(), 2, "Growth should allocate enough capacity for the load factor.");
+
+    for key in 1..1000 {
+        map.insert(key, key);
+        assert!(
+            map.len() * 20 <= map.cap() * 19,
+            "The load factor of 19/20 shouldn't be exceeded."
+        );
+    }
+    assert!(map.cap() < 1200, "A high load factor should keep the HashMap compact.");
+
+    for key in 0..1000 {
+        assert_eq!(map.get(&key), Some(&key));
+    }
+
+    map.reserve(500);
+    let cap = map.cap();
+    for key in 1000..1500 {
+        map.insert(key, key);
+    }
+    assert_eq!(map.cap(), cap, "Reserved capacity should respect the load factor.");
+}
+
+#[test]
+fn test_shrink() {
+    let mut map: HashMap<usize, usize> = HashMap::new();
+    for key in 0..10_000 {
+        map.insert(key, key);
+    }
+    for key in 100..10_000 {
+        map.remove(&key);
+    }
+
+    let cap = map.cap();
+    map.shrink_to(1000);
+    assert_eq!(map.cap(), 1000, "shrink_to should respect the provided minimum capacity.");
+    map.shrink_to(cap);
+    assert_eq!(map.cap(), 1000, "shrink_to shouldn't grow the HashMap.");
+
+    map.shrink_to_fit();
+    assert_eq!(map.cap(), 125, "shrink_to_fit should fit entries to the load factor.");
+    for key in 0..100 {
+        assert_eq!(map.get(&key), Some(&key), "Shrinking shouldn't lose any entries.");
+    }
+
+    map.shrink_to(0);
+    assert_eq!(map.cap(), 125, "Shrinking shouldn't exceed the load factor.");
+
+    for key in 0..100 {
+        map.remove(&key);
+    }
+    map.shrink_to_fit();
+    assert_eq!(map.cap(), 0, "An empty HashMap should be deallocated.");
+}
+
+#[test]
+fn test_auto_shrink() {
+    let policy = HashPolicy::new().with_shrink_threshold(1, 5);
+    let mut map: HashMap<usize, usize> = HashMap::with_policy(policy);
+    for key in 0..10_000 {
+        map.insert(key, key);
+    }
+    let full_cap = map.cap();
+
+    for key in 0..9_900 {
+        assert_eq!(map.remove(&key), Some(key));
+        assert!(
+            map.len() * 5 >= map.cap() || map.cap() == policy.min_cap(),
+            "The HashMap should shrink when the threshold is crossed."
+        );
+    }
+
+    assert!(map.cap() < full_cap / 10, "The HashMap should have shrunk after a large purge.");
+    for key in 9_900..10_000 {
+        assert_eq!(map.get(&key), Some(&key), "Shrinking shouldn't lose any entries.");
+    }
+}
+
+#[test]
+fn test_retain() {
+    let mut map = HashMap::with_cap_and_hasher(16, BadHasherBuilder);
+    let cap = map.cap();
+
+    // A cluster which wraps around the end of the buckets, followed by entries displaced by it.
+    for value in 0..4 {
+        map.insert(ManualHash::new(cap as u64 - 2, value), 0);
+    }
+    for value in 4..8 {
+        map.insert(ManualHash::new(0, value), 0);
+    }
+
+    map.retain(|_, calls| {
+        *calls += 1;
+        false
+    });
+    assert!(map.is_empty(), "retain should remove every entry rejected by the predicate.");
+
+    for value in 0..4 {
+        map.insert(ManualHash::new(cap as u64 - 2, value), 0);
+    }
+    for value in 4..8 {
+        map.insert(ManualHash::new(0, value), 0);
+    }
+
+    let mut calls = 0;
+    map.retain(|key, count| {
+        calls += 1;
+        *count += 1;
+        (4..8).any(|value| *key == ManualHash::new(0, value))
+    });
+    assert_eq!(calls, 8, "The predicate should be invoked exactly once for each entry.");
+    assert_eq!(map.len(), 4);
+    for value in 4..8 {
+        assert_eq!(
+            map.get(&ManualHash::new(0, value)),
+            Some(&1),
+            "Entries shifted back during retain should remain reachable."
+        );
+    }
+    assert_eq!(map.cap(), cap);
+}
+
+#[test]
+fn test_drain() {
+    let counter = CountedDrop::new(0);
+    let mut map: HashMap<usize, CountedDrop> = HashMap::new();
+    for key in 0..100 {
+        map.insert(key, counter.clone());
+    }
+    let cap = map.cap();
+
+    let mut drain = map.drain();
+    assert_eq!(drain.len(), 100);
+    drop(drain.by_ref().take(10).collect::<Vector<_>>());
+    assert_eq!(*counter.borrow(), 10);
+    drop(drain);
+
+    assert_eq!(*counter.borrow(), 100, "Dropping a Drain should drop all remaining entries.");
+    assert!(map.is_empty());
+    assert_eq!(map.cap(), cap, "Draining should retain the HashMap's capacity.");
+
+    map.insert(1, counter.clone());
+    assert!(map.contains(&1), "A drained HashMap should remain usable.");
+
+    mem::forget(map.drain());
+    assert!(map.is_empty(), "A leaked Drain should leave the HashMap empty.");
+    map.insert(2, counter.clone());
+    assert!(map.contains(&2), "A leaked Drain should leave the HashMap usable.");
+}
+
+#[test]
+fn test_extract_if() {
+    let mut map: HashMap<usize, usize> = (0..1000).map(|key| (key, key)).collect();
+
+    let mut extracted = map.extract_if(|key, _| key % 3 == 0);
+    for _ in 0..100 {
+        let (key, value) = extracted.next().expect("extract_if should produce matching entries");
+        assert_eq!(key % 3, 0);
+        assert_eq!(key, value);
+    }
+    drop(extracted);
+
+    assert_eq!(map.len(), 900, "Dropping ExtractIf early should retain remaining entries.");
+    let remaining = (0..1000).filter(|key| map.contains(key)).count();
+    assert_eq!(remaining, 900, "All remaining entries should be reachable after early drop.");
+
+    let count = map.extract_if(|key, _| key % 3 == 0).count();
+    assert_eq!(count, 234);
+    assert!((0..1000).all(|key| map.contains(&key) == (key % 3 != 0)));
+
+    let policy = HashPolicy::new().with_shrink_threshold(1, 4);
+    let mut map: HashMap<usize, usize> = HashMap::with_policy(policy);
+    map.extend((0..1000).map(|key| (key, key)));
+    let cap = map.cap();
+
+    assert_eq!(map.extract_if(|key, _| *key >= 10).count(), 990);
+    assert!(map.cap() < cap, "extract_if should shrink once complete, according to the policy.");
+    assert!((0..10).all(|key| map.contains(&key)));
+}
+
+#[test]
+fn test_raw_entry() {
+    // Hashes which are unrelated to the HashMap's hasher, and collide frequently.
+    let hash_of = |key: usize| (key % 10) as u64;
+
+    let mut map: HashMap<usize, usize> = HashMap::new();
+    for key in 0..100 {
+        assert_eq!(map.insert_with_hash(hash_of(key), key, key), None);
+    }
+    assert_eq!(map.insert_with_hash(hash_of(5), 5, 50), Some(5));
+    assert_eq!(map.len(), 100);
+
+    for key in 0..100 {
+        assert_eq!(
+            map.raw_entry().from_hash(hash_of(key), |k| *k == key).map(|(_, v)| *v),
+            Some(if key == 5 { 50 } else { key }),
+            "Entries inserted with a precomputed hash should be found after the HashMap grows."
+        );
+    }
+    assert_eq!(map.raw_entry().from_hash(hash_of(100), |k| *k == 100), None);
+
+    match map.raw_entry_mut().from_hash(hash_of(42), |k| *k == 42) {
+        RawEntryMut::Occupied(entry) => {
+            assert_eq!(entry.hash(), hash_of(42));
+            assert_eq!(entry.remove_entry(), (42, 42));
+        },
+        RawEntryMut::Vacant(_) => panic!("Raw entry for an existing key should be occupied."),
+    }
+    assert_eq!(map.raw_entry().from_hash(hash_of(42), |k| *k == 42), None);
+    assert_eq!(map.raw_entry().from_hash(hash_of(52), |k| *k == 52), Some((&52, &52)));
+
+    let (key, value) = map.raw_entry_mut()
+        .from_hash(hash_of(42), |k| *k == 42)
+        .and_modify(|_, _| panic!("and_modify shouldn't be invoked for a vacant entry."))
+        .or_insert(42, 0);
+    *value += 1;
+    assert_eq!(*key, 42);
+    assert_eq!(map.raw_entry().from_hash(hash_of(42), |k| *k == 42), Some((&42, &1)));
+    assert_eq!(map.len(), 100);
+}
@@ -1,12 +1,23 @@
 //! Collections based on the [`Hash`](std::hash::Hash) trait, including [`HashMap`] and [`HashSet`]
-//! for storing unique values or key-value pairs.
+//! for storing unique values or key-value pairs, [`IndexMap`] and [`IndexSet`] for doing so while
+//! preserving insertion order, [`HashMultiMap`] and [`HashBag`] for storing repeated keys or
+//! values, as well as [`SwissMap`], an alternative map layout.
 #![cfg(feature = "contiguous")]
 #![warn(missing_docs)]
 
+pub mod index;
 pub mod map;
+pub mod multi;
 pub mod set;
+pub mod swiss;
 
 #[doc(inline)]
-pub use map::HashMap;
+pub use index::{IndexMap, IndexSet};
+#[doc(inline)]
+pub use map::{HashMap, HashPolicy};
+#[doc(inline)]
+pub use multi::{HashBag, HashMultiMap};
 #[doc(inline)]
 pub use set::HashSet;
+#[doc(inline)]
+pub use swiss::SwissMap;
@@ -0,0 +1,395 @@
+use std::borrow::Borrow;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{BuildHasher, Hash, RandomState};
+
+use super::{
+    BagFlatIter, BagIter, BagMerge, Difference, GetAll, Intersection, IntoDifference,
+    IntoIntersection, IntoSymmetricDifference, IntoUnion, SymmetricDifference, Union,
+};
+use crate::collections::hash::HashMap;
+use crate::collections::hash::map::OccupiedEntry;
+
+/// A counted multiset, storing each distinct element once alongside the number of times it occurs,
+/// with the help of the [`Hash`] trait.
+///
+/// Relies on a [`HashMap`] of counts internally, see documentation there for additional details.
+/// An element is only present in the HashBag while its count is non-zero.
+///
+/// [`len`](HashBag::len) returns the number of distinct elements, while
+/// [`total_len`](HashBag::total_len) returns the sum of all counts.
+///
+/// The multiset operations ([`union`](HashBag::union), [`intersection`](HashBag::intersection),
+/// [`difference`](HashBag::difference) and
+/// [`symmetric_difference`](HashBag::symmetric_difference)) produce borrowed iterators over
+/// elements and their resulting counts, in the style of
+/// [`SetIterator`](crate::collections::traits::set::SetIterator). Each has an `into_` counterpart
+/// which consumes both HashBags and produces owned elements instead.
+///
+/// It is a logic error for elements in a HashBag to be manipulated in a way that changes their
+/// hash. Because of this, HashBag's API prevents mutable access to its elements.
+///
+/// # Time Complexity
+/// See [`HashMap`] with the following additions.
+///
+/// Variables are defined as follows:
+/// - `n`: The number of distinct elements in the HashBag.
+/// - `m`: The number of distinct elements in the second HashBag.
+/// - `c`: The total count of all elements in the HashBag.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `total_len` | `O(1)` |
+/// | `insert`, `insert_many` | `O(1)`*, `O(n)` |
+/// | `remove_one`, `remove_many`, `remove_all` | `O(1)` |
+/// | `count` | `O(1)` |
+/// | `difference`, `into_difference`** | `O(n)` |
+/// | `symmetric_difference`, `into_symmetric_difference`** | `O(n+m)` |
+/// | `intersection`, `into_intersection`** | `O(n)` |
+/// | `union`, `into_union`** | `O(n+m)` |
+/// | `is_subset` | `O(n)` |
+/// | `is_superset` | `O(m)` |
+/// | `flat_iter`** | `O(c)` |
+///
+/// \* If the HashMap doesn't have enough capacity for a new distinct element, `insert` will take
+/// `O(n)`.
+///
+/// \** When exhausted.
+pub struct HashBag<T: Hash + Eq, B: BuildHasher = RandomState> {
+    pub(crate) map: HashMap<T, usize, B>,
+    pub(crate) total_len: usize,
+}
+
+impl<T: Hash + Eq, B: BuildHasher + Default> HashBag<T, B> {
+    /// Creates a new HashBag with capacity 0 and the default value for `B`. Memory will be
+    /// allocated when the capacity changes.
+    pub fn new() -> HashBag<T, B> {
+        HashBag {
+            map: HashMap::new(),
+            total_len: 0,
+        }
+    }
+
+    /// Creates a new HashBag with enough capacity for `cap` distinct elements and the default value
+    /// for `B`.
+    pub fn with_cap(cap: usize) -> HashBag<T, B> {
+        HashBag {
+            map: HashMap::with_cap(cap),
+            total_len: 0,
+        }
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> HashBag<T, B> {
+    /// Creates a new HashBag with capacity 0 and the provided `hasher`.
+    pub fn with_hasher(hasher: B) -> HashBag<T, B> {
+        HashBag {
+            map: HashMap::with_hasher(hasher),
+            total_len: 0,
+        }
+    }
+
+    /// Creates a new HashBag with enough capacity for `cap` distinct elements and the provided
+    /// `hasher`.
+    pub fn with_cap_and_hasher(cap: usize, hasher: B) -> HashBag<T, B> {
+        HashBag {
+            map: HashMap::with_cap_and_hasher(cap, hasher),
+            total_len: 0,
+        }
+    }
+
+    /// Returns the number of distinct elements in the HashBag.
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the total number of elements in the HashBag, counting each occurrence.
+    pub const fn total_len(&self) -> usize {
+        self.total_len
+    }
+
+    /// Returns true if the HashBag contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns a reference to the HashBag's hasher.
+    pub const fn hasher(&self) -> &B {
+        self.map.hasher()
+    }
+
+    /// Adds a single occurrence of `item` to the HashBag, returning the number of occurrences
+    /// before the insertion. If `item` was already present, the existing element is kept.
+    pub fn insert(&mut self, item: T) -> usize {
+        self.insert_many(item, 1)
+    }
+
+    /// Adds `n` occurrences of `item` to the HashBag, returning the number of occurrences before
+    /// the insertion. Inserting 0 occurrences has no effect.
+    pub fn insert_many(&mut self, item: T, n: usize) -> usize {
+        if n == 0 {
+            return self.count(&item);
+        }
+
+        let count = self.map.entry(item).or_insert(0);
+        let prev = *count;
+        *count += n;
+        self.total_len += n;
+        prev
+    }
+
+    /// Removes a single occurrence of `item` from the HashBag, returning the number of occurrences
+    /// before the removal. If this was the last occurrence, the element is removed too.
+    pub fn remove_one<Q>(&mut self, item: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_many(item, 1)
+    }
+
+    /// Removes up to `n` occurrences of `item` from the HashBag, returning the number of
+    /// occurrences before the removal. If no occurrences remain, the element is removed too.
+    pub fn remove_many<Q>(&mut self, item: &Q, n: usize) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(index) = self.map.find_index_for_key(item) else {
+            return 0;
+        };
+        let mut entry = OccupiedEntry {
+            map: &mut self.map,
+            index,
+        };
+
+        let prev = *entry.get();
+        if n >= prev {
+            entry.remove();
+            self.total_len -= prev;
+        } else {
+            *entry.get_mut() -= n;
+            self.total_len -= n;
+        }
+        prev
+    }
+
+    /// Removes every occurrence of `item` from the HashBag, returning the stored element and its
+    /// count if it was present.
+    pub fn remove_all<Q>(&mut self, item: &Q) -> Option<(T, usize)>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (item, count) = self.map.remove_entry(item)?;
+        self.total_len -= count;
+        Some((item, count))
+    }
+
+    /// Returns a reference to the contained element equal to `item`, or None if there isn't one.
+    pub fn get<Q>(&self, item: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_entry(item).map(|(item, _)| item)
+    }
+
+    /// Returns an iterator which produces a reference to the contained element equal to `item`
+    /// once for each of its occurrences. If `item` isn't present, the iterator is empty.
+    pub fn get_all<Q>(&self, item: &Q) -> GetAll<'_, T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.map.get_entry(item) {
+            Some((item, count)) => GetAll {
+                item: Some(item),
+                remaining: *count,
+            },
+            None => GetAll {
+                item: None,
+                remaining: 0,
+            },
+        }
+    }
+
+    /// Returns the number of occurrences of `item` in the HashBag.
+    pub fn count<Q>(&self, item: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(item).copied().unwrap_or(0)
+    }
+
+    /// Returns true if the HashBag contains at least one occurrence of `item`.
+    pub fn contains<Q>(&self, item: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains(item)
+    }
+
+    /// Increases the capacity of the HashBag to ensure that len + `extra` distinct elements will
+    /// fit.
+    pub fn reserve(&mut self, extra: usize) {
+        self.map.reserve(extra)
+    }
+
+    /// Returns an iterator over all distinct elements and their counts, with the elements as
+    /// references.
+    pub fn iter(&self) -> BagIter<'_, T> {
+        self.into_iter()
+    }
+
+    /// Returns an iterator over all elements in the HashBag, as references. Each element is
+    /// repeated once for each of its occurrences.
+    pub fn flat_iter(&self) -> BagFlatIter<'_, T> {
+        BagFlatIter {
+            groups: self.map.iter(),
+            front: None,
+            len: self.total_len,
+        }
+    }
+
+    /// Creates an owned iterator over all elements in `self`, each with its count in `self` minus
+    /// its count in `other`. Elements with a resulting count of zero are skipped. (`self \ other`)
+    pub fn into_difference(self, other: Self) -> IntoDifference<T, B> {
+        IntoDifference {
+            inner: self.into_iter(),
+            other,
+        }
+    }
+
+    /// Creates a borrowed iterator over all elements in `self`, each with its count in `self` minus
+    /// its count in `other`. Elements with a resulting count of zero are skipped. (`self \ other`)
+    pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, T, B> {
+        Difference {
+            inner: self.iter(),
+            other,
+        }
+    }
+
+    /// Creates an owned iterator over all elements in either `self` or `other`, each with the
+    /// absolute difference of its counts. Elements with a resulting count of zero are skipped.
+    /// (`self △ other`)
+    pub fn into_symmetric_difference(self, other: Self) -> IntoSymmetricDifference<T, B> {
+        IntoSymmetricDifference {
+            inner: BagMerge::new(self, other),
+        }
+    }
+
+    /// Creates a borrowed iterator over all elements in either `self` or `other`, each with the
+    /// absolute difference of its counts. Elements with a resulting count of zero are skipped.
+    /// (`self △ other`)
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, T, B> {
+        SymmetricDifference {
+            inner: self.difference(other).chain(other.difference(self)),
+        }
+    }
+
+    /// Creates an owned iterator over all elements in both `self` and `other`, each with the
+    /// smaller of its counts. (`self ∩ other`)
+    pub fn into_intersection(self, other: Self) -> IntoIntersection<T, B> {
+        IntoIntersection {
+            inner: self.into_iter(),
+            other,
+        }
+    }
+
+    /// Creates a borrowed iterator over all elements in both `self` and `other`, each with the
+    /// smaller of its counts. (`self ∩ other`)
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, T, B> {
+        Intersection {
+            inner: self.iter(),
+            other,
+        }
+    }
+
+    /// Creates an owned iterator over all elements in either `self` or `other`, each with the
+    /// larger of its counts. (`self ∪ other`)
+    pub fn into_union(self, other: Self) -> IntoUnion<T, B> {
+        IntoUnion {
+            inner: BagMerge::new(self, other),
+        }
+    }
+
+    /// Creates a borrowed iterator over all elements in either `self` or `other`, each with the
+    /// larger of its counts. (`self ∪ other`)
+    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, T, B> {
+        Union {
+            inner: self.iter(),
+            other_inner: other.iter(),
+            this: self,
+            other,
+        }
+    }
+
+    /// Returns true if every element of `self` occurs at least as many times in `other`. (`self ⊆
+    /// other`)
+    pub fn is_subset(&self, other: &Self) -> bool {
+        other.is_superset(self)
+    }
+
+    /// Returns true if every element of `other` occurs at least as many times in `self`. (`self ⊇
+    /// other`)
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.len() <= self.len()
+            && other.iter().all(|(item, count)| self.count(item) >= count)
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher + Default> Default for HashBag<T, B> {
+    fn default() -> Self {
+        HashBag::new()
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> Extend<T> for HashBag<T, B> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+
+    fn extend_one(&mut self, item: T) {
+        self.insert(item);
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher + Default> FromIterator<T> for HashBag<T, B> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bag = HashBag::new();
+        bag.extend(iter);
+        bag
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> PartialEq for HashBag<T, B> {
+    /// Two HashBags are considered equal if they contain exactly the same elements, with the same
+    /// counts.
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self.total_len() == other.total_len()
+            && self.is_subset(other)
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> Eq for HashBag<T, B> {}
+
+impl<T: Hash + Eq + Debug, B: BuildHasher + Debug> Debug for HashBag<T, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HashBag")
+            .field("map", &self.map)
+            .field("total_len", &self.total_len)
+            .finish()
+    }
+}
+
+impl<T: Hash + Eq + Debug, B: BuildHasher> Display for HashBag<T, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "#")?;
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,540 @@
+use std::cmp;
+use std::hash::{BuildHasher, Hash};
+use std::iter::{Chain, FusedIterator};
+use std::slice::Iter as ArrIter;
+
+use super::{HashBag, HashMultiMap};
+use crate::collections::contiguous::Vector;
+use crate::collections::hash::map::{IntoIter as MapIntoIter, Iter as MapIter, Keys as MapKeys};
+
+impl<K: Hash + Eq, V, B: BuildHasher> IntoIterator for HashMultiMap<K, V, B> {
+    type Item = (K, Vector<V>);
+
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.map.into_iter())
+    }
+}
+
+/// A type for owned iteration over a [`HashMultiMap`]'s groups. Produces values of type
+/// `(K, Vector<V>)`.
+///
+/// See [`HashMultiMap::into_iter`].
+pub struct IntoIter<K, V>(pub(crate) MapIntoIter<K, Vector<V>>);
+
+impl<K: Hash + Eq, V> Iterator for IntoIter<K, V> {
+    type Item = (K, Vector<V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K: Hash + Eq, V> FusedIterator for IntoIter<K, V> {}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher> IntoIterator for &'a HashMultiMap<K, V, B> {
+    type Item = (&'a K, &'a [V]);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter(self.map.iter())
+    }
+}
+
+/// A type for borrowed iteration over a [`HashMultiMap`]'s groups. Produces values of type
+/// `(&K, &[V])`.
+///
+/// See [`HashMultiMap::iter`].
+pub struct Iter<'a, K, V>(pub(crate) MapIter<'a, K, Vector<V>>);
+
+impl<'a, K: Hash + Eq, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a [V]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, group)| (key, &**group))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K: Hash + Eq, V> FusedIterator for Iter<'a, K, V> {}
+
+/// A type for borrowed iteration over every key-value pair in a [`HashMultiMap`]. Produces values
+/// of type `(&K, &V)`.
+///
+/// See [`HashMultiMap::flat_iter`].
+pub struct FlatIter<'a, K, V> {
+    pub(crate) groups: MapIter<'a, K, Vector<V>>,
+    pub(crate) front: Option<(&'a K, ArrIter<'a, V>)>,
+    pub(crate) len: usize,
+}
+
+impl<'a, K: Hash + Eq, V> Iterator for FlatIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, values)) = &mut self.front
+                && let Some(value) = values.next()
+            {
+                self.len -= 1;
+                return Some((key, value));
+            }
+
+            let (key, group) = self.groups.next()?;
+            self.front = Some((key, group.iter()));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K: Hash + Eq, V> ExactSizeIterator for FlatIter<'a, K, V> {}
+
+impl<'a, K: Hash + Eq, V> FusedIterator for FlatIter<'a, K, V> {}
+
+/// A type for borrowed iteration over a [`HashMultiMap`]'s distinct keys. Produces values of type
+/// `&K`.
+///
+/// See [`HashMultiMap::keys`].
+pub struct Keys<'a, K, V>(pub(crate) MapKeys<'a, K, Vector<V>>);
+
+impl<'a, K: Hash + Eq, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K: Hash + Eq, V> FusedIterator for Keys<'a, K, V> {}
+
+/// A type for borrowed iteration over all values in a [`HashMultiMap`]. Produces values of type
+/// `&V`.
+///
+/// See [`HashMultiMap::values`].
+pub struct Values<'a, K, V>(pub(crate) FlatIter<'a, K, V>);
+
+impl<'a, K: Hash + Eq, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K: Hash + Eq, V> ExactSizeIterator for Values<'a, K, V> {}
+
+impl<'a, K: Hash + Eq, V> FusedIterator for Values<'a, K, V> {}
+
+impl<T: Hash + Eq, B: BuildHasher> IntoIterator for HashBag<T, B> {
+    type Item = (T, usize);
+
+    type IntoIter = BagIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BagIntoIter(self.map.into_iter())
+    }
+}
+
+/// A type for owned iteration over a [`HashBag`]. Produces values of type `(T, usize)`, each
+/// distinct element with its count.
+///
+/// See [`HashBag::into_iter`].
+pub struct BagIntoIter<T>(pub(crate) MapIntoIter<T, usize>);
+
+impl<T: Hash + Eq> Iterator for BagIntoIter<T> {
+    type Item = (T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T: Hash + Eq> FusedIterator for BagIntoIter<T> {}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> IntoIterator for &'a HashBag<T, B> {
+    type Item = (&'a T, usize);
+
+    type IntoIter = BagIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BagIter(self.map.iter())
+    }
+}
+
+/// A type for borrowed iteration over a [`HashBag`]. Produces values of type `(&T, usize)`, each
+/// distinct element with its count.
+///
+/// See [`HashBag::iter`].
+pub struct BagIter<'a, T>(pub(crate) MapIter<'a, T, usize>);
+
+impl<'a, T: Hash + Eq> Iterator for BagIter<'a, T> {
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(item, count)| (item, *count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T: Hash + Eq> FusedIterator for BagIter<'a, T> {}
+
+/// A type for borrowed iteration over every occurrence of every element in a [`HashBag`]. Produces
+/// values of type `&T`.
+///
+/// See [`HashBag::flat_iter`].
+pub struct BagFlatIter<'a, T> {
+    pub(crate) groups: MapIter<'a, T, usize>,
+    pub(crate) front: Option<(&'a T, usize)>,
+    pub(crate) len: usize,
+}
+
+impl<'a, T: Hash + Eq> Iterator for BagFlatIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((item, remaining)) = &mut self.front
+                && *remaining > 0
+            {
+                *remaining -= 1;
+                self.len -= 1;
+                return Some(item);
+            }
+
+            let (item, count) = self.groups.next()?;
+            self.front = Some((item, *count));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T: Hash + Eq> ExactSizeIterator for BagFlatIter<'a, T> {}
+
+impl<'a, T: Hash + Eq> FusedIterator for BagFlatIter<'a, T> {}
+
+/// A type for borrowed iteration over every occurrence of a single element in a [`HashBag`].
+/// Produces values of type `&T`.
+///
+/// See [`HashBag::get_all`].
+pub struct GetAll<'a, T> {
+    pub(crate) item: Option<&'a T>,
+    pub(crate) remaining: usize,
+}
+
+impl<'a, T> Iterator for GetAll<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        self.item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for GetAll<'a, T> {}
+
+impl<'a, T> FusedIterator for GetAll<'a, T> {}
+
+/// A type for borrowed iteration over the multiset difference of two [`HashBag`]s. Produces values
+/// of type `(&T, usize)`.
+///
+/// See [`HashBag::difference`].
+pub struct Difference<'a, T: Hash + Eq, B: BuildHasher> {
+    pub(crate) inner: BagIter<'a, T>,
+    pub(crate) other: &'a HashBag<T, B>,
+}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> Iterator for Difference<'a, T, B> {
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (item, count) in self.inner.by_ref() {
+            let remaining = count.saturating_sub(self.other.count(item));
+            if remaining > 0 {
+                return Some((item, remaining));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> FusedIterator for Difference<'a, T, B> {}
+
+/// A type for borrowed iteration over the multiset symmetric difference of two [`HashBag`]s.
+/// Produces values of type `(&T, usize)`.
+///
+/// See [`HashBag::symmetric_difference`].
+pub struct SymmetricDifference<'a, T: Hash + Eq, B: BuildHasher> {
+    pub(crate) inner: Chain<Difference<'a, T, B>, Difference<'a, T, B>>,
+}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> Iterator for SymmetricDifference<'a, T, B> {
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> FusedIterator for SymmetricDifference<'a, T, B> {}
+
+/// A type for borrowed iteration over the multiset intersection of two [`HashBag`]s. Produces
+/// values of type `(&T, usize)`.
+///
+/// See [`HashBag::intersection`].
+pub struct Intersection<'a, T: Hash + Eq, B: BuildHasher> {
+    pub(crate) inner: BagIter<'a, T>,
+    pub(crate) other: &'a HashBag<T, B>,
+}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> Iterator for Intersection<'a, T, B> {
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (item, count) in self.inner.by_ref() {
+            let shared = cmp::min(count, self.other.count(item));
+            if shared > 0 {
+                return Some((item, shared));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> FusedIterator for Intersection<'a, T, B> {}
+
+/// A type for borrowed iteration over the multiset union of two [`HashBag`]s. Produces values of
+/// type `(&T, usize)`.
+///
+/// See [`HashBag::union`].
+pub struct Union<'a, T: Hash + Eq, B: BuildHasher> {
+    pub(crate) inner: BagIter<'a, T>,
+    pub(crate) other_inner: BagIter<'a, T>,
+    pub(crate) this: &'a HashBag<T, B>,
+    pub(crate) other: &'a HashBag<T, B>,
+}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> Iterator for Union<'a, T, B> {
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((item, count)) = self.inner.next() {
+            return Some((item, cmp::max(count, self.other.count(item))));
+        }
+
+        // Elements in both HashBags have already been produced with the larger count.
+        self.other_inner.by_ref().find(|(item, _)| !self.this.contains(*item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, a) = self.inner.size_hint();
+        let (_, b) = self.other_inner.size_hint();
+        (0, a.zip(b).and_then(|(a, b)| a.checked_add(b)))
+    }
+}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> FusedIterator for Union<'a, T, B> {}
+
+/// A type for owned iteration over the multiset difference of two [`HashBag`]s. Produces values
+/// of type `(T, usize)`.
+///
+/// See [`HashBag::into_difference`].
+pub struct IntoDifference<T: Hash + Eq, B: BuildHasher> {
+    pub(crate) inner: BagIntoIter<T>,
+    pub(crate) other: HashBag<T, B>,
+}
+
+impl<T: Hash + Eq, B: BuildHasher> Iterator for IntoDifference<T, B> {
+    type Item = (T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (item, count) in self.inner.by_ref() {
+            let remaining = count.saturating_sub(self.other.count(&item));
+            if remaining > 0 {
+                return Some((item, remaining));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> FusedIterator for IntoDifference<T, B> {}
+
+/// A type for owned iteration over the multiset symmetric difference of two [`HashBag`]s.
+/// Produces values of type `(T, usize)`.
+///
+/// See [`HashBag::into_symmetric_difference`].
+pub struct IntoSymmetricDifference<T: Hash + Eq, B: BuildHasher> {
+    pub(crate) inner: BagMerge<T, B>,
+}
+
+impl<T: Hash + Eq, B: BuildHasher> Iterator for IntoSymmetricDifference<T, B> {
+    type Item = (T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next(usize::abs_diff)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> FusedIterator for IntoSymmetricDifference<T, B> {}
+
+/// A type for owned iteration over the multiset intersection of two [`HashBag`]s. Produces values
+/// of type `(T, usize)`.
+///
+/// See [`HashBag::into_intersection`].
+pub struct IntoIntersection<T: Hash + Eq, B: BuildHasher> {
+    pub(crate) inner: BagIntoIter<T>,
+    pub(crate) other: HashBag<T, B>,
+}
+
+impl<T: Hash + Eq, B: BuildHasher> Iterator for IntoIntersection<T, B> {
+    type Item = (T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (item, count) in self.inner.by_ref() {
+            let shared = cmp::min(count, self.other.count(&item));
+            if shared > 0 {
+                return Some((item, shared));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> FusedIterator for IntoIntersection<T, B> {}
+
+/// A type for owned iteration over the multiset union of two [`HashBag`]s. Produces values of type
+/// `(T, usize)`.
+///
+/// See [`HashBag::into_union`].
+pub struct IntoUnion<T: Hash + Eq, B: BuildHasher> {
+    pub(crate) inner: BagMerge<T, B>,
+}
+
+impl<T: Hash + Eq, B: BuildHasher> Iterator for IntoUnion<T, B> {
+    type Item = (T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next(cmp::max)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: Hash + Eq, B: BuildHasher> FusedIterator for IntoUnion<T, B> {}
+
+/// The state shared by owned iterators which visit the elements of two [`HashBag`]s: first those
+/// of the first HashBag, removing each from `other` to combine the counts, and then those which
+/// are only in `other`.
+pub(crate) struct BagMerge<T: Hash + Eq, B: BuildHasher> {
+    pub(crate) inner: BagIntoIter<T>,
+    pub(crate) other: Option<HashBag<T, B>>,
+    pub(crate) other_inner: Option<BagIntoIter<T>>,
+}
+
+impl<T: Hash + Eq, B: BuildHasher> BagMerge<T, B> {
+    /// Creates a new BagMerge over the elements of `this` and then `other`.
+    pub(crate) fn new(this: HashBag<T, B>, other: HashBag<T, B>) -> BagMerge<T, B> {
+        BagMerge {
+            inner: this.into_iter(),
+            other: Some(other),
+            other_inner: None,
+        }
+    }
+
+    /// Produces the next element in either HashBag, with its counts combined by `combine`. An
+    /// element which is only in one HashBag has a count of zero in the other. Elements with a
+    /// combined count of zero are skipped.
+    fn next<F: Fn(usize, usize) -> usize>(&mut self, combine: F) -> Option<(T, usize)> {
+        if let Some(other) = &mut self.other {
+            for (item, count) in self.inner.by_ref() {
+                let other_count = other.remove_all(&item).map_or(0, |(_, count)| count);
+                let combined = combine(count, other_count);
+                if combined > 0 {
+                    return Some((item, combined));
+                }
+            }
+
+            // The elements left in other aren't in the first HashBag.
+            self.other_inner = self.other.take().map(HashBag::into_iter);
+        }
+
+        self.other_inner.as_mut()?.find_map(|(item, count)| {
+            let combined = combine(0, count);
+            (combined > 0).then_some((item, combined))
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.inner.size_hint();
+        let other_len = match (&self.other, &self.other_inner) {
+            (Some(other), _) => other.len(),
+            (None, Some(other_inner)) => other_inner.size_hint().0,
+            (None, None) => 0,
+        };
+        (0, upper.and_then(|upper| upper.checked_add(other_len)))
+    }
+}
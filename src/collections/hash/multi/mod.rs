@@ -0,0 +1,16 @@
+//! A module containing [`HashMultiMap`] and [`HashBag`] and associated types.
+//!
+//! [`HashMultiMap`] associates each key with a group of values, while [`HashBag`] counts the
+//! occurrences of each element. The other included types provide grouped and flattened iteration
+//! over both collections, as well as the multiset operations for [`HashBag`].
+//!
+//! [`HashMultiMap`] and [`HashBag`] are also re-exported under the parent module.
+
+mod bag;
+mod iter;
+mod multi_map;
+mod tests;
+
+pub use bag::*;
+pub use iter::*;
+pub use multi_map::*;
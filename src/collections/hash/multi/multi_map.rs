@@ -0,0 +1,254 @@
+use std::borrow::Borrow;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{BuildHasher, Hash, RandomState};
+
+use super::{FlatIter, Iter, Keys, Values};
+use crate::collections::contiguous::Vector;
+use crate::collections::hash::HashMap;
+use crate::collections::hash::map::OccupiedEntry;
+
+/// A map from keys to groups of values, with the help of the [`Hash`] trait. Each key can be
+/// associated with any number of values, which are kept in the order they were inserted.
+///
+/// Relies on a [`HashMap`] of [`Vector`]s internally, see documentation there for additional
+/// details. A key is only present in the HashMultiMap while it has at least one value.
+///
+/// [`len`](HashMultiMap::len) returns the number of distinct keys, while
+/// [`total_len`](HashMultiMap::total_len) returns the number of values across all keys.
+///
+/// # Time Complexity
+/// See [`HashMap`] with the following additions.
+///
+/// Variables are defined as follows:
+/// - `n`: The number of values in the HashMultiMap.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `total_len` | `O(1)` |
+/// | `insert` | `O(1)`*, `O(n)` |
+/// | `remove_one` | `O(1)` |
+/// | `remove_all` | `O(1)` |
+/// | `get_all` | `O(1)` |
+/// | `count` | `O(1)` |
+/// | `flat_iter`**, `values`** | `O(n)` |
+///
+/// \* If either the HashMap or the key's group doesn't have enough capacity for the new value,
+/// `insert` will take `O(n)`.
+///
+/// \** When exhausted.
+pub struct HashMultiMap<K: Hash + Eq, V, B: BuildHasher = RandomState> {
+    pub(crate) map: HashMap<K, Vector<V>, B>,
+    pub(crate) total_len: usize,
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default> HashMultiMap<K, V, B> {
+    /// Creates a new HashMultiMap with capacity 0 and the default value for `B`. Memory will be
+    /// allocated when the capacity changes.
+    pub fn new() -> HashMultiMap<K, V, B> {
+        HashMultiMap {
+            map: HashMap::new(),
+            total_len: 0,
+        }
+    }
+
+    /// Creates a new HashMultiMap with enough capacity for `cap` distinct keys and the default
+    /// value for `B`.
+    pub fn with_cap(cap: usize) -> HashMultiMap<K, V, B> {
+        HashMultiMap {
+            map: HashMap::with_cap(cap),
+            total_len: 0,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher> HashMultiMap<K, V, B> {
+    /// Creates a new HashMultiMap with capacity 0 and the provided `hasher`.
+    pub fn with_hasher(hasher: B) -> HashMultiMap<K, V, B> {
+        HashMultiMap {
+            map: HashMap::with_hasher(hasher),
+            total_len: 0,
+        }
+    }
+
+    /// Creates a new HashMultiMap with enough capacity for `cap` distinct keys and the provided
+    /// `hasher`.
+    pub fn with_cap_and_hasher(cap: usize, hasher: B) -> HashMultiMap<K, V, B> {
+        HashMultiMap {
+            map: HashMap::with_cap_and_hasher(cap, hasher),
+            total_len: 0,
+        }
+    }
+
+    /// Returns the number of distinct keys in the HashMultiMap.
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns the total number of values in the HashMultiMap, across all keys.
+    pub const fn total_len(&self) -> usize {
+        self.total_len
+    }
+
+    /// Returns true if the HashMultiMap contains no values.
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns a reference to the HashMultiMap's hasher.
+    pub const fn hasher(&self) -> &B {
+        self.map.hasher()
+    }
+
+    /// Adds `value` to the end of the group associated with `key`, creating the group if `key` is
+    /// not yet present.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.map.entry(key).or_default().push(value);
+        self.total_len += 1;
+    }
+
+    /// Removes the most recently inserted value associated with `key`, returning it if there is
+    /// one. If this was the last value for `key`, the key is removed too.
+    pub fn remove_one<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.map.find_index_for_key(key)?;
+        let mut entry = OccupiedEntry {
+            map: &mut self.map,
+            index,
+        };
+        let value = entry.get_mut().pop();
+
+        // Removing through the entry avoids hashing and probing for the key a second time.
+        if entry.get().is_empty() {
+            entry.remove();
+        }
+
+        self.total_len -= 1;
+        value
+    }
+
+    /// Removes `key` and all of its associated values from the HashMultiMap, returning the values
+    /// in insertion order if the key was present.
+    pub fn remove_all<Q>(&mut self, key: &Q) -> Option<Vector<V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let group = self.map.remove(key)?;
+        self.total_len -= group.len();
+        Some(group)
+    }
+
+    /// Returns a slice of all values associated with `key`, in insertion order. If `key` isn't
+    /// present, the slice is empty.
+    pub fn get_all<Q>(&self, key: &Q) -> &[V]
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|group| &**group).unwrap_or(&[])
+    }
+
+    /// Returns a mutable slice of all values associated with `key`, in insertion order. If `key`
+    /// isn't present, the slice is empty.
+    pub fn get_all_mut<Q>(&mut self, key: &Q) -> &mut [V]
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_mut(key).map(|group| &mut **group).unwrap_or(&mut [])
+    }
+
+    /// Returns the number of values associated with `key`.
+    pub fn count<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map_or(0, |group| group.len())
+    }
+
+    /// Returns true if at least one value is associated with `key`.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains(key)
+    }
+
+    /// Increases the capacity of the HashMultiMap to ensure that len + `extra` distinct keys will
+    /// fit.
+    pub fn reserve(&mut self, extra: usize) {
+        self.map.reserve(extra)
+    }
+
+    /// Returns an iterator over all keys and their groups of values, as references.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.into_iter()
+    }
+
+    /// Returns an iterator over every key-value pair in the HashMultiMap, as references. Keys are
+    /// repeated once for each of their values.
+    pub fn flat_iter(&self) -> FlatIter<'_, K, V> {
+        FlatIter {
+            groups: self.map.iter(),
+            front: None,
+            len: self.total_len,
+        }
+    }
+
+    /// Returns an iterator over all distinct keys in the HashMultiMap, as references.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.map.keys())
+    }
+
+    /// Returns an iterator over all values in the HashMultiMap, as references.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.flat_iter())
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default> Default for HashMultiMap<K, V, B> {
+    fn default() -> Self {
+        HashMultiMap::new()
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher> Extend<(K, V)> for HashMultiMap<K, V, B> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+
+    fn extend_one(&mut self, (key, value): (K, V)) {
+        self.insert(key, value);
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default> FromIterator<(K, V)> for HashMultiMap<K, V, B> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = HashMultiMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Hash + Eq + Debug, V: Debug, B: BuildHasher + Debug> Debug for HashMultiMap<K, V, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HashMultiMap")
+            .field("map", &self.map)
+            .field("total_len", &self.total_len)
+            .finish()
+    }
+}
+
+impl<K: Hash + Eq + Debug, V: Debug, B: BuildHasher> Display for HashMultiMap<K, V, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "#")?;
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
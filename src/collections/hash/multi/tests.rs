@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use super::*;
+use crate::collections::contiguous::Vector;
+use crate::collections::hash::HashMap;
+use crate::util::alloc::CountedDrop;
+
+#[test]
+fn test_multi_map_groups() {
+    let mut map: HashMultiMap<&str, usize> = HashMultiMap::new();
+    map.insert("a", 1);
+    map.insert("b", 2);
+    map.insert("a", 3);
+    map.insert("a", 4);
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.total_len(), 4);
+    assert_eq!(map.get_all("a"), [1, 3, 4], "Values should be kept in insertion order.");
+    assert_eq!(map.get_all("c"), []);
+    assert_eq!(map.count("a"), 3);
+    assert_eq!(map.count("c"), 0);
+
+    assert_eq!(map.remove_one("a"), Some(4));
+    assert_eq!(map.remove_one("b"), Some(2));
+    assert!(!map.contains("b"), "Keys should be removed along with their last value.");
+    assert_eq!(map.remove_one("b"), None);
+    assert_eq!(map.total_len(), 2);
+
+    assert_eq!(map.remove_all("a").as_deref(), Some(&[1, 3][..]));
+    assert_eq!(map.remove_all("a"), None);
+    assert!(map.is_empty());
+    assert_eq!(map.total_len(), 0);
+}
+
+#[test]
+fn test_multi_map_iteration() {
+    let map: HashMultiMap<usize, usize> = (0..30).map(|i| (i % 3, i)).collect();
+    assert_eq!(map.iter().count(), 3);
+    assert!(map.iter().all(|(key, group)| {
+        group.len() == 10 && group.iter().all(|v| v % 3 == *key)
+    }));
+
+    let flat = map.flat_iter();
+    assert_eq!(flat.len(), 30);
+    let mut pairs: Vector<(usize, usize)> = flat.map(|(k, v)| (*k, *v)).collect();
+    pairs.sort();
+    let mut expected: Vector<(usize, usize)> = (0..30).map(|i| (i % 3, i)).collect();
+    expected.sort();
+    assert_eq!(pairs, expected, "Every key should be repeated once for each of its values.");
+
+    assert_eq!(map.values().copied().sum::<usize>(), (0..30).sum());
+    assert_eq!(map.keys().copied().sum::<usize>(), 3);
+
+    let owned: HashMap<usize, Vector<usize>> = map.into_iter().collect();
+    assert_eq!(*owned[&1], [1, 4, 7, 10, 13, 16, 19, 22, 25, 28]);
+}
+
+#[test]
+fn test_multi_map_drops() {
+    let counter = CountedDrop::new(0);
+    let mut map: HashMultiMap<usize, CountedDrop> = HashMultiMap::new();
+    for i in 0..20 {
+        map.insert(i % 4, counter.clone());
+    }
+
+    map.remove_one(&0);
+    drop(map.remove_all(&1));
+    assert_eq!(*counter.borrow(), 6);
+
+    drop(map);
+    assert_eq!(*counter.borrow(), 20, "Every value should be dropped exactly once.");
+}
+
+#[test]
+fn test_bag_counts() {
+    let mut bag: HashBag<char> = "mississippi".chars().collect();
+    assert_eq!(bag.len(), 4);
+    assert_eq!(bag.total_len(), 11);
+    assert_eq!(bag.count(&'s'), 4);
+    assert_eq!(bag.count(&'x'), 0);
+    assert_eq!(bag.get_all(&'p').collect::<Vector<_>>(), [&'p', &'p'].into_iter().collect());
+    assert_eq!(bag.get_all(&'x').len(), 0);
+
+    assert_eq!(bag.insert('m'), 1);
+    assert_eq!(bag.insert_many('z', 3), 0);
+    assert_eq!(bag.remove_one(&'m'), 2);
+    assert_eq!(bag.remove_many(&'s', 3), 4);
+    assert_eq!(bag.count(&'s'), 1);
+    assert_eq!(bag.remove_many(&'i', 10), 4);
+    assert!(!bag.contains(&'i'), "Elements should be removed when their count reaches zero.");
+    assert_eq!(bag.remove_one(&'i'), 0);
+    assert_eq!(bag.remove_all(&'z'), Some(('z', 3)));
+    assert_eq!(bag.remove_all(&'z'), None);
+
+    assert_eq!(bag.total_len(), 4);
+    assert_eq!(bag.flat_iter().len(), 4);
+    let mut flat: Vector<char> = bag.flat_iter().copied().collect();
+    flat.sort();
+    assert_eq!(*flat, ['m', 'p', 'p', 's']);
+}
+
+#[test]
+fn test_bag_operations() {
+    let a: HashBag<usize> = [1, 1, 1, 2, 2, 3].into_iter().collect();
+    let b: HashBag<usize> = [1, 2, 2, 2, 4].into_iter().collect();
+
+    let collect = |iter: &mut dyn Iterator<Item = (&usize, usize)>| {
+        let mut result: Vector<(usize, usize)> = iter.map(|(item, count)| (*item, count)).collect();
+        result.sort();
+        result
+    };
+
+    assert_eq!(*collect(&mut a.union(&b)), [(1, 3), (2, 3), (3, 1), (4, 1)]);
+    assert_eq!(*collect(&mut a.intersection(&b)), [(1, 1), (2, 2)]);
+    assert_eq!(*collect(&mut a.difference(&b)), [(1, 2), (3, 1)]);
+    assert_eq!(*collect(&mut b.difference(&a)), [(2, 1), (4, 1)]);
+    assert_eq!(*collect(&mut a.symmetric_difference(&b)), [(1, 2), (2, 1), (3, 1), (4, 1)]);
+
+    let mut difference = a.difference(&a);
+    assert_eq!(difference.size_hint(), (0, Some(3)), "Items may all be filtered out.");
+    assert_eq!(difference.next(), None);
+    assert_eq!(a.symmetric_difference(&a).size_hint(), (0, Some(6)));
+    assert_eq!(a.intersection(&HashBag::new()).size_hint().0, 0);
+
+    let small: HashBag<usize> = [1, 1, 2].into_iter().collect();
+    assert!(small.is_subset(&a));
+    assert!(!small.is_subset(&b), "Subsets should account for counts.");
+    assert!(a.is_superset(&small));
+
+    assert_eq!(a, [3, 2, 1, 2, 1, 1].into_iter().collect());
+    assert_ne!(a, small);
+}
+
+#[test]
+fn test_bag_owned_operations() {
+    let a = || [1, 1, 1, 2, 2, 3].into_iter().collect::<HashBag<usize>>();
+    let b = || [1, 2, 2, 2, 4].into_iter().collect::<HashBag<usize>>();
+
+    let collect = |iter: &mut dyn Iterator<Item = (usize, usize)>| {
+        let mut result: Vector<(usize, usize)> = iter.collect();
+        result.sort();
+        result
+    };
+
+    assert_eq!(*collect(&mut a().into_union(b())), [(1, 3), (2, 3), (3, 1), (4, 1)]);
+    assert_eq!(*collect(&mut a().into_intersection(b())), [(1, 1), (2, 2)]);
+    assert_eq!(*collect(&mut a().into_difference(b())), [(1, 2), (3, 1)]);
+    assert_eq!(*collect(&mut b().into_difference(a())), [(2, 1), (4, 1)]);
+    assert_eq!(
+        *collect(&mut a().into_symmetric_difference(b())),
+        [(1, 2), (2, 1), (3, 1), (4, 1)]
+    );
+
+    let mut union = a().into_union(HashBag::new());
+    assert_eq!(union.size_hint(), (0, Some(3)));
+    assert_eq!(union.by_ref().count(), 3);
+    assert_eq!(union.next(), None);
+}
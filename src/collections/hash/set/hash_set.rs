@@ -4,21 +4,21 @@ use std::hash::{BuildHasher, Hash, RandomState};
 use std::iter::TrustedLen;
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign};
 
-use super::Iter;
-use crate::collections::contiguous::Vector;
+use super::{Drain, ExtractIf, Iter};
 use crate::collections::hash::HashMap;
+use crate::collections::hash::map::ExtractState;
 #[doc(inline)]
-pub use crate::collections::hash::map::IndexNoCap;
+pub use crate::collections::hash::map::{HashPolicy, IndexNoCap};
 #[doc(inline)]
 pub use crate::collections::traits::set::{SetInterface, SetIterator};
 use crate::util::fmt::DebugRaw;
-use crate::util::result::ResultExtension;
 
 /// A set of values that prevents duplicates with the help of the [`Hash`] trait.
 ///
 /// Relies on [`HashMap`] internally, see documentation there for additional details.
 ///
-/// A custom load factor is not supported at this point, with the default being 4/5.
+/// The load factor, growth factor and minimum capacity can be configured with a [`HashPolicy`],
+/// which is forwarded to the inner HashMap. The default load factor is 4/5.
 ///
 /// It is a logic error for keys in a HashSet to be manipulated in a way that changes their hash.
 /// Because of this, HashSet's API prevents mutable access to its keys.
@@ -42,6 +42,8 @@ use crate::util::result::ResultExtension;
 /// | `\|=` | `O(n+m)`**, `O(m)` |
 /// | `is_subset` | `O(m)` |
 /// | `is_superset` | `O(n)` |
+/// | `retain` | `O(n)` |
+/// | `drain`*, `extract_if`* | `O(n)` |
 ///
 /// In the event of a has collision, all methods will take additional time. This additional time is
 /// kept at a minimum and hash collisions are unlikely especially with a large capacity.
@@ -71,6 +73,14 @@ impl<T: Hash + Eq, B: BuildHasher + Default> HashSet<T, B> {
             inner: HashMap::with_cap(cap),
         }
     }
+
+    /// Creates a new HashSet with capacity 0, the provided `policy` and the default value for `B`.
+    /// Memory will be allocated according to `policy` when the capacity changes.
+    pub fn with_policy(policy: HashPolicy) -> HashSet<T, B> {
+        HashSet {
+            inner: HashMap::with_policy(policy),
+        }
+    }
 }
 
 impl<T: Hash + Eq, B: BuildHasher> HashSet<T, B> {
@@ -88,6 +98,13 @@ impl<T: Hash + Eq, B: BuildHasher> HashSet<T, B> {
         }
     }
 
+    /// Creates a new HashSet with the provided `cap`acity, `hasher` and `policy`.
+    pub fn with_cap_hasher_and_policy(cap: usize, hasher: B, policy: HashPolicy) -> HashSet<T, B> {
+        HashSet {
+            inner: HashMap::with_cap_hasher_and_policy(cap, hasher, policy),
+        }
+    }
+
     /// Returns the length of the HashSet (the number of elements it contains).
     pub const fn len(&self) -> usize {
         self.inner.len()
@@ -103,27 +120,17 @@ impl<T: Hash + Eq, B: BuildHasher> HashSet<T, B> {
         self.inner.cap()
     }
 
+    /// Returns the [`HashPolicy`] used by the HashSet to determine when and how to allocate.
+    pub const fn policy(&self) -> HashPolicy {
+        self.inner.policy()
+    }
+
     /// Inserts the provided item into the HashSet, increasing its capacity if required. If the item
     /// was already included, no change is made and the method returns false. In other words, the
     /// method returns true if the insertion changes the HashSet.
     pub fn insert(&mut self, item: T) -> bool {
-        if self.inner.should_grow() {
-            self.inner.grow()
-        }
-
-        // SAFETY: We've just grown if necessary.
-        let index = unsafe { self.inner.find_index_for_key(&item).unwrap_unchecked() };
-
-        // The Bucket at index is either empty or contains an equal item.
-        match self.inner.arr[index] {
-            Some(_) => false,
-            None => {
-                // Create a new Bucket with the provided values.
-                self.inner.arr[index] = Some((item, ()));
-                self.inner.len += 1;
-                true
-            },
-        }
+        // Inserting a unit value doesn't replace the existing item.
+        self.inner.insert(item, ()).is_none()
     }
 
     /// Inserts the provided item, without checking if the HashSet has enough capacity. If the item
@@ -138,18 +145,9 @@ impl<T: Hash + Eq, B: BuildHasher> HashSet<T, B> {
     /// Panics if the HashSet has a capacity of 0, as it isn't possible to find a bucket associated
     /// with the item.
     pub unsafe fn insert_unchecked(&mut self, item: T) -> bool {
-        let index = self.inner.find_index_for_key(&item).ok_or(IndexNoCap).throw();
-
-        // The Bucket at index is either empty or contains an equal item.
-        match &mut self.inner.arr[index] {
-            Some(_) => true,
-            None => {
-                // Create a new Bucket with the provided values.
-                self.inner.arr[index] = Some((item, ()));
-                self.inner.len += 1;
-                false
-            },
-        }
+        // SAFETY: The safety requirements of this method are the same as those of
+        // HashMap::insert_unchecked.
+        unsafe { self.inner.insert_unchecked(item, ()) }.is_none()
     }
 
     /// Returns true if the HashSet contains `item`.
@@ -189,6 +187,46 @@ impl<T: Hash + Eq, B: BuildHasher> HashSet<T, B> {
         self.inner.reserve(extra)
     }
 
+    /// Shrinks the HashSet to the smallest capacity that can hold its current elements without
+    /// exceeding the load factor. An empty HashSet is deallocated entirely.
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+
+    /// Shrinks the HashSet so that its capacity is no less than `min_cap` and no less than the
+    /// smallest capacity that can hold its current elements without exceeding the load factor.
+    pub fn shrink_to(&mut self, min_cap: usize) {
+        self.inner.shrink_to(min_cap)
+    }
+
+    /// Retains only the elements for which `f` returns true, removing all others in place. `f` is
+    /// invoked exactly once for each element, in an unspecified order.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.inner.retain(|item, _| f(item))
+    }
+
+    /// Removes all elements from the HashSet, returning them as an iterator. The capacity of the
+    /// HashSet is retained, unless automatic shrinking is enabled by its [`HashPolicy`].
+    ///
+    /// If the returned iterator is dropped before being exhausted, all remaining elements are
+    /// dropped as well.
+    pub fn drain(&mut self) -> Drain<'_, T, B> {
+        Drain(self.inner.drain())
+    }
+
+    /// Returns an iterator which removes and produces every element for which `pred` returns true,
+    /// in an unspecified order. `pred` is invoked at most once for each element.
+    ///
+    /// If the returned iterator is dropped before being exhausted, the remaining elements are
+    /// retained, leaving the HashSet valid.
+    pub fn extract_if<F: FnMut(&T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, T, F, B> {
+        ExtractIf {
+            state: ExtractState::new(&self.inner),
+            map: &mut self.inner,
+            pred,
+        }
+    }
+
     /// Returns an iterator over all elements in the HashSet, as references.
     #[inline(always)]
     pub fn iter<'a>(&'a self) -> Iter<'a, T> {
@@ -250,21 +288,7 @@ impl<T: Hash + Eq + Clone, B: BuildHasher + Default> BitAnd for HashSet<T, B> {
 impl<T: Hash + Eq, B: BuildHasher> BitAndAssign for HashSet<T, B> {
     /// Removes all items not in `rhs` from `self` to form an intersection in place.
     fn bitand_assign(&mut self, rhs: Self) {
-        let mut to_remove = Vector::with_cap(self.cap());
-        for item in self.iter() {
-            if !rhs.contains(item) {
-                to_remove.push(
-                    // SAFETY: We are in a loop over self, so cap > 0.
-                    unsafe { self.inner.find_index_for_key(item).unwrap_unchecked() }
-                );
-            }
-        }
-        for index in to_remove {
-            if self.inner.arr[index].is_some() {
-                self.inner.arr[index] = None;
-                self.inner.len -= 1;
-            }
-        }
+        self.retain(|item| rhs.contains(item));
     }
 }
 
@@ -387,13 +411,14 @@ impl<T: Hash + Eq + Debug, B: BuildHasher + Debug> Debug for HashSet<T, B> {
             .field_with("buckets", |f| f.debug_list().entries(
                 self.inner.arr.iter()
                     .map(|o| DebugRaw(match o {
-                        Some((t, _)) => format!("{t:?}"),
+                        Some(pair) => format!("{:?}", pair.key),
                         None => "_".into(),
                     }))
             ).finish())
             .field("len", &self.len())
             .field("cap", &self.cap())
             .field("hasher", &self.inner.hasher)
+            .field("policy", &self.inner.policy)
             .finish()
     }
 }
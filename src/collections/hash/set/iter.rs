@@ -2,7 +2,8 @@ use std::hash::{BuildHasher, Hash};
 use std::iter::FusedIterator;
 
 use super::HashSet;
-use crate::collections::hash::map::{IntoKeys, Keys};
+use crate::collections::hash::HashMap;
+use crate::collections::hash::map::{Drain as MapDrain, ExtractState, IntoKeys, Keys};
 #[cfg(doc)]
 use crate::collections::traits::set::SetIterator;
 
@@ -63,3 +64,64 @@ impl<'a, T: Hash + Eq> Iterator for Iter<'a, T> {
 }
 
 impl<'a, T: Hash + Eq> FusedIterator for Iter<'a, T> {}
+
+/// A type for draining iteration over a [`HashSet`], which removes all elements while keeping the
+/// allocated capacity. Produces values of type `T`.
+///
+/// See [`HashSet::drain`].
+pub struct Drain<'a, T: Hash + Eq, B: BuildHasher>(pub(crate) MapDrain<'a, T, (), B>);
+
+impl<'a, T: Hash + Eq, B: BuildHasher> Iterator for Drain<'a, T, B> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(item, _)| item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> ExactSizeIterator for Drain<'a, T, B> {}
+
+impl<'a, T: Hash + Eq, B: BuildHasher> FusedIterator for Drain<'a, T, B> {}
+
+/// A type for in-place extraction from a [`HashSet`], removing and producing every element for
+/// which the predicate returns true. Produces values of type `T`.
+///
+/// If this iterator is dropped before being exhausted, the remaining elements are left in the
+/// HashSet.
+///
+/// See [`HashSet::extract_if`].
+pub struct ExtractIf<'a, T: Hash + Eq, F: FnMut(&T) -> bool, B: BuildHasher> {
+    pub(crate) map: &'a mut HashMap<T, (), B>,
+    pub(crate) state: ExtractState,
+    pub(crate) pred: F,
+}
+
+impl<'a, T: Hash + Eq, F: FnMut(&T) -> bool, B: BuildHasher> Iterator for ExtractIf<'a, T, F, B> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pred = &mut self.pred;
+        self.state.next(self.map, |item, _| pred(item)).map(|(item, _)| item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.map.len()))
+    }
+}
+
+impl<'a, T, F, B> FusedIterator for ExtractIf<'a, T, F, B>
+where
+    T: Hash + Eq,
+    F: FnMut(&T) -> bool,
+    B: BuildHasher,
+{}
+
+impl<'a, T: Hash + Eq, F: FnMut(&T) -> bool, B: BuildHasher> Drop for ExtractIf<'a, T, F, B> {
+    fn drop(&mut self) {
+        self.map.shrink_if_sparse();
+    }
+}
@@ -37,3 +37,26 @@ fn test_hash_collisions() {
         "Hash collisions should be handled in a wrapping manner."
     );
 }
+
+#[test]
+fn test_removing_iterators() {
+    let mut set: HashSet<usize> = (0..100).collect();
+
+    set.retain(|item| item % 2 == 0);
+    assert_eq!(set.len(), 50);
+    assert!((0..100).all(|item| set.contains(&item) == (item % 2 == 0)));
+
+    let mut extracted: Vector<_> = set.extract_if(|item| item % 4 == 0).collect();
+    extracted.sort();
+    assert_eq!(*extracted, *(0..100).step_by(4).collect::<Vector<_>>());
+    assert_eq!(set.len(), 25);
+
+    let cap = set.cap();
+    assert_eq!(set.drain().filter(|item| item % 4 == 2).count(), 25);
+    assert!(set.is_empty());
+    assert_eq!(set.cap(), cap, "Draining should retain the HashSet's capacity.");
+
+    let mut lhs: HashSet<usize> = (0..10).collect();
+    lhs &= (5..15).collect();
+    assert_eq!(lhs, (5..10).collect(), "&= should retain only the shared elements.");
+}
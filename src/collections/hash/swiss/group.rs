@@ -0,0 +1,118 @@
+use std::mem;
+
+/// The number of control bytes in a [`Group`].
+pub(crate) const GROUP_WIDTH: usize = mem::size_of::<u64>();
+
+/// The control byte of a bucket which is empty.
+pub(crate) const EMPTY: u8 = 0b1111_1111;
+
+/// The control byte of a bucket which is empty, but may have been passed over by a probe sequence.
+pub(crate) const DELETED: u8 = 0b1000_0000;
+
+/// A word with the lowest bit of each byte set.
+const LSB: u64 = 0x0101_0101_0101_0101;
+
+/// A word with the highest bit of each byte set.
+const MSB: u64 = 0x8080_8080_8080_8080;
+
+/// Returns the 7-bit tag for the provided `hash`, taken from its top bits so that it is independent
+/// of the bucket index, which is taken from the bottom bits.
+pub(crate) const fn tag(hash: u64) -> u8 {
+    (hash >> (u64::BITS - 7)) as u8
+}
+
+/// Returns true if the provided control byte represents a full bucket.
+pub(crate) const fn is_full(ctrl: u8) -> bool {
+    ctrl & 0b1000_0000 == 0
+}
+
+/// A group of [`GROUP_WIDTH`] control bytes, loaded into a single word.
+///
+/// Each bucket of a [`SwissMap`](super::SwissMap) has a control byte, which is one of:
+/// - [`EMPTY`] (`0b1111_1111`): The bucket has never been filled since the last rehash.
+/// - [`DELETED`] (`0b1000_0000`): A tombstone, left when removing an entry that a probe sequence
+///   may have passed over.
+/// - A tag (`0b0xxx_xxxx`): The bucket is full, and the tag holds the top 7 bits of its hash.
+///
+/// Because the high bit is only clear for full buckets, a Group can be searched with a handful of
+/// portable arithmetic and bitwise operations, rather than checking each byte (or worse, each key)
+/// individually.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Group(u64);
+
+impl Group {
+    /// Loads the first [`GROUP_WIDTH`] bytes of `ctrl` as a Group. The first byte is stored in the
+    /// least significant byte of the word, regardless of the platform's endianness.
+    ///
+    /// # Panics
+    /// Panics if `ctrl` is shorter than [`GROUP_WIDTH`].
+    pub(crate) fn load(ctrl: &[u8]) -> Group {
+        let mut bytes = [0; GROUP_WIDTH];
+        bytes.copy_from_slice(&ctrl[..GROUP_WIDTH]);
+        Group(u64::from_le_bytes(bytes))
+    }
+
+    /// Returns a [`BitMask`] of the bytes in this Group which are equal to `tag`.
+    ///
+    /// This uses the classic "has zero byte" trick on `group ^ repeat(tag)`, which can produce
+    /// false positives for a byte directly following a true match. False positives are always full
+    /// buckets though (because [`EMPTY`] and [`DELETED`] have their high bit set), so they are
+    /// harmless as long as the caller compares the key.
+    pub(crate) const fn match_tag(self, tag: u8) -> BitMask {
+        let cmp = self.0 ^ LSB.wrapping_mul(tag as u64);
+        BitMask(cmp.wrapping_sub(LSB) & !cmp & MSB)
+    }
+
+    /// Returns a [`BitMask`] of the bytes in this Group which are [`EMPTY`].
+    pub(crate) const fn match_empty(self) -> BitMask {
+        // Only EMPTY has both of its top two bits set.
+        BitMask(self.0 & (self.0 << 1) & MSB)
+    }
+
+    /// Returns a [`BitMask`] of the bytes in this Group which are [`EMPTY`] or [`DELETED`].
+    pub(crate) const fn match_empty_or_deleted(self) -> BitMask {
+        BitMask(self.0 & MSB)
+    }
+}
+
+/// A mask with the high bit of each matching byte in a [`Group`] set. Iterates over the indices of
+/// the matching bytes, in ascending order.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BitMask(u64);
+
+impl BitMask {
+    /// Returns true if any byte matched.
+    pub(crate) const fn any(self) -> bool {
+        self.0 != 0
+    }
+
+    /// Returns the index of the first matching byte, if any.
+    pub(crate) const fn lowest(self) -> Option<usize> {
+        if self.any() {
+            Some(self.leading_unmatched())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of bytes before the first match (or [`GROUP_WIDTH`] if there are none).
+    pub(crate) const fn leading_unmatched(self) -> usize {
+        self.0.trailing_zeros() as usize / 8
+    }
+
+    /// Returns the number of bytes after the last match (or [`GROUP_WIDTH`] if there are none).
+    pub(crate) const fn trailing_unmatched(self) -> usize {
+        self.0.leading_zeros() as usize / 8
+    }
+}
+
+impl Iterator for BitMask {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.lowest()?;
+        // Clear the lowest set bit.
+        self.0 &= self.0 - 1;
+        Some(index)
+    }
+}
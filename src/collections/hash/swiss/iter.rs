@@ -0,0 +1,350 @@
+use std::alloc::{Allocator, Global};
+use std::hash::{BuildHasher, Hash, RandomState};
+use std::iter::{FusedIterator, Zip};
+use std::mem::{self, MaybeUninit};
+use std::slice::Iter as ArrIter;
+use std::slice::IterMut as ArrIterMut;
+
+use super::SwissMap;
+use super::group;
+use super::raw_table::RawTable;
+
+impl<K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> IntoIterator for SwissMap<K, V, B, A> {
+    type Item = (K, V);
+
+    type IntoIter = IntoIter<K, V, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            table: self.table,
+            index: 0,
+        }
+    }
+}
+
+/// A type for owned iteration over a [`SwissMap`]. Produces values of type `(K, V)`.
+///
+/// See [`SwissMap::into_iter`].
+pub struct IntoIter<K, V, A: Allocator + Clone = Global> {
+    pub(crate) table: RawTable<K, V, A>,
+    pub(crate) index: usize,
+}
+
+impl<K: Hash + Eq, V, A: Allocator + Clone> Iterator for IntoIter<K, V, A> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.table.cap() {
+            let index = self.index;
+            self.index += 1;
+
+            if self.table.is_full(index) {
+                // SAFETY: The bucket is full. Removing the pair marks it as empty or deleted, so
+                // the table won't drop it again.
+                return Some(unsafe { self.table.remove_at(index) });
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.table.len, Some(self.table.len))
+    }
+}
+
+impl<K: Hash + Eq, V, A: Allocator + Clone> ExactSizeIterator for IntoIter<K, V, A> {}
+
+impl<K: Hash + Eq, V, A: Allocator + Clone> FusedIterator for IntoIter<K, V, A> {}
+
+impl<'a, K, V, B, A> IntoIterator for &'a SwissMap<K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            inner: self.table.ctrl.iter().zip(self.table.slots.iter()),
+            len: self.len(),
+        }
+    }
+}
+
+/// A type for borrowed iteration over a [`SwissMap`]. Produces values of type `(&K, &V)`.
+///
+/// See [`SwissMap::iter`].
+pub struct Iter<'a, K, V> {
+    pub(crate) inner: Zip<ArrIter<'a, u8>, ArrIter<'a, MaybeUninit<(K, V)>>>,
+    pub(crate) len: usize,
+}
+
+impl<'a, K: Hash + Eq, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, slot) = self.inner.find(|(ctrl, _)| group::is_full(**ctrl))?;
+        self.len -= 1;
+
+        // SAFETY: The bucket is full, so the slot is initialized.
+        let (key, value) = unsafe { slot.assume_init_ref() };
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K: Hash + Eq, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K: Hash + Eq, V> FusedIterator for Iter<'a, K, V> {}
+
+/// A type for owned iteration over a [`SwissMap`]'s keys. Produces values of type `K`.
+///
+/// See [`SwissMap::into_keys`].
+pub struct IntoKeys<K, V, A: Allocator + Clone = Global>(pub(crate) IntoIter<K, V, A>);
+
+impl<K: Hash + Eq, V, A: Allocator + Clone> Iterator for IntoKeys<K, V, A> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, _)| k)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K: Hash + Eq, V, A: Allocator + Clone> FusedIterator for IntoKeys<K, V, A> {}
+
+/// A type for borrowed iteration over a [`SwissMap`]'s keys. Produces values of type `&K`.
+///
+/// See [`SwissMap::keys`].
+pub struct Keys<'a, K, V>(pub(crate) Iter<'a, K, V>);
+
+impl<'a, K: Hash + Eq, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, _)| k)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K: Hash + Eq, V> FusedIterator for Keys<'a, K, V> {}
+
+/// A type for owned iteration over a [`SwissMap`]'s values. Produces values of type `V`.
+///
+/// See [`SwissMap::into_values`].
+pub struct IntoValues<K, V, A: Allocator + Clone = Global>(pub(crate) IntoIter<K, V, A>);
+
+impl<K: Hash + Eq, V, A: Allocator + Clone> Iterator for IntoValues<K, V, A> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K: Hash + Eq, V, A: Allocator + Clone> FusedIterator for IntoValues<K, V, A> {}
+
+/// A type for mutable iteration over a [`SwissMap`]'s values. Produces values of type `&mut V`.
+///
+/// See [`SwissMap::values_mut`].
+pub struct ValuesMut<'a, K, V> {
+    pub(crate) inner: Zip<ArrIter<'a, u8>, ArrIterMut<'a, MaybeUninit<(K, V)>>>,
+    pub(crate) len: usize,
+}
+
+impl<'a, K: Hash + Eq, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, slot) = self.inner.find(|(ctrl, _)| group::is_full(**ctrl))?;
+        self.len -= 1;
+
+        // SAFETY: The bucket is full, so the slot is initialized.
+        Some(unsafe { &mut slot.assume_init_mut().1 })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K: Hash + Eq, V> FusedIterator for ValuesMut<'a, K, V> {}
+
+/// A type for borrowed iteration over a [`SwissMap`]'s values. Produces values of type `&V`.
+///
+/// See [`SwissMap::values`].
+pub struct Values<'a, K, V>(pub(crate) Iter<'a, K, V>);
+
+impl<'a, K: Hash + Eq, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K: Hash + Eq, V> FusedIterator for Values<'a, K, V> {}
+
+/// A type for draining iteration over a [`SwissMap`], which removes all entries while keeping the
+/// allocated capacity. Produces values of type `(K, V)`.
+///
+/// The table is moved out of the SwissMap when this iterator is created, so the SwissMap remains
+/// valid (and empty) even if this iterator is leaked. Any entries which haven't been produced are
+/// dropped along with the iterator.
+///
+/// See [`SwissMap::drain`].
+pub struct Drain<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a mut SwissMap<K, V, B, A>,
+    pub(crate) table: RawTable<K, V, A>,
+    pub(crate) index: usize,
+}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> Iterator for Drain<'a, K, V, B, A> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.table.cap() {
+            let index = self.index;
+            self.index += 1;
+
+            if self.table.is_full(index) {
+                // SAFETY: The bucket is full. Removing the pair marks it as empty or deleted, so
+                // the table won't drop it again.
+                return Some(unsafe { self.table.remove_at(index) });
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.table.len, Some(self.table.len))
+    }
+}
+
+impl<'a, K, V, B, A> ExactSizeIterator for Drain<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{}
+
+impl<'a, K, V, B, A> FusedIterator for Drain<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> Drop for Drain<'a, K, V, B, A> {
+    fn drop(&mut self) {
+        // Drop any entries that haven't been produced, then clear the tombstones left behind before
+        // returning the empty table to the SwissMap so that the allocation can be reused.
+        self.for_each(drop);
+        let max_len = self.map.policy.max_len_for_cap(self.table.cap());
+        self.table.reset(max_len);
+
+        mem::swap(&mut self.map.table, &mut self.table);
+        self.map.shrink_if_sparse();
+    }
+}
+
+/// A type for in-place extraction from a [`SwissMap`], removing and producing every entry for
+/// which the predicate returns true. Produces values of type `(K, V)`.
+///
+/// Removal leaves a tombstone rather than moving other entries, so the buckets are simply visited
+/// in order. If this iterator is dropped before being exhausted, the remaining entries are left in
+/// the SwissMap.
+///
+/// See [`SwissMap::extract_if`].
+pub struct ExtractIf<
+    'a,
+    K: Hash + Eq,
+    V,
+    F: FnMut(&K, &mut V) -> bool,
+    B: BuildHasher = RandomState,
+    A: Allocator + Clone = Global,
+> {
+    pub(crate) map: &'a mut SwissMap<K, V, B, A>,
+    pub(crate) index: usize,
+    pub(crate) pred: F,
+}
+
+impl<'a, K, V, F, B, A> Iterator for ExtractIf<'a, K, V, F, B, A>
+where
+    K: Hash + Eq,
+    F: FnMut(&K, &mut V) -> bool,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let table = &mut self.map.table;
+
+        while self.index < table.cap() {
+            let index = self.index;
+            self.index += 1;
+
+            if !table.is_full(index) {
+                continue;
+            }
+
+            // SAFETY: The bucket is full.
+            let (key, value) = unsafe { table.pair_mut(index) };
+            if (self.pred)(key, value) {
+                // SAFETY: The bucket is full, and its index has been passed, so it won't be
+                // visited again.
+                return Some(unsafe { table.remove_at(index) });
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.map.len()))
+    }
+}
+
+impl<'a, K, V, F, B, A> FusedIterator for ExtractIf<'a, K, V, F, B, A>
+where
+    K: Hash + Eq,
+    F: FnMut(&K, &mut V) -> bool,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{}
+
+impl<'a, K, V, F, B, A> Drop for ExtractIf<'a, K, V, F, B, A>
+where
+    K: Hash + Eq,
+    F: FnMut(&K, &mut V) -> bool,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn drop(&mut self) {
+        // Shrinking is deferred until extraction is complete, so that indices remain valid.
+        self.map.shrink_if_sparse();
+    }
+}
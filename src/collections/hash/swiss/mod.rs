@@ -0,0 +1,22 @@
+//! A module containing [`SwissMap`] and associated types.
+//!
+//! SwissMap is an alternative to [`HashMap`](crate::collections::hash::HashMap) with a
+//! SwissTable-style memory layout, storing a separate array of 7-bit hash tags which are scanned a
+//! group at a time. The other included types provide owned and borrowed iteration over entries,
+//! keys or values in a map, in-place access through [`Entry`] and [`RawEntryMut`], and removal
+//! through [`Drain`] and [`ExtractIf`].
+//!
+//! [`SwissMap`] is also re-exported under the parent module.
+
+mod entry;
+mod group;
+mod iter;
+mod raw_entry;
+mod raw_table;
+mod swiss_map;
+mod tests;
+
+pub use entry::*;
+pub use iter::*;
+pub use raw_entry::*;
+pub use swiss_map::*;
@@ -0,0 +1,305 @@
+use std::alloc::{Allocator, Global};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+
+use super::SwissMap;
+
+/// A builder for immutable lookups in a [`SwissMap`] using a precomputed hash and a custom
+/// equality closure, rather than the SwissMap's hasher and [`Eq`].
+///
+/// See [`SwissMap::raw_entry`].
+pub struct RawEntryBuilder<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a SwissMap<K, V, B, A>,
+}
+
+impl<'a, K, V, B, A> RawEntryBuilder<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    /// Finds the entry with the provided `hash` for which `is_match` returns true, returning the
+    /// key-value pair or None if there is no such entry.
+    ///
+    /// `is_match` is only invoked for keys whose tag matches the top bits of `hash`.
+    pub fn from_hash<F>(self, hash: u64, is_match: F) -> Option<(&'a K, &'a V)>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        let map = self.map;
+        let index = map.table.find(hash, is_match)?;
+        // SAFETY: find only returns the indices of full buckets.
+        let (key, value) = unsafe { map.table.pair(index) };
+        Some((key, value))
+    }
+}
+
+impl<'a, K, V, B, A> Debug for RawEntryBuilder<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawEntryBuilder").finish_non_exhaustive()
+    }
+}
+
+/// A builder for mutable lookups in a [`SwissMap`] using a precomputed hash and a custom equality
+/// closure, producing a [`RawEntryMut`].
+///
+/// See [`SwissMap::raw_entry_mut`].
+pub struct RawEntryBuilderMut<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a mut SwissMap<K, V, B, A>,
+}
+
+impl<'a, K, V, B, A> RawEntryBuilderMut<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    /// Finds the bucket for an entry with the provided `hash` for which `is_match` returns true,
+    /// returning it as a [`RawEntryMut`]. If there is no such entry, the SwissMap's capacity is
+    /// increased if required, so that the vacant entry can always be filled.
+    ///
+    /// `is_match` is only invoked for keys whose tag matches the top bits of `hash`.
+    pub fn from_hash<F>(self, hash: u64, is_match: F) -> RawEntryMut<'a, K, V, B, A>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        let map = self.map;
+
+        match map.locate(hash, is_match) {
+            Ok(index) => RawEntryMut::Occupied(RawOccupiedEntryMut {
+                map,
+                index,
+            }),
+            Err(index) => RawEntryMut::Vacant(RawVacantEntryMut {
+                map,
+                index,
+                hash,
+            }),
+        }
+    }
+}
+
+impl<'a, K, V, B, A> Debug for RawEntryBuilderMut<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawEntryBuilderMut").finish_non_exhaustive()
+    }
+}
+
+/// A view into a single bucket of a [`SwissMap`], located with a precomputed hash, which is either
+/// occupied or vacant.
+///
+/// See [`SwissMap::raw_entry_mut`].
+pub enum RawEntryMut<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    /// A RawEntryMut for a key which is already associated with a value in the SwissMap.
+    Occupied(RawOccupiedEntryMut<'a, K, V, B, A>),
+    /// A RawEntryMut for a key which isn't associated with any value in the SwissMap.
+    Vacant(RawVacantEntryMut<'a, K, V, B, A>),
+}
+
+impl<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> RawEntryMut<'a, K, V, B, A> {
+    /// Ensures that the entry has a value by inserting the provided `key` and `value` if it is
+    /// vacant. Returns references to the key and value.
+    ///
+    /// It is a logic error for `key` to not match the hash and equality closure used to locate
+    /// this entry.
+    pub fn or_insert(self, key: K, value: V) -> (&'a K, &'a mut V) {
+        match self {
+            RawEntryMut::Occupied(entry) => entry.into_key_value(),
+            RawEntryMut::Vacant(entry) => entry.insert(key, value),
+        }
+    }
+
+    /// Ensures that the entry has a value by inserting the key-value pair returned by `default` if
+    /// it is vacant. Returns references to the key and value.
+    pub fn or_insert_with<F: FnOnce() -> (K, V)>(self, default: F) -> (&'a K, &'a mut V) {
+        match self {
+            RawEntryMut::Occupied(entry) => entry.into_key_value(),
+            RawEntryMut::Vacant(entry) => {
+                let (key, value) = default();
+                entry.insert(key, value)
+            },
+        }
+    }
+
+    /// Invokes `f` with references to the key and value if the entry is occupied. Vacant entries
+    /// are left unchanged. Returns self, so that the entry can be used again.
+    pub fn and_modify<F: FnOnce(&K, &mut V)>(self, f: F) -> RawEntryMut<'a, K, V, B, A> {
+        match self {
+            RawEntryMut::Occupied(mut entry) => {
+                let (key, value) = entry.pair_mut();
+                f(key, value);
+                RawEntryMut::Occupied(entry)
+            },
+            RawEntryMut::Vacant(entry) => RawEntryMut::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K, V, B, A> Debug for RawEntryMut<'a, K, V, B, A>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RawEntryMut::Occupied(entry) => f.debug_tuple("RawEntryMut").field(entry).finish(),
+            RawEntryMut::Vacant(entry) => f.debug_tuple("RawEntryMut").field(entry).finish(),
+        }
+    }
+}
+
+/// A view into a full bucket of a [`SwissMap`]. Part of the [`RawEntryMut`] enum.
+pub struct RawOccupiedEntryMut<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a mut SwissMap<K, V, B, A>,
+    pub(crate) index: usize,
+}
+
+impl<'a, K, V, B, A> RawOccupiedEntryMut<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    /// Returns references to the key and value in the bucket, with only the value being mutable.
+    fn pair_mut(&mut self) -> (&K, &mut V) {
+        // SAFETY: A RawOccupiedEntryMut is only ever constructed for a full bucket.
+        let (key, value) = unsafe { self.map.table.pair_mut(self.index) };
+        (key, value)
+    }
+
+    /// Returns a reference to the key of this entry.
+    pub fn key(&self) -> &K {
+        // SAFETY: A RawOccupiedEntryMut is only ever constructed for a full bucket.
+        unsafe { &self.map.table.pair(self.index).0 }
+    }
+
+    /// Returns the hash of this entry's key. Because SwissMap doesn't store hashes, the key is
+    /// hashed again with the SwissMap's hasher.
+    pub fn hash(&self) -> u64 {
+        self.map.hasher.hash_one(self.key())
+    }
+
+    /// Returns a reference to the value of this entry.
+    pub fn get(&self) -> &V {
+        // SAFETY: A RawOccupiedEntryMut is only ever constructed for a full bucket.
+        unsafe { &self.map.table.pair(self.index).1 }
+    }
+
+    /// Returns a mutable reference to the value of this entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        self.pair_mut().1
+    }
+
+    /// Converts the entry into a mutable reference to its value, with the same lifetime as the
+    /// borrow of the SwissMap.
+    pub fn into_mut(self) -> &'a mut V {
+        self.into_key_value().1
+    }
+
+    /// Converts the entry into references to its key and value, with the same lifetime as the
+    /// borrow of the SwissMap.
+    pub fn into_key_value(self) -> (&'a K, &'a mut V) {
+        // SAFETY: A RawOccupiedEntryMut is only ever constructed for a full bucket.
+        let (key, value) = unsafe { self.map.table.pair_mut(self.index) };
+        (key, value)
+    }
+
+    /// Replaces the value of this entry with `value`, returning the previous value.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes this entry from the SwissMap, returning the contained key-value pair.
+    pub fn remove_entry(self) -> (K, V) {
+        // SAFETY: A RawOccupiedEntryMut is only ever constructed for a full bucket, and it is
+        // consumed here, so the bucket can't be accessed through it again.
+        let removed = unsafe { self.map.table.remove_at(self.index) };
+        self.map.shrink_if_sparse();
+        removed
+    }
+
+    /// Removes this entry from the SwissMap, returning the contained value.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
+
+impl<'a, K, V, B, A> Debug for RawOccupiedEntryMut<'a, K, V, B, A>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawOccupiedEntryMut")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+/// A view into an empty or deleted bucket of a [`SwissMap`]. Part of the [`RawEntryMut`] enum.
+pub struct RawVacantEntryMut<'a, K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone = Global> {
+    pub(crate) map: &'a mut SwissMap<K, V, B, A>,
+    pub(crate) index: usize,
+    pub(crate) hash: u64,
+}
+
+impl<'a, K, V, B, A> RawVacantEntryMut<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    /// Returns the hash that was used to locate this entry, and whose tag would be stored when
+    /// inserting through it.
+    pub const fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Inserts the provided `key` and `value` into the SwissMap, tagging the bucket with the hash
+    /// used to locate this entry. Returns references to the key and value.
+    ///
+    /// It is a logic error for `key` to not match the hash and equality closure used to locate
+    /// this entry, or for the hash to differ from the one that the SwissMap's hasher produces for
+    /// `key`.
+    pub fn insert(self, key: K, value: V) -> (&'a K, &'a mut V) {
+        let RawVacantEntryMut { map, index, hash } = self;
+
+        // The SwissMap made room for an insertion when this entry was created, and has been
+        // mutably borrowed since then, so the bucket at index is still free.
+        map.table.insert_at(index, hash, (key, value));
+
+        RawOccupiedEntryMut {
+            map,
+            index,
+        }.into_key_value()
+    }
+}
+
+impl<'a, K, V, B, A> Debug for RawVacantEntryMut<'a, K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawVacantEntryMut")
+            .field("hash", &self.hash)
+            .finish()
+    }
+}
@@ -0,0 +1,247 @@
+use std::alloc::{Allocator, Global};
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+
+use super::group::{self, DELETED, EMPTY, GROUP_WIDTH, Group};
+use crate::collections::contiguous::Array;
+
+/// The untyped storage behind a [`SwissMap`](super::SwissMap): an array of control bytes and a
+/// separate array of key-value slots, without any knowledge of hashing.
+///
+/// When allocated, the capacity is a power of two no smaller than [`GROUP_WIDTH`], and the control
+/// bytes array has an additional [`GROUP_WIDTH`] bytes at the end, which mirror the first group.
+/// This allows a [`Group`] to be loaded from any index without wrapping.
+pub(crate) struct RawTable<K, V, A: Allocator + Clone = Global> {
+    pub(crate) ctrl: Array<u8, A>,
+    pub(crate) slots: Array<MaybeUninit<(K, V)>, A>,
+    pub(crate) len: usize,
+    /// The number of entries that can be inserted into empty buckets before the table has to be
+    /// resized. Tombstones ([`DELETED`] buckets) count against this.
+    pub(crate) growth_left: usize,
+}
+
+/// A triangular probe sequence over groups, which visits every group exactly once when the
+/// capacity is a power of two.
+struct ProbeSeq {
+    pos: usize,
+    stride: usize,
+}
+
+impl ProbeSeq {
+    const fn new(hash: u64, mask: usize) -> ProbeSeq {
+        ProbeSeq {
+            pos: hash as usize & mask,
+            stride: 0,
+        }
+    }
+
+    const fn next(&mut self, mask: usize) {
+        self.stride += GROUP_WIDTH;
+        self.pos = (self.pos + self.stride) & mask;
+    }
+}
+
+impl<K, V, A: Allocator + Clone> RawTable<K, V, A> {
+    /// Creates a new RawTable with capacity 0 which will use the provided allocator, without
+    /// allocating.
+    pub(crate) fn new_in(alloc: A) -> RawTable<K, V, A> {
+        RawTable {
+            ctrl: Array::new_in(alloc.clone()),
+            slots: Array::new_in(alloc),
+            len: 0,
+            growth_left: 0,
+        }
+    }
+
+    /// Creates a new RawTable with the provided `cap`acity, which must either be 0 or a power of
+    /// two no smaller than [`GROUP_WIDTH`]. `max_len` is the number of entries that the table can
+    /// hold before it has to be resized, which must be less than `cap`, so that at least one bucket
+    /// is always empty and probing terminates.
+    pub(crate) fn with_cap_in(cap: usize, max_len: usize, alloc: A) -> RawTable<K, V, A> {
+        if cap == 0 {
+            return RawTable::new_in(alloc);
+        }
+
+        debug_assert!(cap.is_power_of_two() && cap >= GROUP_WIDTH && max_len < cap);
+
+        RawTable {
+            ctrl: Array::repeat_item_in(EMPTY, cap + GROUP_WIDTH, alloc.clone()),
+            slots: Array::new_uninit_in(cap, alloc),
+            len: 0,
+            growth_left: max_len,
+        }
+    }
+
+    /// Returns a reference to the allocator used by the RawTable.
+    pub(crate) const fn allocator(&self) -> &A {
+        self.slots.allocator()
+    }
+
+    /// Returns the number of buckets in the RawTable.
+    pub(crate) const fn cap(&self) -> usize {
+        self.slots.size
+    }
+
+    /// Returns the mask used to wrap indices. Only valid if the capacity isn't 0.
+    const fn mask(&self) -> usize {
+        self.cap() - 1
+    }
+
+    /// Returns true if the bucket at `index` is full.
+    pub(crate) fn is_full(&self, index: usize) -> bool {
+        group::is_full(self.ctrl[index])
+    }
+
+    /// Returns a reference to the key-value pair in the bucket at `index`.
+    ///
+    /// # Safety
+    /// The bucket at `index` must be full.
+    pub(crate) unsafe fn pair(&self, index: usize) -> &(K, V) {
+        // SAFETY: The caller guarantees that the bucket is full, so the slot is initialized.
+        unsafe { self.slots[index].assume_init_ref() }
+    }
+
+    /// Returns a mutable reference to the key-value pair in the bucket at `index`.
+    ///
+    /// # Safety
+    /// The bucket at `index` must be full.
+    pub(crate) unsafe fn pair_mut(&mut self, index: usize) -> &mut (K, V) {
+        // SAFETY: The caller guarantees that the bucket is full, so the slot is initialized.
+        unsafe { self.slots[index].assume_init_mut() }
+    }
+
+    /// Sets the control byte of the bucket at `index`, as well as its mirror if it is within the
+    /// first group.
+    fn set_ctrl(&mut self, index: usize, ctrl: u8) {
+        // For index >= GROUP_WIDTH, this is just index again.
+        let mirror = (index.wrapping_sub(GROUP_WIDTH) & self.mask()) + GROUP_WIDTH;
+
+        self.ctrl[index] = ctrl;
+        self.ctrl[mirror] = ctrl;
+    }
+
+    /// Finds the index of the full bucket with the provided `hash`, for which `is_match` returns
+    /// true, or None if there is no such bucket.
+    ///
+    /// Only the tags of each group are compared until a potential match is found, so keys are
+    /// rarely touched unless they are equal.
+    pub(crate) fn find<F>(&self, hash: u64, mut is_match: F) -> Option<usize>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        if self.cap() == 0 {
+            return None;
+        }
+
+        let tag = group::tag(hash);
+        let mut probe = ProbeSeq::new(hash, self.mask());
+
+        loop {
+            let group = Group::load(&self.ctrl[probe.pos..]);
+
+            for offset in group.match_tag(tag) {
+                let index = (probe.pos + offset) & self.mask();
+
+                // SAFETY: Only full buckets can match a tag.
+                if is_match(unsafe { &self.pair(index).0 }) {
+                    return Some(index);
+                }
+            }
+
+            // An empty bucket ends the probe sequence, because an entry with this hash would have
+            // been inserted there.
+            if group.match_empty().any() {
+                return None;
+            }
+
+            probe.next(self.mask());
+        }
+    }
+
+    /// Finds the index of the first empty or deleted bucket in the probe sequence for `hash`.
+    ///
+    /// # Panics
+    /// Panics if the capacity of the RawTable is 0.
+    pub(crate) fn find_insert_slot(&self, hash: u64) -> usize {
+        assert!(self.cap() > 0, "RawTable should have a capacity greater than 0");
+
+        let mut probe = ProbeSeq::new(hash, self.mask());
+
+        loop {
+            let group = Group::load(&self.ctrl[probe.pos..]);
+
+            if let Some(offset) = group.match_empty_or_deleted().lowest() {
+                return (probe.pos + offset) & self.mask();
+            }
+
+            probe.next(self.mask());
+        }
+    }
+
+    /// Writes `pair` into the empty or deleted bucket at `index`, tagged with `hash`. This method
+    /// doesn't check [`growth_left`](RawTable::growth_left), which should be positive if the bucket
+    /// is empty.
+    pub(crate) fn insert_at(&mut self, index: usize, hash: u64, pair: (K, V)) {
+        if self.ctrl[index] == EMPTY {
+            self.growth_left -= 1;
+        }
+
+        self.set_ctrl(index, group::tag(hash));
+        self.slots[index].write(pair);
+        self.len += 1;
+    }
+
+    /// Removes and returns the key-value pair in the full bucket at `index`.
+    ///
+    /// The bucket is marked as [`EMPTY`] if no probe sequence could have passed over it, which is
+    /// the case when it doesn't belong to a run of [`GROUP_WIDTH`] or more consecutive non-empty
+    /// buckets. Otherwise, it is marked as [`DELETED`].
+    ///
+    /// # Safety
+    /// The bucket at `index` must be full.
+    pub(crate) unsafe fn remove_at(&mut self, index: usize) -> (K, V) {
+        let before = Group::load(&self.ctrl[index.wrapping_sub(GROUP_WIDTH) & self.mask()..]);
+        let after = Group::load(&self.ctrl[index..]);
+
+        let run = before.match_empty().trailing_unmatched()
+            + after.match_empty().leading_unmatched();
+        let ctrl = if run >= GROUP_WIDTH {
+            DELETED
+        } else {
+            self.growth_left += 1;
+            EMPTY
+        };
+
+        self.set_ctrl(index, ctrl);
+        self.len -= 1;
+
+        // SAFETY: The caller guarantees that the bucket was full, and it has now been marked as
+        // empty or deleted, so the pair won't be read again.
+        unsafe { self.slots[index].assume_init_read() }
+    }
+
+    /// Marks every bucket of an empty RawTable as [`EMPTY`], clearing any tombstones, and sets
+    /// [`growth_left`](RawTable::growth_left) to `max_len`.
+    pub(crate) fn reset(&mut self, max_len: usize) {
+        debug_assert_eq!(self.len, 0);
+
+        self.ctrl.fill(EMPTY);
+        self.growth_left = max_len;
+    }
+}
+
+impl<K, V, A: Allocator + Clone> Drop for RawTable<K, V, A> {
+    fn drop(&mut self) {
+        if !mem::needs_drop::<(K, V)>() {
+            return;
+        }
+
+        for index in 0..self.cap() {
+            if self.is_full(index) {
+                // SAFETY: The bucket is full, so the slot is initialized, and it won't be accessed
+                // again. The Array itself only deallocates the slots.
+                unsafe { ptr::drop_in_place(self.slots[index].as_mut_ptr()); }
+            }
+        }
+    }
+}
@@ -0,0 +1,783 @@
+use std::alloc::{Allocator, Global};
+use std::borrow::Borrow;
+use std::cmp;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{BuildHasher, Hash, RandomState};
+use std::iter::TrustedLen;
+use std::mem;
+use std::ops::Index;
+
+use super::group::{DELETED, GROUP_WIDTH};
+use super::raw_table::RawTable;
+use super::{
+    Drain, Entry, ExtractIf, IntoKeys, IntoValues, Iter, Keys, OccupiedEntry, RawEntryBuilder,
+    RawEntryBuilderMut, VacantEntry, Values, ValuesMut,
+};
+use crate::collections::hash::HashPolicy;
+use crate::util::error::{CapacityOverflow, NoValueForKey};
+use crate::util::fmt::DebugRaw;
+use crate::util::result::ResultExtension;
+
+#[cfg(doc)]
+use crate::collections::hash::HashMap;
+
+/// The policy used by a SwissMap when none is provided. Scanning groups of tags keeps probing cheap
+/// even when the table is nearly full, so a higher load factor than [`HashPolicy::DEFAULT`] is
+/// used, along with a minimum capacity of one group.
+pub(crate) const DEFAULT_POLICY: HashPolicy = HashPolicy::DEFAULT
+    .with_load_factor(7, 8)
+    .with_min_cap(GROUP_WIDTH);
+
+/// A map of keys to values which relies on the keys implementing [`Hash`], using a SwissTable-style
+/// memory layout.
+///
+/// SwissMap is an alternative to [`HashMap`], with the same API. Rather than storing each entry
+/// alongside its hash, SwissMap keeps a separate array of one byte per bucket, holding 7 bits of
+/// each entry's hash (a tag). Probing scans groups of 8 tags at once using portable bitwise
+/// operations on a single word, and only compares keys when a tag matches, so lookups rarely touch
+/// key-value memory for unrelated entries. Removal leaves tombstones, which are cleared when the
+/// SwissMap is next resized.
+///
+/// Allocation is configured with a [`HashPolicy`], as with HashMap, with a few differences:
+/// - The capacity of a SwissMap is always 0 or a power of two no smaller than 8, so capacities
+///   chosen by the policy are rounded up to the next valid capacity.
+/// - Unless another policy is provided, the load factor is 7/8 and the minimum capacity is 8.
+/// - [`with_cap`](SwissMap::with_cap) and similar constructors allocate enough buckets to hold
+///   `cap` entries without exceeding the load factor, rather than exactly `cap` buckets.
+/// - Because hashes aren't stored, resizing requires every key to be hashed again.
+///
+/// It is a logic error for keys in a SwissMap to be manipulated in a way that changes their hash.
+/// Because of this, SwissMap's API prevents mutable access to its keys.
+///
+/// # Time Complexity
+/// For this analysis of time complexity, variables are defined as follows:
+/// - `n`: The number of items in the SwissMap.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `len` | `O(1)` |
+/// | `insert` | `O(1)`**, `O(n)` |
+/// | `insert_unchecked` | `O(1)`* |
+/// | `entry` | `O(1)`**, `O(n)` |
+/// | `insert_with_hash` | `O(1)`**, `O(n)` |
+/// | `raw_entry` | `O(1)`* |
+/// | `raw_entry_mut` | `O(1)`**, `O(n)` |
+/// | `get` | `O(1)`* |
+/// | `remove` | `O(1)`* |
+/// | `contains` | `O(1)`* |
+/// | `reserve` | `O(n)`***, `O(1)` |
+/// | `shrink_to_fit` | `O(n)` |
+/// | `shrink_to` | `O(n)` |
+/// | `retain` | `O(n)` |
+/// | `drain` | `O(n)`**** |
+/// | `extract_if` | `O(n)`**** |
+///
+/// \* In the event of a hash collision, these functions will take additional time, while a valid
+/// / correct location is found.
+///
+/// \** If the SwissMap doesn't have enough capacity for the new element, `insert` and `entry` will
+/// take `O(n)`.
+/// \* applies as well.
+///
+/// \*** If the SwissMap has enough capacity for the additional items already, `reserve` is `O(1)`.
+///
+/// \**** When exhausted.
+///
+/// If automatic shrinking is enabled through the SwissMap's [`HashPolicy`], `remove` will take
+/// `O(n)` when it causes the SwissMap to shrink.
+pub struct SwissMap<K: Hash + Eq, V, B: BuildHasher = RandomState, A: Allocator + Clone = Global> {
+    pub(crate) table: RawTable<K, V, A>,
+    pub(crate) hasher: B,
+    pub(crate) policy: HashPolicy,
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default> SwissMap<K, V, B> {
+    /// Creates a new SwissMap with capacity 0 and the default value for `B`. Memory will be
+    /// allocated when the capacity changes.
+    pub fn new() -> SwissMap<K, V, B> {
+        SwissMap::new_in(Global)
+    }
+
+    /// Creates a new SwissMap with enough capacity to hold `cap` entries and the default value for
+    /// `B`.
+    pub fn with_cap(cap: usize) -> SwissMap<K, V, B> {
+        SwissMap::with_cap_in(cap, Global)
+    }
+
+    /// Creates a new SwissMap with capacity 0, the provided `policy` and the default value for `B`.
+    /// Memory will be allocated according to `policy` when the capacity changes.
+    pub fn with_policy(policy: HashPolicy) -> SwissMap<K, V, B> {
+        SwissMap::with_cap_hasher_and_policy_in(0, B::default(), policy, Global)
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default, A: Allocator + Clone> SwissMap<K, V, B, A> {
+    /// Creates a new SwissMap with capacity 0 and the default value for `B`, which will use the
+    /// provided allocator. Memory will be allocated when the capacity changes.
+    pub fn new_in(alloc: A) -> SwissMap<K, V, B, A> {
+        SwissMap::with_hasher_in(B::default(), alloc)
+    }
+
+    /// Creates a new SwissMap with enough capacity to hold `cap` entries. The default hasher and
+    /// the provided allocator will be used.
+    pub fn with_cap_in(cap: usize, alloc: A) -> SwissMap<K, V, B, A> {
+        SwissMap::with_cap_hasher_and_policy_in(cap, B::default(), DEFAULT_POLICY, alloc)
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher> SwissMap<K, V, B> {
+    /// Creates a new SwissMap with capacity 0 and the provided `hasher`.
+    pub fn with_hasher(hasher: B) -> SwissMap<K, V, B> {
+        SwissMap::with_hasher_in(hasher, Global)
+    }
+
+    /// Creates a new SwissMap with enough capacity to hold `cap` entries and the provided `hasher`.
+    pub fn with_cap_and_hasher(cap: usize, hasher: B) -> SwissMap<K, V, B> {
+        SwissMap::with_cap_hasher_and_policy_in(cap, hasher, DEFAULT_POLICY, Global)
+    }
+
+    /// Creates a new SwissMap with enough capacity to hold `cap` entries according to `policy`,
+    /// with the provided `hasher`.
+    pub fn with_cap_hasher_and_policy(
+        cap: usize,
+        hasher: B,
+        policy: HashPolicy,
+    ) -> SwissMap<K, V, B> {
+        SwissMap::with_cap_hasher_and_policy_in(cap, hasher, policy, Global)
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> SwissMap<K, V, B, A> {
+    /// Creates a new SwissMap with capacity 0, the provided `hasher` and the provided allocator.
+    pub fn with_hasher_in(hasher: B, alloc: A) -> SwissMap<K, V, B, A> {
+        SwissMap::with_cap_hasher_and_policy_in(0, hasher, DEFAULT_POLICY, alloc)
+    }
+
+    /// Creates a new SwissMap with enough capacity to hold `cap` entries according to `policy`,
+    /// with the provided `hasher` and allocator. All allocations made by the SwissMap, including
+    /// those made while growing, will use `alloc`.
+    ///
+    /// # Panics
+    /// Panics if the capacity would overflow.
+    pub fn with_cap_hasher_and_policy_in(
+        cap: usize,
+        hasher: B,
+        policy: HashPolicy,
+        alloc: A,
+    ) -> SwissMap<K, V, B, A> {
+        let cap = table_cap(policy.min_cap_for_len(cap));
+
+        SwissMap {
+            table: RawTable::with_cap_in(cap, policy.max_len_for_cap(cap), alloc),
+            hasher,
+            policy,
+        }
+    }
+
+    /// Returns a reference to the allocator used by the SwissMap.
+    pub const fn allocator(&self) -> &A {
+        self.table.allocator()
+    }
+
+    /// Returns the length of the SwissMap (the number of entries it contains).
+    pub const fn len(&self) -> usize {
+        self.table.len
+    }
+
+    /// Returns true if the SwissMap contains no entries.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the current capacity of the SwissMap, as a number of buckets. Because of the load
+    /// factor, this is greater than the number of entries it can hold without resizing.
+    pub const fn cap(&self) -> usize {
+        self.table.cap()
+    }
+
+    /// Returns the [`HashPolicy`] used by the SwissMap to determine when and how to allocate.
+    pub const fn policy(&self) -> HashPolicy {
+        self.policy
+    }
+
+    /// Returns a reference to the SwissMap's hasher, which can be used to precompute hashes for
+    /// [`insert_with_hash`](SwissMap::insert_with_hash) and [`raw_entry`](SwissMap::raw_entry).
+    pub const fn hasher(&self) -> &B {
+        &self.hasher
+    }
+
+    /// Inserts the provided `key`-`value` pair into the SwissMap, increasing its capacity if
+    /// required. If the key was already associated with a value, the previous value is returned.
+    ///
+    /// As with the standard library, the key isn't changed if it already exists.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let hash = self.hasher.hash_one(&key);
+        self.insert_with_hash(hash, key, value)
+    }
+
+    /// Inserts the provided `key`-`value` pair without checking if the SwissMap has enough
+    /// capacity. If the key was already associated with a value, the previous value is returned.
+    ///
+    /// As with the standard library, the key isn't changed if it already exists.
+    ///
+    /// # Safety
+    /// It is the responsibility of the caller to ensure that the SwissMap has enough capacity to
+    /// add the provided entry, using methods like [`reserve`][SwissMap::reserve] or
+    /// [`with_cap`](SwissMap::with_cap).
+    ///
+    /// # Panics
+    /// Panics if the SwissMap has a capacity of 0, as it isn't possible to find a bucket
+    /// associated with the key.
+    pub unsafe fn insert_unchecked(&mut self, key: K, value: V) -> Option<V> {
+        let hash = self.hasher.hash_one(&key);
+        self.insert_hashed(hash, key, value)
+    }
+
+    /// Inserts the provided `key`-`value` pair using a precomputed `hash`, rather than hashing the
+    /// key with the SwissMap's hasher. The SwissMap's capacity is increased if required. If the
+    /// key was already associated with a value, the previous value is returned.
+    ///
+    /// Unlike [`HashMap`], SwissMap doesn't store hashes, so every key is hashed again with the
+    /// SwissMap's [`hasher`](SwissMap::hasher) when it is resized. Because of this, it is a logic
+    /// error for `hash` to differ from the hash that the hasher produces for `key`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::SwissMap;
+    /// # use std::hash::BuildHasher;
+    /// let mut map: SwissMap<&str, usize> = SwissMap::new();
+    /// let hash = map.hasher().hash_one("key");
+    ///
+    /// map.insert_with_hash(hash, "key", 1);
+    /// assert_eq!(map.get("key"), Some(&1));
+    /// ```
+    pub fn insert_with_hash(&mut self, hash: u64, key: K, value: V) -> Option<V> {
+        match self.locate(hash, |existing| *existing == key) {
+            Ok(index) => {
+                // SAFETY: locate only returns Ok with the indices of full buckets.
+                let pair = unsafe { self.table.pair_mut(index) };
+                Some(mem::replace(&mut pair.1, value))
+            },
+            Err(index) => {
+                self.table.insert_at(index, hash, (key, value));
+                None
+            },
+        }
+    }
+
+    /// Returns the [`Entry`] associated with the provided `key`, which can be used to inspect or
+    /// manipulate the bucket in place. The key is only hashed once, regardless of how the Entry is
+    /// used.
+    ///
+    /// If the Entry is vacant, the SwissMap's capacity is increased if required, so that it can
+    /// always be filled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::SwissMap;
+    /// let mut counts: SwissMap<char, usize> = SwissMap::new();
+    /// for c in "hello".chars() {
+    ///     *counts.entry(c).or_default() += 1;
+    /// }
+    /// assert_eq!(counts.get(&'l'), Some(&2));
+    /// assert_eq!(counts.get(&'o'), Some(&1));
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, B, A> {
+        let hash = self.hasher.hash_one(&key);
+
+        match self.locate(hash, |existing| *existing == key) {
+            Ok(index) => Entry::Occupied(OccupiedEntry {
+                map: self,
+                index,
+            }),
+            Err(index) => Entry::Vacant(VacantEntry {
+                map: self,
+                index,
+                hash,
+                key,
+            }),
+        }
+    }
+
+    /// Returns a [`RawEntryBuilder`], for looking up an entry with a precomputed hash and a custom
+    /// equality closure. This avoids hashing the key again and doesn't require the lookup key to
+    /// implement [`Borrow`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::SwissMap;
+    /// # use std::hash::BuildHasher;
+    /// let mut map: SwissMap<String, usize> = SwissMap::new();
+    /// map.insert(String::from("key"), 1);
+    ///
+    /// let hash = map.hasher().hash_one("key");
+    /// assert_eq!(map.raw_entry().from_hash(hash, |k| k == "key"), Some((&"key".into(), &1)));
+    /// ```
+    pub const fn raw_entry(&self) -> RawEntryBuilder<'_, K, V, B, A> {
+        RawEntryBuilder {
+            map: self,
+        }
+    }
+
+    /// Returns a [`RawEntryBuilderMut`], for locating a bucket with a precomputed hash and a custom
+    /// equality closure, which can then be inspected or manipulated in place.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::SwissMap;
+    /// # use standard_lib::collections::hash::swiss::RawEntryMut;
+    /// # use std::hash::BuildHasher;
+    /// let mut map: SwissMap<&str, usize> = SwissMap::new();
+    /// let hash = map.hasher().hash_one("key");
+    ///
+    /// for _ in 0..3 {
+    ///     match map.raw_entry_mut().from_hash(hash, |k| *k == "key") {
+    ///         RawEntryMut::Occupied(mut entry) => *entry.get_mut() += 1,
+    ///         RawEntryMut::Vacant(entry) => {
+    ///             entry.insert("key", 1);
+    ///         },
+    ///     }
+    /// }
+    /// assert_eq!(map.get("key"), Some(&3));
+    /// ```
+    pub const fn raw_entry_mut(&mut self) -> RawEntryBuilderMut<'_, K, V, B, A> {
+        RawEntryBuilderMut {
+            map: self,
+        }
+    }
+
+    /// Returns the entry for the provided `key` as a key-value pair or None if there is no entry.
+    pub fn get_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_index_for_key(key)?;
+        // SAFETY: find_index_for_key only returns the indices of full buckets.
+        let (key, value) = unsafe { self.table.pair(index) };
+        Some((key, value))
+    }
+
+    /// Returns a reference to the value associated with the provided `key` or None if the map
+    /// contains no values for `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_entry(key).map(|(_, value)| value)
+    }
+
+    /// Returns a mutable reference to the value associated with the provided `key` or None if the
+    /// map contains no values for `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_index_for_key(key)?;
+        // SAFETY: find_index_for_key only returns the indices of full buckets.
+        Some(unsafe { &mut self.table.pair_mut(index).1 })
+    }
+
+    /// Removes the entry associated with `key`, returning it if it exists.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_index_for_key(key)?;
+        // SAFETY: find_index_for_key only returns the indices of full buckets.
+        let removed = unsafe { self.table.remove_at(index) };
+        self.shrink_if_sparse();
+        Some(removed)
+    }
+
+    /// Removes the entry associated with `key`, returning the value if it exists.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Returns true if the SwissMap contains `key`.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find_index_for_key(key).is_some()
+    }
+
+    /// Increases the capacity of the SwissMap to ensure that len + `extra` entries will fit without
+    /// exceeding the load factor.
+    ///
+    /// # Panics
+    /// Panics if the capacity would overflow.
+    pub fn reserve(&mut self, extra: usize) {
+        if extra <= self.table.growth_left { return; }
+
+        let new_len = self.len().strict_add(extra);
+        if !self.rehash_in_place(new_len) {
+            let new_cap = table_cap(self.policy.min_cap_for_len(new_len));
+            self.resize(cmp::max(new_cap, self.cap()));
+        }
+    }
+
+    /// Shrinks the SwissMap to the smallest capacity that can hold its current entries without
+    /// exceeding the load factor, clearing any tombstones. An empty SwissMap is deallocated
+    /// entirely.
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    /// Shrinks the SwissMap so that its capacity is no less than `min_cap` and no less than the
+    /// smallest capacity that can hold its current entries without exceeding the load factor,
+    /// rounded up to a valid capacity. If the current capacity is already below this, no change is
+    /// made.
+    pub fn shrink_to(&mut self, min_cap: usize) {
+        let new_cap = table_cap(cmp::max(min_cap, self.policy.min_cap_for_len(self.len())));
+        if new_cap >= self.cap() { return; }
+
+        self.resize(new_cap);
+    }
+
+    /// Retains only the entries for which `f` returns true, removing all others in place. `f` is
+    /// invoked exactly once for each entry, in an unspecified order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::SwissMap;
+    /// let mut map: SwissMap<usize, usize> = (0..10).map(|i| (i, i * 10)).collect();
+    /// map.retain(|k, v| {
+    ///     *v += 1;
+    ///     k % 2 == 0
+    /// });
+    /// assert_eq!(map.len(), 5);
+    /// assert_eq!(map.get(&4), Some(&41));
+    /// assert_eq!(map.get(&5), None);
+    /// ```
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.extract_if(|k, v| !f(k, v)).for_each(drop);
+    }
+
+    /// Removes all entries from the SwissMap, returning them as an iterator. The capacity of the
+    /// SwissMap is retained, unless automatic shrinking is enabled by its [`HashPolicy`].
+    ///
+    /// If the returned iterator is dropped before being exhausted, all remaining entries are
+    /// dropped as well.
+    pub fn drain(&mut self) -> Drain<'_, K, V, B, A> {
+        let empty = RawTable::new_in(self.allocator().clone());
+
+        Drain {
+            table: mem::replace(&mut self.table, empty),
+            map: self,
+            index: 0,
+        }
+    }
+
+    /// Returns an iterator which removes and produces every entry for which `pred` returns true,
+    /// in an unspecified order. `pred` is invoked at most once for each entry.
+    ///
+    /// If the returned iterator is dropped before being exhausted, the remaining entries are
+    /// retained, leaving the SwissMap valid.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::hash::SwissMap;
+    /// let mut map: SwissMap<usize, usize> = (0..10).map(|i| (i, i)).collect();
+    /// let mut odd: Vec<_> = map.extract_if(|k, _| k % 2 == 1).map(|(k, _)| k).collect();
+    /// odd.sort();
+    /// assert_eq!(odd, [1, 3, 5, 7, 9]);
+    /// assert_eq!(map.len(), 5);
+    /// ```
+    pub const fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F, B, A>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        ExtractIf {
+            map: self,
+            index: 0,
+            pred,
+        }
+    }
+
+    /// Returns an iterator over all key-value pairs in the SwissMap, as references.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.into_iter()
+    }
+
+    /// Consumes self and returns an iterator over all contained keys.
+    pub fn into_keys(self) -> IntoKeys<K, V, A> {
+        IntoKeys(self.into_iter())
+    }
+
+    /// Returns an iterator over all keys in the SwissMap, as references.
+    pub fn keys<'a>(&'a self) -> Keys<'a, K, V> {
+        Keys(self.iter())
+    }
+
+    /// Consumes self and returns an iterator over all contained values.
+    pub fn into_values(self) -> IntoValues<K, V, A> {
+        IntoValues(self.into_iter())
+    }
+
+    /// Returns an iterator over all values in the SwissMap, as mutable references.
+    pub fn values_mut<'a>(&'a mut self) -> ValuesMut<'a, K, V> {
+        let RawTable { ctrl, slots, len, .. } = &mut self.table;
+
+        ValuesMut {
+            inner: ctrl.iter().zip(slots.iter_mut()),
+            len: *len,
+        }
+    }
+
+    /// Returns an iterator over all values in the SwissMap, as references.
+    pub fn values<'a>(&'a self) -> Values<'a, K, V> {
+        Values(self.iter())
+    }
+}
+
+/// Rounds a capacity chosen by a [`HashPolicy`] up to a valid capacity for a [`RawTable`], which
+/// is either 0 or a power of two no smaller than [`GROUP_WIDTH`].
+///
+/// # Panics
+/// Panics if the capacity would overflow.
+pub(crate) fn table_cap(cap: usize) -> usize {
+    if cap == 0 {
+        return 0;
+    }
+
+    let cap = cap.checked_next_power_of_two().ok_or(CapacityOverflow).throw();
+    cmp::max(cap, GROUP_WIDTH)
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher, A: Allocator + Clone> SwissMap<K, V, B, A> {
+    /// Finds the index of the bucket containing `key`, or None if the SwissMap doesn't contain it.
+    pub(crate) fn find_index_for_key<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.table.find(self.hasher.hash_one(key), |existing| existing.borrow() == key)
+    }
+
+    /// Inserts the provided `key`-`value` pair using a precomputed `hash`, without checking if the
+    /// SwissMap has enough capacity.
+    ///
+    /// # Panics
+    /// Panics if the SwissMap has a capacity of 0.
+    pub(crate) fn insert_hashed(&mut self, hash: u64, key: K, value: V) -> Option<V> {
+        if let Some(index) = self.table.find(hash, |existing| *existing == key) {
+            // SAFETY: find only returns the indices of full buckets.
+            let pair = unsafe { self.table.pair_mut(index) };
+            return Some(mem::replace(&mut pair.1, value));
+        }
+
+        let index = self.table.find_insert_slot(hash);
+        self.table.insert_at(index, hash, (key, value));
+        None
+    }
+
+    /// Finds the bucket for an entry with the provided `hash` for which `is_match` returns true.
+    /// Returns `Ok` with the index of a full bucket, or `Err` with the index of a free bucket into
+    /// which the entry can be inserted, having increased the capacity if required.
+    pub(crate) fn locate<F>(&mut self, hash: u64, is_match: F) -> Result<usize, usize>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        if let Some(index) = self.table.find(hash, is_match) {
+            return Ok(index);
+        }
+
+        self.reserve_one();
+        Err(self.table.find_insert_slot(hash))
+    }
+
+    /// Ensures that an entry can be inserted into an empty bucket without resizing, either by
+    /// clearing tombstones or by growing according to the SwissMap's [`HashPolicy`].
+    pub(crate) fn reserve_one(&mut self) {
+        if self.table.growth_left > 0 { return; }
+
+        if !self.rehash_in_place(self.len() + 1) {
+            let new_cap = table_cap(self.policy.grown_cap(self.cap(), self.len()));
+            self.resize(new_cap);
+        }
+    }
+
+    /// Rehashes the SwissMap at its current capacity to clear tombstones, if it would then be at
+    /// most half full with `new_len` entries. Returns true if the SwissMap was rehashed.
+    fn rehash_in_place(&mut self, new_len: usize) -> bool {
+        let rehash = self.cap() > 0 && new_len <= self.policy.max_len_for_cap(self.cap()) / 2;
+
+        if rehash {
+            self.resize(self.cap());
+        }
+        rehash
+    }
+
+    /// Shrinks the SwissMap if its length has fallen below the shrink threshold of its policy.
+    /// This is called after removals, rather than from within [`RawTable::remove_at`], so that
+    /// indices remain valid while removing multiple entries.
+    pub(crate) fn shrink_if_sparse(&mut self) {
+        if let Some(new_cap) = self.policy.shrunk_cap(self.cap(), self.len()) {
+            let new_cap = table_cap(new_cap);
+            if new_cap < self.cap() {
+                self.resize(new_cap);
+            }
+        }
+    }
+
+    /// Moves every entry into a new RawTable with the provided `new_cap`acity, hashing each key
+    /// again. `new_cap` must be a valid capacity which can hold the current entries without
+    /// exceeding the load factor.
+    pub(crate) fn resize(&mut self, new_cap: usize) {
+        let max_len = self.policy.max_len_for_cap(new_cap);
+        let mut new_table = RawTable::with_cap_in(new_cap, max_len, self.allocator().clone());
+
+        for index in 0..self.cap() {
+            if !self.table.is_full(index) {
+                continue;
+            }
+
+            // Hash the key while it is still in place, so that the old table remains valid if the
+            // hasher panics. The new table will drop any pairs that have already been moved.
+            // SAFETY: The bucket is full.
+            let hash = self.hasher.hash_one(unsafe { &self.table.pair(index).0 });
+            // SAFETY: The bucket is full.
+            let pair = unsafe { self.table.remove_at(index) };
+
+            let new_index = new_table.find_insert_slot(hash);
+            new_table.insert_at(new_index, hash, pair);
+        }
+
+        // The old table is now empty, so dropping it only deallocates.
+        self.table = new_table;
+    }
+}
+
+impl<K, V, B, A> Default for SwissMap<K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher + Default,
+    A: Allocator + Clone + Default,
+{
+    fn default() -> Self {
+        SwissMap::new_in(A::default())
+    }
+}
+
+impl<Q, K, V> Index<&Q> for SwissMap<K, V>
+where
+    Q: Hash + Eq + ?Sized,
+    K: Hash + Eq + Borrow<Q>,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).ok_or(NoValueForKey).throw()
+    }
+}
+
+impl<K, V, B, A> Extend<(K, V)> for SwissMap<K, V, B, A>
+where
+    K: Hash + Eq,
+    B: BuildHasher + Default,
+    A: Allocator + Clone,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+
+    fn extend_one(&mut self, item: (K, V)) {
+        self.insert(item.0, item.1);
+    }
+
+    fn extend_reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    unsafe fn extend_one_unchecked(&mut self, item: (K, V))
+    where
+        Self: Sized,
+    {
+        // SAFETY: extend_reserve is implemented correctly, so all other safety requirements are the
+        // responsibility of the caller.
+        unsafe { self.insert_unchecked(item.0, item.1); }
+    }
+}
+
+impl<K, V, B, I> From<I> for SwissMap<K, V, B>
+where
+    K: Hash + Eq,
+    B: BuildHasher + Default,
+    I: Iterator<Item = (K, V)> + ExactSizeIterator + TrustedLen,
+{
+    fn from(value: I) -> Self {
+        let iter = value.into_iter();
+        let mut map = SwissMap::with_cap(iter.len());
+
+        for (key, value) in iter {
+            // SAFETY: SwissMap has been created with the right capacity.
+            unsafe { map.insert_unchecked(key, value); }
+        }
+
+        map
+    }
+}
+
+impl<K: Hash + Eq, V, B: BuildHasher + Default> FromIterator<(K, V)> for SwissMap<K, V, B> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(value: I) -> Self {
+        let iter = value.into_iter();
+        let mut map = SwissMap::with_cap(iter.size_hint().0);
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+impl<K, V, B, A> Debug for SwissMap<K, V, B, A>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    B: BuildHasher + Debug,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SwissMap")
+            .field_with("buckets", |f| f.debug_list().entries(
+                (0..self.cap()).map(|index| DebugRaw(if self.table.is_full(index) {
+                    // SAFETY: The bucket is full.
+                    let (key, value) = unsafe { self.table.pair(index) };
+                    format!("({:?}: {:?})", key, value)
+                } else if self.table.ctrl[index] == DELETED {
+                    "x".into()
+                } else {
+                    "_".into()
+                }))
+            ).finish())
+            .field("len", &self.len())
+            .field("cap", &self.cap())
+            .field("hasher", &self.hasher)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl<K, V, B, A> Display for SwissMap<K, V, B, A>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    B: BuildHasher,
+    A: Allocator + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "#")?;
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,307 @@
+#![cfg(test)]
+
+use std::hash::BuildHasher;
+use std::mem;
+
+use super::group::{DELETED, EMPTY, Group};
+use super::*;
+use crate::collections::contiguous::Vector;
+use crate::collections::hash::{HashMap, HashPolicy};
+use crate::util::alloc::CountedDrop;
+use crate::util::hash::{BadHasherBuilder, ManualHash};
+use crate::util::random::XorShift;
+
+#[test]
+fn test_group_matching() {
+    let ctrl = [0x12, EMPTY, 0x13, DELETED, 0x12, 0x00, 0x7F, EMPTY];
+    let group = Group::load(&ctrl);
+
+    let tags: Vector<usize> = group.match_tag(0x12).collect();
+    assert!(tags.contains(&0) && tags.contains(&4), "Equal tags should always match.");
+    assert!(
+        tags.iter().all(|index| ctrl[*index] & 0x80 == 0),
+        "Tag matches should never include empty or deleted bytes."
+    );
+
+    assert_eq!(*group.match_empty().collect::<Vector<_>>(), [1, 7]);
+    assert_eq!(*group.match_empty_or_deleted().collect::<Vector<_>>(), [1, 3, 7]);
+    assert_eq!(group.match_tag(0x55).lowest(), None);
+}
+
+#[test]
+fn test_basic_operations() {
+    let mut map: SwissMap<usize, usize> = SwissMap::new();
+    assert_eq!(map.cap(), 0);
+    assert_eq!(map.get(&0), None);
+    assert_eq!(map.remove(&0), None);
+
+    for key in 0..1000 {
+        assert_eq!(map.insert(key, key * 2), None);
+    }
+    assert_eq!(map.insert(10, 0), Some(20), "Inserting an existing key should replace the value.");
+    assert_eq!(map.len(), 1000);
+    assert!(map.cap().is_power_of_two());
+
+    for key in 0..1000 {
+        assert_eq!(map.get(&key), Some(&if key == 10 { 0 } else { key * 2 }));
+    }
+
+    for key in (0..1000).step_by(2) {
+        assert_eq!(map.remove(&key), Some(if key == 10 { 0 } else { key * 2 }));
+    }
+    assert_eq!(map.len(), 500);
+    assert!((0..1000).all(|key| map.contains(&key) == (key % 2 == 1)));
+
+    for value in map.values_mut() {
+        *value += 1;
+    }
+    assert_eq!(map[&1], 3);
+    assert_eq!(map.iter().len(), 500);
+    assert_eq!(map.keys().copied().sum::<usize>(), 250_000);
+
+    map.shrink_to_fit();
+    assert_eq!(map.cap(), 1024, "shrink_to_fit should use the smallest power of two that fits.");
+    assert!((0..1000).all(|key| map.contains(&key) == (key % 2 == 1)));
+}
+
+#[test]
+fn test_collisions() {
+    let mut map = SwissMap::with_hasher(BadHasherBuilder);
+    for value in 0..100 {
+        map.insert(ManualHash::new(0, value), value);
+    }
+
+    for value in (0..100).step_by(3) {
+        assert_eq!(map.remove(&ManualHash::new(0, value)), Some(value));
+    }
+
+    for value in 0..100 {
+        assert_eq!(
+            map.get(&ManualHash::new(0, value)),
+            (value % 3 != 0).then_some(&value),
+            "Colliding entries should remain reachable after removals leave tombstones."
+        );
+    }
+}
+
+#[test]
+fn test_tombstone_churn() {
+    let mut map: SwissMap<usize, usize> = SwissMap::with_cap(1000);
+    let cap = map.cap();
+
+    // While at most half of the SwissMap is full, running out of empty buckets should only cause a
+    // rehash at the same capacity.
+    for round in 0..100 {
+        for key in 0..500 {
+            map.insert(round * 500 + key, key);
+        }
+        for key in 0..500 {
+            assert_eq!(map.remove(&(round * 500 + key)), Some(key));
+        }
+    }
+
+    assert!(map.is_empty());
+    assert_eq!(map.cap(), cap, "Tombstones should be cleared without growing the SwissMap.");
+}
+
+#[test]
+fn test_drops() {
+    let counter = CountedDrop::new(0);
+    let mut map: SwissMap<usize, CountedDrop> = SwissMap::new();
+    for key in 0..100 {
+        map.insert(key, counter.clone());
+    }
+
+    map.insert(0, counter.clone());
+    assert_eq!(*counter.borrow(), 1, "Replaced values should be returned and dropped.");
+
+    for key in 0..10 {
+        map.remove(&key);
+    }
+    assert_eq!(*counter.borrow(), 11);
+
+    let mut iter = map.into_iter();
+    iter.next();
+    assert_eq!(iter.len(), 89);
+    drop(iter);
+    assert_eq!(*counter.borrow(), 101, "Every value should be dropped exactly once.");
+}
+
+#[test]
+fn test_matches_hash_map() {
+    let mut swiss: SwissMap<u64, u64> = SwissMap::new();
+    let mut hash: HashMap<u64, u64> = HashMap::new();
+
+    for state in XorShift::default().take(50_000) {
+        let key = state % 2000;
+        match state % 3 {
+            0 => assert_eq!(swiss.remove(&key), hash.remove(&key)),
+            _ => assert_eq!(swiss.insert(key, state), hash.insert(key, state)),
+        }
+    }
+
+    assert_eq!(swiss.len(), hash.len());
+    assert!(hash.iter().all(|(key, value)| swiss.get(key) == Some(value)));
+}
+
+#[test]
+fn test_entry() {
+    let mut map: SwissMap<usize, usize> = SwissMap::new();
+    for key in 0..100 {
+        *map.entry(key % 10).or_default() += 1;
+    }
+    assert_eq!(map.len(), 10);
+    assert!((0..10).all(|key| map[&key] == 10));
+
+    map.entry(3).and_modify(|value| *value = 0).or_insert(1);
+    map.entry(30).and_modify(|value| *value = 0).or_insert(1);
+    assert_eq!((map[&3], map[&30]), (0, 1));
+
+    match map.entry(5) {
+        Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), (5, 10)),
+        Entry::Vacant(_) => panic!("Entry for an existing key should be occupied."),
+    }
+    match map.entry(5) {
+        Entry::Occupied(_) => panic!("Entry for a removed key should be vacant."),
+        Entry::Vacant(entry) => assert_eq!(*entry.insert_entry(7).get(), 7),
+    }
+    assert_eq!(map.entry(6).key(), &6);
+    assert_eq!(*map.entry(100).or_insert_with_key(|key| key * 2), 200);
+    assert_eq!(map.len(), 12);
+}
+
+#[test]
+fn test_raw_entry() {
+    let mut map: SwissMap<usize, usize> = SwissMap::new();
+    let hashes: Vector<u64> = (0..100_usize).map(|key| map.hasher().hash_one(key)).collect();
+
+    for key in 0..100 {
+        assert_eq!(map.insert_with_hash(hashes[key], key, key), None);
+    }
+    assert_eq!(map.insert_with_hash(hashes[5], 5, 50), Some(5));
+
+    for key in 0..100 {
+        assert_eq!(
+            map.raw_entry().from_hash(hashes[key], |k| *k == key).map(|(_, v)| *v),
+            Some(if key == 5 { 50 } else { key }),
+            "Entries inserted with a precomputed hash should be found after the SwissMap grows."
+        );
+    }
+
+    match map.raw_entry_mut().from_hash(hashes[42], |k| *k == 42) {
+        RawEntryMut::Occupied(entry) => {
+            assert_eq!(entry.hash(), hashes[42]);
+            assert_eq!(entry.remove_entry(), (42, 42));
+        },
+        RawEntryMut::Vacant(_) => panic!("Raw entry for an existing key should be occupied."),
+    }
+    assert_eq!(map.raw_entry().from_hash(hashes[42], |k| *k == 42), None);
+
+    let (key, value) = map.raw_entry_mut()
+        .from_hash(hashes[42], |k| *k == 42)
+        .and_modify(|_, _| panic!("Vacant raw entries shouldn't be modified."))
+        .or_insert(42, 420);
+    assert_eq!((*key, *value), (42, 420));
+    assert_eq!(map.len(), 100);
+}
+
+#[test]
+fn test_policy() {
+    let sparse = HashPolicy::new().with_load_factor(1, 2);
+    let mut map: SwissMap<usize, usize> = SwissMap::with_policy(sparse);
+    for key in 0..1000 {
+        map.insert(key, key);
+        assert!(map.len() * 2 <= map.cap(), "The load factor of 1/2 shouldn't be exceeded.");
+    }
+    assert_eq!(map.policy(), sparse);
+
+    map.reserve(1000);
+    let cap = map.cap();
+    for key in 1000..2000 {
+        map.insert(key, key);
+    }
+    assert_eq!(map.cap(), cap, "Reserved capacity should respect the load factor.");
+
+    for key in 100..2000 {
+        map.remove(&key);
+    }
+    map.shrink_to(1000);
+    assert_eq!(map.cap(), 1024, "shrink_to should round the minimum capacity up.");
+    map.shrink_to(0);
+    assert_eq!(map.cap(), 256, "Shrinking shouldn't exceed the load factor.");
+    assert!((0..100).all(|key| map.get(&key) == Some(&key)));
+
+    let policy = HashPolicy::new().with_shrink_threshold(1, 5);
+    let mut map: SwissMap<usize, usize> = SwissMap::with_policy(policy);
+    map.extend((0..10_000).map(|key| (key, key)));
+    let full_cap = map.cap();
+
+    for key in 0..9_900 {
+        assert_eq!(map.remove(&key), Some(key));
+    }
+    assert!(map.cap() < full_cap / 10, "The SwissMap should have shrunk after a large purge.");
+    assert!((9_900..10_000).all(|key| map.get(&key) == Some(&key)));
+}
+
+#[test]
+fn test_retain_and_extract_if() {
+    let mut map = SwissMap::with_hasher(BadHasherBuilder);
+    for value in 0..100 {
+        map.insert(ManualHash::new(0, value), value);
+    }
+
+    let mut calls = 0;
+    map.retain(|_, value| {
+        calls += 1;
+        *value % 2 == 0
+    });
+    assert_eq!(calls, 100, "The predicate should be invoked exactly once for each entry.");
+    assert_eq!(map.len(), 50);
+    for value in 0..100 {
+        assert_eq!(
+            map.get(&ManualHash::new(0, value)),
+            (value % 2 == 0).then_some(&value),
+            "Colliding entries should remain reachable after retain."
+        );
+    }
+
+    let mut extracted = map.extract_if(|_, value| *value % 4 == 0);
+    assert!(extracted.by_ref().take(5).all(|(_, value)| value % 4 == 0));
+    drop(extracted);
+    assert_eq!(map.len(), 45, "Dropping ExtractIf early should retain remaining entries.");
+
+    assert_eq!(map.extract_if(|_, value| *value % 4 == 0).count(), 20);
+    assert!(map.values().all(|value| value % 4 == 2));
+}
+
+#[test]
+fn test_drain() {
+    let counter = CountedDrop::new(0);
+    let mut map: SwissMap<usize, CountedDrop> = SwissMap::new();
+    for key in 0..100 {
+        map.insert(key, counter.clone());
+    }
+    for key in 0..10 {
+        map.remove(&key);
+    }
+    assert_eq!(counter.take(), 10);
+    let cap = map.cap();
+
+    let mut drain = map.drain();
+    assert_eq!(drain.len(), 90);
+    drop(drain.by_ref().take(10).collect::<Vector<_>>());
+    assert_eq!(counter.take(), 10);
+    drop(drain);
+
+    assert_eq!(counter.take(), 80, "Dropping a Drain should drop all remaining entries.");
+    assert!(map.is_empty());
+    assert_eq!(map.cap(), cap, "Draining should retain the SwissMap's capacity.");
+
+    map.extend((0..100).map(|key| (key, counter.clone())));
+    assert_eq!(map.cap(), cap, "Draining should clear tombstones.");
+
+    mem::forget(map.drain());
+    assert!(map.is_empty(), "A leaked Drain should leave the SwissMap empty.");
+    map.insert(2, counter.clone());
+    assert!(map.contains(&2), "A leaked Drain should leave the SwissMap usable.");
+}
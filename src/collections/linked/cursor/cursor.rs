@@ -1,12 +1,16 @@
 use std::hash::{Hash, Hasher};
 use std::hint;
 use std::marker::PhantomData;
+use std::mem;
+use std::ops::RangeBounds;
+use std::ptr;
 
 use derive_more::IsVariant;
 
 use super::{State, StateMut};
 use crate::collections::linked::list::{Length, LinkedList, ListContents, ListState, Node, NodePtr};
 use crate::util::error::{CapacityOverflow, IndexOutOfBounds};
+use crate::util::range::resolve_range;
 use crate::util::result::ResultExtension;
 
 /// A type for bi-directional traversal and mutation of [`LinkedList`]s. See
@@ -42,15 +46,9 @@ use CursorPosition::*;
 
 impl<T> Cursor<T> {
     pub const fn list(self) -> LinkedList<T> {
-        match self.state {
-            Empty => LinkedList {
-                state: ListState::Empty,
-                _phantom: PhantomData,
-            },
-            Full(CursorContents { list, .. }) => LinkedList {
-                state: ListState::Full(list),
-                _phantom: PhantomData,
-            },
+        match self.into_state() {
+            Empty => LinkedList::new(),
+            Full(CursorContents { list, .. }) => LinkedList::from_contents(list),
         }
     }
 
@@ -143,20 +141,7 @@ impl<T> Cursor<T> {
     pub const fn move_next(&mut self) -> &mut Self {
         match &mut self.state {
             Empty => (),
-            Full(CursorContents { list, pos }) => match pos {
-                Head => *pos = Ptr {
-                    ptr: list.head,
-                    index: 0,
-                },
-                Tail => (),
-                Ptr { ptr, index } => *pos = match ptr.next() {
-                    Some(next_node) => Ptr {
-                        ptr: *next_node,
-                        index: *index + 1,
-                    },
-                    None => Tail,
-                },
-            },
+            Full(CursorContents { list, pos }) => pos.move_next(list),
         }
         self
     }
@@ -164,20 +149,7 @@ impl<T> Cursor<T> {
     pub const fn move_prev(&mut self) -> &mut Self {
         match &mut self.state {
             Empty => (),
-            Full(CursorContents { list, pos }) => match pos {
-                Head => (),
-                Tail => *pos = Ptr {
-                    ptr: list.tail,
-                    index: list.last_index(),
-                },
-                Ptr { ptr, index } => *pos = match ptr.prev() {
-                    Some(prev_node) => Ptr {
-                        ptr: *prev_node,
-                        index: *index - 1,
-                    },
-                    None => Head,
-                },
-            },
+            Full(CursorContents { list, pos }) => pos.move_prev(list),
         }
         self
     }
@@ -213,8 +185,9 @@ impl<T> Cursor<T> {
             Full(CursorContents { list, pos }) => match pos {
                 Head => list.push_front(value),
                 Tail => list.push_back(value),
-                Ptr { ptr, .. } => {
+                Ptr { ptr, index } => {
                     list.len = list.len.checked_add(1).ok_or(CapacityOverflow).throw();
+                    *index += 1;
 
                     let node = NodePtr::from_node(Node {
                         value,
@@ -292,18 +265,19 @@ impl<T> Cursor<T> {
                         match node.prev {
                             Some(prev_node) => {
                                 *prev_node.next_mut() = None;
-                                list.head = prev_node;
+                                list.tail = prev_node;
                                 // SAFETY: We've removed 1 node from a list we know to have at least
-                                // two: node and next_node.
+                                // two: node and prev_node.
                                 list.len = unsafe { list.len.checked_sub(1).unwrap_unchecked() };
                             },
                             None => self.state = Empty,
                         }
                         Some(node.value)
                     },
-                    Ptr { ptr, .. } => {
+                    Ptr { ptr, index } => {
                         match ptr.prev_mut() {
                             Some(prev_ptr) => {
+                                *index -= 1;
                                 let prev_node = prev_ptr.take_node();
                                 match prev_node.prev {
                                     Some(second_prev) => {
@@ -320,7 +294,7 @@ impl<T> Cursor<T> {
                                 list.len = unsafe { list.len.checked_sub(1).unwrap_unchecked() };
                                 Some(prev_node.value)
                             },
-                            // We are on a node without a next value, so we return None, despite not
+                            // We are on a node without a prev value, so we return None, despite not
                             // being empty.
                             None => None,
                         }
@@ -518,73 +492,177 @@ impl<T> Cursor<T> {
     }
 
     pub const fn split_before(self) -> (LinkedList<T>, LinkedList<T>) {
-        match &self.state {
-            Empty => (LinkedList::new(), LinkedList::new()),
-            Full(CursorContents { list, pos }) => match pos {
-                Head => (LinkedList::new(), self.list()),
-                Tail => (self.list(), LinkedList::new()),
-                Ptr { index, .. } if *index == 0 => (LinkedList::new(), self.list()),
-                Ptr { ptr, index } => (
-                    LinkedList {
-                        state: ListState::Full(ListContents {
-                            // SAFETY: index = 0 matches the previous branch.
-                            len: unsafe { Length::new_unchecked(*index) },
-                            head: list.head,
-                            // SAFETY: index != 0, so prev is Some.
-                            tail: unsafe { ptr.prev().unwrap_unchecked() },
-                        }),
-                        _phantom: PhantomData,
-                    },
-                    LinkedList {
-                        state: ListState::Full(ListContents {
-                            // SAFETY: index is in the range 0..list.len so list.len - index as at
-                            // least 1.
-                            len: unsafe { Length::new_unchecked(list.len.get() - *index) },
-                            head: *ptr,
-                            tail: list.tail,
-                        }),
-                        _phantom: PhantomData,
-                    },
-                ),
+        let Full(CursorContents { list, pos }) = self.into_state() else {
+            return (LinkedList::new(), LinkedList::new());
+        };
+
+        match pos {
+            Head | Ptr { index: 0, .. } => (LinkedList::new(), LinkedList::from_contents(list)),
+            Tail => (LinkedList::from_contents(list), LinkedList::new()),
+            Ptr { ptr, index } => {
+                // SAFETY: index != 0, so prev is Some.
+                let tail = unsafe { ptr.prev().unwrap_unchecked() };
+                // Cut the links between the two lists, so that neither can reach the other.
+                *tail.next_mut() = None;
+                *ptr.prev_mut() = None;
+
+                (
+                    LinkedList::from_contents(ListContents {
+                        // SAFETY: index = 0 matches the previous branch.
+                        len: unsafe { Length::new_unchecked(index) },
+                        head: list.head,
+                        tail,
+                    }),
+                    LinkedList::from_contents(ListContents {
+                        // SAFETY: index is in the range 0..list.len so list.len - index is at
+                        // least 1.
+                        len: unsafe { Length::new_unchecked(list.len.get() - index) },
+                        head: ptr,
+                        tail: list.tail,
+                    }),
+                )
             },
         }
     }
 
     pub const fn split_after(self) -> (LinkedList<T>, LinkedList<T>) {
-        match &self.state {
-            Empty => (LinkedList::new(), LinkedList::new()),
+        let Full(CursorContents { list, pos }) = self.into_state() else {
+            return (LinkedList::new(), LinkedList::new());
+        };
+
+        match pos {
+            Head => (LinkedList::new(), LinkedList::from_contents(list)),
+            Tail => (LinkedList::from_contents(list), LinkedList::new()),
+            Ptr { index, .. } if index == list.last_index() => {
+                (LinkedList::from_contents(list), LinkedList::new())
+            },
+            Ptr { ptr, index } => {
+                // SAFETY: index = list.last_index() matches the previous branch, so next is Some.
+                let head = unsafe { ptr.next().unwrap_unchecked() };
+                // Cut the links between the two lists, so that neither can reach the other.
+                *ptr.next_mut() = None;
+                *head.prev_mut() = None;
+
+                (
+                    LinkedList::from_contents(ListContents {
+                        // SAFETY: value is at least 1.
+                        len: unsafe { Length::new_unchecked(index + 1) },
+                        head: list.head,
+                        tail: ptr,
+                    }),
+                    LinkedList::from_contents(ListContents {
+                        // SAFETY: index = list.last_index() matches the previous branch, so
+                        // list.last_index() - index is > 0.
+                        len: unsafe { Length::new_unchecked(list.last_index() - index) },
+                        head,
+                        tail: list.tail,
+                    }),
+                )
+            },
+        }
+    }
+
+    /// Links all elements of `other` into the list before the cursor, without moving any values.
+    /// If the cursor is at the head, they are added to the front of the list instead.
+    ///
+    /// The cursor stays on the same element, although its index increases by the length of
+    /// `other`.
+    ///
+    /// # Panics
+    /// Panics if the combined length of the lists overflows.
+    pub fn splice_before(&mut self, mut other: LinkedList<T>) {
+        let ListState::Full(other) = mem::take(&mut other.state) else { return };
+
+        match &mut self.state {
+            Empty => self.state = Full(CursorContents { list: other, pos: Tail }),
             Full(CursorContents { list, pos }) => match pos {
-                Head => (LinkedList::new(), self.list()),
-                Tail => (self.list(), LinkedList::new()),
-                Ptr { index, .. } if *index == list.last_index() => {
-                    (LinkedList::new(), self.list())
+                Head => list.splice_after(None, other),
+                Tail => list.splice_after(Some(list.tail), other),
+                Ptr { ptr, index } => {
+                    *index += other.len.get();
+                    list.splice_after(*ptr.prev(), other);
                 },
-                Ptr { ptr, index } => (
-                    LinkedList {
-                        state: ListState::Full(ListContents {
-                            // SAFETY: value is at least 1.
-                            len: unsafe { Length::new_unchecked(*index + 1) },
-                            head: list.head,
-                            tail: *ptr,
-                        }),
-                        _phantom: PhantomData,
-                    },
-                    LinkedList {
-                        state: ListState::Full(ListContents {
-                            // SAFETY: index = list.last_index() matches the previous branch, so
-                            // list.last_index() - index is > 0.
-                            len: unsafe { Length::new_unchecked(list.last_index() - *index) },
-                            // SAFETY: index = list.last_index() matches the previous branch, so
-                            // next is Some.
-                            head: unsafe { ptr.next().unwrap_unchecked() },
-                            tail: list.tail,
-                        }),
-                        _phantom: PhantomData,
-                    },
-                ),
             },
         }
     }
+
+    /// Links all elements of `other` into the list after the cursor, without moving any values. If
+    /// the cursor is at the tail, they are added to the back of the list instead.
+    ///
+    /// # Panics
+    /// Panics if the combined length of the lists overflows.
+    pub fn splice_after(&mut self, mut other: LinkedList<T>) {
+        let ListState::Full(other) = mem::take(&mut other.state) else { return };
+
+        match &mut self.state {
+            Empty => self.state = Full(CursorContents { list: other, pos: Head }),
+            Full(CursorContents { list, pos }) => match pos {
+                Head => list.splice_after(None, other),
+                Tail => list.splice_after(Some(list.tail), other),
+                Ptr { ptr, .. } => list.splice_after(Some(*ptr), other),
+            },
+        }
+    }
+
+    /// Removes the elements in the provided range of indices from the list, returning them as a
+    /// new LinkedList. No values are moved, only the links at either end of the range are cut.
+    ///
+    /// If the cursor is on an element after the range, its index is reduced accordingly. If it is
+    /// on an element within the range, it moves to the first element after the range, or the tail
+    /// if there isn't one.
+    ///
+    /// # Panics
+    /// Panics if the range starts after it ends or ends after the length of the list.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::linked::LinkedList;
+    /// let mut cursor = (0..6).collect::<LinkedList<_>>().cursor_front();
+    /// cursor.move_to(4);
+    /// let middle = cursor.cut(1..3);
+    /// assert!(middle.iter().eq(&[1, 2]));
+    /// assert_eq!(cursor.read(), Some(&4));
+    /// assert_eq!(cursor.index(), Some(2));
+    /// assert!(cursor.list().iter().eq(&[0, 3, 4, 5]));
+    /// ```
+    pub fn cut<R: RangeBounds<usize>>(&mut self, range: R) -> LinkedList<T> {
+        let range = resolve_range(range, self.len()).throw();
+        let count = range.len();
+
+        let contents = match &mut self.state {
+            Full(contents) if count != 0 => contents,
+            _ => return LinkedList::new(),
+        };
+
+        if count == contents.list.len.get() {
+            return mem::replace(self, LinkedList::new().cursor_head()).list();
+        }
+
+        let first = contents.seek(range.start);
+        let last = contents.seek(range.end - 1);
+
+        if let Ptr { ptr, index } = contents.pos {
+            if index >= range.end {
+                contents.pos = Ptr { ptr, index: index - count };
+            } else if index >= range.start {
+                contents.pos = match *last.next() {
+                    Some(ptr) => Ptr { ptr, index: range.start },
+                    None => Tail,
+                };
+            }
+        }
+
+        // SAFETY: first..=last is in order and contains count nodes, which is less than the length
+        // of the list.
+        let cut = unsafe {
+            contents.list.detach(first, last, Length::new_unchecked(count))
+        };
+
+        LinkedList {
+            state: ListState::Full(cut),
+            _phantom: PhantomData,
+        }
+    }
 }
 
 impl<T> Cursor<T> {
@@ -630,7 +708,12 @@ impl<T> Cursor<T> {
     pub fn push_front(&mut self, value: T) {
         match &mut self.state {
             Empty => self.state = CursorState::single(value, Head),
-            Full(CursorContents { list, .. }) => list.push_front(value),
+            Full(CursorContents { list, pos }) => {
+                list.push_front(value);
+                if let Ptr { index, .. } = pos {
+                    *index += 1;
+                }
+            },
         }
     }
 
@@ -649,6 +732,7 @@ impl<T> Cursor<T> {
                     // If we're pointing to the node we need to pop, move to head. Might be strange
                     // but at least its obvious that we've moved.
                     Ptr { ptr, .. } if *ptr == list.head => *pos = Head,
+                    Ptr { index, .. } => *index -= 1,
                     _ => (),
                 }
 
@@ -688,7 +772,7 @@ impl<T> Cursor<T> {
                         // preceded by at least one more.
                         let new_tail = unsafe { node.prev.unwrap_unchecked() };
                         list.tail = new_tail;
-                        *new_tail.prev_mut() = None;
+                        *new_tail.next_mut() = None;
                         list.len = new_len;
                     },
                     None => self.state = Empty,
@@ -719,6 +803,16 @@ impl<T> Cursor<T> {
 }
 
 impl<T> Cursor<T> {
+    /// Moves the state out of the cursor without dropping the list it contains, so that the list
+    /// can be handed on.
+    pub(crate) const fn into_state(self) -> CursorState<T> {
+        // SAFETY: self is forgotten straight after being read from, so the list is only owned by
+        // the returned state.
+        let state = unsafe { ptr::read(&self.state) };
+        mem::forget(self);
+        state
+    }
+
     pub(crate) fn checked_seek(&self, index: usize) -> Result<NodePtr<T>, IndexOutOfBounds> {
         Ok(self.checked_contents_for_index(index)?.seek(index))
     }
@@ -769,13 +863,31 @@ impl<T> CursorState<T> {
 
 impl<T> CursorContents<T> {
     pub fn seek(&self, target: usize) -> NodePtr<T> {
-        match self.pos {
-            Head | Tail => self.list.seek(target),
+        self.pos.seek(&self.list, target)
+    }
+}
+
+impl<T> Drop for Cursor<T> {
+    fn drop(&mut self) {
+        if let Full(CursorContents { list, .. }) = mem::replace(&mut self.state, Empty) {
+            drop(LinkedList::from_contents(list));
+        }
+    }
+}
+
+// Movement is shared between the owning Cursor and the borrowing CursorRef and CursorMut, so it is
+// implemented on the position itself, with the list contents passed in alongside it.
+impl<T> CursorPosition<T> {
+    /// Returns the node at `target`, starting from whichever of the current position or the ends
+    /// of `list` is closest.
+    pub fn seek(&self, list: &ListContents<T>, target: usize) -> NodePtr<T> {
+        match *self {
+            Head | Tail => list.seek(target),
             Ptr { ptr, index } => {
-                let end = if target < self.list.len.get() / 2 {
-                    (self.list.head, 0)
+                let end = if target < list.len.get() / 2 {
+                    (list.head, 0)
                 } else {
-                    (self.list.tail, self.list.last_index())
+                    (list.tail, list.last_index())
                 };
 
                 let offset_ptr = target as i128 - index as i128;
@@ -784,24 +896,105 @@ impl<T> CursorContents<T> {
                 let offset_end = target as i128 - end.1 as i128;
                 let end_dist = offset_end.unsigned_abs() as usize;
 
-                if ptr_dist < end_dist {
-                    self.seek_n(ptr_dist, ptr, offset_ptr.is_positive())
+                let (count, start, forward) = if ptr_dist < end_dist {
+                    (ptr_dist, ptr, offset_ptr.is_positive())
+                } else {
+                    (end_dist, end.0, offset_end.is_positive())
+                };
+
+                if forward {
+                    list.seek_fwd(count, start)
                 } else {
-                    self.seek_n(end_dist, end.0, offset_end.is_positive())
+                    list.seek_bwd(count, start)
                 }
             },
         }
     }
 
-    pub fn seek_n(&self, count: usize, start: NodePtr<T>, forward: bool) -> NodePtr<T> {
-        if forward {
-            self.list.seek_fwd(count, start)
+    pub const fn move_next(&mut self, list: &ListContents<T>) {
+        *self = match *self {
+            Head => Ptr { ptr: list.head, index: 0 },
+            Tail => Tail,
+            Ptr { ptr, index } => match ptr.next() {
+                Some(next_node) => Ptr { ptr: *next_node, index: index + 1 },
+                None => Tail,
+            },
+        };
+    }
+
+    pub const fn move_prev(&mut self, list: &ListContents<T>) {
+        *self = match *self {
+            Head => Head,
+            Tail => Ptr { ptr: list.tail, index: list.last_index() },
+            Ptr { ptr, index } => match ptr.prev() {
+                Some(prev_node) => Ptr { ptr: *prev_node, index: index - 1 },
+                None => Head,
+            },
+        };
+    }
+
+    /// Moves `offset` places through `list`, stopping at the head or tail if that position is
+    /// outside of the list.
+    pub const fn move_offset(&mut self, list: &ListContents<T>, offset: isize) {
+        if offset == 0 { return; }
+
+        *self = match self.offset(list, offset) {
+            Some((ptr, index)) => Ptr { ptr, index },
+            None if offset < 0 => Head,
+            None => Tail,
+        };
+    }
+
+    /// Returns the node `offset` places from the position and its index, or [`None`] if that
+    /// position is outside of the list.
+    pub const fn offset(
+        &self,
+        list: &ListContents<T>,
+        offset: isize,
+    ) -> Option<(NodePtr<T>, usize)> {
+        let forward = offset > 0;
+        let (mut ptr, index, steps) = match (self, forward) {
+            (Ptr { ptr, index }, _) => (*ptr, *index, offset.unsigned_abs()),
+            (Head, true) => (list.head, 0, offset.unsigned_abs() - 1),
+            (Tail, false) if offset != 0 => {
+                (list.tail, list.last_index(), offset.unsigned_abs() - 1)
+            },
+            _ => return None,
+        };
+
+        let target = if forward {
+            match index.checked_add(steps) {
+                Some(target) if target < list.len.get() => target,
+                _ => return None,
+            }
         } else {
-            self.list.seek_bwd(count, start)
+            match index.checked_sub(steps) {
+                Some(target) => target,
+                None => return None,
+            }
+        };
+
+        let mut remaining = steps;
+        while remaining > 0 {
+            let link = if forward { ptr.next() } else { ptr.prev() };
+            // SAFETY: target is within the list, so there are at least steps nodes in this
+            // direction.
+            ptr = unsafe { link.unwrap_unchecked() };
+            remaining -= 1;
         }
+
+        Some((ptr, target))
+    }
+}
+
+impl<T> Clone for CursorPosition<T> {
+    fn clone(&self) -> Self {
+        *self
     }
 }
 
+impl<T> Copy for CursorPosition<T> {}
+
 impl<T> Hash for CursorPosition<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
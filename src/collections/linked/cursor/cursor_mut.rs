@@ -0,0 +1,233 @@
+use super::{CursorPosition, CursorRef, State, StateMut};
+use crate::collections::linked::list::{LinkedList, ListContents, ListState};
+use crate::util::error::IndexOutOfBounds;
+use crate::util::result::ResultExtension;
+
+use CursorPosition::*;
+
+/// A cursor which borrows a [`LinkedList`] mutably, allowing for bi-directional traversal and
+/// editing without taking ownership of the list. See [`LinkedList::cursor_front_mut`] and
+/// [`LinkedList::cursor_back_mut`] to create one.
+///
+/// # Examples
+/// ```
+/// # use standard_lib::collections::linked::LinkedList;
+/// let mut list: LinkedList<_> = (0..5).collect();
+///
+/// let mut cursor = list.cursor_front_mut();
+/// cursor.move_to(2);
+/// cursor.push_next(10);
+/// assert_eq!(cursor.pop_prev(), Some(1));
+/// assert_eq!(cursor.index(), Some(1));
+///
+/// assert!(list.iter().eq(&[0, 2, 10, 3, 4]));
+/// ```
+pub struct CursorMut<'a, T> {
+    pub(crate) list: &'a mut LinkedList<T>,
+    pub(crate) pos: CursorPosition<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Returns a read-only cursor at the same position, which borrows from this one.
+    pub const fn as_cursor_ref(&self) -> CursorRef<'_, T> {
+        CursorRef {
+            list: self.list,
+            pos: self.pos,
+        }
+    }
+
+    /// Returns the index of the element that the cursor points to, or [`None`] if it is at the
+    /// head or tail.
+    pub const fn index(&self) -> Option<usize> {
+        self.as_cursor_ref().index()
+    }
+
+    /// Returns a reference to the element that the cursor points to, or [`None`] if it is at the
+    /// head or tail.
+    pub const fn read(&self) -> Option<&T> {
+        self.as_cursor_ref().read()
+    }
+
+    /// Returns a mutable reference to the element that the cursor points to, or [`None`] if it is
+    /// at the head or tail.
+    pub const fn read_mut(&mut self) -> Option<&mut T> {
+        match self.pos {
+            Ptr { mut ptr, .. } => Some(ptr.value_mut()),
+            Head | Tail => None,
+        }
+    }
+
+    /// Returns a reference to the element after the cursor, if there is one.
+    pub const fn read_next(&self) -> Option<&T> {
+        self.as_cursor_ref().read_next()
+    }
+
+    /// Returns a mutable reference to the element after the cursor, if there is one.
+    pub const fn read_next_mut(&mut self) -> Option<&mut T> {
+        self.read_offset_mut(1)
+    }
+
+    /// Returns a reference to the element before the cursor, if there is one.
+    pub const fn read_prev(&self) -> Option<&T> {
+        self.as_cursor_ref().read_prev()
+    }
+
+    /// Returns a mutable reference to the element before the cursor, if there is one.
+    pub const fn read_prev_mut(&mut self) -> Option<&mut T> {
+        self.read_offset_mut(-1)
+    }
+
+    /// Returns a reference to the element `offset` places after the cursor, or before it if
+    /// `offset` is negative. Returns [`None`] if that position is outside of the list.
+    pub const fn read_offset(&self, offset: isize) -> Option<&T> {
+        self.as_cursor_ref().read_offset(offset)
+    }
+
+    /// Returns a mutable reference to the element `offset` places after the cursor, or before it
+    /// if `offset` is negative. Returns [`None`] if that position is outside of the list.
+    pub const fn read_offset_mut(&mut self, offset: isize) -> Option<&mut T> {
+        let Some(list) = self.as_cursor_ref().contents() else { return None };
+
+        match self.pos.offset(list, offset) {
+            Some((mut ptr, _)) => Some(ptr.value_mut()),
+            None => None,
+        }
+    }
+
+    /// Returns the state of the cursor, including a reference to the element it points to.
+    pub const fn state(&self) -> State<'_, T> {
+        self.as_cursor_ref().state()
+    }
+
+    /// Returns the state of the cursor, including a mutable reference to the element it points to.
+    pub const fn state_mut(&mut self) -> StateMut<'_, T> {
+        match self.pos {
+            _ if self.list.is_empty() => StateMut::Empty,
+            Head => StateMut::Head,
+            Tail => StateMut::Tail,
+            Ptr { mut ptr, .. } => StateMut::Node(ptr.value_mut()),
+        }
+    }
+
+    /// Returns true if the cursor is at the 'ghost' element before the start of a non-empty list.
+    pub const fn is_head(&self) -> bool {
+        self.as_cursor_ref().is_head()
+    }
+
+    /// Returns true if the cursor is at the 'ghost' element after the end of a non-empty list.
+    pub const fn is_tail(&self) -> bool {
+        self.as_cursor_ref().is_tail()
+    }
+
+    /// Moves the cursor to the next element, or the tail if it is on the last element.
+    pub const fn move_next(&mut self) -> &mut Self {
+        if let ListState::Full(list) = &self.list.state {
+            self.pos.move_next(list);
+        }
+        self
+    }
+
+    /// Moves the cursor to the previous element, or the head if it is on the first element.
+    pub const fn move_prev(&mut self) -> &mut Self {
+        if let ListState::Full(list) = &self.list.state {
+            self.pos.move_prev(list);
+        }
+        self
+    }
+
+    /// Moves the cursor `offset` places forwards, or backwards if `offset` is negative. If that
+    /// position is outside of the list, the cursor moves to the head or tail instead.
+    pub const fn move_offset(&mut self, offset: isize) -> &mut Self {
+        if let ListState::Full(list) = &self.list.state {
+            self.pos.move_offset(list, offset);
+        }
+        self
+    }
+
+    /// Moves the cursor to the element at `index`, panicking on a failure.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds of the list.
+    pub fn move_to(&mut self, index: usize) -> &mut Self {
+        self.try_move_to(index).throw()
+    }
+
+    /// Moves the cursor to the element at `index`, returning an [`Err`] on a failure rather than
+    /// panicking.
+    pub fn try_move_to(&mut self, index: usize) -> Result<&mut Self, IndexOutOfBounds> {
+        let list = self.list.checked_contents_for_index(index)?;
+        self.pos = Ptr {
+            ptr: self.pos.seek(list, index),
+            index,
+        };
+        Ok(self)
+    }
+
+    /// Inserts `value` into the list directly after the cursor. If the cursor is at the tail, the
+    /// value is added to the back of the list instead.
+    ///
+    /// # Panics
+    /// Panics if the length of the list overflows.
+    pub fn push_next(&mut self, value: T) {
+        match (&mut self.list.state, self.pos) {
+            (ListState::Empty, _) => {
+                self.list.push_back(value);
+                self.pos = Head;
+            },
+            (ListState::Full(list), Head) => list.push_front(value),
+            (ListState::Full(list), Tail) => list.push_back(value),
+            (ListState::Full(list), Ptr { ptr, .. }) => {
+                list.splice_after(Some(ptr), ListContents::wrap_one(value));
+            },
+        }
+    }
+
+    /// Inserts `value` into the list directly before the cursor. If the cursor is at the head, the
+    /// value is added to the front of the list instead.
+    ///
+    /// # Panics
+    /// Panics if the length of the list overflows.
+    pub fn push_prev(&mut self, value: T) {
+        match (&mut self.list.state, self.pos) {
+            (ListState::Empty, _) => {
+                self.list.push_back(value);
+                self.pos = Tail;
+            },
+            (ListState::Full(list), Head) => list.push_front(value),
+            (ListState::Full(list), Tail) => list.push_back(value),
+            (ListState::Full(list), Ptr { ptr, index }) => {
+                list.splice_after(*ptr.prev(), ListContents::wrap_one(value));
+                self.pos = Ptr { ptr, index: index + 1 };
+            },
+        }
+    }
+
+    /// Removes the element after the cursor and returns it, if there is one. If the cursor is at
+    /// the head, the first element of the list is removed.
+    pub fn pop_next(&mut self) -> Option<T> {
+        match self.pos {
+            Head => self.list.pop_front(),
+            Tail => None,
+            Ptr { ptr, .. } => {
+                let next = (*ptr.next())?;
+                // SAFETY: next follows the cursor's node, so it is part of the list.
+                Some(unsafe { self.list.unlink(next) })
+            },
+        }
+    }
+
+    /// Removes the element before the cursor and returns it, if there is one. If the cursor is at
+    /// the tail, the last element of the list is removed.
+    pub fn pop_prev(&mut self) -> Option<T> {
+        match self.pos {
+            Head => None,
+            Tail => self.list.pop_back(),
+            Ptr { ptr, index } => {
+                let prev = (*ptr.prev())?;
+                self.pos = Ptr { ptr, index: index - 1 };
+                // SAFETY: prev precedes the cursor's node, so it is part of the list.
+                Some(unsafe { self.list.unlink(prev) })
+            },
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use super::{CursorPosition, State};
+use crate::collections::linked::list::{LinkedList, ListContents, ListState};
+use crate::util::error::IndexOutOfBounds;
+use crate::util::result::ResultExtension;
+
+use CursorPosition::*;
+
+/// A cursor which borrows a [`LinkedList`] immutably, allowing for bi-directional traversal
+/// without taking ownership of the list. See [`LinkedList::cursor_front_ref`] and
+/// [`LinkedList::cursor_back_ref`] to create one.
+///
+/// Any number of CursorRefs can exist for the same list at once, and they can be freely copied.
+///
+/// # Examples
+/// ```
+/// # use standard_lib::collections::linked::LinkedList;
+/// let list: LinkedList<_> = (0..5).collect();
+/// let mut cursor = list.cursor_front_ref();
+/// cursor.move_to(3);
+/// assert_eq!(cursor.read(), Some(&3));
+/// assert_eq!(cursor.read_offset(-2), Some(&1));
+/// assert_eq!(list.len(), 5);
+/// ```
+pub struct CursorRef<'a, T> {
+    pub(crate) list: &'a LinkedList<T>,
+    pub(crate) pos: CursorPosition<T>,
+}
+
+impl<'a, T> CursorRef<'a, T> {
+    /// Returns the list that the cursor borrows.
+    pub const fn list(&self) -> &'a LinkedList<T> {
+        self.list
+    }
+
+    /// Returns the index of the element that the cursor points to, or [`None`] if it is at the
+    /// head or tail.
+    pub const fn index(&self) -> Option<usize> {
+        match self.pos {
+            Ptr { index, .. } => Some(index),
+            Head | Tail => None,
+        }
+    }
+
+    /// Returns a reference to the element that the cursor points to, or [`None`] if it is at the
+    /// head or tail.
+    pub const fn read(&self) -> Option<&'a T> {
+        match self.pos {
+            Ptr { ptr, .. } => Some(ptr.value()),
+            Head | Tail => None,
+        }
+    }
+
+    /// Returns a reference to the element after the cursor, if there is one.
+    pub const fn read_next(&self) -> Option<&'a T> {
+        self.read_offset(1)
+    }
+
+    /// Returns a reference to the element before the cursor, if there is one.
+    pub const fn read_prev(&self) -> Option<&'a T> {
+        self.read_offset(-1)
+    }
+
+    /// Returns a reference to the element `offset` places after the cursor, or before it if
+    /// `offset` is negative. Returns [`None`] if that position is outside of the list.
+    pub const fn read_offset(&self, offset: isize) -> Option<&'a T> {
+        let Some(list) = self.contents() else { return None };
+
+        match self.pos.offset(list, offset) {
+            Some((ptr, _)) => Some(ptr.value()),
+            None => None,
+        }
+    }
+
+    /// Returns the state of the cursor, including a reference to the element it points to.
+    pub const fn state(&self) -> State<'a, T> {
+        match self.pos {
+            _ if self.list.is_empty() => State::Empty,
+            Head => State::Head,
+            Tail => State::Tail,
+            Ptr { ptr, .. } => State::Node(ptr.value()),
+        }
+    }
+
+    /// Returns true if the cursor is at the 'ghost' element before the start of a non-empty list.
+    pub const fn is_head(&self) -> bool {
+        !self.list.is_empty() && self.pos.is_head()
+    }
+
+    /// Returns true if the cursor is at the 'ghost' element after the end of a non-empty list.
+    pub const fn is_tail(&self) -> bool {
+        !self.list.is_empty() && self.pos.is_tail()
+    }
+
+    /// Moves the cursor to the next element, or the tail if it is on the last element.
+    pub const fn move_next(&mut self) -> &mut Self {
+        if let Some(list) = self.contents() {
+            self.pos.move_next(list);
+        }
+        self
+    }
+
+    /// Moves the cursor to the previous element, or the head if it is on the first element.
+    pub const fn move_prev(&mut self) -> &mut Self {
+        if let Some(list) = self.contents() {
+            self.pos.move_prev(list);
+        }
+        self
+    }
+
+    /// Moves the cursor `offset` places forwards, or backwards if `offset` is negative. If that
+    /// position is outside of the list, the cursor moves to the head or tail instead.
+    pub const fn move_offset(&mut self, offset: isize) -> &mut Self {
+        if let Some(list) = self.contents() {
+            self.pos.move_offset(list, offset);
+        }
+        self
+    }
+
+    /// Moves the cursor to the element at `index`, panicking on a failure.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds of the list.
+    pub fn move_to(&mut self, index: usize) -> &mut Self {
+        self.try_move_to(index).throw()
+    }
+
+    /// Moves the cursor to the element at `index`, returning an [`Err`] on a failure rather than
+    /// panicking.
+    pub fn try_move_to(&mut self, index: usize) -> Result<&mut Self, IndexOutOfBounds> {
+        let list = self.list.checked_contents_for_index(index)?;
+        self.pos = Ptr {
+            ptr: self.pos.seek(list, index),
+            index,
+        };
+        Ok(self)
+    }
+
+    pub(crate) const fn contents(&self) -> Option<&'a ListContents<T>> {
+        match &self.list.state {
+            ListState::Empty => None,
+            ListState::Full(contents) => Some(contents),
+        }
+    }
+}
+
+impl<'a, T> Clone for CursorRef<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for CursorRef<'a, T> {}
@@ -1,5 +1,9 @@
 mod cursor;
+mod cursor_mut;
+mod cursor_ref;
 mod state;
 
 pub use cursor::*;
+pub use cursor_mut::*;
+pub use cursor_ref::*;
 pub use state::*;
@@ -0,0 +1,64 @@
+use std::ptr::NonNull;
+
+use super::Link;
+
+/// A trait which maps between values and the [`Link`] embedded in them, so that an
+/// [`IntrusiveList`](super::IntrusiveList) can find the value that each link belongs to.
+///
+/// Adapters are usually unit structs, with [`OFFSET`](Adapter::OFFSET) computed by
+/// [`offset_of!`](std::mem::offset_of).
+///
+/// # Safety
+/// `OFFSET` must be the offset in bytes of a field of type [`Link`] within `Value`. The provided
+/// methods rely on this to convert between pointers to the value and pointers to the link, so they
+/// mustn't be overridden.
+///
+/// # Examples
+/// ```
+/// # use std::mem;
+/// # use standard_lib::collections::linked::intrusive::{Adapter, Link};
+/// struct Task {
+///     id: u32,
+///     link: Link,
+/// }
+///
+/// struct TaskAdapter;
+///
+/// // SAFETY: OFFSET is the offset of the link field, which is a Link.
+/// unsafe impl Adapter for TaskAdapter {
+///     type Value = Task;
+///     const OFFSET: usize = mem::offset_of!(Task, link);
+/// }
+/// ```
+pub unsafe trait Adapter {
+    /// The type of value which contains the [`Link`].
+    type Value;
+
+    /// The offset in bytes of the [`Link`] within `Value`.
+    const OFFSET: usize;
+
+    /// Returns a reference to the [`Link`] embedded in `value`.
+    fn link(value: &Self::Value) -> &Link {
+        // SAFETY: The implementation guarantees that a Link lives at OFFSET within the value.
+        unsafe { Self::link_ptr(NonNull::from(value)).as_ref() }
+    }
+
+    /// Returns a pointer to the [`Link`] embedded in the value pointed to by `value`. The result
+    /// keeps the provenance of `value`, so it can be converted back with
+    /// [`value_ptr`](Adapter::value_ptr).
+    fn link_ptr(value: NonNull<Self::Value>) -> NonNull<Link> {
+        // SAFETY: The implementation guarantees that OFFSET is within the bounds of the value.
+        unsafe { value.byte_add(Self::OFFSET).cast() }
+    }
+
+    /// Returns a pointer to the value which contains the [`Link`] pointed to by `link`.
+    ///
+    /// # Safety
+    /// `link` must have been produced by [`link_ptr`](Adapter::link_ptr) for a value of this
+    /// Adapter.
+    unsafe fn value_ptr(link: NonNull<Link>) -> NonNull<Self::Value> {
+        // SAFETY: The caller ensures that link is OFFSET bytes into a value, and was derived from
+        // a pointer to the whole value.
+        unsafe { link.byte_sub(Self::OFFSET).cast() }
+    }
+}
@@ -0,0 +1,69 @@
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::ptr::NonNull;
+
+use super::{Adapter, IntrusiveList, Link};
+
+impl<'l, 'a, A: Adapter> IntoIterator for &'l IntrusiveList<'a, A> {
+    type Item = Pin<&'a A::Value>;
+
+    type IntoIter = Iter<'l, 'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the values of an [`IntrusiveList`], which borrows the list so that it can't be
+/// modified during iteration.
+pub struct Iter<'l, 'a, A: Adapter> {
+    pub(crate) front: Option<NonNull<Link>>,
+    pub(crate) back: Option<NonNull<Link>>,
+    // The number of values left to yield, which stops front and back from crossing over.
+    pub(crate) len: usize,
+    pub(crate) _phantom: PhantomData<&'l IntrusiveList<'a, A>>,
+}
+
+impl<'l, 'a, A: Adapter> Iterator for Iter<'l, 'a, A> {
+    type Item = Pin<&'a A::Value>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 { return None; }
+        let link = self.front?;
+        self.len -= 1;
+
+        // SAFETY: link is part of the list, which is borrowed for the lifetime of the iterator.
+        unsafe {
+            self.front = link.as_ref().next.get();
+            Some(IntrusiveList::<A>::value(link))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'l, 'a, A: Adapter> DoubleEndedIterator for Iter<'l, 'a, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 { return None; }
+        let link = self.back?;
+        self.len -= 1;
+
+        // SAFETY: link is part of the list, which is borrowed for the lifetime of the iterator.
+        unsafe {
+            self.back = link.as_ref().prev.get();
+            Some(IntrusiveList::<A>::value(link))
+        }
+    }
+}
+
+impl<'l, 'a, A: Adapter> ExactSizeIterator for Iter<'l, 'a, A> {}
+
+impl<'l, 'a, A: Adapter> FusedIterator for Iter<'l, 'a, A> {}
@@ -0,0 +1,58 @@
+use std::cell::Cell;
+use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomPinned;
+use std::ptr::NonNull;
+
+/// A pair of links to be embedded as a field of a struct, which allows values of that struct to be
+/// added to an [`IntrusiveList`](super::IntrusiveList).
+///
+/// A Link can only be part of one list at a time. To add a value to multiple lists at once, embed
+/// one Link for each list, with a separate [`Adapter`](super::Adapter) for each field.
+///
+/// Link is [`!Unpin`](PhantomPinned), so any struct containing one must be pinned before it can be
+/// linked, guaranteeing that it won't move while other values point to it.
+pub struct Link {
+    pub(crate) prev: Cell<Option<NonNull<Link>>>,
+    pub(crate) next: Cell<Option<NonNull<Link>>>,
+    // The id of the list which the link is part of, or 0 if it isn't part of one.
+    pub(crate) owner: Cell<usize>,
+    _pinned: PhantomPinned,
+}
+
+impl Link {
+    /// Creates a new Link which isn't part of any list.
+    pub const fn new() -> Link {
+        Link {
+            prev: Cell::new(None),
+            next: Cell::new(None),
+            owner: Cell::new(0),
+            _pinned: PhantomPinned,
+        }
+    }
+
+    /// Returns true if the Link is currently part of a list.
+    pub const fn is_linked(&self) -> bool {
+        self.owner.get() != 0
+    }
+
+    /// Marks the Link as not being part of any list.
+    pub(crate) fn reset(&self) {
+        self.prev.set(None);
+        self.next.set(None);
+        self.owner.set(0);
+    }
+}
+
+impl Default for Link {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for Link {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Link")
+            .field("linked", &self.is_linked())
+            .finish()
+    }
+}
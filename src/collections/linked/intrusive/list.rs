@@ -0,0 +1,298 @@
+use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::{Adapter, Iter, Link};
+#[doc(inline)]
+pub use crate::util::error::AlreadyLinked;
+use crate::util::result::ResultExtension;
+
+/// The id for the next IntrusiveList to be created. Ids start at 1, because a [`Link`] with an
+/// owner of 0 isn't part of any list.
+///
+/// Ids are never reused, because [`IntrusiveList::remove`] trusts a link's owner to decide whether
+/// it is safe to unlink it. If the counter wrapped around, a new list could share an id with one
+/// that is still alive, or be given an id of 0, which would let it unlink values from the other
+/// list or let a value be linked into two lists at once. Running out of ids panics instead.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// A doubly linked list which links values that are stored elsewhere, such as in pinned boxes or
+/// arenas, rather than allocating a node for each of them.
+///
+/// Each value contains a [`Link`] field, and an [`Adapter`] maps between values and their links.
+/// The list borrows its values for `'a`, so they are guaranteed to outlive it. Values must be
+/// pinned, because other values in the list point to their links. When the list is dropped, all of
+/// its values are unlinked, so that they can be added to another list.
+///
+/// Each list has a unique id which is stored in the links of its values. This allows
+/// [`remove`](IntrusiveList::remove) to unlink a value in `O(1)` given only a reference to it,
+/// while still rejecting values which belong to another list.
+///
+/// Values are only accessible through shared references, so any mutable state should make use of
+/// interior mutability, such as [`Cell`](std::cell::Cell).
+///
+/// # Time Complexity
+/// For this analysis of time complexity, variables are defined as follows:
+/// - `n`: The number of items in the IntrusiveList.
+///
+/// | Method | Complexity |
+/// |-|-|
+/// | `len` | `O(1)` |
+/// | `front/back` | `O(1)` |
+/// | `push_front/back` | `O(1)` |
+/// | `pop_front/back` | `O(1)` |
+/// | `contains` | `O(1)` |
+/// | `remove` | `O(1)` |
+/// | `clear` | `O(n)` |
+///
+/// # Examples
+/// ```
+/// # use std::mem;
+/// # use std::pin::pin;
+/// # use standard_lib::collections::linked::intrusive::{Adapter, IntrusiveList, Link};
+/// struct Task {
+///     id: u32,
+///     link: Link,
+/// }
+///
+/// struct TaskAdapter;
+///
+/// // SAFETY: OFFSET is the offset of the link field, which is a Link.
+/// unsafe impl Adapter for TaskAdapter {
+///     type Value = Task;
+///     const OFFSET: usize = mem::offset_of!(Task, link);
+/// }
+///
+/// let a = pin!(Task { id: 0, link: Link::new() });
+/// let b = pin!(Task { id: 1, link: Link::new() });
+/// let c = pin!(Task { id: 2, link: Link::new() });
+///
+/// let mut queue = IntrusiveList::<TaskAdapter>::new();
+/// queue.push_back(a.as_ref());
+/// queue.push_back(b.as_ref());
+/// queue.push_back(c.as_ref());
+///
+/// assert!(queue.remove(&b));
+/// assert!(queue.iter().map(|task| task.id).eq([0, 2]));
+/// ```
+pub struct IntrusiveList<'a, A: Adapter> {
+    pub(crate) head: Option<NonNull<Link>>,
+    pub(crate) tail: Option<NonNull<Link>>,
+    pub(crate) len: usize,
+    pub(crate) id: usize,
+    pub(crate) _phantom: PhantomData<(A, Pin<&'a A::Value>)>,
+}
+
+impl<'a, A: Adapter> IntrusiveList<'a, A> {
+    /// Creates a new IntrusiveList with no elements.
+    ///
+    /// # Panics
+    /// Panics if every id has already been given to a list, which can only happen after
+    /// [`usize::MAX`] lists have been created.
+    pub fn new() -> IntrusiveList<'a, A> {
+        let id = NEXT_ID.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |id| id.checked_add(1))
+            .expect("IntrusiveList ids should never run out");
+
+        IntrusiveList {
+            head: None,
+            tail: None,
+            len: 0,
+            id,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the length of the IntrusiveList.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the IntrusiveList contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the first element in the list, if it exists.
+    pub fn front(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: head is part of this list.
+        self.head.map(|link| unsafe { Self::value(link) })
+    }
+
+    /// Returns the last element in the list, if it exists.
+    pub fn back(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: tail is part of this list.
+        self.tail.map(|link| unsafe { Self::value(link) })
+    }
+
+    /// Links the provided value to the front of the IntrusiveList.
+    ///
+    /// # Panics
+    /// Panics if the value is already part of a list.
+    pub fn push_front(&mut self, value: Pin<&'a A::Value>) {
+        self.try_push_front(value).throw()
+    }
+
+    /// Links the provided value to the front of the IntrusiveList, returning an [`Err`] rather
+    /// than panicking if the value is already part of a list.
+    pub fn try_push_front(&mut self, value: Pin<&'a A::Value>) -> Result<(), AlreadyLinked> {
+        let link = self.claim(value)?;
+        // SAFETY: head is part of this list and link has just been claimed.
+        unsafe { self.link_between(None, self.head, link) };
+        Ok(())
+    }
+
+    /// Links the provided value to the back of the IntrusiveList.
+    ///
+    /// # Panics
+    /// Panics if the value is already part of a list.
+    pub fn push_back(&mut self, value: Pin<&'a A::Value>) {
+        self.try_push_back(value).throw()
+    }
+
+    /// Links the provided value to the back of the IntrusiveList, returning an [`Err`] rather than
+    /// panicking if the value is already part of a list.
+    pub fn try_push_back(&mut self, value: Pin<&'a A::Value>) -> Result<(), AlreadyLinked> {
+        let link = self.claim(value)?;
+        // SAFETY: tail is part of this list and link has just been claimed.
+        unsafe { self.link_between(self.tail, None, link) };
+        Ok(())
+    }
+
+    /// Unlinks the first element from the list and returns it, if the list isn't empty.
+    pub fn pop_front(&mut self) -> Option<Pin<&'a A::Value>> {
+        let head = self.head?;
+        // SAFETY: head is part of this list.
+        unsafe {
+            self.unlink(head);
+            Some(Self::value(head))
+        }
+    }
+
+    /// Unlinks the last element from the list and returns it, if the list isn't empty.
+    pub fn pop_back(&mut self) -> Option<Pin<&'a A::Value>> {
+        let tail = self.tail?;
+        // SAFETY: tail is part of this list.
+        unsafe {
+            self.unlink(tail);
+            Some(Self::value(tail))
+        }
+    }
+
+    /// Returns true if `value` is part of this IntrusiveList, without traversing it.
+    pub fn contains(&self, value: &A::Value) -> bool {
+        A::link(value).owner.get() == self.id
+    }
+
+    /// Unlinks `value` from the IntrusiveList without traversing it, returning true if it was part
+    /// of the list. If it is part of another list or no list at all, nothing happens.
+    pub fn remove(&mut self, value: &A::Value) -> bool {
+        if !self.contains(value) { return false; }
+
+        // SAFETY: The link's owner is this list's id, so it is part of this list.
+        unsafe { self.unlink(A::link_ptr(NonNull::from(value))) };
+        true
+    }
+
+    /// Unlinks all elements from the IntrusiveList.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Returns an iterator over the values of the IntrusiveList, from front to back.
+    pub fn iter(&self) -> Iter<'_, 'a, A> {
+        self.into_iter()
+    }
+}
+
+impl<'a, A: Adapter> IntrusiveList<'a, A> {
+    /// Returns a pointer to the link of `value`, marking it as part of this list.
+    fn claim(&self, value: Pin<&'a A::Value>) -> Result<NonNull<Link>, AlreadyLinked> {
+        let link = A::link(value.get_ref());
+        if link.is_linked() { return Err(AlreadyLinked); }
+
+        link.owner.set(self.id);
+        Ok(A::link_ptr(NonNull::from(value.get_ref())))
+    }
+
+    /// Links `link` into the list between `prev` and `next`, which must be adjacent. A `prev` of
+    /// [`None`] means the front of the list and a `next` of [`None`] means the back.
+    ///
+    /// # Safety
+    /// `prev` and `next` must be adjacent links in this list, and `link` must belong to a value
+    /// which is borrowed for `'a` and isn't part of any other list.
+    unsafe fn link_between(
+        &mut self,
+        prev: Option<NonNull<Link>>,
+        next: Option<NonNull<Link>>,
+        link: NonNull<Link>,
+    ) {
+        // SAFETY: The caller ensures that all of the links belong to values which are still alive.
+        unsafe {
+            link.as_ref().prev.set(prev);
+            link.as_ref().next.set(next);
+
+            match prev {
+                Some(prev) => prev.as_ref().next.set(Some(link)),
+                None => self.head = Some(link),
+            }
+            match next {
+                Some(next) => next.as_ref().prev.set(Some(link)),
+                None => self.tail = Some(link),
+            }
+        }
+        self.len += 1;
+    }
+
+    /// Unlinks `link` from the list, leaving it free to join another list.
+    ///
+    /// # Safety
+    /// `link` must be part of this list.
+    unsafe fn unlink(&mut self, link: NonNull<Link>) {
+        // SAFETY: The caller ensures that link is part of this list, so it and its neighbours all
+        // belong to values which are still alive.
+        unsafe {
+            let (prev, next) = (link.as_ref().prev.get(), link.as_ref().next.get());
+
+            match prev {
+                Some(prev) => prev.as_ref().next.set(next),
+                None => self.head = next,
+            }
+            match next {
+                Some(next) => next.as_ref().prev.set(prev),
+                None => self.tail = prev,
+            }
+
+            link.as_ref().reset();
+        }
+        self.len -= 1;
+    }
+
+    /// Returns the value which contains `link`.
+    ///
+    /// # Safety
+    /// `link` must be part of this list, or have been part of it, and been unlinked since.
+    pub(crate) unsafe fn value(link: NonNull<Link>) -> Pin<&'a A::Value> {
+        // SAFETY: The link was claimed from a pinned value borrowed for 'a, with link_ptr.
+        unsafe { Pin::new_unchecked(A::value_ptr(link).as_ref()) }
+    }
+}
+
+impl<'a, A: Adapter> Default for IntrusiveList<'a, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, A: Adapter> Drop for IntrusiveList<'a, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<'a, A: Adapter<Value: Debug>> Debug for IntrusiveList<'a, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,13 @@
+//! An intrusive doubly linked list, which links values that are stored elsewhere rather than
+//! allocating a node for each of them. See [`IntrusiveList`] for more details.
+
+mod adapter;
+mod iter;
+mod link;
+mod list;
+mod tests;
+
+pub use adapter::*;
+pub use iter::*;
+pub use link::*;
+pub use list::*;
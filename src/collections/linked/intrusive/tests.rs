@@ -0,0 +1,150 @@
+#![cfg(test)]
+
+use std::mem;
+use std::pin::Pin;
+
+use super::*;
+use crate::collections::contiguous::Vector;
+use crate::util::panic::assert_panics;
+
+struct Task {
+    id: u32,
+    link: Link,
+    other_link: Link,
+}
+
+struct TaskAdapter;
+
+// SAFETY: OFFSET is the offset of the link field, which is a Link.
+unsafe impl Adapter for TaskAdapter {
+    type Value = Task;
+    const OFFSET: usize = mem::offset_of!(Task, link);
+}
+
+struct OtherAdapter;
+
+// SAFETY: OFFSET is the offset of the other_link field, which is a Link.
+unsafe impl Adapter for OtherAdapter {
+    type Value = Task;
+    const OFFSET: usize = mem::offset_of!(Task, other_link);
+}
+
+/// Creates `count` pinned Tasks with ascending ids.
+fn tasks(count: u32) -> Vector<Pin<Box<Task>>> {
+    (0..count).map(|id| Box::pin(Task { id, link: Link::new(), other_link: Link::new() })).collect()
+}
+
+fn ids<A: Adapter<Value = Task>>(list: &IntrusiveList<'_, A>) -> Vector<u32> {
+    list.iter().map(|task| task.id).collect()
+}
+
+#[test]
+fn test_push_and_pop() {
+    let tasks = tasks(5);
+    let mut list = IntrusiveList::<TaskAdapter>::new();
+    list.push_back(tasks[2].as_ref());
+    list.push_back(tasks[3].as_ref());
+    list.push_front(tasks[1].as_ref());
+    list.push_front(tasks[0].as_ref());
+    list.push_back(tasks[4].as_ref());
+
+    assert_eq!(list.len(), 5);
+    assert_eq!(*ids(&list), [0, 1, 2, 3, 4]);
+    assert!(list.iter().rev().map(|task| task.id).eq([4, 3, 2, 1, 0]));
+    assert_eq!(list.front().map(|task| task.id), Some(0));
+    assert_eq!(list.back().map(|task| task.id), Some(4));
+
+    assert_eq!(list.pop_front().map(|task| task.id), Some(0));
+    assert_eq!(list.pop_back().map(|task| task.id), Some(4));
+    assert!(!tasks[0].link.is_linked(), "Popped values should be unlinked.");
+    assert_eq!(*ids(&list), [1, 2, 3]);
+
+    let mut iter = list.iter();
+    assert_eq!(iter.next().map(|task| task.id), Some(1));
+    assert_eq!(iter.next_back().map(|task| task.id), Some(3));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next().map(|task| task.id), Some(2));
+    assert!(iter.next_back().is_none(), "The ends of the iterator shouldn't cross over.");
+
+    list.clear();
+    assert!(list.is_empty());
+    assert!(list.pop_front().is_none());
+    assert!(tasks.iter().all(|task| !task.link.is_linked()));
+}
+
+#[test]
+fn test_remove() {
+    let tasks = tasks(4);
+    let mut list = IntrusiveList::<TaskAdapter>::new();
+    let mut other = IntrusiveList::<TaskAdapter>::new();
+    for task in &tasks[..3] {
+        list.push_back(task.as_ref());
+    }
+    other.push_back(tasks[3].as_ref());
+
+    assert!(list.contains(&tasks[1]));
+    assert!(!list.contains(&tasks[3]), "Values in another list shouldn't be contained.");
+    assert!(!list.remove(&tasks[3]), "Removing a value in another list should do nothing.");
+    assert_eq!(other.len(), 1);
+
+    assert!(list.remove(&tasks[1]));
+    assert!(!list.remove(&tasks[1]), "Removing a value twice should do nothing.");
+    assert_eq!(*ids(&list), [0, 2]);
+    assert!(list.remove(&tasks[0]));
+    assert!(list.remove(&tasks[2]));
+    assert!(list.is_empty());
+    assert!(list.front().is_none() && list.back().is_none());
+
+    // Moving a value to the front, as an LRU cache would on each access.
+    for task in &tasks[..3] {
+        list.push_back(task.as_ref());
+    }
+    list.remove(&tasks[2]);
+    list.push_front(tasks[2].as_ref());
+    assert_eq!(*ids(&list), [2, 0, 1]);
+    assert!(list.iter().rev().map(|task| task.id).eq([1, 0, 2]));
+}
+
+#[test]
+fn test_already_linked() {
+    let tasks = tasks(2);
+    let mut list = IntrusiveList::<TaskAdapter>::new();
+    let mut other = IntrusiveList::<TaskAdapter>::new();
+    list.push_back(tasks[0].as_ref());
+
+    assert!(list.try_push_back(tasks[0].as_ref()).is_err());
+    assert!(other.try_push_front(tasks[0].as_ref()).is_err());
+    assert_eq!((list.len(), other.len()), (1, 0), "A failed push shouldn't modify either list.");
+
+    // A separate link in the same value can join another list.
+    let mut separate = IntrusiveList::<OtherAdapter>::new();
+    separate.push_back(tasks[0].as_ref());
+    separate.push_back(tasks[1].as_ref());
+    assert_eq!(*ids(&separate), [0, 1]);
+
+    assert_panics!({
+        let task = Box::pin(Task { id: 0, link: Link::new(), other_link: Link::new() });
+        let mut list = IntrusiveList::<TaskAdapter>::new();
+        list.push_back(task.as_ref());
+        list.push_back(task.as_ref());
+    });
+}
+
+#[test]
+fn test_drop() {
+    let tasks = tasks(3);
+    {
+        let mut list = IntrusiveList::<TaskAdapter>::new();
+        for task in tasks.iter() {
+            list.push_back(task.as_ref());
+        }
+    }
+    assert!(
+        tasks.iter().all(|task| !task.link.is_linked()),
+        "Dropping a list should unlink all of its values."
+    );
+
+    let mut list = IntrusiveList::<TaskAdapter>::new();
+    list.push_back(tasks[1].as_ref());
+    assert_eq!(*ids(&list), [1], "Values should be able to join a new list after a drop.");
+}
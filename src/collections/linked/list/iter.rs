@@ -3,7 +3,7 @@ use std::marker::PhantomData;
 
 use ListState::*;
 
-use super::{LinkedList, ListContents, ListState};
+use super::{Link, LinkedList, ListContents, ListState};
 
 impl<T> IntoIterator for LinkedList<T> {
     type Item = T;
@@ -223,3 +223,40 @@ impl<'a, T> ExactSizeIterator for Iter<'a, T> {
 
 // SAFETY: IterMut::size_hint returns the exact length of the iterator.
 unsafe impl<'a, T> TrustedLen for Iter<'a, T> {}
+
+/// A type for in-place extraction from a [`LinkedList`], unlinking and producing every element for
+/// which the predicate returns true. Produces values of type `T`.
+///
+/// If this iterator is dropped before being exhausted, the remaining elements are left in the
+/// LinkedList.
+///
+/// See [`LinkedList::extract_if`].
+pub struct ExtractIf<'a, T, F: FnMut(&mut T) -> bool> {
+    pub(crate) list: &'a mut LinkedList<T>,
+    pub(crate) next: Link<T>,
+    pub(crate) pred: F,
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'a, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(mut node) = self.next {
+            // Move on before unlinking, so that the next node is never lost.
+            self.next = *node.next();
+
+            if (self.pred)(node.value_mut()) {
+                // SAFETY: node was reached by following the links from the head of the list, and
+                // hasn't been unlinked yet.
+                return Some(unsafe { self.list.unlink(node) });
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.list.len()))
+    }
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool> FusedIterator for ExtractIf<'a, T, F> {}
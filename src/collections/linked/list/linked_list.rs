@@ -1,14 +1,17 @@
 use std::fmt::{self, Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
+use std::hint;
 use std::marker::PhantomData;
 use std::mem;
 use std::ops::{Index, IndexMut};
 
 use derive_more::IsVariant;
 
-use super::{Iter, IterMut, Length, Node, NodePtr, ONE};
+use super::{ExtractIf, Iter, IterMut, Length, Link, Node, NodePtr, ONE};
 use crate::collections::contiguous::Vector;
-use crate::collections::linked::cursor::{Cursor, CursorContents, CursorPosition, CursorState};
+use crate::collections::linked::cursor::{
+    Cursor, CursorContents, CursorMut, CursorPosition, CursorRef, CursorState,
+};
 #[doc(inline)]
 pub use crate::util::error::{CapacityOverflow, IndexOutOfBounds};
 use crate::util::result::ResultExtension;
@@ -32,6 +35,9 @@ use crate::util::result::ResultExtension;
 /// | `remove` | `O(min(i, n-i))` |
 /// | `replace` | `O(min(i, n-i))` |
 /// | `append` | `O(1)` |
+/// | `split_off` | `O(min(i, n-i))` |
+/// | `sort` | `O(n log n)` |
+/// | `retain` | `O(n)` |
 /// | `contains` | `O(n)` |
 ///
 /// As a general note, modern computer architecture isn't kind to linked lists, (or more
@@ -283,21 +289,95 @@ impl<T> LinkedList<T> {
         ))
     }
 
-    pub fn append(&mut self, other: LinkedList<T>) {
+    /// Moves all elements from `other` onto the end of the LinkedList, by relinking the nodes at
+    /// either end rather than moving any values.
+    pub fn append(&mut self, mut other: LinkedList<T>) {
+        let Full(other_contents) = mem::take(&mut other.state) else { return };
+
         match &mut self.state {
-            Empty => *self = other,
-            Full(self_contents) => match &other.state {
-                Empty => {},
-                Full(other_contents) => {
-                    self_contents.len = self_contents.len
-                        .checked_add(other_contents.len.get())
-                        .ok_or(CapacityOverflow).throw();
-
-                    *self_contents.tail.next_mut() = Some(other_contents.head);
-                    *other_contents.head.prev_mut() = Some(self_contents.tail);
-                    self_contents.tail = other_contents.tail;
-                },
+            Empty => self.state = Full(other_contents),
+            Full(self_contents) => {
+                let tail = self_contents.tail;
+                self_contents.splice_after(Some(tail), other_contents);
+            },
+        }
+    }
+
+    /// Splits the LinkedList in two at the provided index, returning a new LinkedList containing
+    /// the elements from `at` onwards. No values are moved, only the links between the two nodes
+    /// on either side of `at` are cut.
+    ///
+    /// # Panics
+    /// Panics if `at` is greater than the length of the LinkedList.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::linked::LinkedList;
+    /// let mut list: LinkedList<_> = (0..5).collect();
+    /// let back = list.split_off(2);
+    /// assert!(list.iter().eq(&[0, 1]));
+    /// assert!(back.iter().eq(&[2, 3, 4]));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        self.try_split_off(at).throw()
+    }
+
+    /// Splits the LinkedList in two at the provided index, returning an [`Err`] rather than
+    /// panicking if `at` is greater than the length of the LinkedList.
+    pub fn try_split_off(&mut self, at: usize) -> Result<LinkedList<T>, IndexOutOfBounds> {
+        if at == self.len() { return Ok(LinkedList::new()); }
+        if at == 0 { return Ok(mem::take(self)); }
+
+        let contents = self.checked_contents_for_index_mut(at)?;
+        let first = contents.seek(at);
+        let tail = contents.tail;
+
+        // SAFETY: 0 < at < len, so first..=tail is part of the list but doesn't include the head.
+        // It contains len - at elements, which is at least 1.
+        let back = unsafe {
+            contents.detach(first, tail, Length::new_unchecked(contents.len.get() - at))
+        };
+
+        Ok(LinkedList {
+            state: Full(back),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Retains only the elements for which `f` returns true, unlinking and dropping all others.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::linked::LinkedList;
+    /// let mut list: LinkedList<_> = (0..10).collect();
+    /// list.retain(|i| i % 3 == 0);
+    /// assert!(list.iter().eq(&[0, 3, 6, 9]));
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.extract_if(|item| !f(item)).for_each(drop);
+    }
+
+    /// Returns an iterator which visits each element in order, unlinking and producing those for
+    /// which `pred` returns true.
+    ///
+    /// If the iterator is dropped before it is exhausted, the remaining elements are kept.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::linked::LinkedList;
+    /// let mut list: LinkedList<_> = (0..6).collect();
+    /// let evens: LinkedList<_> = list.extract_if(|i| *i % 2 == 0).collect();
+    /// assert!(evens.iter().eq(&[0, 2, 4]));
+    /// assert!(list.iter().eq(&[1, 3, 5]));
+    /// ```
+    pub const fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, T, F> {
+        ExtractIf {
+            next: match &self.state {
+                Empty => None,
+                Full(ListContents { head, .. }) => Some(*head),
             },
+            list: self,
+            pred,
         }
     }
 
@@ -359,6 +439,50 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Creates a [`CursorRef`] which borrows the list, pointing to the 'ghost' element before the
+    /// start of the list.
+    pub const fn cursor_head_ref(&self) -> CursorRef<'_, T> {
+        CursorRef { list: self, pos: CursorPosition::Head }
+    }
+
+    /// Creates a [`CursorRef`] which borrows the list, pointing to the 'ghost' element after the
+    /// end of the list.
+    pub const fn cursor_tail_ref(&self) -> CursorRef<'_, T> {
+        CursorRef { list: self, pos: CursorPosition::Tail }
+    }
+
+    /// Creates a [`CursorRef`] which borrows the list, pointing to the first element.
+    pub const fn cursor_front_ref(&self) -> CursorRef<'_, T> {
+        CursorRef { list: self, pos: self.state.front_position() }
+    }
+
+    /// Creates a [`CursorRef`] which borrows the list, pointing to the last element.
+    pub const fn cursor_back_ref(&self) -> CursorRef<'_, T> {
+        CursorRef { list: self, pos: self.state.back_position() }
+    }
+
+    /// Creates a [`CursorMut`] which borrows the list mutably, pointing to the 'ghost' element
+    /// before the start of the list.
+    pub const fn cursor_head_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { list: self, pos: CursorPosition::Head }
+    }
+
+    /// Creates a [`CursorMut`] which borrows the list mutably, pointing to the 'ghost' element
+    /// after the end of the list.
+    pub const fn cursor_tail_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { list: self, pos: CursorPosition::Tail }
+    }
+
+    /// Creates a [`CursorMut`] which borrows the list mutably, pointing to the first element.
+    pub const fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { pos: self.state.front_position(), list: self }
+    }
+
+    /// Creates a [`CursorMut`] which borrows the list mutably, pointing to the last element.
+    pub const fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { pos: self.state.back_position(), list: self }
+    }
+
     pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         self.into_iter()
     }
@@ -385,6 +509,13 @@ impl<T: Eq> LinkedList<T> {
 }
 
 impl<T> LinkedList<T> {
+    pub(crate) const fn from_contents(contents: ListContents<T>) -> LinkedList<T> {
+        LinkedList {
+            state: Full(contents),
+            _phantom: PhantomData,
+        }
+    }
+
     pub(crate) fn checked_seek(&self, index: usize) -> Result<NodePtr<T>, IndexOutOfBounds> {
         Ok(self.checked_contents_for_index(index)?.seek(index))
     }
@@ -423,6 +554,43 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Unlinks the provided node from the LinkedList, returning its value.
+    ///
+    /// # Safety
+    /// `node` must be part of this LinkedList.
+    pub(crate) unsafe fn unlink(&mut self, node: NodePtr<T>) -> T {
+        let Full(contents) = &mut self.state else {
+            // SAFETY: The caller ensures that node is part of the list, so it isn't empty.
+            unsafe { hint::unreachable_unchecked() }
+        };
+
+        let node = node.take_node();
+        match contents.len.checked_sub(1) {
+            Some(new_len) => contents.len = new_len,
+            None => {
+                self.state = Empty;
+                return node.value;
+            },
+        }
+
+        match node.prev {
+            Some(prev) => *prev.next_mut() = node.next,
+            None => {
+                // SAFETY: The list had more than one element, so the head has a next node.
+                contents.head = unsafe { node.next.unwrap_unchecked() };
+            },
+        }
+        match node.next {
+            Some(next) => *next.prev_mut() = node.prev,
+            None => {
+                // SAFETY: The list had more than one element, so the tail has a previous node.
+                contents.tail = unsafe { node.prev.unwrap_unchecked() };
+            },
+        }
+
+        node.value
+    }
+
     #[allow(clippy::unwrap_used)]
     pub(crate) fn verify_double_links(&self) {
         match self.state {
@@ -489,6 +657,72 @@ impl<T> ListContents<T> {
         self.tail = node;
     }
 
+    /// Links all nodes from `other` into the list after `prev`, or at the front if `prev` is
+    /// `None`.
+    ///
+    /// # Panics
+    /// Panics if the combined length overflows.
+    pub fn splice_after(&mut self, prev: Link<T>, other: ListContents<T>) {
+        self.len = self.len.checked_add(other.len.get()).ok_or(CapacityOverflow).throw();
+
+        let next = match prev {
+            Some(prev) => *prev.next(),
+            None => Some(self.head),
+        };
+
+        *other.head.prev_mut() = prev;
+        *other.tail.next_mut() = next;
+
+        match prev {
+            Some(prev) => *prev.next_mut() = Some(other.head),
+            None => self.head = other.head,
+        }
+        match next {
+            Some(next) => *next.prev_mut() = Some(other.tail),
+            None => self.tail = other.tail,
+        }
+    }
+
+    /// Unlinks the nodes from `first` to `last` inclusive, returning them as their own list.
+    ///
+    /// # Safety
+    /// `first..=last` must be a range of nodes in this list, in order, containing `len` nodes. It
+    /// mustn't contain every node in the list, because the list would be left empty.
+    pub const unsafe fn detach(
+        &mut self,
+        first: NodePtr<T>,
+        last: NodePtr<T>,
+        len: Length,
+    ) -> ListContents<T> {
+        let prev = *first.prev();
+        let next = *last.next();
+
+        match prev {
+            Some(prev) => *prev.next_mut() = next,
+            // SAFETY: The range doesn't contain every node, so if it starts at the head, there is a
+            // node after it.
+            None => self.head = unsafe { next.unwrap_unchecked() },
+        }
+        match next {
+            Some(next) => *next.prev_mut() = prev,
+            // SAFETY: The range doesn't contain every node, so if it ends at the tail, there is a
+            // node before it.
+            None => self.tail = unsafe { prev.unwrap_unchecked() },
+        }
+
+        *first.prev_mut() = None;
+        *last.next_mut() = None;
+
+        // SAFETY: The range doesn't contain every node, so at least one remains.
+        self.len = unsafe { Length::new_unchecked(self.len.get() - len.get()) };
+
+        ListContents {
+            len,
+            head: first,
+            tail: last,
+        }
+    }
+
     pub fn wrap_one(value: T) -> ListContents<T> {
         let node = NodePtr::from_node(Node {
             value,
@@ -512,6 +746,25 @@ impl<T> ListState<T> {
     pub fn single(value: T) -> ListState<T> {
         Full(ListContents::wrap_one(value))
     }
+
+    /// Returns the cursor position of the first element, or the head if the list is empty.
+    pub const fn front_position(&self) -> CursorPosition<T> {
+        match self {
+            Empty => CursorPosition::Head,
+            Full(ListContents { head, .. }) => CursorPosition::Ptr { ptr: *head, index: 0 },
+        }
+    }
+
+    /// Returns the cursor position of the last element, or the tail if the list is empty.
+    pub const fn back_position(&self) -> CursorPosition<T> {
+        match self {
+            Empty => CursorPosition::Tail,
+            Full(contents) => CursorPosition::Ptr {
+                ptr: contents.tail,
+                index: contents.last_index(),
+            },
+        }
+    }
 }
 
 impl<T> Index<usize> for LinkedList<T> {
@@ -2,6 +2,8 @@ mod iter;
 mod length;
 mod linked_list;
 mod node;
+mod sort;
+mod tests;
 
 pub use iter::*;
 pub(crate) use length::*;
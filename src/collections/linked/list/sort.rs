@@ -0,0 +1,202 @@
+use std::cmp::Ordering;
+
+use super::{Link, LinkedList, ListContents, ListState, NodePtr};
+
+impl<T> LinkedList<T> {
+    /// Sorts the LinkedList in ascending order, with a stable merge sort.
+    ///
+    /// Sorting is done in place by relinking nodes, so no values are moved and no memory is
+    /// allocated. If the implementation of [`Ord`] for `T` panics, the LinkedList still contains
+    /// all of its elements, in an unspecified order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use standard_lib::collections::linked::LinkedList;
+    /// let mut list: LinkedList<_> = [5, 2, 4, 1, 3].into_iter().collect();
+    /// list.sort();
+    /// assert!(list.iter().eq(&[1, 2, 3, 4, 5]));
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.merge_sort(&mut T::lt);
+    }
+
+    /// Sorts the LinkedList with a stable merge sort, using `compare` to order elements.
+    ///
+    /// If `compare` panics, the LinkedList still contains all of its elements, in an unspecified
+    /// order.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.merge_sort(&mut |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the LinkedList with a stable merge sort, ordering elements by the key produced by
+    /// `f`.
+    ///
+    /// If `f` panics, the LinkedList still contains all of its elements, in an unspecified order.
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.merge_sort(&mut |a, b| f(a).lt(&f(b)));
+    }
+
+    /// Sorts the LinkedList with a bottom-up merge sort, which merges runs of increasing length
+    /// until the whole list is one run.
+    pub(crate) fn merge_sort<F>(&mut self, is_less: &mut F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let ListState::Full(contents) = &mut self.state else { return };
+        let len = contents.len.get();
+
+        let mut merger = Merger {
+            merged_head: None,
+            merged_tail: None,
+            left: None,
+            left_len: 0,
+            right: Some(contents.head),
+            right_len: 0,
+            contents,
+        };
+
+        let mut run = 1;
+        while run < len {
+            merger.merge_pass(run, len, is_less);
+            run = run.saturating_mul(2);
+        }
+    }
+}
+
+/// The state of a merge pass over a list. While merging, only the `next` links are followed and
+/// updated. When dropped, all nodes are linked back into a single list and the `prev` links are
+/// rebuilt, so that a panicking comparison can't leave the list broken.
+struct Merger<'a, T> {
+    contents: &'a mut ListContents<T>,
+    // The nodes that have already been merged during this pass, in order.
+    merged_head: Link<T>,
+    merged_tail: Link<T>,
+    // The remainder of the left run.
+    left: Link<T>,
+    left_len: usize,
+    // The remainder of the right run, followed by all of the nodes that haven't been visited yet.
+    right: Link<T>,
+    right_len: usize,
+}
+
+impl<'a, T> Merger<'a, T> {
+    /// Merges each pair of adjacent runs of length `run` throughout the list.
+    fn merge_pass<F>(&mut self, run: usize, len: usize, is_less: &mut F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut remaining = len;
+
+        while remaining > 0 {
+            self.left = self.right;
+            self.left_len = run.min(remaining);
+            remaining -= self.left_len;
+
+            self.right = self.left;
+            for _ in 0..self.left_len {
+                self.right = self.right.and_then(|node| *node.next());
+            }
+            self.right_len = run.min(remaining);
+            remaining -= self.right_len;
+
+            while let Some(node) = self.take_lesser(is_less) {
+                match self.merged_tail {
+                    Some(tail) => *tail.next_mut() = Some(node),
+                    None => self.merged_head = Some(node),
+                }
+                self.merged_tail = Some(node);
+            }
+        }
+
+        self.relink();
+    }
+
+    /// Removes and returns the lesser of the heads of the two runs, preferring the left run when
+    /// they are equal, so that the sort is stable.
+    fn take_lesser<F>(&mut self, is_less: &mut F) -> Link<T>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let take_left = match (self.left, self.right) {
+            _ if self.left_len == 0 && self.right_len == 0 => return None,
+            (Some(_), _) if self.right_len == 0 => true,
+            (Some(left), Some(right)) if self.left_len != 0 => {
+                !is_less(right.value(), left.value())
+            },
+            _ => false,
+        };
+
+        let (run, run_len) = if take_left {
+            (&mut self.left, &mut self.left_len)
+        } else {
+            (&mut self.right, &mut self.right_len)
+        };
+
+        let node = *run;
+        *run = node.and_then(|node| *node.next());
+        *run_len -= 1;
+        node
+    }
+
+    /// Links the merged nodes, the rest of the left run and then the right run and all following
+    /// nodes into a single list, which becomes the start of the next pass.
+    fn relink(&mut self) {
+        let mut rest = self.right;
+
+        if self.left_len != 0 && let Some(left) = self.left {
+            // The links within the left run are untouched, but its last node may still point to a
+            // node which has already been merged.
+            let mut last: NodePtr<T> = left;
+            for _ in 1..self.left_len {
+                last = last.next().unwrap_or(last);
+            }
+            *last.next_mut() = rest;
+            rest = Some(left);
+        }
+
+        match self.merged_tail {
+            Some(tail) => *tail.next_mut() = rest,
+            None => self.merged_head = rest,
+        }
+
+        if let Some(head) = self.merged_head {
+            self.contents.head = head;
+        }
+
+        self.merged_head = None;
+        self.merged_tail = None;
+        self.left = None;
+        self.left_len = 0;
+        self.right = Some(self.contents.head);
+        self.right_len = 0;
+    }
+}
+
+impl<'a, T> Drop for Merger<'a, T> {
+    fn drop(&mut self) {
+        self.relink();
+
+        // Rebuild the prev links by walking the list from the head, which also finds the tail.
+        let mut prev = None;
+        let mut curr = Some(self.contents.head);
+        while let Some(node) = curr {
+            *node.prev_mut() = prev;
+            prev = Some(node);
+            curr = *node.next();
+        }
+
+        if let Some(tail) = prev {
+            self.contents.tail = tail;
+        }
+    }
+}
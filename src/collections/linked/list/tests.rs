@@ -0,0 +1,425 @@
+#![cfg(test)]
+
+use std::cmp::Ordering;
+use std::panic::{self, AssertUnwindSafe};
+
+use super::*;
+use crate::collections::linked::cursor::State;
+use crate::collections::contiguous::Vector;
+use crate::util::alloc::{CountedDrop, counted};
+use crate::util::panic::assert_panics;
+use crate::util::random::random;
+
+#[test]
+fn test_split_off_and_append() {
+    let mut list: LinkedList<_> = (0..6).collect();
+    let mut back = list.split_off(4);
+    list.verify_double_links();
+    back.verify_double_links();
+    assert!(list.iter().eq(&[0, 1, 2, 3]));
+    assert!(back.iter().eq(&[4, 5]));
+    assert!(back.iter().rev().eq(&[5, 4]), "The split off list should be linked backwards too.");
+
+    back.append(list.split_off(0));
+    assert!(list.is_empty(), "Splitting at 0 should take the whole list.");
+    assert!(list.split_off(0).is_empty());
+    assert!(back.split_off(6).is_empty(), "Splitting at len should produce an empty list.");
+    back.verify_double_links();
+    assert!(back.iter().eq(&[4, 5, 0, 1, 2, 3]));
+
+    let counter = CountedDrop::new(0);
+    let mut list: LinkedList<_> = counted(&counter, 5);
+    let other = list.split_off(2);
+    list.append(counted(&counter, 3));
+    assert_eq!(counter.take(), 0, "Appending shouldn't drop any values.");
+    drop(list);
+    assert_eq!(counter.take(), 5);
+    drop(other);
+    assert_eq!(counter.take(), 3, "Each element should be dropped exactly once.");
+
+    assert_panics!({
+        let mut list: LinkedList<_> = (0..3).collect();
+        list.split_off(4);
+    });
+}
+
+#[test]
+fn test_cursor_split() {
+    let counter = CountedDrop::new(0);
+
+    let mut cursor = counted::<LinkedList<_>>(&counter, 5).cursor_front();
+    cursor.move_to(2);
+    let (front, back) = cursor.split_before();
+    front.verify_double_links();
+    back.verify_double_links();
+    assert_eq!((front.len(), back.len()), (2, 3));
+    drop(front);
+    assert_eq!(counter.take(), 2, "Dropping one half shouldn't drop the other.");
+    drop(back);
+    assert_eq!(counter.take(), 3);
+
+    let mut cursor = (0..5).collect::<LinkedList<_>>().cursor_front();
+    cursor.move_to(2);
+    let (front, back) = cursor.split_after();
+    assert!(front.iter().eq(&[0, 1, 2]));
+    assert!(back.iter().rev().eq(&[4, 3]));
+}
+
+#[test]
+fn test_cursor_split_at_ends() {
+    let (front, back) = (0..3).collect::<LinkedList<_>>().cursor_back().split_after();
+    assert_eq!((front.len(), back.len()), (3, 0), "Nothing should follow the back element.");
+    front.verify_double_links();
+    assert!(front.iter().eq(&[0, 1, 2]));
+
+    let (front, back) = (0..3).collect::<LinkedList<_>>().cursor_front().split_before();
+    assert_eq!((front.len(), back.len()), (0, 3), "Nothing should precede the front element.");
+    back.verify_double_links();
+    assert!(back.iter().eq(&[0, 1, 2]));
+}
+
+#[test]
+fn test_cursor_splice() {
+    let mut cursor = (0..4).collect::<LinkedList<_>>().cursor_front();
+    cursor.move_to(2);
+    cursor.splice_before((10..12).collect());
+    assert_eq!(cursor.index(), Some(4), "Splicing before should shift the cursor's index.");
+    assert_eq!(cursor.read(), Some(&2));
+    cursor.splice_after((20..22).collect());
+    assert_eq!(cursor.read_next(), Some(&20));
+    cursor.splice_after(LinkedList::new());
+
+    let mut list = cursor.list();
+    list.verify_double_links();
+    assert!(list.iter().eq(&[0, 1, 10, 11, 2, 20, 21, 3]));
+
+    let mut cursor = list.split_off(8).cursor_head();
+    cursor.splice_before((0..2).collect());
+    cursor.move_offset(-1);
+    cursor.splice_after((2..4).collect());
+    let list = cursor.list();
+    list.verify_double_links();
+    assert!(list.iter().eq(&[0, 1, 2, 3]));
+}
+
+#[test]
+fn test_cursor_cut() {
+    let mut cursor = (0..8).collect::<LinkedList<_>>().cursor_front();
+    cursor.move_to(6);
+
+    let cut = cursor.cut(2..5);
+    cut.verify_double_links();
+    assert!(cut.iter().eq(&[2, 3, 4]));
+    assert_eq!(cursor.index(), Some(3), "A cursor after the range should have its index reduced.");
+
+    let cut = cursor.cut(3..);
+    assert!(cut.iter().eq(&[6, 7]));
+    assert!(cursor.is_tail(), "A cursor inside the range should move past it.");
+
+    assert!(cursor.cut(1..1).is_empty());
+    let cut = cursor.cut(..);
+    assert!(cut.iter().eq(&[0, 1, 5]));
+    assert!(cursor.is_empty());
+
+    let mut cursor = (0..4).collect::<LinkedList<_>>().cursor_front();
+    cursor.move_to(1);
+    assert!(cursor.cut(..2).iter().eq(&[0, 1]));
+    assert_eq!((cursor.read(), cursor.index()), (Some(&2), Some(0)));
+    let list = cursor.list();
+    list.verify_double_links();
+    assert!(list.iter().eq(&[2, 3]));
+
+    assert_panics!({
+        let mut cursor = (0..3).collect::<LinkedList<_>>().cursor_front();
+        cursor.cut(2..4);
+    });
+}
+
+#[test]
+fn test_sort() {
+    for len in [0, 1, 2, 3, 10, 33, 100, 1000] {
+        for max in [4, u64::MAX] {
+            let mut list: LinkedList<_> = random(len, max);
+            let mut expected = Vector::from_iter_sized(list.iter().copied());
+            expected.sort();
+
+            // Sorting shouldn't move any values, so record where each value lives beforehand.
+            let mut addresses = Vector::from_iter_sized(list.iter().map(|i| i as *const u64));
+            addresses.sort();
+
+            list.sort();
+            list.verify_double_links();
+            assert!(list.iter().eq(expected.iter()), "Sort failed for length {len}.");
+
+            let mut after = Vector::from_iter_sized(list.iter().map(|i| i as *const u64));
+            after.sort();
+            assert_eq!(addresses, after, "Sorting should relink nodes, not move values.");
+        }
+    }
+
+    // Pair each key with its original index, which should stay in ascending order for each key.
+    let mut list: LinkedList<_> = random::<Vector<_>>(500, 8).into_iter().enumerate().collect();
+    list.sort_by_key(|(_, key)| *key);
+    let pairs = Vector::from_iter_sized(list.iter().copied());
+    assert!(pairs.is_sorted_by_key(|(index, key)| (*key, *index)), "Sorting should be stable.");
+
+    let mut list: LinkedList<_> = (0..100).collect();
+    list.sort_by(|a, b| b.cmp(a));
+    assert!(list.iter().eq((0..100).rev().collect::<Vector<_>>().iter()));
+}
+
+#[test]
+fn test_sort_panic_safety() {
+    struct Item {
+        value: u64,
+        _counter: CountedDrop,
+    }
+
+    let counter = CountedDrop::new(0);
+
+    for len in [10, 100, 1000] {
+        for panic_after in [0, len / 2, len] {
+            let input: LinkedList<_> = random(len, 50);
+            let mut list: LinkedList<_> = input.iter()
+                .map(|value| Item { value: *value, _counter: counter.clone() })
+                .collect();
+
+            let mut comparisons = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                list.sort_by(|a, b| {
+                    comparisons += 1;
+                    if comparisons > panic_after {
+                        panic!("Comparison limit reached");
+                    }
+                    a.value.cmp(&b.value)
+                });
+            }));
+            assert!(result.is_err(), "Sorting should make more than {panic_after} comparisons.");
+            assert_eq!(counter.take(), 0, "Sorting shouldn't drop any elements when panicking.");
+
+            list.verify_double_links();
+            assert_eq!(list.len(), len);
+            let mut values = Vector::from_iter_sized(list.iter().map(|item| item.value));
+            let mut expected = Vector::from_iter_sized(input.iter().copied());
+            values.sort();
+            expected.sort();
+            assert_eq!(values, expected, "Every element should be in the list exactly once.");
+
+            drop(list);
+            assert_eq!(counter.take(), len, "Each element should be dropped exactly once.");
+        }
+    }
+
+    let mut list: LinkedList<_> = (0..3).collect();
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        list.sort_by(|_, _| -> Ordering { panic!("Comparison failed") });
+    }));
+    list.verify_double_links();
+    assert_eq!(list.len(), 3);
+}
+
+#[test]
+fn test_retain_and_extract_if() {
+    let mut list: LinkedList<_> = (0..10).collect();
+    list.retain(|i| i % 3 != 0);
+    list.verify_double_links();
+    assert!(list.iter().eq(&[1, 2, 4, 5, 7, 8]));
+
+    let extracted: LinkedList<_> = list.extract_if(|i| *i % 2 == 0).collect();
+    list.verify_double_links();
+    assert!(extracted.iter().eq(&[2, 4, 8]));
+    assert!(list.iter().eq(&[1, 5, 7]));
+
+    assert_eq!(list.extract_if(|_| true).next(), Some(1));
+    assert!(list.iter().eq(&[5, 7]), "Dropping ExtractIf early should keep the rest.");
+
+    list.retain(|_| false);
+    assert!(list.is_empty());
+
+    let counter = CountedDrop::new(0);
+    let mut list: LinkedList<_> = counted(&counter, 6);
+    let mut index = 0;
+    list.retain(|_| {
+        index += 1;
+        index % 2 == 0
+    });
+    assert_eq!(counter.take(), 3, "Removed elements should be dropped.");
+    drop(list);
+    assert_eq!(counter.take(), 3);
+}
+
+#[test]
+fn test_cursor_ref() {
+    let list: LinkedList<_> = (0..5).collect();
+    let mut cursor = list.cursor_head_ref();
+    assert!(cursor.is_head());
+    assert_eq!(cursor.read_next(), Some(&0));
+    assert_eq!(cursor.read_offset(5), Some(&4));
+    assert_eq!(cursor.read_offset(6), None, "Reading past the end should return None.");
+
+    cursor.move_to(2);
+    let copy = cursor;
+    cursor.move_next();
+    assert_eq!((cursor.read(), cursor.index()), (Some(&3), Some(3)));
+    assert_eq!(copy.read(), Some(&2), "Moving a copy shouldn't move the original.");
+    assert_eq!(cursor.read_offset(-3), Some(&0));
+    assert_eq!(cursor.read_offset(-4), None);
+
+    cursor.move_offset(2);
+    assert!(cursor.is_tail(), "Moving past the end should stop at the tail.");
+    assert_eq!(cursor.read_prev(), Some(&4));
+    cursor.move_offset(-6);
+    assert!(cursor.is_head(), "Moving past the start should stop at the head.");
+    cursor.move_prev();
+    assert!(cursor.is_head());
+
+    assert!(list.cursor_back_ref().try_move_to(5).is_err());
+    assert_eq!(list.cursor_back_ref().state(), State::Node(&4));
+    assert_eq!(list.cursor_tail_ref().move_prev().read(), Some(&4));
+
+    let empty = LinkedList::<u32>::new();
+    let mut cursor = empty.cursor_front_ref();
+    assert!(cursor.state().is_empty());
+    assert!(!cursor.is_head() && !cursor.is_tail());
+    assert_eq!(cursor.move_next().read_next(), None);
+}
+
+#[test]
+fn test_cursor_mut() {
+    let mut list: LinkedList<_> = (0..3).collect();
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.push_prev(10);
+    cursor.push_next(11);
+    assert_eq!((cursor.read(), cursor.index()), (Some(&0), Some(1)));
+    assert_eq!(cursor.read_offset(-1), Some(&10));
+    if let Some(value) = cursor.read_next_mut() {
+        *value += 1;
+    }
+    assert_eq!(cursor.pop_prev(), Some(10));
+    assert_eq!(cursor.index(), Some(0), "Popping the previous element should shift the index.");
+    assert_eq!(cursor.pop_prev(), None);
+
+    cursor.move_offset(3);
+    assert_eq!(cursor.read(), Some(&2));
+    assert_eq!(cursor.pop_next(), None);
+    cursor.push_next(20);
+    cursor.move_next().move_next();
+    assert!(cursor.is_tail());
+    assert_eq!(cursor.pop_prev(), Some(20), "Popping before the tail should pop the back.");
+
+    list.verify_double_links();
+    assert!(list.iter().eq(&[0, 12, 1, 2]));
+
+    let mut cursor = list.cursor_head_mut();
+    while cursor.pop_next().is_some() {}
+    assert!(cursor.state().is_empty());
+    cursor.push_prev(1);
+    cursor.push_prev(2);
+    assert!(cursor.is_tail());
+    cursor.move_to(0);
+    cursor.push_prev(0);
+    assert_eq!(cursor.index(), Some(1));
+    list.verify_double_links();
+    assert!(list.iter().eq(&[0, 1, 2]));
+
+    let counter = CountedDrop::new(0);
+    let mut list: LinkedList<_> = counted(&counter, 4);
+    let mut cursor = list.cursor_back_mut();
+    drop(cursor.pop_prev());
+    drop(cursor.pop_next());
+    cursor.move_to(0);
+    drop(cursor.pop_next());
+    assert_eq!(counter.take(), 2, "Each popped element should be dropped once.");
+    assert_eq!(list.len(), 2);
+    drop(list);
+    assert_eq!(counter.take(), 2);
+}
+
+#[test]
+fn test_cursor_edits() {
+    let mut cursor = (0..4).collect::<LinkedList<_>>().cursor_tail();
+    assert_eq!(cursor.pop_prev(), Some(3));
+    assert_eq!(cursor.pop_back(), Some(2));
+    cursor.move_prev();
+    cursor.push_prev(10);
+    cursor.push_front(11);
+    assert_eq!((cursor.read(), cursor.index()), (Some(&1), Some(3)));
+    assert_eq!(cursor.pop_front(), Some(11));
+    assert_eq!(cursor.pop_prev(), Some(10));
+    assert_eq!(cursor.index(), Some(1), "The index should follow insertions and removals.");
+    assert_eq!(cursor.read_offset(-1), Some(&0));
+
+    cursor.move_offset(-1);
+    assert_eq!((cursor.read(), cursor.index()), (Some(&0), Some(0)));
+    assert_eq!(cursor.read_offset(1), Some(&1));
+    let list = cursor.list();
+    list.verify_double_links();
+    assert!(list.iter().eq(&[0, 1]));
+}
+
+#[test]
+fn test_cursor_pop_prev_at_tail() {
+    let mut cursor = (0..3).collect::<LinkedList<_>>().cursor_tail();
+    assert_eq!(cursor.pop_prev(), Some(2));
+    assert_eq!(cursor.front(), Some(&0), "Popping before the tail shouldn't move the head.");
+    assert_eq!(cursor.back(), Some(&1), "Popping before the tail should move the tail back.");
+    assert!(cursor.is_tail());
+
+    let list = cursor.list();
+    list.verify_double_links();
+    assert!(list.iter().eq(&[0, 1]));
+}
+
+#[test]
+fn test_cursor_pop_back() {
+    let mut cursor = (0..3).collect::<LinkedList<_>>().cursor_front();
+    assert_eq!(cursor.pop_back(), Some(2));
+    assert_eq!(cursor.back(), Some(&1));
+
+    // Moving back from the new back should reach the front, rather than stopping at the head.
+    cursor.move_next().move_prev();
+    assert_eq!(cursor.read(), Some(&0), "Popping the back should keep the new back's prev link.");
+    cursor.move_next().move_next();
+    assert!(cursor.is_tail(), "Popping the back should clear the new back's next link.");
+
+    let list = cursor.list();
+    list.verify_double_links();
+    assert!(list.iter().eq(&[0, 1]));
+}
+
+#[test]
+fn test_cursor_index_tracking() {
+    let mut cursor = (0..3).collect::<LinkedList<_>>().cursor_front();
+    cursor.move_next();
+
+    cursor.push_prev(10);
+    assert_eq!(cursor.index(), Some(2), "push_prev should shift the index.");
+    cursor.push_front(11);
+    assert_eq!(cursor.index(), Some(3), "push_front should shift the index.");
+    assert_eq!(cursor.pop_front(), Some(11));
+    assert_eq!(cursor.index(), Some(2), "pop_front should shift the index.");
+    assert_eq!(cursor.pop_prev(), Some(10));
+    assert_eq!(cursor.index(), Some(1), "pop_prev should shift the index.");
+
+    cursor.push_next(12);
+    assert_eq!(cursor.index(), Some(1), "push_next shouldn't shift the index.");
+    assert_eq!((cursor.read(), cursor.get(1)), (Some(&1), &1));
+    assert_eq!(cursor.read_offset(-1), Some(&0));
+    assert_eq!(cursor.read_offset(2), Some(&2));
+}
+
+#[test]
+fn test_cursor_drop() {
+    let counter = CountedDrop::new(0);
+    let mut cursor = counted::<LinkedList<_>>(&counter, 3).cursor_front();
+    cursor.move_next();
+    drop(cursor);
+    assert_eq!(counter.take(), 3, "Dropping a Cursor should drop its elements.");
+
+    let list = counted::<LinkedList<_>>(&counter, 3).cursor_back().list();
+    let (front, back) = list.cursor_front().split_after();
+    assert_eq!(counter.take(), 0, "Taking the list out of a Cursor shouldn't drop anything.");
+    drop((front, back));
+    assert_eq!(counter.take(), 3);
+}
@@ -1,11 +1,14 @@
 //! Linked collection types. Primarily revolves around [`LinkedList`] and its accompanying
-//! [`Cursor`] type.
+//! [`Cursor`] types.
 #![cfg(feature = "linked")]
 
 pub mod cursor;
+pub mod intrusive;
 pub mod list;
 
 #[doc(inline)]
-pub use cursor::Cursor;
+pub use cursor::{Cursor, CursorMut, CursorRef};
+#[doc(inline)]
+pub use intrusive::IntrusiveList;
 #[doc(inline)]
 pub use list::LinkedList;
@@ -9,7 +9,7 @@
 //! saves me from writing some of the more repetitive functionality.
 #![cfg(feature = "collections")]
 
-// pub mod binary_tree;
+pub mod binary_tree;
 pub mod circular;
 pub mod cons;
 pub mod contiguous;
@@ -62,6 +62,7 @@
 #![feature(slice_from_ptr_range)]
 #![feature(type_changing_struct_update)]
 #![feature(option_array_transpose)]
+#![feature(allocator_api)]
 
 // #![warn(missing_docs)]
 #![warn(clippy::missing_safety_doc)]
@@ -71,6 +72,7 @@
 #![warn(clippy::unwrap_used)]
 #![allow(clippy::module_inception)]
 
+pub mod alloc;
 pub mod collections;
 pub mod fs;
 
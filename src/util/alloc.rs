@@ -33,3 +33,23 @@ impl Drop for CountedDrop {
         self.0.replace_with(|v| *v + 1);
     }
 }
+
+/// Creates a collection of `count` CountedDrops sharing the provided counter.
+pub fn counted<C: FromIterator<CountedDrop>>(counter: &CountedDrop, count: usize) -> C {
+    (0..count).map(|_| counter.clone()).collect()
+}
+
+/// A value which panics when it is dropped if `panics` is true. Its counter still records the drop
+/// while unwinding. Used to check that collections stay valid when dropping an element panics.
+pub struct PanicOnDrop {
+    pub _counter: CountedDrop,
+    pub panics: bool,
+}
+
+impl Drop for PanicOnDrop {
+    fn drop(&mut self) {
+        if self.panics {
+            panic!("PanicOnDrop");
+        }
+    }
+}
@@ -1,6 +1,9 @@
+use std::alloc::{self, Layout};
 use std::error::Error;
 use std::fmt::{self, Display, Formatter};
 
+use derive_more::{Display, Error, From};
+
 #[derive(Debug)]
 pub struct IndexOutOfBounds {
     pub index: usize,
@@ -15,13 +18,39 @@ impl Display for IndexOutOfBounds {
 
 impl Error for IndexOutOfBounds {}
 
-/// TODO
+/// An error indicating that a range of indices is invalid for a collection, either because it
+/// extends past the end of the collection or because it starts after it ends.
+#[derive(Debug, Clone)]
+pub struct InvalidRange {
+    /// The first index in the range.
+    pub start: usize,
+    /// The index after the last index in the range.
+    pub end: usize,
+    /// The length of the collection that the range was applied to.
+    pub len: usize,
+}
+
+impl Display for InvalidRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Range {}..{} is invalid for collection with {} elements!",
+            self.start, self.end, self.len
+        )
+    }
+}
+
+impl Error for InvalidRange {}
+
+/// An error indicating that the requested capacity of a collection would exceed the maximum
+/// allowed size of an allocation, [`isize::MAX`] bytes, or the fixed capacity of a collection such
+/// as [`ArrayVec`](crate::collections::contiguous::ArrayVec).
 ///
-/// Note: This error is no longer returned by any public part of the API, but it is thrown during
-/// panics. This is because a capacity overflow has such a small chance of occurring that it isn't
-/// worth handling in most placed. Most machines wouldn't have enough memory to overflow a non-ZST
-/// collection with a u64 length.
-#[derive(Debug)]
+/// Note: Other than for fixed capacity collections, this error is only returned as part of a
+/// [`TryReserveError`], otherwise it is thrown during panics. This is because a capacity overflow
+/// has such a small chance of occurring that it isn't worth handling in most placed. Most machines
+/// wouldn't have enough memory to overflow a non-ZST collection with a u64 length.
+#[derive(Debug, Clone)]
 pub struct CapacityOverflow;
 
 impl Display for CapacityOverflow {
@@ -32,6 +61,104 @@ impl Display for CapacityOverflow {
 
 impl Error for CapacityOverflow {}
 
+/// An error indicating that a value couldn't be added to a fixed capacity collection, such as
+/// [`ArrayVec`](crate::collections::contiguous::ArrayVec), because there isn't enough room for it.
+/// The value is returned as part of the error, so that the caller doesn't lose it.
+#[derive(Debug, Clone)]
+pub struct CapacityError<T> {
+    /// The value that couldn't be added.
+    pub value: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Consumes the error, returning the value that couldn't be added.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Display for CapacityError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&CapacityOverflow, f)
+    }
+}
+
+impl<T: fmt::Debug> Error for CapacityError<T> {}
+
+/// An error indicating that the global allocator failed to provide memory for the contained
+/// [`Layout`].
+#[derive(Debug, Clone)]
+pub struct AllocError {
+    /// The layout of the allocation that failed.
+    pub layout: Layout,
+}
+
+impl Display for AllocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Memory allocation of {} bytes failed!", self.layout.size())
+    }
+}
+
+impl Error for AllocError {}
+
+/// The error type returned by fallible allocation methods, such as
+/// [`Vector::try_reserve`](crate::collections::contiguous::Vector::try_reserve) and
+/// [`Array::try_realloc`](crate::collections::contiguous::Array::try_realloc).
+#[derive(Debug, Display, Clone, From, Error)]
+pub enum TryReserveError {
+    /// The requested capacity exceeded the maximum allowed size of an allocation.
+    CapacityOverflow(CapacityOverflow),
+    /// The allocator failed to provide the requested memory.
+    AllocError(AllocError),
+}
+
+impl TryReserveError {
+    /// Handles the error in the same way as the infallible allocation methods do: a
+    /// [`CapacityOverflow`] is thrown as a panic, while an [`AllocError`] is passed to
+    /// [`alloc::handle_alloc_error`], which aborts by default.
+    ///
+    /// # Panics
+    /// Panics if the error is a [`CapacityOverflow`].
+    pub(crate) fn handle(self) -> ! {
+        match self {
+            TryReserveError::CapacityOverflow(error) => panic!("{}", error),
+            TryReserveError::AllocError(error) => alloc::handle_alloc_error(error.layout),
+        }
+    }
+}
+
+/// The error type returned by fallible methods which add a single value to a growable collection,
+/// such as [`Vector::try_push`](crate::collections::contiguous::Vector::try_push) and
+/// [`Vector::try_insert`](crate::collections::contiguous::Vector::try_insert). The value that
+/// couldn't be added is returned alongside the [`TryReserveError`], so that the caller can retry or
+/// fall back without losing it.
+#[derive(Debug, Clone)]
+pub struct TryPushError<T> {
+    /// The reason that the collection couldn't grow.
+    pub error: TryReserveError,
+    /// The value that couldn't be added.
+    pub value: T,
+}
+
+impl<T> TryPushError<T> {
+    /// Consumes the error, returning the value that couldn't be added.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Display for TryPushError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<T: fmt::Debug> Error for TryPushError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
 #[derive(Debug)]
 pub struct NoValueForKey;
 
@@ -42,3 +169,17 @@ impl Display for NoValueForKey {
 }
 
 impl Error for NoValueForKey {}
+
+/// An error indicating that a value couldn't be added to an
+/// [`IntrusiveList`](crate::collections::linked::IntrusiveList), because its
+/// [`Link`](crate::collections::linked::intrusive::Link) is already part of a list.
+#[derive(Debug, Clone)]
+pub struct AlreadyLinked;
+
+impl Display for AlreadyLinked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Value is already linked into an intrusive list!")
+    }
+}
+
+impl Error for AlreadyLinked {}
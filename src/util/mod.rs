@@ -1,11 +1,15 @@
 #![warn(missing_docs)]
 
+#[cfg(test)]
 pub mod alloc;
 pub mod error;
 pub mod fmt;
 pub mod fs;
 pub mod hash;
 pub mod panic;
+#[cfg(test)]
+pub mod random;
+pub mod range;
 pub mod result;
 
 pub(crate) mod sealed {
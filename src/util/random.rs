@@ -0,0 +1,46 @@
+/// The seed used by [`XorShift::default`] and [`random`].
+pub const SEED: u64 = 0x2545_F491_4F6C_DD1D;
+
+/// A xorshift pseudo-random number generator, so that tests which need arbitrary values produce
+/// the same sequence on every run.
+#[derive(Debug, Clone)]
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// Creates a generator from `seed`, which must not be zero.
+    pub const fn new(seed: u64) -> XorShift {
+        XorShift {
+            state: seed,
+        }
+    }
+
+    /// Advances the generator, returning the next number in its sequence.
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
+
+impl Default for XorShift {
+    fn default() -> XorShift {
+        XorShift::new(SEED)
+    }
+}
+
+impl Iterator for XorShift {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        Some(self.next_u64())
+    }
+}
+
+/// Creates a collection of `len` pseudo-random numbers less than `max`, seeded by `len` so that
+/// collections of different lengths don't share a prefix.
+pub fn random<C: FromIterator<u64>>(len: usize, max: u64) -> C {
+    XorShift::new(SEED ^ len as u64).take(len).map(|value| value % max).collect()
+}
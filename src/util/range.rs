@@ -0,0 +1,30 @@
+use std::ops::{Bound, Range, RangeBounds};
+
+use crate::util::error::InvalidRange;
+
+/// Resolves any type implementing [`RangeBounds<usize>`] into a [`Range`] of indices for a
+/// collection of length `len`. Unbounded ends are replaced with `0` and `len` respectively.
+///
+/// # Errors
+/// Returns an [`InvalidRange`] if the range ends after `len` or starts after it ends.
+pub(crate) fn resolve_range<R: RangeBounds<usize>>(
+    range: R,
+    len: usize,
+) -> Result<Range<usize>, InvalidRange> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end.saturating_add(1),
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => len,
+    };
+
+    if start > end || end > len {
+        Err(InvalidRange { start, end, len })
+    } else {
+        Ok(start..end)
+    }
+}